@@ -0,0 +1,60 @@
+// Decoding a large AppendEntries request into owned fields and into an
+// Arena, which takes the whole tree from a few chunks, and freeing each.
+// Run with `cargo bench --bench arena`.
+
+#![allow(non_snake_case, non_upper_case_globals)]
+
+mod common;
+
+#[allow(dead_code, clippy::all)]
+#[path = "../testdata/golden/RaftRequest/proto/append_entries_request.rs"]
+mod owned;
+
+#[allow(dead_code, clippy::all)]
+#[path = "../testdata/golden/ArenaRequest/proto/append_entries_request.rs"]
+mod arena;
+
+use protobuf::{Arena, DecodeIn, Protobuf, ProtobufEncode};
+
+#[global_allocator]
+static ALLOCATOR: common::CountingAllocator = common::CountingAllocator;
+
+fn main() {
+  let mut request = owned::protobuf::ProtoAppendEntriesRequest::new();
+  request.set_term(2);
+  request.set_prev_log_index(6);
+  request.set_prev_log_term(1);
+  request.set_commit_index(5);
+  request.set_leader_name("n1".to_string());
+  for i in 0..10_000 {
+    let mut entry = owned::protobuf::proto_append_entries_request::ProtoLogEntry::new();
+    entry.set_index(7 + i);
+    entry.set_term(2);
+    entry.set_command_name("put".to_string());
+    entry.set_command(vec![0xab; 256]);
+    request.add_entries(entry);
+  }
+  let mut input = Vec::new();
+  request.Encode(&mut input).unwrap();
+
+  common::bench("append_entries/10000/decode/owned", input.len(), || {
+    let mut decoded = owned::protobuf::ProtoAppendEntriesRequest::new();
+    decoded.Decode(&mut input.as_slice()).unwrap();
+  });
+  // The arena starts empty, and grows by chunks twice the size of the last.
+  common::bench("append_entries/10000/decode/arena", input.len(), || {
+    let arena = Arena::new();
+    DecodeIn(&arena, &input, arena::protobuf::ProtoAppendEntriesRequest::new()).unwrap();
+  });
+
+  // Freeing each tree: the owned one a message, string and vector at a
+  // time, the arena one a chunk at a time.
+  let mut decoded = owned::protobuf::ProtoAppendEntriesRequest::new();
+  decoded.Decode(&mut input.as_slice()).unwrap();
+  let ((), _, frees) = common::count_allocations(|| drop(decoded));
+  println!("append_entries/10000/drop/owned: {} frees", frees);
+  let arena = Arena::new();
+  DecodeIn(&arena, &input, arena::protobuf::ProtoAppendEntriesRequest::new()).unwrap();
+  let ((), _, frees) = common::count_allocations(|| drop(arena));
+  println!("append_entries/10000/drop/arena: {} frees", frees);
+}
@@ -0,0 +1,53 @@
+// What the benchmarks share: a global allocator counting the allocations
+// and frees made while a benchmark runs, and a loop timing it.
+
+use std::alloc::{GlobalAlloc, Layout, System};
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::time::Instant;
+
+static kAllocations: AtomicUsize = AtomicUsize::new(0);
+static kFrees: AtomicUsize = AtomicUsize::new(0);
+
+// Installed by each benchmark with #[global_allocator]. A realloc counts as
+// an allocation.
+pub struct CountingAllocator;
+
+unsafe impl GlobalAlloc for CountingAllocator {
+  unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+    kAllocations.fetch_add(1, Ordering::Relaxed);
+    unsafe { System.alloc(layout) }
+  }
+
+  unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+    kFrees.fetch_add(1, Ordering::Relaxed);
+    unsafe { System.dealloc(ptr, layout) }
+  }
+
+  unsafe fn realloc(&self, ptr: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+    kAllocations.fetch_add(1, Ordering::Relaxed);
+    unsafe { System.realloc(ptr, layout, new_size) }
+  }
+}
+
+// The allocations and frees made by `f`.
+pub fn count_allocations<T>(f: impl FnOnce() -> T) -> (T, usize, usize) {
+  let allocations = kAllocations.load(Ordering::Relaxed);
+  let frees = kFrees.load(Ordering::Relaxed);
+  let result = f();
+  (result, kAllocations.load(Ordering::Relaxed) - allocations, kFrees.load(Ordering::Relaxed) - frees)
+}
+
+// Runs `f` for about half a second and prints its time per run, its
+// throughput over `bytes` and the allocations and frees of one run.
+pub fn bench(name: &str, bytes: usize, mut f: impl FnMut()) {
+  let ((), allocations, frees) = count_allocations(&mut f);
+  let start = Instant::now();
+  let mut runs = 0u32;
+  while start.elapsed().as_millis() < 500 {
+    f();
+    runs += 1;
+  }
+  let per_run = start.elapsed() / runs;
+  let throughput = bytes as f64 / per_run.as_secs_f64() / (1024.0 * 1024.0);
+  println!("{:<40} {:>12?} {:>10.1} MiB/s {:>8} allocs {:>8} frees", name, per_run, throughput, allocations, frees);
+}
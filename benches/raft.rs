@@ -0,0 +1,86 @@
+// Encoding and decoding the raft messages at a few sizes, and the
+// CodeGeneratorRequest of testdata/CodeGenRequest.pbf, for comparing changes
+// to TagIter and to the generated code. Run with `cargo bench --bench raft`.
+
+#![allow(non_snake_case, non_upper_case_globals)]
+
+mod common;
+
+#[allow(dead_code, clippy::all)]
+#[path = "../testdata/golden/RaftRequest/proto/append_entries_request.rs"]
+mod append_entries;
+
+#[allow(dead_code, clippy::all)]
+#[path = "../testdata/golden/RaftRequest/proto/request_vote_request.rs"]
+mod request_vote;
+
+#[allow(dead_code, clippy::all)]
+#[path = "../google/protobuf/descriptor.rs"]
+mod descriptor;
+
+#[allow(dead_code, clippy::all)]
+#[path = "../google/protobuf/compiler/plugin.rs"]
+mod plugin;
+
+use append_entries::protobuf::ProtoAppendEntriesRequest;
+use append_entries::protobuf::proto_append_entries_request::ProtoLogEntry;
+use plugin::google::protobuf::compiler::CodeGeneratorRequest;
+use request_vote::protobuf::ProtoRequestVoteRequest;
+use protobuf::{Protobuf, ProtobufEncode};
+
+#[global_allocator]
+static ALLOCATOR: common::CountingAllocator = common::CountingAllocator;
+
+static kCodeGenRequest: &[u8] = include_bytes!("../testdata/CodeGenRequest.pbf");
+
+fn append_entries(entries: u64) -> ProtoAppendEntriesRequest {
+  let mut request = ProtoAppendEntriesRequest::new();
+  request.set_term(2);
+  request.set_prev_log_index(6);
+  request.set_prev_log_term(1);
+  request.set_commit_index(5);
+  request.set_leader_name("n1".to_string());
+  for i in 0..entries {
+    let mut entry = ProtoLogEntry::new();
+    entry.set_index(7 + i);
+    entry.set_term(2);
+    entry.set_command_name("put".to_string());
+    entry.set_command(vec![0xab; 64]);
+    request.add_entries(entry);
+  }
+  request
+}
+
+// Benchmarks encoding `message` into a buffer kept between runs, so that
+// only what encoding itself allocates is counted, then decoding it again.
+fn bench_message<M: Protobuf + ProtobufEncode>(name: &str, message: &M, new: fn() -> M) {
+  let mut input = Vec::new();
+  message.Encode(&mut input).unwrap();
+  let mut output = Vec::with_capacity(input.len());
+  common::bench(&format!("{}/encode", name), input.len(), || {
+    output.clear();
+    message.Encode(&mut output).unwrap();
+  });
+  assert_eq!(output, input);
+  common::bench(&format!("{}/decode", name), input.len(), || {
+    let mut decoded = new();
+    decoded.Decode(&mut input.as_slice()).unwrap();
+  });
+}
+
+fn main() {
+  for entries in [0, 10, 10_000] {
+    bench_message(&format!("append_entries/{}", entries), &append_entries(entries), ProtoAppendEntriesRequest::new);
+  }
+
+  let mut request_vote = ProtoRequestVoteRequest::new();
+  request_vote.set_term(3);
+  request_vote.set_last_log_index(41);
+  request_vote.set_last_log_term(2);
+  request_vote.set_candidate_name("n2".to_string());
+  bench_message("request_vote", &request_vote, ProtoRequestVoteRequest::new);
+
+  let mut code_gen_request = CodeGeneratorRequest::new();
+  code_gen_request.Decode(&mut &kCodeGenRequest[..]).unwrap();
+  bench_message("code_gen_request", &code_gen_request, CodeGeneratorRequest::new);
+}
@@ -0,0 +1,56 @@
+// Varint and tag decoding from an in-memory slice, which takes the fast
+// path, against the byte-at-a-time path of a reader that isn't in memory.
+
+#![allow(non_snake_case, non_upper_case_globals)]
+
+mod common;
+
+#[allow(dead_code, clippy::all)]
+#[path = "../testdata/golden/RaftRequest/proto/append_entries_request.rs"]
+mod owned;
+
+use protobuf::{EncodeVarintField, IoReader, Protobuf, ProtobufEncode, TagIter};
+
+#[global_allocator]
+static ALLOCATOR: common::CountingAllocator = common::CountingAllocator;
+
+fn main() {
+  // Varint fields with one-byte tags and two-byte tags, and values from one
+  // to ten bytes long.
+  let mut varints = Vec::new();
+  for i in 0..100_000u64 {
+    let tag = if i % 4 == 0 { 200 } else { 1 + i % 15 };
+    EncodeVarintField(&mut varints, tag, i.wrapping_mul(0x9e3779b97f4a7c15) >> (i % 64)).unwrap();
+  }
+  common::bench("tags/100000/slice", varints.len(), || {
+    assert_eq!(TagIter::new(&mut varints.as_slice()).count(), 100_000);
+  });
+  common::bench("tags/100000/reader", varints.len(), || {
+    assert_eq!(TagIter::new(&mut IoReader::new(varints.as_slice())).count(), 100_000);
+  });
+
+  let mut request = owned::protobuf::ProtoAppendEntriesRequest::new();
+  request.set_term(2);
+  request.set_prev_log_index(6);
+  request.set_prev_log_term(1);
+  request.set_commit_index(5);
+  request.set_leader_name("n1".to_string());
+  for i in 0..10_000 {
+    let mut entry = owned::protobuf::proto_append_entries_request::ProtoLogEntry::new();
+    entry.set_index(7 + i);
+    entry.set_term(2);
+    entry.set_command_name("put".to_string());
+    entry.set_command(vec![0xab; 16]);
+    request.add_entries(entry);
+  }
+  let mut input = Vec::new();
+  request.Encode(&mut input).unwrap();
+  common::bench("append_entries/10000/decode/slice", input.len(), || {
+    let mut decoded = owned::protobuf::ProtoAppendEntriesRequest::new();
+    decoded.Decode(&mut input.as_slice()).unwrap();
+  });
+  common::bench("append_entries/10000/decode/reader", input.len(), || {
+    let mut decoded = owned::protobuf::ProtoAppendEntriesRequest::new();
+    decoded.Decode(&mut IoReader::new(input.as_slice())).unwrap();
+  });
+}
@@ -0,0 +1,145 @@
+// Generating code from a build script, without protoc:
+//
+//   let mut codegen = Codegen::new();
+//   codegen.include("proto").file("raft.proto").option("derive=Clone");
+//   codegen.run().unwrap();
+//
+// generates raft.rs under OUT_DIR, for the crate to mount with
+// #[path=...] or include!. Each .proto file read is reported to cargo
+// so that editing one reruns the build script.
+
+use std::env;
+use std::fmt;
+use std::fs;
+use std::path::PathBuf;
+use crate::plugin::google::protobuf::compiler::CodeGeneratorRequest;
+use crate::parser;
+use crate::ProtobufGenerator;
+
+#[derive(Debug)]
+pub enum CodegenError {
+  // Neither out_dir() nor OUT_DIR says where the code goes.
+  NoOutputDir,
+  // "file:line:column: message", pointing into the .proto file.
+  ParseFailed(String),
+  // The generator's own errors, one per line.
+  GenerateFailed(String),
+  WriteFailed(String)
+}
+
+impl fmt::Display for CodegenError {
+  fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+    match *self {
+      CodegenError::NoOutputDir => write!(f, "no output directory: call out_dir() or run from a build script"),
+      CodegenError::ParseFailed(ref error) => write!(f, "cannot parse {}", error),
+      CodegenError::GenerateFailed(ref error) => write!(f, "cannot generate code:\n{}", error),
+      CodegenError::WriteFailed(ref error) => write!(f, "cannot write {}", error)
+    }
+  }
+}
+
+impl std::error::Error for CodegenError {}
+
+#[derive(Default)]
+pub struct Codegen {
+  files: Vec<String>,
+  include_paths: Vec<PathBuf>,
+  options: Vec<String>,
+  out_dir: Option<PathBuf>
+}
+
+impl Codegen {
+  pub fn new() -> Codegen {
+    Codegen::default()
+  }
+
+  // A file to generate, named as it is imported: relative to an include
+  // directory.
+  pub fn file(&mut self, name: &str) -> &mut Codegen {
+    self.files.push(name.to_string());
+    self
+  }
+
+  // A directory to look for files and their imports in, after those added
+  // before it. Without any, files are looked for in the current directory.
+  pub fn include<P: Into<PathBuf>>(&mut self, dir: P) -> &mut Codegen {
+    self.include_paths.push(dir.into());
+    self
+  }
+
+  // A generator option, as passed to protoc in --rust_out=OPTION:dir,
+  // e.g. "json" or "derive=Hash".
+  pub fn option(&mut self, option: &str) -> &mut Codegen {
+    self.options.push(option.to_string());
+    self
+  }
+
+  // Where to write the generated code instead of OUT_DIR.
+  pub fn out_dir<P: Into<PathBuf>>(&mut self, dir: P) -> &mut Codegen {
+    self.out_dir = Some(dir.into());
+    self
+  }
+
+  // Generates the code and returns the files written. Running under cargo,
+  // it prints a rerun-if-changed line for every .proto file read.
+  pub fn run(&self) -> Result<Vec<PathBuf>, CodegenError> {
+    let cargo_out_dir = env::var_os("OUT_DIR");
+    let out_dir = match (&self.out_dir, &cargo_out_dir) {
+      (Some(dir), _) => dir.clone(),
+      (None, Some(dir)) => PathBuf::from(dir),
+      (None, None) => return Err(CodegenError::NoOutputDir)
+    };
+    let include_paths = if self.include_paths.is_empty() { vec![PathBuf::from(".")] } else { self.include_paths.clone() };
+
+    let mut request = CodeGeneratorRequest::new();
+    request.set_file_to_generate(self.files.clone());
+    request.set_parameter(self.options.join(","));
+    let proto_files = match parser::load_files(&self.files, &include_paths) {
+      Ok(proto_files) => proto_files,
+      Err(error) => return Err(CodegenError::ParseFailed(error.to_string()))
+    };
+    if cargo_out_dir.is_some() {
+      for proto in proto_files.iter() {
+        if let Some(path) = parser::find_file(proto.get_name(), &include_paths) {
+          println!("cargo:rerun-if-changed={}", path.display());
+        }
+      }
+    }
+    request.set_proto_file(proto_files);
+
+    let response = ProtobufGenerator::new(&request).translate();
+    if response.has_error() {
+      return Err(CodegenError::GenerateFailed(response.get_error().to_string()));
+    }
+    let mut written = Vec::new();
+    for file in response.get_file().iter() {
+      let path = out_dir.join(file.get_name());
+      let result = fs::create_dir_all(path.parent().unwrap()).and_then(|()| {
+        fs::write(&path, file.get_content())
+      });
+      match result {
+        Ok(()) => written.push(path),
+        Err(error) => return Err(CodegenError::WriteFailed(format!("{}: {}", path.display(), error)))
+      }
+    }
+    Ok(written)
+  }
+}
+
+#[test]
+fn test_codegen() {
+  let dir = env::temp_dir().join(format!("codegen-{}", std::process::id()));
+  let out_dir = dir.join("out");
+  let written = Codegen::new().file("proto/log_entry.proto").out_dir(&out_dir).run().unwrap();
+  assert_eq!(written, vec![out_dir.join("proto/log_entry.rs")]);
+  let code = fs::read_to_string(&written[0]).unwrap();
+  assert!(code.contains("pub struct ProtoLogEntry {"));
+
+  fs::write(dir.join("bad.proto"), "message Bad {\n  optional int32 = 1;\n}\n").unwrap();
+  let error = Codegen::new().include(&dir).file("bad.proto").out_dir(&out_dir).run().unwrap_err();
+  assert_eq!(error.to_string(), "cannot parse bad.proto:2:18: Expected field name.");
+
+  let error = Codegen::new().file("proto/missing.proto").out_dir(&out_dir).run().unwrap_err();
+  assert_eq!(error.to_string(), "cannot parse proto/missing.proto: File not found.");
+  fs::remove_dir_all(&dir).unwrap();
+}
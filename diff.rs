@@ -0,0 +1,329 @@
+// Comparing encoded messages by their schema, without their generated code:
+//
+//   let schema = Schema::load(&["raft.proto".to_string()], &[PathBuf::from("proto")])?;
+//   for difference in schema.diff("raft.AppendEntries", &a, &b, &DiffOptions::default())? {
+//     println!("{}", difference);
+//   }
+//
+// Messages are read into the JSON values the json generator option gives
+// them, so this agrees with protobuf::DiffMessages on their decoded form.
+// Enum numbers the schema doesn't name, which decoding keeps as unknown
+// fields, are compared as numbers.
+
+use std::fmt;
+use std::path::PathBuf;
+use protobuf::{Protobuf, TagIter, TaggedValue, Json, json};
+use protobuf::{DecodeZigZag32, DecodeZigZag64, DecodeFloat, DecodeDouble};
+use protobuf::{DecodePackedVarints, DecodePackedFixed32, DecodePackedFixed64};
+use protobuf::{Difference, DiffOptions, DiffTypedJson};
+use crate::descriptor::google::protobuf::{FileDescriptorSet, FileDescriptorProto, DescriptorProto};
+use crate::descriptor::google::protobuf::{FieldDescriptorProto, EnumDescriptorProto};
+use crate::descriptor::google::protobuf::field_descriptor_proto::{TypeDouble, TypeFloat, TypeInt64, TypeUint64};
+use crate::descriptor::google::protobuf::field_descriptor_proto::{TypeInt32, TypeFixed64, TypeFixed32, TypeBool};
+use crate::descriptor::google::protobuf::field_descriptor_proto::{TypeString, TypeGroup, TypeMessage, TypeBytes};
+use crate::descriptor::google::protobuf::field_descriptor_proto::{TypeUint32, TypeEnum, TypeSfixed32, TypeSfixed64};
+use crate::descriptor::google::protobuf::field_descriptor_proto::{TypeSint32, TypeSint64, LabelRepeated};
+use crate::parser;
+
+#[derive(Debug)]
+pub enum SchemaError {
+  // "file:line:column: message", pointing into the .proto file.
+  ParseFailed(String),
+  // A FileDescriptorSet that doesn't decode.
+  BadDescriptorSet(String),
+  UnknownType(String),
+  // The type name, and the field whose value doesn't decode if it is
+  // known.
+  MalformedMessage(String)
+}
+
+impl fmt::Display for SchemaError {
+  fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+    match *self {
+      SchemaError::ParseFailed(ref error) => write!(f, "cannot parse {}", error),
+      SchemaError::BadDescriptorSet(ref error) => write!(f, "cannot decode descriptor set: {}", error),
+      SchemaError::UnknownType(ref name) => write!(f, "unknown message type {}", name),
+      SchemaError::MalformedMessage(ref name) => write!(f, "malformed {}", name)
+    }
+  }
+}
+
+impl std::error::Error for SchemaError {}
+
+pub struct Schema {
+  files: Vec<FileDescriptorProto>
+}
+
+impl Schema {
+  // The files and their imports, found as Codegen finds them.
+  pub fn load(files: &[String], include_paths: &[PathBuf]) -> Result<Schema, SchemaError> {
+    match parser::load_files(files, include_paths) {
+      Ok(files) => Ok(Schema { files }),
+      Err(error) => Err(SchemaError::ParseFailed(error.to_string()))
+    }
+  }
+
+  // A FileDescriptorSet such as protoc's --descriptor_set_out writes.
+  pub fn from_descriptor_set(data: &[u8]) -> Result<Schema, SchemaError> {
+    let mut set = FileDescriptorSet::new();
+    match set.Decode(&mut &data[..]) {
+      Ok(()) => Ok(Schema { files: set.take_file() }),
+      Err(error) => Err(SchemaError::BadDescriptorSet(error.to_string()))
+    }
+  }
+
+  // The message type with the full name `type_name`, e.g.
+  // "protobuf.ProtoAppendEntriesRequest".
+  fn message(&self, type_name: &str) -> Option<&DescriptorProto> {
+    fn search<'a>(scope: &str, messages: &'a [DescriptorProto], type_name: &str) -> Option<&'a DescriptorProto> {
+      for message in messages.iter() {
+        let name = format!("{}{}", scope, message.get_name());
+        if name == type_name {
+          return Some(message);
+        }
+        if let Some(found) = search(&format!("{}.", name), message.get_nested_type(), type_name) {
+          return Some(found);
+        }
+      }
+      None
+    }
+    let type_name = type_name.trim_start_matches('.');
+    self.files.iter().find_map(|file| search(&package_scope(file), file.get_message_type(), type_name))
+  }
+
+  fn enumeration(&self, type_name: &str) -> Option<&EnumDescriptorProto> {
+    fn search<'a>(scope: &str, messages: &'a [DescriptorProto], enums: &'a [EnumDescriptorProto],
+                  type_name: &str) -> Option<&'a EnumDescriptorProto> {
+      if let Some(found) = enums.iter().find(|e| format!("{}{}", scope, e.get_name()) == type_name) {
+        return Some(found);
+      }
+      messages.iter().find_map(|message| {
+        search(&format!("{}{}.", scope, message.get_name()), message.get_nested_type(), message.get_enum_type(), type_name)
+      })
+    }
+    let type_name = type_name.trim_start_matches('.');
+    self.files.iter().find_map(|file| {
+      search(&package_scope(file), file.get_message_type(), file.get_enum_type(), type_name)
+    })
+  }
+
+  // The message of type `type_name` encoded in `data`, as JSON.
+  pub fn to_json(&self, type_name: &str, data: &[u8]) -> Result<Json, SchemaError> {
+    match self.message(type_name) {
+      Some(descriptor) => self.message_json(descriptor, type_name.trim_start_matches('.'), data).map(Json::Object),
+      None => Err(SchemaError::UnknownType(type_name.to_string()))
+    }
+  }
+
+  pub fn diff(&self, type_name: &str, a: &[u8], b: &[u8], options: &DiffOptions) -> Result<Vec<Difference>, SchemaError> {
+    let descriptor = self.message(type_name).ok_or_else(|| SchemaError::UnknownType(type_name.to_string()))?;
+    let (a, b) = (self.to_json(type_name, a)?, self.to_json(type_name, b)?);
+    Ok(DiffTypedJson(&a, &b, &|field| self.is_float_field(descriptor, field), options))
+  }
+
+  // Whether the field at a dotted path of field names, going through
+  // message fields, is a float or double field, to which float_tolerance
+  // applies.
+  fn is_float_field(&self, descriptor: &DescriptorProto, path: &str) -> bool {
+    let (name, rest) = match path.split_once('.') {
+      Some((name, rest)) => (name, Some(rest)),
+      None => (path, None)
+    };
+    let field = match descriptor.get_field().iter().find(|field| field.get_name() == name) {
+      Some(field) => field,
+      None => return false
+    };
+    match rest {
+      None => matches!(field.get_type(), TypeFloat | TypeDouble),
+      Some(rest) => field.get_type() == TypeMessage
+        && self.message(field.get_type_name()).is_some_and(|nested| self.is_float_field(nested, rest))
+    }
+  }
+
+  // Later values of a singular field replace earlier ones, or merge into
+  // them for messages, as decoding does.
+  fn message_json(&self, descriptor: &DescriptorProto, type_name: &str, data: &[u8]) -> Result<json::Object, SchemaError> {
+    let mut object = json::Object::new();
+    let mut reader = data;
+    let mut fields = TagIter::new(&mut reader);
+    for value in fields.by_ref() {
+      let tag = match value {
+        TaggedValue::Varint(tag, _) | TaggedValue::Fixed64(tag, _) | TaggedValue::Fixed32(tag, _) | TaggedValue::Raw(tag, _) => tag,
+        TaggedValue::StartGroup | TaggedValue::EndGroup => break
+      };
+      let field = match descriptor.get_field().iter().find(|field| field.get_number() as u64 == tag) {
+        Some(field) => field,
+        None => continue
+      };
+      let name = field.get_name().to_string();
+      let values = match (field.get_type(), &value) {
+        (TypeMessage, TaggedValue::Raw(_, data)) => {
+          let nested_type = field.get_type_name().trim_start_matches('.');
+          match self.message(nested_type) {
+            Some(nested) => vec![Json::Object(self.message_json(nested, nested_type, data)?)],
+            None => return Err(SchemaError::UnknownType(nested_type.to_string()))
+          }
+        }
+        _ => match self.field_json(field, &value) {
+          Some(values) => values,
+          None => return Err(SchemaError::MalformedMessage(format!("{}.{}", type_name, name)))
+        }
+      };
+      if values.is_empty() {
+        continue;
+      }
+      if field.get_label() == LabelRepeated {
+        match object.entry(name).or_insert_with(|| Json::List(Vec::new())) {
+          Json::List(ref mut list) => list.extend(values),
+          _ => unreachable!()
+        }
+        continue;
+      }
+      for value in values {
+        match (object.get_mut(&name), value) {
+          (Some(Json::Object(ref mut earlier)), Json::Object(later)) => merge_objects(earlier, later),
+          (_, value) => {
+            object.insert(name.clone(), value);
+          }
+        }
+      }
+    }
+    if fields.is_malformed() {
+      return Err(SchemaError::MalformedMessage(type_name.to_string()));
+    }
+    Ok(object)
+  }
+
+  // The values of one occurrence of a field other than a message, several
+  // if it is packed, or none if its wire type is wrong for the field, which
+  // decoding keeps as an unknown field. None if the value doesn't decode.
+  fn field_json(&self, field: &FieldDescriptorProto, value: &TaggedValue) -> Option<Vec<Json>> {
+    let json = match (field.get_type(), value) {
+      (TypeString, TaggedValue::Raw(_, data)) => Json::String(String::from_utf8(data.to_vec()).ok()?),
+      (TypeBytes, TaggedValue::Raw(_, data)) => Json::String(json::to_base64(data)),
+      (_, TaggedValue::Raw(_, data)) if field.get_label() == LabelRepeated => {
+        let values: Vec<Json> = match field.get_type() {
+          TypeFixed32 | TypeSfixed32 | TypeFloat => {
+            DecodePackedFixed32(data)?.into_iter().filter_map(|v| self.scalar_json(field, v as u64)).collect()
+          }
+          TypeFixed64 | TypeSfixed64 | TypeDouble => {
+            DecodePackedFixed64(data)?.into_iter().filter_map(|v| self.scalar_json(field, v)).collect()
+          }
+          _ => DecodePackedVarints(data)?.into_iter().filter_map(|v| self.scalar_json(field, v)).collect()
+        };
+        return Some(values);
+      }
+      (TypeFixed32 | TypeSfixed32 | TypeFloat, &TaggedValue::Fixed32(_, v)) => self.scalar_json(field, v as u64)?,
+      (TypeFixed64 | TypeSfixed64 | TypeDouble, &TaggedValue::Fixed64(_, v)) => self.scalar_json(field, v)?,
+      (TypeFixed32 | TypeSfixed32 | TypeFloat | TypeFixed64 | TypeSfixed64 | TypeDouble, _) => return Some(Vec::new()),
+      // Decoding keeps these as unknown fields.
+      (TypeString | TypeBytes | TypeMessage | TypeGroup, _) => return Some(Vec::new()),
+      (_, &TaggedValue::Varint(_, v)) => self.scalar_json(field, v)?,
+      _ => return Some(Vec::new())
+    };
+    Some(vec![json])
+  }
+
+  // The scalar `field` holding the bits `v`, None for a string, bytes or
+  // message field.
+  fn scalar_json(&self, field: &FieldDescriptorProto, v: u64) -> Option<Json> {
+    Some(match field.get_type() {
+      TypeInt32 => Json::Number(v as i32 as f64),
+      TypeSint32 => Json::Number(DecodeZigZag32(v) as f64),
+      TypeUint32 | TypeFixed32 => Json::Number(v as u32 as f64),
+      TypeSfixed32 => Json::Number(v as u32 as i32 as f64),
+      TypeFloat => Json::Number(DecodeFloat(v as u32) as f64),
+      TypeDouble => Json::Number(DecodeDouble(v)),
+      TypeBool => Json::Boolean(v != 0),
+      TypeInt64 | TypeSfixed64 => Json::String((v as i64).to_string()),
+      TypeSint64 => Json::String(DecodeZigZag64(v).to_string()),
+      TypeUint64 | TypeFixed64 => Json::String(v.to_string()),
+      TypeEnum => {
+        let number = v as i32;
+        let name = self.enumeration(field.get_type_name()).and_then(|descriptor| {
+          descriptor.get_value().iter().find(|value| value.get_number() == number)
+        });
+        match name {
+          Some(value) => Json::String(value.get_name().to_string()),
+          None => Json::Number(number as f64)
+        }
+      }
+      TypeString | TypeBytes | TypeMessage | TypeGroup => return None
+    })
+  }
+}
+
+fn package_scope(file: &FileDescriptorProto) -> String {
+  if file.get_package().is_empty() {
+    String::new()
+  } else {
+    format!("{}.", file.get_package())
+  }
+}
+
+fn merge_objects(earlier: &mut json::Object, later: json::Object) {
+  for (name, value) in later {
+    match (earlier.get_mut(&name), value) {
+      (Some(Json::Object(ref mut earlier)), Json::Object(later)) => merge_objects(earlier, later),
+      (Some(Json::List(ref mut earlier)), Json::List(later)) => earlier.extend(later),
+      (_, value) => {
+        earlier.insert(name, value);
+      }
+    }
+  }
+}
+
+#[test]
+fn test_schema_to_json() {
+  let schema = Schema::load(&["proto/append_entries_request.proto".to_string()], &[PathBuf::from(".")]).unwrap();
+  // Term 2, an entry with index 7 and command "put", the leader "n1", a
+  // second entry with index 8 and an unknown field 9.
+  let data: &[u8] = &[0x08, 0x02, 0x32, 0x07, 0x08, 0x07, 0x1a, 0x03, b'p', b'u', b't',
+                      0x2a, 0x02, b'n', b'1', 0x32, 0x02, 0x08, 0x08, 0x4a, 0x01, 0x00];
+  let json = schema.to_json("protobuf.ProtoAppendEntriesRequest", data).unwrap();
+  assert_eq!(json.to_string(),
+             r#"{"Entries":[{"CommandName":"put","Index":"7"},{"Index":"8"}],"LeaderName":"n1","Term":"2"}"#);
+
+  assert!(matches!(schema.to_json("protobuf.Missing", data), Err(SchemaError::UnknownType(_))));
+  let error = schema.to_json("protobuf.ProtoAppendEntriesRequest", &[0x2a, 0x01, 0xff]).unwrap_err();
+  assert_eq!(error.to_string(), "malformed protobuf.ProtoAppendEntriesRequest.LeaderName");
+  assert!(schema.to_json("protobuf.ProtoAppendEntriesRequest", &[0x32, 0x05, 0x08]).is_err());
+  assert!(schema.to_json("protobuf.ProtoAppendEntriesRequest", &[0x32, 0x01, 0x08]).is_err());
+  // A string and a message field with a varint, which decoding keeps as
+  // unknown fields, are left out.
+  let mismatched = schema.to_json("protobuf.ProtoAppendEntriesRequest", &[0x08, 0x02, 0x28, 0x01, 0x30, 0x01]).unwrap();
+  assert_eq!(mismatched.to_string(), r#"{"Term":"2"}"#);
+
+  use protobuf::ProtobufEncode;
+  let mut set = FileDescriptorSet::new();
+  set.set_file(parser::load_files(&["proto/append_entries_request.proto".to_string()], &[PathBuf::from(".")]).unwrap());
+  let mut encoded = Vec::new();
+  assert!(set.Encode(&mut encoded).is_ok());
+  let schema = Schema::from_descriptor_set(&encoded).unwrap();
+  assert_eq!(schema.to_json("protobuf.ProtoAppendEntriesRequest", data).unwrap(), json);
+  assert!(Schema::from_descriptor_set(&[0x0a, 0x03, 0x0a, 0x01, 0xff]).is_err());
+  assert!(Schema::from_descriptor_set(&encoded[..encoded.len() - 1]).is_err());
+}
+
+#[test]
+fn test_schema_diff_float_tolerance() {
+  let schema = Schema::load(&["metrics.proto".to_string()], &[PathBuf::from("testdata")]).unwrap();
+  // A sample with value 1.0 and count 3, and scale 0.5, then a window
+  // with scale 0.25.
+  let a: &[u8] = &[0x0a, 0x0b, 0x11, 0, 0, 0, 0, 0, 0, 0xf0, 0x3f, 0x18, 0x03, 0x15, 0, 0, 0, 0x3f,
+                   0x1a, 0x05, 0x15, 0, 0, 0x80, 0x3e];
+  // The same with value 1.0000001, count 4 and window scale 0.25000006.
+  let b: &[u8] = &[0x0a, 0x0b, 0x11, 0x9b, 0xf2, 0xd7, 0x1a, 0x00, 0x00, 0xf0, 0x3f, 0x18, 0x04,
+                   0x15, 0, 0, 0, 0x3f, 0x1a, 0x05, 0x15, 0x02, 0, 0x80, 0x3e];
+  let options = DiffOptions { float_tolerance: 0.001, ..DiffOptions::default() };
+  let paths: Vec<String> = schema.diff("metrics.Series", a, b, &options).unwrap().into_iter().map(|d| d.path).collect();
+  assert_eq!(paths, ["samples[0].count"]);
+  let paths: Vec<String> = schema.diff("metrics.Series", a, b, &DiffOptions::default()).unwrap()
+    .into_iter().map(|d| d.path).collect();
+  assert_eq!(paths, ["samples[0].count", "samples[0].value", "window.scale"]);
+
+  // Counts 3 and 4 are within a tolerance of 2, but aren't floats.
+  let options = DiffOptions { float_tolerance: 2.0, ..DiffOptions::default() };
+  assert_eq!(schema.diff("metrics.Series", a, b, &options).unwrap().len(), 1);
+  assert!(matches!(schema.diff("metrics.Missing", a, b, &options), Err(SchemaError::UnknownType(_))));
+}
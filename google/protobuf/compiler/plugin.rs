@@ -0,0 +1,637 @@
+// Generated by protoc-gen-rust from google/protobuf/compiler/plugin.proto. Do not edit.
+
+pub mod google {
+  pub mod protobuf {
+    pub mod compiler {
+      #[allow(unused_imports)]
+      use ::protobuf::{
+        Protobuf, Reader, TagIter, TaggedValue,
+        DecodeZigZag32, DecodeZigZag64, DecodeFloat, DecodeDouble,
+        DecodePackedVarints, DecodePackedFixed32, DecodePackedFixed64,
+        ProtobufEncode, Writer, EncodeError,
+        EncodeTaggedValue, EncodeMessageField, EncodeRawField,
+        EncodeVarintField, EncodeFixed32Field, EncodeFixed64Field,
+        EncodeZigZag32, EncodeZigZag64, EncodeFloat, EncodeDouble,
+      };
+      #[allow(unused_imports)]
+      use ::protobuf::prelude::*;
+
+      /// An encoded CodeGeneratorRequest is written to the plugin's stdin.
+      pub struct CodeGeneratorRequest {
+        /// The .proto files that were explicitly listed on the command-line.  The
+        /// code generator should generate code only for these files.  Each file's
+        /// descriptor will be included in proto_file, below.
+        file_to_generate: Vec<String>,
+        /// The generator parameter passed on the command-line.
+        parameter: Option<String>,
+        /// FileDescriptorProtos for all files in files_to_generate and everything
+        /// they import.  The files will appear in topological order, so each file
+        /// appears before any file that imports it.
+        ///
+        /// protoc guarantees that all proto_files will be written after
+        /// the fields above, even though this is not technically guaranteed by the
+        /// protobuf wire format.  This theoretically could allow a plugin to stream
+        /// in the FileDescriptorProtos and handle them one by one rather than read
+        /// the entire set into memory at once.  However, as of this writing, this
+        /// is not similarly optimized on protoc's end -- it will store all fields in
+        /// memory at once before sending them to the plugin.
+        proto_file: Vec<crate::descriptor::google::protobuf::FileDescriptorProto>,
+        unknown_fields: Vec<TaggedValue>,
+      }
+
+      impl CodeGeneratorRequest {
+        pub const fn new() -> CodeGeneratorRequest {
+          CodeGeneratorRequest {
+            file_to_generate: Vec::new(),
+            parameter: None,
+            proto_file: Vec::new(),
+            unknown_fields: Vec::new(),
+          }
+        }
+
+        pub fn default_instance() -> &'static CodeGeneratorRequest {
+          static INSTANCE: CodeGeneratorRequest = CodeGeneratorRequest::new();
+          &INSTANCE
+        }
+
+        pub fn get_file_to_generate(&self) -> &[String] {
+          &self.file_to_generate
+        }
+
+        pub fn set_file_to_generate(&mut self, value: Vec<String>) {
+          self.file_to_generate = value;
+        }
+
+        pub fn clear_file_to_generate(&mut self) {
+          self.file_to_generate.clear();
+        }
+
+        pub fn mut_file_to_generate(&mut self) -> &mut Vec<String> {
+          &mut self.file_to_generate
+        }
+
+        pub fn take_file_to_generate(&mut self) -> Vec<String> {
+          core::mem::take(&mut self.file_to_generate)
+        }
+
+        pub fn add_file_to_generate(&mut self, value: String) {
+          self.file_to_generate.push(value);
+        }
+
+        pub fn file_to_generate_len(&self) -> usize {
+          self.file_to_generate.len()
+        }
+
+        pub fn has_parameter(&self) -> bool {
+          self.parameter.is_some()
+        }
+
+        pub fn get_parameter(&self) -> &str {
+          self.parameter.as_deref().unwrap_or("")
+        }
+
+        pub fn set_parameter(&mut self, value: String) {
+          self.parameter = Some(value);
+        }
+
+        pub fn clear_parameter(&mut self) {
+          self.parameter = None;
+        }
+
+        pub fn mut_parameter(&mut self) -> &mut String {
+          self.parameter.get_or_insert_with(|| String::from(""))
+        }
+
+        pub fn take_parameter(&mut self) -> String {
+          self.parameter.take().unwrap_or_else(|| String::from(""))
+        }
+
+        pub fn get_proto_file(&self) -> &[crate::descriptor::google::protobuf::FileDescriptorProto] {
+          &self.proto_file
+        }
+
+        pub fn set_proto_file(&mut self, value: Vec<crate::descriptor::google::protobuf::FileDescriptorProto>) {
+          self.proto_file = value;
+        }
+
+        pub fn clear_proto_file(&mut self) {
+          self.proto_file.clear();
+        }
+
+        pub fn mut_proto_file(&mut self) -> &mut Vec<crate::descriptor::google::protobuf::FileDescriptorProto> {
+          &mut self.proto_file
+        }
+
+        pub fn take_proto_file(&mut self) -> Vec<crate::descriptor::google::protobuf::FileDescriptorProto> {
+          core::mem::take(&mut self.proto_file)
+        }
+
+        pub fn add_proto_file(&mut self, value: crate::descriptor::google::protobuf::FileDescriptorProto) {
+          self.proto_file.push(value);
+        }
+
+        pub fn proto_file_len(&self) -> usize {
+          self.proto_file.len()
+        }
+
+        // Fields this message's schema doesn't know, and enum numbers outside
+        // the enum, in the order they were decoded.
+        pub fn get_unknown_fields(&self) -> &[TaggedValue] {
+          &self.unknown_fields
+        }
+      }
+
+      impl Protobuf for CodeGeneratorRequest {
+        fn DecodePartial(&mut self, reader: &mut dyn Reader) -> bool {
+          let mut tags = TagIter::new(reader);
+          for tag_option in tags.by_ref() {
+            match tag_option {
+              TaggedValue::Raw(1, value) => {
+                match String::from_utf8(value.into_vec()) {
+                  Ok(value) => self.add_file_to_generate(value),
+                  Err(_) => return false
+                }
+              }
+              TaggedValue::Raw(2, value) => {
+                match String::from_utf8(value.into_vec()) {
+                  Ok(value) => self.set_parameter(value),
+                  Err(_) => return false
+                }
+              }
+              TaggedValue::Raw(15, value) => {
+                let mut reader = value;
+                let mut message = crate::descriptor::google::protobuf::FileDescriptorProto::new();
+                if !message.DecodePartial(&mut reader) {
+                  return false;
+                }
+                self.add_proto_file(message);
+              }
+              unknown => self.unknown_fields.push(unknown)
+            }
+          }
+          !tags.is_malformed()
+        }
+
+        fn AppendMissingFields(&self, prefix: &str, missing: &mut Vec<String>) {
+          for (i, value) in self.proto_file.iter().enumerate() {
+            for path in value.missing_fields() {
+              missing.push(format!("{}proto_file[{}].{}", prefix, i, path));
+            }
+          }
+        }
+      }
+
+      impl ProtobufEncode for CodeGeneratorRequest {
+        fn Encode(&self, writer: &mut dyn Writer) -> Result<(), EncodeError> {
+          for value in self.file_to_generate.iter() {
+            EncodeRawField(writer, 1, value.as_bytes())?;
+          }
+          for value in self.parameter.iter() {
+            EncodeRawField(writer, 2, value.as_bytes())?;
+          }
+          for value in self.proto_file.iter() {
+            EncodeMessageField(writer, 15, value)?;
+          }
+          for unknown in self.unknown_fields.iter() {
+            EncodeTaggedValue(writer, unknown)?;
+          }
+          Ok(())
+        }
+      }
+
+      /// The plugin writes an encoded CodeGeneratorResponse to stdout.
+      pub struct CodeGeneratorResponse {
+        /// Error message.  If non-empty, code generation failed.  The plugin process
+        /// should exit with status code zero even if it reports an error in this way.
+        ///
+        /// This should be used to indicate errors in .proto files which prevent the
+        /// code generator from generating correct code.  Errors which indicate a
+        /// problem in protoc itself -- such as the input CodeGeneratorRequest being
+        /// unparseable -- should be reported by writing a message to stderr and
+        /// exiting with a non-zero status code.
+        error: Option<String>,
+        /// A bitmask of supported features that the code generator supports.
+        /// This is a bitwise "or" of values from the Feature enum.
+        supported_features: Option<u64>,
+        file: Vec<code_generator_response::File>,
+        unknown_fields: Vec<TaggedValue>,
+      }
+
+      impl CodeGeneratorResponse {
+        pub const fn new() -> CodeGeneratorResponse {
+          CodeGeneratorResponse {
+            error: None,
+            supported_features: None,
+            file: Vec::new(),
+            unknown_fields: Vec::new(),
+          }
+        }
+
+        pub fn default_instance() -> &'static CodeGeneratorResponse {
+          static INSTANCE: CodeGeneratorResponse = CodeGeneratorResponse::new();
+          &INSTANCE
+        }
+
+        pub fn has_error(&self) -> bool {
+          self.error.is_some()
+        }
+
+        pub fn get_error(&self) -> &str {
+          self.error.as_deref().unwrap_or("")
+        }
+
+        pub fn set_error(&mut self, value: String) {
+          self.error = Some(value);
+        }
+
+        pub fn clear_error(&mut self) {
+          self.error = None;
+        }
+
+        pub fn mut_error(&mut self) -> &mut String {
+          self.error.get_or_insert_with(|| String::from(""))
+        }
+
+        pub fn take_error(&mut self) -> String {
+          self.error.take().unwrap_or_else(|| String::from(""))
+        }
+
+        pub fn has_supported_features(&self) -> bool {
+          self.supported_features.is_some()
+        }
+
+        pub fn get_supported_features(&self) -> u64 {
+          self.supported_features.unwrap_or(0)
+        }
+
+        pub fn set_supported_features(&mut self, value: u64) {
+          self.supported_features = Some(value);
+        }
+
+        pub fn clear_supported_features(&mut self) {
+          self.supported_features = None;
+        }
+
+        pub fn mut_supported_features(&mut self) -> &mut u64 {
+          self.supported_features.get_or_insert(0)
+        }
+
+        pub fn take_supported_features(&mut self) -> u64 {
+          self.supported_features.take().unwrap_or(0)
+        }
+
+        pub fn get_file(&self) -> &[code_generator_response::File] {
+          &self.file
+        }
+
+        pub fn set_file(&mut self, value: Vec<code_generator_response::File>) {
+          self.file = value;
+        }
+
+        pub fn clear_file(&mut self) {
+          self.file.clear();
+        }
+
+        pub fn mut_file(&mut self) -> &mut Vec<code_generator_response::File> {
+          &mut self.file
+        }
+
+        pub fn take_file(&mut self) -> Vec<code_generator_response::File> {
+          core::mem::take(&mut self.file)
+        }
+
+        pub fn add_file(&mut self, value: code_generator_response::File) {
+          self.file.push(value);
+        }
+
+        pub fn file_len(&self) -> usize {
+          self.file.len()
+        }
+
+        // Fields this message's schema doesn't know, and enum numbers outside
+        // the enum, in the order they were decoded.
+        pub fn get_unknown_fields(&self) -> &[TaggedValue] {
+          &self.unknown_fields
+        }
+      }
+
+      pub mod code_generator_response {
+        #[allow(unused_imports)]
+        use ::protobuf::{
+          Protobuf, Reader, TagIter, TaggedValue,
+          DecodeZigZag32, DecodeZigZag64, DecodeFloat, DecodeDouble,
+          DecodePackedVarints, DecodePackedFixed32, DecodePackedFixed64,
+          ProtobufEncode, Writer, EncodeError,
+          EncodeTaggedValue, EncodeMessageField, EncodeRawField,
+          EncodeVarintField, EncodeFixed32Field, EncodeFixed64Field,
+          EncodeZigZag32, EncodeZigZag64, EncodeFloat, EncodeDouble,
+        };
+        #[allow(unused_imports)]
+        use ::protobuf::prelude::*;
+
+        /// Represents a single generated file.
+        pub struct File {
+          /// The file name, relative to the output directory.  The name must not
+          /// contain "." or ".." components and must be relative, not be absolute (so,
+          /// the file cannot lie outside the output directory).  "/" must be used as
+          /// the path separator, not "\".
+          ///
+          /// If the name is omitted, the content will be appended to the previous
+          /// file.  This allows the generator to break large files into small chunks,
+          /// and allows the generated text to be streamed back to protoc so that large
+          /// files need not reside completely in memory at one time.  Note that as of
+          /// this writing protoc does not optimize for this -- it will read the entire
+          /// CodeGeneratorResponse before writing files to disk.
+          name: Option<String>,
+          /// If non-empty, indicates that the named file should already exist, and the
+          /// content here is to be inserted into that file at a defined insertion
+          /// point.  This feature allows a code generator to extend the output
+          /// produced by another code generator.  The original generator may provide
+          /// insertion points by placing special annotations in the file that look
+          /// like:
+          ///   @@protoc_insertion_point(NAME)
+          /// The annotation can have arbitrary text before and after it on the line,
+          /// which allows it to be placed in a comment.  NAME should be replaced with
+          /// an identifier naming the point -- this is what other generators will use
+          /// as the insertion_point.  Code inserted at this point will be placed
+          /// immediately above the line containing the insertion point (thus multiple
+          /// insertions to the same point will come out in the order they were added).
+          /// The double-@ is intended to make it unlikely that the generated code
+          /// could contain things that look like insertion points by accident.
+          ///
+          /// For example, the C++ code generator places the following line in the
+          /// .pb.h files that it generates:
+          ///   // @@protoc_insertion_point(namespace_scope)
+          /// This line appears within the scope of the file's package namespace, but
+          /// outside of any particular class.  Another plugin can then specify the
+          /// insertion_point "namespace_scope" to generate additional classes or
+          /// other declarations that should be placed in this scope.
+          ///
+          /// Note that if the line containing the insertion point begins with
+          /// whitespace, the same whitespace will be added to every line of the
+          /// inserted text.  This is useful for languages like Python, where
+          /// indentation matters.  In these languages, the insertion point comment
+          /// should be indented the same amount as any inserted code will need to be
+          /// in order to work correctly in that context.
+          ///
+          /// The code generator that generates the initial file and the one which
+          /// inserts into it must both run as part of a single invocation of protoc.
+          /// Code generators are executed in the order in which they appear on the
+          /// command line.
+          ///
+          /// If |insertion_point| is present, |name| must also be present.
+          insertion_point: Option<String>,
+          /// The file contents.
+          content: Option<String>,
+          unknown_fields: Vec<TaggedValue>,
+        }
+
+        impl File {
+          pub const fn new() -> File {
+            File {
+              name: None,
+              insertion_point: None,
+              content: None,
+              unknown_fields: Vec::new(),
+            }
+          }
+
+          pub fn default_instance() -> &'static File {
+            static INSTANCE: File = File::new();
+            &INSTANCE
+          }
+
+          pub fn has_name(&self) -> bool {
+            self.name.is_some()
+          }
+
+          pub fn get_name(&self) -> &str {
+            self.name.as_deref().unwrap_or("")
+          }
+
+          pub fn set_name(&mut self, value: String) {
+            self.name = Some(value);
+          }
+
+          pub fn clear_name(&mut self) {
+            self.name = None;
+          }
+
+          pub fn mut_name(&mut self) -> &mut String {
+            self.name.get_or_insert_with(|| String::from(""))
+          }
+
+          pub fn take_name(&mut self) -> String {
+            self.name.take().unwrap_or_else(|| String::from(""))
+          }
+
+          pub fn has_insertion_point(&self) -> bool {
+            self.insertion_point.is_some()
+          }
+
+          pub fn get_insertion_point(&self) -> &str {
+            self.insertion_point.as_deref().unwrap_or("")
+          }
+
+          pub fn set_insertion_point(&mut self, value: String) {
+            self.insertion_point = Some(value);
+          }
+
+          pub fn clear_insertion_point(&mut self) {
+            self.insertion_point = None;
+          }
+
+          pub fn mut_insertion_point(&mut self) -> &mut String {
+            self.insertion_point.get_or_insert_with(|| String::from(""))
+          }
+
+          pub fn take_insertion_point(&mut self) -> String {
+            self.insertion_point.take().unwrap_or_else(|| String::from(""))
+          }
+
+          pub fn has_content(&self) -> bool {
+            self.content.is_some()
+          }
+
+          pub fn get_content(&self) -> &str {
+            self.content.as_deref().unwrap_or("")
+          }
+
+          pub fn set_content(&mut self, value: String) {
+            self.content = Some(value);
+          }
+
+          pub fn clear_content(&mut self) {
+            self.content = None;
+          }
+
+          pub fn mut_content(&mut self) -> &mut String {
+            self.content.get_or_insert_with(|| String::from(""))
+          }
+
+          pub fn take_content(&mut self) -> String {
+            self.content.take().unwrap_or_else(|| String::from(""))
+          }
+
+          // Fields this message's schema doesn't know, and enum numbers outside
+          // the enum, in the order they were decoded.
+          pub fn get_unknown_fields(&self) -> &[TaggedValue] {
+            &self.unknown_fields
+          }
+        }
+
+        impl Protobuf for File {
+          fn DecodePartial(&mut self, reader: &mut dyn Reader) -> bool {
+            let mut tags = TagIter::new(reader);
+            for tag_option in tags.by_ref() {
+              match tag_option {
+                TaggedValue::Raw(1, value) => {
+                  match String::from_utf8(value.into_vec()) {
+                    Ok(value) => self.set_name(value),
+                    Err(_) => return false
+                  }
+                }
+                TaggedValue::Raw(2, value) => {
+                  match String::from_utf8(value.into_vec()) {
+                    Ok(value) => self.set_insertion_point(value),
+                    Err(_) => return false
+                  }
+                }
+                TaggedValue::Raw(15, value) => {
+                  match String::from_utf8(value.into_vec()) {
+                    Ok(value) => self.set_content(value),
+                    Err(_) => return false
+                  }
+                }
+                unknown => self.unknown_fields.push(unknown)
+              }
+            }
+            !tags.is_malformed()
+          }
+
+          fn AppendMissingFields(&self, _prefix: &str, _missing: &mut Vec<String>) {
+          }
+        }
+
+        impl ProtobufEncode for File {
+          fn Encode(&self, writer: &mut dyn Writer) -> Result<(), EncodeError> {
+            for value in self.name.iter() {
+              EncodeRawField(writer, 1, value.as_bytes())?;
+            }
+            for value in self.insertion_point.iter() {
+              EncodeRawField(writer, 2, value.as_bytes())?;
+            }
+            for value in self.content.iter() {
+              EncodeRawField(writer, 15, value.as_bytes())?;
+            }
+            for unknown in self.unknown_fields.iter() {
+              EncodeTaggedValue(writer, unknown)?;
+            }
+            Ok(())
+          }
+        }
+
+        /// Sync with code_generator.h.
+        #[derive(Clone, Copy, PartialEq, Eq, Debug)]
+        pub enum Feature {
+          FeatureNone = 0,
+          FeatureProto3Optional = 1,
+        }
+
+        pub use self::Feature::{FeatureNone, FeatureProto3Optional};
+
+        impl Feature {
+          pub fn from_i32(value: i32) -> Option<Feature> {
+            match value {
+              0 => Some(FeatureNone),
+              1 => Some(FeatureProto3Optional),
+              _ => None
+            }
+          }
+
+          pub fn value(&self) -> i32 {
+            *self as i32
+          }
+
+          pub fn name(&self) -> &'static str {
+            match *self {
+              FeatureNone => "FEATURE_NONE",
+              FeatureProto3Optional => "FEATURE_PROTO3_OPTIONAL",
+            }
+          }
+
+          pub fn from_name(name: &str) -> Option<Feature> {
+            match name {
+              "FEATURE_NONE" => Some(FeatureNone),
+              "FEATURE_PROTO3_OPTIONAL" => Some(FeatureProto3Optional),
+              _ => None
+            }
+          }
+        }
+
+        impl Default for Feature {
+          fn default() -> Feature {
+            FeatureNone
+          }
+        }
+
+      }
+
+      impl Protobuf for CodeGeneratorResponse {
+        fn DecodePartial(&mut self, reader: &mut dyn Reader) -> bool {
+          let mut tags = TagIter::new(reader);
+          for tag_option in tags.by_ref() {
+            match tag_option {
+              TaggedValue::Raw(1, value) => {
+                match String::from_utf8(value.into_vec()) {
+                  Ok(value) => self.set_error(value),
+                  Err(_) => return false
+                }
+              }
+              TaggedValue::Varint(2, value) => {
+                self.set_supported_features(value);
+              }
+              TaggedValue::Raw(15, value) => {
+                let mut reader: &[u8] = &value;
+                let mut message = code_generator_response::File::new();
+                if !message.DecodePartial(&mut reader) {
+                  return false;
+                }
+                self.add_file(message);
+              }
+              unknown => self.unknown_fields.push(unknown)
+            }
+          }
+          !tags.is_malformed()
+        }
+
+        fn AppendMissingFields(&self, prefix: &str, missing: &mut Vec<String>) {
+          for (i, value) in self.file.iter().enumerate() {
+            for path in value.missing_fields() {
+              missing.push(format!("{}file[{}].{}", prefix, i, path));
+            }
+          }
+        }
+      }
+
+      impl ProtobufEncode for CodeGeneratorResponse {
+        fn Encode(&self, writer: &mut dyn Writer) -> Result<(), EncodeError> {
+          for value in self.error.iter() {
+            EncodeRawField(writer, 1, value.as_bytes())?;
+          }
+          for value in self.supported_features.iter() {
+            EncodeVarintField(writer, 2, *value)?;
+          }
+          for value in self.file.iter() {
+            EncodeMessageField(writer, 15, value)?;
+          }
+          for unknown in self.unknown_fields.iter() {
+            EncodeTaggedValue(writer, unknown)?;
+          }
+          Ok(())
+        }
+      }
+
+    }
+  }
+}
@@ -0,0 +1,6554 @@
+// Generated by protoc-gen-rust from google/protobuf/descriptor.proto. Do not edit.
+
+pub mod google {
+  pub mod protobuf {
+    #[allow(unused_imports)]
+    use ::protobuf::{
+      Protobuf, Reader, TagIter, TaggedValue,
+      DecodeZigZag32, DecodeZigZag64, DecodeFloat, DecodeDouble,
+      DecodePackedVarints, DecodePackedFixed32, DecodePackedFixed64,
+      ProtobufEncode, Writer, EncodeError,
+      EncodeTaggedValue, EncodeMessageField, EncodeRawField,
+      EncodeVarintField, EncodeFixed32Field, EncodeFixed64Field,
+      EncodeZigZag32, EncodeZigZag64, EncodeFloat, EncodeDouble,
+    };
+    #[allow(unused_imports)]
+    use ::protobuf::prelude::*;
+
+    /// The protocol compiler can output a FileDescriptorSet containing the .proto
+    /// files it parses.
+    pub struct FileDescriptorSet {
+      file: Vec<FileDescriptorProto>,
+      unknown_fields: Vec<TaggedValue>,
+    }
+
+    impl FileDescriptorSet {
+      pub const fn new() -> FileDescriptorSet {
+        FileDescriptorSet {
+          file: Vec::new(),
+          unknown_fields: Vec::new(),
+        }
+      }
+
+      pub fn default_instance() -> &'static FileDescriptorSet {
+        static INSTANCE: FileDescriptorSet = FileDescriptorSet::new();
+        &INSTANCE
+      }
+
+      pub fn get_file(&self) -> &[FileDescriptorProto] {
+        &self.file
+      }
+
+      pub fn set_file(&mut self, value: Vec<FileDescriptorProto>) {
+        self.file = value;
+      }
+
+      pub fn clear_file(&mut self) {
+        self.file.clear();
+      }
+
+      pub fn mut_file(&mut self) -> &mut Vec<FileDescriptorProto> {
+        &mut self.file
+      }
+
+      pub fn take_file(&mut self) -> Vec<FileDescriptorProto> {
+        core::mem::take(&mut self.file)
+      }
+
+      pub fn add_file(&mut self, value: FileDescriptorProto) {
+        self.file.push(value);
+      }
+
+      pub fn file_len(&self) -> usize {
+        self.file.len()
+      }
+
+      // Fields this message's schema doesn't know, and enum numbers outside
+      // the enum, in the order they were decoded.
+      pub fn get_unknown_fields(&self) -> &[TaggedValue] {
+        &self.unknown_fields
+      }
+    }
+
+    impl Protobuf for FileDescriptorSet {
+      fn DecodePartial(&mut self, reader: &mut dyn Reader) -> bool {
+        let mut tags = TagIter::new(reader);
+        for tag_option in tags.by_ref() {
+          match tag_option {
+            TaggedValue::Raw(1, value) => {
+              let mut reader = value;
+              let mut message = FileDescriptorProto::new();
+              if !message.DecodePartial(&mut reader) {
+                return false;
+              }
+              self.add_file(message);
+            }
+            unknown => self.unknown_fields.push(unknown)
+          }
+        }
+        !tags.is_malformed()
+      }
+
+      fn AppendMissingFields(&self, prefix: &str, missing: &mut Vec<String>) {
+        for (i, value) in self.file.iter().enumerate() {
+          for path in value.missing_fields() {
+            missing.push(format!("{}file[{}].{}", prefix, i, path));
+          }
+        }
+      }
+    }
+
+    impl ProtobufEncode for FileDescriptorSet {
+      fn Encode(&self, writer: &mut dyn Writer) -> Result<(), EncodeError> {
+        for value in self.file.iter() {
+          EncodeMessageField(writer, 1, value)?;
+        }
+        for unknown in self.unknown_fields.iter() {
+          EncodeTaggedValue(writer, unknown)?;
+        }
+        Ok(())
+      }
+    }
+
+    /// Describes a complete .proto file.
+    pub struct FileDescriptorProto {
+      /// file name, relative to root of source tree
+      name: Option<String>,
+      /// e.g. "foo", "foo.bar", etc.
+      package: Option<String>,
+      /// Names of files imported by this file.
+      dependency: Vec<String>,
+      /// Indexes of the public imported files in the dependency list above.
+      public_dependency: Vec<i32>,
+      /// Indexes of the weak imported files in the dependency list.
+      /// For Google-internal migration only. Do not use.
+      weak_dependency: Vec<i32>,
+      /// All top-level definitions in this file.
+      message_type: Vec<DescriptorProto>,
+      enum_type: Vec<EnumDescriptorProto>,
+      service: Vec<ServiceDescriptorProto>,
+      extension: Vec<FieldDescriptorProto>,
+      options: Option<FileOptions>,
+      /// This field contains optional information about the original source code.
+      /// You may safely remove this entire field whithout harming runtime
+      /// functionality of the descriptors -- the information is needed only by
+      /// development tools.
+      source_code_info: Option<SourceCodeInfo>,
+      /// The syntax of the proto file.
+      /// The supported values are "proto2" and "proto3".
+      syntax: Option<String>,
+      unknown_fields: Vec<TaggedValue>,
+    }
+
+    impl FileDescriptorProto {
+      pub const fn new() -> FileDescriptorProto {
+        FileDescriptorProto {
+          name: None,
+          package: None,
+          dependency: Vec::new(),
+          public_dependency: Vec::new(),
+          weak_dependency: Vec::new(),
+          message_type: Vec::new(),
+          enum_type: Vec::new(),
+          service: Vec::new(),
+          extension: Vec::new(),
+          options: None,
+          source_code_info: None,
+          syntax: None,
+          unknown_fields: Vec::new(),
+        }
+      }
+
+      pub fn default_instance() -> &'static FileDescriptorProto {
+        static INSTANCE: FileDescriptorProto = FileDescriptorProto::new();
+        &INSTANCE
+      }
+
+      pub fn has_name(&self) -> bool {
+        self.name.is_some()
+      }
+
+      pub fn get_name(&self) -> &str {
+        self.name.as_deref().unwrap_or("")
+      }
+
+      pub fn set_name(&mut self, value: String) {
+        self.name = Some(value);
+      }
+
+      pub fn clear_name(&mut self) {
+        self.name = None;
+      }
+
+      pub fn mut_name(&mut self) -> &mut String {
+        self.name.get_or_insert_with(|| String::from(""))
+      }
+
+      pub fn take_name(&mut self) -> String {
+        self.name.take().unwrap_or_else(|| String::from(""))
+      }
+
+      pub fn has_package(&self) -> bool {
+        self.package.is_some()
+      }
+
+      pub fn get_package(&self) -> &str {
+        self.package.as_deref().unwrap_or("")
+      }
+
+      pub fn set_package(&mut self, value: String) {
+        self.package = Some(value);
+      }
+
+      pub fn clear_package(&mut self) {
+        self.package = None;
+      }
+
+      pub fn mut_package(&mut self) -> &mut String {
+        self.package.get_or_insert_with(|| String::from(""))
+      }
+
+      pub fn take_package(&mut self) -> String {
+        self.package.take().unwrap_or_else(|| String::from(""))
+      }
+
+      pub fn get_dependency(&self) -> &[String] {
+        &self.dependency
+      }
+
+      pub fn set_dependency(&mut self, value: Vec<String>) {
+        self.dependency = value;
+      }
+
+      pub fn clear_dependency(&mut self) {
+        self.dependency.clear();
+      }
+
+      pub fn mut_dependency(&mut self) -> &mut Vec<String> {
+        &mut self.dependency
+      }
+
+      pub fn take_dependency(&mut self) -> Vec<String> {
+        core::mem::take(&mut self.dependency)
+      }
+
+      pub fn add_dependency(&mut self, value: String) {
+        self.dependency.push(value);
+      }
+
+      pub fn dependency_len(&self) -> usize {
+        self.dependency.len()
+      }
+
+      pub fn get_public_dependency(&self) -> &[i32] {
+        &self.public_dependency
+      }
+
+      pub fn set_public_dependency(&mut self, value: Vec<i32>) {
+        self.public_dependency = value;
+      }
+
+      pub fn clear_public_dependency(&mut self) {
+        self.public_dependency.clear();
+      }
+
+      pub fn mut_public_dependency(&mut self) -> &mut Vec<i32> {
+        &mut self.public_dependency
+      }
+
+      pub fn take_public_dependency(&mut self) -> Vec<i32> {
+        core::mem::take(&mut self.public_dependency)
+      }
+
+      pub fn add_public_dependency(&mut self, value: i32) {
+        self.public_dependency.push(value);
+      }
+
+      pub fn public_dependency_len(&self) -> usize {
+        self.public_dependency.len()
+      }
+
+      pub fn get_weak_dependency(&self) -> &[i32] {
+        &self.weak_dependency
+      }
+
+      pub fn set_weak_dependency(&mut self, value: Vec<i32>) {
+        self.weak_dependency = value;
+      }
+
+      pub fn clear_weak_dependency(&mut self) {
+        self.weak_dependency.clear();
+      }
+
+      pub fn mut_weak_dependency(&mut self) -> &mut Vec<i32> {
+        &mut self.weak_dependency
+      }
+
+      pub fn take_weak_dependency(&mut self) -> Vec<i32> {
+        core::mem::take(&mut self.weak_dependency)
+      }
+
+      pub fn add_weak_dependency(&mut self, value: i32) {
+        self.weak_dependency.push(value);
+      }
+
+      pub fn weak_dependency_len(&self) -> usize {
+        self.weak_dependency.len()
+      }
+
+      pub fn get_message_type(&self) -> &[DescriptorProto] {
+        &self.message_type
+      }
+
+      pub fn set_message_type(&mut self, value: Vec<DescriptorProto>) {
+        self.message_type = value;
+      }
+
+      pub fn clear_message_type(&mut self) {
+        self.message_type.clear();
+      }
+
+      pub fn mut_message_type(&mut self) -> &mut Vec<DescriptorProto> {
+        &mut self.message_type
+      }
+
+      pub fn take_message_type(&mut self) -> Vec<DescriptorProto> {
+        core::mem::take(&mut self.message_type)
+      }
+
+      pub fn add_message_type(&mut self, value: DescriptorProto) {
+        self.message_type.push(value);
+      }
+
+      pub fn message_type_len(&self) -> usize {
+        self.message_type.len()
+      }
+
+      pub fn get_enum_type(&self) -> &[EnumDescriptorProto] {
+        &self.enum_type
+      }
+
+      pub fn set_enum_type(&mut self, value: Vec<EnumDescriptorProto>) {
+        self.enum_type = value;
+      }
+
+      pub fn clear_enum_type(&mut self) {
+        self.enum_type.clear();
+      }
+
+      pub fn mut_enum_type(&mut self) -> &mut Vec<EnumDescriptorProto> {
+        &mut self.enum_type
+      }
+
+      pub fn take_enum_type(&mut self) -> Vec<EnumDescriptorProto> {
+        core::mem::take(&mut self.enum_type)
+      }
+
+      pub fn add_enum_type(&mut self, value: EnumDescriptorProto) {
+        self.enum_type.push(value);
+      }
+
+      pub fn enum_type_len(&self) -> usize {
+        self.enum_type.len()
+      }
+
+      pub fn get_service(&self) -> &[ServiceDescriptorProto] {
+        &self.service
+      }
+
+      pub fn set_service(&mut self, value: Vec<ServiceDescriptorProto>) {
+        self.service = value;
+      }
+
+      pub fn clear_service(&mut self) {
+        self.service.clear();
+      }
+
+      pub fn mut_service(&mut self) -> &mut Vec<ServiceDescriptorProto> {
+        &mut self.service
+      }
+
+      pub fn take_service(&mut self) -> Vec<ServiceDescriptorProto> {
+        core::mem::take(&mut self.service)
+      }
+
+      pub fn add_service(&mut self, value: ServiceDescriptorProto) {
+        self.service.push(value);
+      }
+
+      pub fn service_len(&self) -> usize {
+        self.service.len()
+      }
+
+      pub fn get_extension(&self) -> &[FieldDescriptorProto] {
+        &self.extension
+      }
+
+      pub fn set_extension(&mut self, value: Vec<FieldDescriptorProto>) {
+        self.extension = value;
+      }
+
+      pub fn clear_extension(&mut self) {
+        self.extension.clear();
+      }
+
+      pub fn mut_extension(&mut self) -> &mut Vec<FieldDescriptorProto> {
+        &mut self.extension
+      }
+
+      pub fn take_extension(&mut self) -> Vec<FieldDescriptorProto> {
+        core::mem::take(&mut self.extension)
+      }
+
+      pub fn add_extension(&mut self, value: FieldDescriptorProto) {
+        self.extension.push(value);
+      }
+
+      pub fn extension_len(&self) -> usize {
+        self.extension.len()
+      }
+
+      pub fn has_options(&self) -> bool {
+        self.options.is_some()
+      }
+
+      pub fn get_options(&self) -> &FileOptions {
+        match self.options { Some(ref value) => value, None => FileOptions::default_instance() }
+      }
+
+      pub fn set_options(&mut self, value: FileOptions) {
+        self.options = Some(value);
+      }
+
+      pub fn clear_options(&mut self) {
+        self.options = None;
+      }
+
+      pub fn mut_options(&mut self) -> &mut FileOptions {
+        self.options.get_or_insert_with(FileOptions::new)
+      }
+
+      pub fn take_options(&mut self) -> FileOptions {
+        self.options.take().unwrap_or_else(FileOptions::new)
+      }
+
+      pub fn has_source_code_info(&self) -> bool {
+        self.source_code_info.is_some()
+      }
+
+      pub fn get_source_code_info(&self) -> &SourceCodeInfo {
+        match self.source_code_info { Some(ref value) => value, None => SourceCodeInfo::default_instance() }
+      }
+
+      pub fn set_source_code_info(&mut self, value: SourceCodeInfo) {
+        self.source_code_info = Some(value);
+      }
+
+      pub fn clear_source_code_info(&mut self) {
+        self.source_code_info = None;
+      }
+
+      pub fn mut_source_code_info(&mut self) -> &mut SourceCodeInfo {
+        self.source_code_info.get_or_insert_with(SourceCodeInfo::new)
+      }
+
+      pub fn take_source_code_info(&mut self) -> SourceCodeInfo {
+        self.source_code_info.take().unwrap_or_else(SourceCodeInfo::new)
+      }
+
+      pub fn has_syntax(&self) -> bool {
+        self.syntax.is_some()
+      }
+
+      pub fn get_syntax(&self) -> &str {
+        self.syntax.as_deref().unwrap_or("")
+      }
+
+      pub fn set_syntax(&mut self, value: String) {
+        self.syntax = Some(value);
+      }
+
+      pub fn clear_syntax(&mut self) {
+        self.syntax = None;
+      }
+
+      pub fn mut_syntax(&mut self) -> &mut String {
+        self.syntax.get_or_insert_with(|| String::from(""))
+      }
+
+      pub fn take_syntax(&mut self) -> String {
+        self.syntax.take().unwrap_or_else(|| String::from(""))
+      }
+
+      // Fields this message's schema doesn't know, and enum numbers outside
+      // the enum, in the order they were decoded.
+      pub fn get_unknown_fields(&self) -> &[TaggedValue] {
+        &self.unknown_fields
+      }
+    }
+
+    impl Protobuf for FileDescriptorProto {
+      fn DecodePartial(&mut self, reader: &mut dyn Reader) -> bool {
+        let mut tags = TagIter::new(reader);
+        for tag_option in tags.by_ref() {
+          match tag_option {
+            TaggedValue::Raw(1, value) => {
+              match String::from_utf8(value.into_vec()) {
+                Ok(value) => self.set_name(value),
+                Err(_) => return false
+              }
+            }
+            TaggedValue::Raw(2, value) => {
+              match String::from_utf8(value.into_vec()) {
+                Ok(value) => self.set_package(value),
+                Err(_) => return false
+              }
+            }
+            TaggedValue::Raw(3, value) => {
+              match String::from_utf8(value.into_vec()) {
+                Ok(value) => self.add_dependency(value),
+                Err(_) => return false
+              }
+            }
+            TaggedValue::Varint(10, value) => {
+              self.add_public_dependency(value as i32);
+            }
+            TaggedValue::Raw(10, packed) => {
+              match DecodePackedVarints(&packed) {
+                Some(values) => {
+                  for value in values.iter() {
+                    self.add_public_dependency(*value as i32);
+                  }
+                }
+                None => return false
+              }
+            }
+            TaggedValue::Varint(11, value) => {
+              self.add_weak_dependency(value as i32);
+            }
+            TaggedValue::Raw(11, packed) => {
+              match DecodePackedVarints(&packed) {
+                Some(values) => {
+                  for value in values.iter() {
+                    self.add_weak_dependency(*value as i32);
+                  }
+                }
+                None => return false
+              }
+            }
+            TaggedValue::Raw(4, value) => {
+              let mut reader = value;
+              let mut message = DescriptorProto::new();
+              if !message.DecodePartial(&mut reader) {
+                return false;
+              }
+              self.add_message_type(message);
+            }
+            TaggedValue::Raw(5, value) => {
+              let mut reader = value;
+              let mut message = EnumDescriptorProto::new();
+              if !message.DecodePartial(&mut reader) {
+                return false;
+              }
+              self.add_enum_type(message);
+            }
+            TaggedValue::Raw(6, value) => {
+              let mut reader = value;
+              let mut message = ServiceDescriptorProto::new();
+              if !message.DecodePartial(&mut reader) {
+                return false;
+              }
+              self.add_service(message);
+            }
+            TaggedValue::Raw(7, value) => {
+              let mut reader = value;
+              let mut message = FieldDescriptorProto::new();
+              if !message.DecodePartial(&mut reader) {
+                return false;
+              }
+              self.add_extension(message);
+            }
+            TaggedValue::Raw(8, value) => {
+              let mut reader = value;
+              if !self.mut_options().DecodePartial(&mut reader) {
+                return false;
+              }
+            }
+            TaggedValue::Raw(9, value) => {
+              let mut reader = value;
+              if !self.mut_source_code_info().DecodePartial(&mut reader) {
+                return false;
+              }
+            }
+            TaggedValue::Raw(12, value) => {
+              match String::from_utf8(value.into_vec()) {
+                Ok(value) => self.set_syntax(value),
+                Err(_) => return false
+              }
+            }
+            unknown => self.unknown_fields.push(unknown)
+          }
+        }
+        !tags.is_malformed()
+      }
+
+      fn AppendMissingFields(&self, prefix: &str, missing: &mut Vec<String>) {
+        for (i, value) in self.message_type.iter().enumerate() {
+          for path in value.missing_fields() {
+            missing.push(format!("{}message_type[{}].{}", prefix, i, path));
+          }
+        }
+        for (i, value) in self.enum_type.iter().enumerate() {
+          for path in value.missing_fields() {
+            missing.push(format!("{}enum_type[{}].{}", prefix, i, path));
+          }
+        }
+        for (i, value) in self.service.iter().enumerate() {
+          for path in value.missing_fields() {
+            missing.push(format!("{}service[{}].{}", prefix, i, path));
+          }
+        }
+        for (i, value) in self.extension.iter().enumerate() {
+          for path in value.missing_fields() {
+            missing.push(format!("{}extension[{}].{}", prefix, i, path));
+          }
+        }
+        match self.options {
+          Some(ref value) => {
+            for path in value.missing_fields() {
+              missing.push(format!("{}options.{}", prefix, path));
+            }
+          }
+          None => ()
+        }
+        match self.source_code_info {
+          Some(ref value) => {
+            for path in value.missing_fields() {
+              missing.push(format!("{}source_code_info.{}", prefix, path));
+            }
+          }
+          None => ()
+        }
+      }
+    }
+
+    impl ProtobufEncode for FileDescriptorProto {
+      fn Encode(&self, writer: &mut dyn Writer) -> Result<(), EncodeError> {
+        for value in self.name.iter() {
+          EncodeRawField(writer, 1, value.as_bytes())?;
+        }
+        for value in self.package.iter() {
+          EncodeRawField(writer, 2, value.as_bytes())?;
+        }
+        for value in self.dependency.iter() {
+          EncodeRawField(writer, 3, value.as_bytes())?;
+        }
+        for value in self.message_type.iter() {
+          EncodeMessageField(writer, 4, value)?;
+        }
+        for value in self.enum_type.iter() {
+          EncodeMessageField(writer, 5, value)?;
+        }
+        for value in self.service.iter() {
+          EncodeMessageField(writer, 6, value)?;
+        }
+        for value in self.extension.iter() {
+          EncodeMessageField(writer, 7, value)?;
+        }
+        for value in self.options.iter() {
+          EncodeMessageField(writer, 8, value)?;
+        }
+        for value in self.source_code_info.iter() {
+          EncodeMessageField(writer, 9, value)?;
+        }
+        for value in self.public_dependency.iter() {
+          EncodeVarintField(writer, 10, *value as i64 as u64)?;
+        }
+        for value in self.weak_dependency.iter() {
+          EncodeVarintField(writer, 11, *value as i64 as u64)?;
+        }
+        for value in self.syntax.iter() {
+          EncodeRawField(writer, 12, value.as_bytes())?;
+        }
+        for unknown in self.unknown_fields.iter() {
+          EncodeTaggedValue(writer, unknown)?;
+        }
+        Ok(())
+      }
+    }
+
+    /// Describes a message type.
+    pub struct DescriptorProto {
+      name: Option<String>,
+      field: Vec<FieldDescriptorProto>,
+      extension: Vec<FieldDescriptorProto>,
+      nested_type: Vec<DescriptorProto>,
+      enum_type: Vec<EnumDescriptorProto>,
+      extension_range: Vec<descriptor_proto::ExtensionRange>,
+      oneof_decl: Vec<OneofDescriptorProto>,
+      options: Option<MessageOptions>,
+      reserved_range: Vec<descriptor_proto::ReservedRange>,
+      /// Reserved field names, which may not be used by fields in the same message.
+      /// A given name may only be reserved once.
+      reserved_name: Vec<String>,
+      unknown_fields: Vec<TaggedValue>,
+    }
+
+    impl DescriptorProto {
+      pub const fn new() -> DescriptorProto {
+        DescriptorProto {
+          name: None,
+          field: Vec::new(),
+          extension: Vec::new(),
+          nested_type: Vec::new(),
+          enum_type: Vec::new(),
+          extension_range: Vec::new(),
+          oneof_decl: Vec::new(),
+          options: None,
+          reserved_range: Vec::new(),
+          reserved_name: Vec::new(),
+          unknown_fields: Vec::new(),
+        }
+      }
+
+      pub fn default_instance() -> &'static DescriptorProto {
+        static INSTANCE: DescriptorProto = DescriptorProto::new();
+        &INSTANCE
+      }
+
+      pub fn has_name(&self) -> bool {
+        self.name.is_some()
+      }
+
+      pub fn get_name(&self) -> &str {
+        self.name.as_deref().unwrap_or("")
+      }
+
+      pub fn set_name(&mut self, value: String) {
+        self.name = Some(value);
+      }
+
+      pub fn clear_name(&mut self) {
+        self.name = None;
+      }
+
+      pub fn mut_name(&mut self) -> &mut String {
+        self.name.get_or_insert_with(|| String::from(""))
+      }
+
+      pub fn take_name(&mut self) -> String {
+        self.name.take().unwrap_or_else(|| String::from(""))
+      }
+
+      pub fn get_field(&self) -> &[FieldDescriptorProto] {
+        &self.field
+      }
+
+      pub fn set_field(&mut self, value: Vec<FieldDescriptorProto>) {
+        self.field = value;
+      }
+
+      pub fn clear_field(&mut self) {
+        self.field.clear();
+      }
+
+      pub fn mut_field(&mut self) -> &mut Vec<FieldDescriptorProto> {
+        &mut self.field
+      }
+
+      pub fn take_field(&mut self) -> Vec<FieldDescriptorProto> {
+        core::mem::take(&mut self.field)
+      }
+
+      pub fn add_field(&mut self, value: FieldDescriptorProto) {
+        self.field.push(value);
+      }
+
+      pub fn field_len(&self) -> usize {
+        self.field.len()
+      }
+
+      pub fn get_extension(&self) -> &[FieldDescriptorProto] {
+        &self.extension
+      }
+
+      pub fn set_extension(&mut self, value: Vec<FieldDescriptorProto>) {
+        self.extension = value;
+      }
+
+      pub fn clear_extension(&mut self) {
+        self.extension.clear();
+      }
+
+      pub fn mut_extension(&mut self) -> &mut Vec<FieldDescriptorProto> {
+        &mut self.extension
+      }
+
+      pub fn take_extension(&mut self) -> Vec<FieldDescriptorProto> {
+        core::mem::take(&mut self.extension)
+      }
+
+      pub fn add_extension(&mut self, value: FieldDescriptorProto) {
+        self.extension.push(value);
+      }
+
+      pub fn extension_len(&self) -> usize {
+        self.extension.len()
+      }
+
+      pub fn get_nested_type(&self) -> &[DescriptorProto] {
+        &self.nested_type
+      }
+
+      pub fn set_nested_type(&mut self, value: Vec<DescriptorProto>) {
+        self.nested_type = value;
+      }
+
+      pub fn clear_nested_type(&mut self) {
+        self.nested_type.clear();
+      }
+
+      pub fn mut_nested_type(&mut self) -> &mut Vec<DescriptorProto> {
+        &mut self.nested_type
+      }
+
+      pub fn take_nested_type(&mut self) -> Vec<DescriptorProto> {
+        core::mem::take(&mut self.nested_type)
+      }
+
+      pub fn add_nested_type(&mut self, value: DescriptorProto) {
+        self.nested_type.push(value);
+      }
+
+      pub fn nested_type_len(&self) -> usize {
+        self.nested_type.len()
+      }
+
+      pub fn get_enum_type(&self) -> &[EnumDescriptorProto] {
+        &self.enum_type
+      }
+
+      pub fn set_enum_type(&mut self, value: Vec<EnumDescriptorProto>) {
+        self.enum_type = value;
+      }
+
+      pub fn clear_enum_type(&mut self) {
+        self.enum_type.clear();
+      }
+
+      pub fn mut_enum_type(&mut self) -> &mut Vec<EnumDescriptorProto> {
+        &mut self.enum_type
+      }
+
+      pub fn take_enum_type(&mut self) -> Vec<EnumDescriptorProto> {
+        core::mem::take(&mut self.enum_type)
+      }
+
+      pub fn add_enum_type(&mut self, value: EnumDescriptorProto) {
+        self.enum_type.push(value);
+      }
+
+      pub fn enum_type_len(&self) -> usize {
+        self.enum_type.len()
+      }
+
+      pub fn get_extension_range(&self) -> &[descriptor_proto::ExtensionRange] {
+        &self.extension_range
+      }
+
+      pub fn set_extension_range(&mut self, value: Vec<descriptor_proto::ExtensionRange>) {
+        self.extension_range = value;
+      }
+
+      pub fn clear_extension_range(&mut self) {
+        self.extension_range.clear();
+      }
+
+      pub fn mut_extension_range(&mut self) -> &mut Vec<descriptor_proto::ExtensionRange> {
+        &mut self.extension_range
+      }
+
+      pub fn take_extension_range(&mut self) -> Vec<descriptor_proto::ExtensionRange> {
+        core::mem::take(&mut self.extension_range)
+      }
+
+      pub fn add_extension_range(&mut self, value: descriptor_proto::ExtensionRange) {
+        self.extension_range.push(value);
+      }
+
+      pub fn extension_range_len(&self) -> usize {
+        self.extension_range.len()
+      }
+
+      pub fn get_oneof_decl(&self) -> &[OneofDescriptorProto] {
+        &self.oneof_decl
+      }
+
+      pub fn set_oneof_decl(&mut self, value: Vec<OneofDescriptorProto>) {
+        self.oneof_decl = value;
+      }
+
+      pub fn clear_oneof_decl(&mut self) {
+        self.oneof_decl.clear();
+      }
+
+      pub fn mut_oneof_decl(&mut self) -> &mut Vec<OneofDescriptorProto> {
+        &mut self.oneof_decl
+      }
+
+      pub fn take_oneof_decl(&mut self) -> Vec<OneofDescriptorProto> {
+        core::mem::take(&mut self.oneof_decl)
+      }
+
+      pub fn add_oneof_decl(&mut self, value: OneofDescriptorProto) {
+        self.oneof_decl.push(value);
+      }
+
+      pub fn oneof_decl_len(&self) -> usize {
+        self.oneof_decl.len()
+      }
+
+      pub fn has_options(&self) -> bool {
+        self.options.is_some()
+      }
+
+      pub fn get_options(&self) -> &MessageOptions {
+        match self.options { Some(ref value) => value, None => MessageOptions::default_instance() }
+      }
+
+      pub fn set_options(&mut self, value: MessageOptions) {
+        self.options = Some(value);
+      }
+
+      pub fn clear_options(&mut self) {
+        self.options = None;
+      }
+
+      pub fn mut_options(&mut self) -> &mut MessageOptions {
+        self.options.get_or_insert_with(MessageOptions::new)
+      }
+
+      pub fn take_options(&mut self) -> MessageOptions {
+        self.options.take().unwrap_or_else(MessageOptions::new)
+      }
+
+      pub fn get_reserved_range(&self) -> &[descriptor_proto::ReservedRange] {
+        &self.reserved_range
+      }
+
+      pub fn set_reserved_range(&mut self, value: Vec<descriptor_proto::ReservedRange>) {
+        self.reserved_range = value;
+      }
+
+      pub fn clear_reserved_range(&mut self) {
+        self.reserved_range.clear();
+      }
+
+      pub fn mut_reserved_range(&mut self) -> &mut Vec<descriptor_proto::ReservedRange> {
+        &mut self.reserved_range
+      }
+
+      pub fn take_reserved_range(&mut self) -> Vec<descriptor_proto::ReservedRange> {
+        core::mem::take(&mut self.reserved_range)
+      }
+
+      pub fn add_reserved_range(&mut self, value: descriptor_proto::ReservedRange) {
+        self.reserved_range.push(value);
+      }
+
+      pub fn reserved_range_len(&self) -> usize {
+        self.reserved_range.len()
+      }
+
+      pub fn get_reserved_name(&self) -> &[String] {
+        &self.reserved_name
+      }
+
+      pub fn set_reserved_name(&mut self, value: Vec<String>) {
+        self.reserved_name = value;
+      }
+
+      pub fn clear_reserved_name(&mut self) {
+        self.reserved_name.clear();
+      }
+
+      pub fn mut_reserved_name(&mut self) -> &mut Vec<String> {
+        &mut self.reserved_name
+      }
+
+      pub fn take_reserved_name(&mut self) -> Vec<String> {
+        core::mem::take(&mut self.reserved_name)
+      }
+
+      pub fn add_reserved_name(&mut self, value: String) {
+        self.reserved_name.push(value);
+      }
+
+      pub fn reserved_name_len(&self) -> usize {
+        self.reserved_name.len()
+      }
+
+      // Fields this message's schema doesn't know, and enum numbers outside
+      // the enum, in the order they were decoded.
+      pub fn get_unknown_fields(&self) -> &[TaggedValue] {
+        &self.unknown_fields
+      }
+    }
+
+    pub mod descriptor_proto {
+      #[allow(unused_imports)]
+      use ::protobuf::{
+        Protobuf, Reader, TagIter, TaggedValue,
+        DecodeZigZag32, DecodeZigZag64, DecodeFloat, DecodeDouble,
+        DecodePackedVarints, DecodePackedFixed32, DecodePackedFixed64,
+        ProtobufEncode, Writer, EncodeError,
+        EncodeTaggedValue, EncodeMessageField, EncodeRawField,
+        EncodeVarintField, EncodeFixed32Field, EncodeFixed64Field,
+        EncodeZigZag32, EncodeZigZag64, EncodeFloat, EncodeDouble,
+      };
+      #[allow(unused_imports)]
+      use ::protobuf::prelude::*;
+
+      pub struct ExtensionRange {
+        start: Option<i32>,
+        end: Option<i32>,
+        unknown_fields: Vec<TaggedValue>,
+      }
+
+      impl ExtensionRange {
+        pub const fn new() -> ExtensionRange {
+          ExtensionRange {
+            start: None,
+            end: None,
+            unknown_fields: Vec::new(),
+          }
+        }
+
+        pub fn default_instance() -> &'static ExtensionRange {
+          static INSTANCE: ExtensionRange = ExtensionRange::new();
+          &INSTANCE
+        }
+
+        pub fn has_start(&self) -> bool {
+          self.start.is_some()
+        }
+
+        pub fn get_start(&self) -> i32 {
+          self.start.unwrap_or(0)
+        }
+
+        pub fn set_start(&mut self, value: i32) {
+          self.start = Some(value);
+        }
+
+        pub fn clear_start(&mut self) {
+          self.start = None;
+        }
+
+        pub fn mut_start(&mut self) -> &mut i32 {
+          self.start.get_or_insert(0)
+        }
+
+        pub fn take_start(&mut self) -> i32 {
+          self.start.take().unwrap_or(0)
+        }
+
+        pub fn has_end(&self) -> bool {
+          self.end.is_some()
+        }
+
+        pub fn get_end(&self) -> i32 {
+          self.end.unwrap_or(0)
+        }
+
+        pub fn set_end(&mut self, value: i32) {
+          self.end = Some(value);
+        }
+
+        pub fn clear_end(&mut self) {
+          self.end = None;
+        }
+
+        pub fn mut_end(&mut self) -> &mut i32 {
+          self.end.get_or_insert(0)
+        }
+
+        pub fn take_end(&mut self) -> i32 {
+          self.end.take().unwrap_or(0)
+        }
+
+        // Fields this message's schema doesn't know, and enum numbers outside
+        // the enum, in the order they were decoded.
+        pub fn get_unknown_fields(&self) -> &[TaggedValue] {
+          &self.unknown_fields
+        }
+      }
+
+      impl Protobuf for ExtensionRange {
+        fn DecodePartial(&mut self, reader: &mut dyn Reader) -> bool {
+          let mut tags = TagIter::new(reader);
+          for tag_option in tags.by_ref() {
+            match tag_option {
+              TaggedValue::Varint(1, value) => {
+                self.set_start(value as i32);
+              }
+              TaggedValue::Varint(2, value) => {
+                self.set_end(value as i32);
+              }
+              unknown => self.unknown_fields.push(unknown)
+            }
+          }
+          !tags.is_malformed()
+        }
+
+        fn AppendMissingFields(&self, _prefix: &str, _missing: &mut Vec<String>) {
+        }
+      }
+
+      impl ProtobufEncode for ExtensionRange {
+        fn Encode(&self, writer: &mut dyn Writer) -> Result<(), EncodeError> {
+          for value in self.start.iter() {
+            EncodeVarintField(writer, 1, *value as i64 as u64)?;
+          }
+          for value in self.end.iter() {
+            EncodeVarintField(writer, 2, *value as i64 as u64)?;
+          }
+          for unknown in self.unknown_fields.iter() {
+            EncodeTaggedValue(writer, unknown)?;
+          }
+          Ok(())
+        }
+      }
+
+      /// Range of reserved tag numbers. Reserved tag numbers may not be used by
+      /// fields or extension ranges in the same message. Reserved ranges may
+      /// not overlap.
+      pub struct ReservedRange {
+        /// Inclusive.
+        start: Option<i32>,
+        /// Exclusive.
+        end: Option<i32>,
+        unknown_fields: Vec<TaggedValue>,
+      }
+
+      impl ReservedRange {
+        pub const fn new() -> ReservedRange {
+          ReservedRange {
+            start: None,
+            end: None,
+            unknown_fields: Vec::new(),
+          }
+        }
+
+        pub fn default_instance() -> &'static ReservedRange {
+          static INSTANCE: ReservedRange = ReservedRange::new();
+          &INSTANCE
+        }
+
+        pub fn has_start(&self) -> bool {
+          self.start.is_some()
+        }
+
+        pub fn get_start(&self) -> i32 {
+          self.start.unwrap_or(0)
+        }
+
+        pub fn set_start(&mut self, value: i32) {
+          self.start = Some(value);
+        }
+
+        pub fn clear_start(&mut self) {
+          self.start = None;
+        }
+
+        pub fn mut_start(&mut self) -> &mut i32 {
+          self.start.get_or_insert(0)
+        }
+
+        pub fn take_start(&mut self) -> i32 {
+          self.start.take().unwrap_or(0)
+        }
+
+        pub fn has_end(&self) -> bool {
+          self.end.is_some()
+        }
+
+        pub fn get_end(&self) -> i32 {
+          self.end.unwrap_or(0)
+        }
+
+        pub fn set_end(&mut self, value: i32) {
+          self.end = Some(value);
+        }
+
+        pub fn clear_end(&mut self) {
+          self.end = None;
+        }
+
+        pub fn mut_end(&mut self) -> &mut i32 {
+          self.end.get_or_insert(0)
+        }
+
+        pub fn take_end(&mut self) -> i32 {
+          self.end.take().unwrap_or(0)
+        }
+
+        // Fields this message's schema doesn't know, and enum numbers outside
+        // the enum, in the order they were decoded.
+        pub fn get_unknown_fields(&self) -> &[TaggedValue] {
+          &self.unknown_fields
+        }
+      }
+
+      impl Protobuf for ReservedRange {
+        fn DecodePartial(&mut self, reader: &mut dyn Reader) -> bool {
+          let mut tags = TagIter::new(reader);
+          for tag_option in tags.by_ref() {
+            match tag_option {
+              TaggedValue::Varint(1, value) => {
+                self.set_start(value as i32);
+              }
+              TaggedValue::Varint(2, value) => {
+                self.set_end(value as i32);
+              }
+              unknown => self.unknown_fields.push(unknown)
+            }
+          }
+          !tags.is_malformed()
+        }
+
+        fn AppendMissingFields(&self, _prefix: &str, _missing: &mut Vec<String>) {
+        }
+      }
+
+      impl ProtobufEncode for ReservedRange {
+        fn Encode(&self, writer: &mut dyn Writer) -> Result<(), EncodeError> {
+          for value in self.start.iter() {
+            EncodeVarintField(writer, 1, *value as i64 as u64)?;
+          }
+          for value in self.end.iter() {
+            EncodeVarintField(writer, 2, *value as i64 as u64)?;
+          }
+          for unknown in self.unknown_fields.iter() {
+            EncodeTaggedValue(writer, unknown)?;
+          }
+          Ok(())
+        }
+      }
+
+    }
+
+    impl Protobuf for DescriptorProto {
+      fn DecodePartial(&mut self, reader: &mut dyn Reader) -> bool {
+        let mut tags = TagIter::new(reader);
+        for tag_option in tags.by_ref() {
+          match tag_option {
+            TaggedValue::Raw(1, value) => {
+              match String::from_utf8(value.into_vec()) {
+                Ok(value) => self.set_name(value),
+                Err(_) => return false
+              }
+            }
+            TaggedValue::Raw(2, value) => {
+              let mut reader = value;
+              let mut message = FieldDescriptorProto::new();
+              if !message.DecodePartial(&mut reader) {
+                return false;
+              }
+              self.add_field(message);
+            }
+            TaggedValue::Raw(6, value) => {
+              let mut reader = value;
+              let mut message = FieldDescriptorProto::new();
+              if !message.DecodePartial(&mut reader) {
+                return false;
+              }
+              self.add_extension(message);
+            }
+            TaggedValue::Raw(3, value) => {
+              let mut reader = value;
+              let mut message = DescriptorProto::new();
+              if !message.DecodePartial(&mut reader) {
+                return false;
+              }
+              self.add_nested_type(message);
+            }
+            TaggedValue::Raw(4, value) => {
+              let mut reader = value;
+              let mut message = EnumDescriptorProto::new();
+              if !message.DecodePartial(&mut reader) {
+                return false;
+              }
+              self.add_enum_type(message);
+            }
+            TaggedValue::Raw(5, value) => {
+              let mut reader: &[u8] = &value;
+              let mut message = descriptor_proto::ExtensionRange::new();
+              if !message.DecodePartial(&mut reader) {
+                return false;
+              }
+              self.add_extension_range(message);
+            }
+            TaggedValue::Raw(8, value) => {
+              let mut reader = value;
+              let mut message = OneofDescriptorProto::new();
+              if !message.DecodePartial(&mut reader) {
+                return false;
+              }
+              self.add_oneof_decl(message);
+            }
+            TaggedValue::Raw(7, value) => {
+              let mut reader = value;
+              if !self.mut_options().DecodePartial(&mut reader) {
+                return false;
+              }
+            }
+            TaggedValue::Raw(9, value) => {
+              let mut reader: &[u8] = &value;
+              let mut message = descriptor_proto::ReservedRange::new();
+              if !message.DecodePartial(&mut reader) {
+                return false;
+              }
+              self.add_reserved_range(message);
+            }
+            TaggedValue::Raw(10, value) => {
+              match String::from_utf8(value.into_vec()) {
+                Ok(value) => self.add_reserved_name(value),
+                Err(_) => return false
+              }
+            }
+            unknown => self.unknown_fields.push(unknown)
+          }
+        }
+        !tags.is_malformed()
+      }
+
+      fn AppendMissingFields(&self, prefix: &str, missing: &mut Vec<String>) {
+        for (i, value) in self.field.iter().enumerate() {
+          for path in value.missing_fields() {
+            missing.push(format!("{}field[{}].{}", prefix, i, path));
+          }
+        }
+        for (i, value) in self.extension.iter().enumerate() {
+          for path in value.missing_fields() {
+            missing.push(format!("{}extension[{}].{}", prefix, i, path));
+          }
+        }
+        for (i, value) in self.nested_type.iter().enumerate() {
+          for path in value.missing_fields() {
+            missing.push(format!("{}nested_type[{}].{}", prefix, i, path));
+          }
+        }
+        for (i, value) in self.enum_type.iter().enumerate() {
+          for path in value.missing_fields() {
+            missing.push(format!("{}enum_type[{}].{}", prefix, i, path));
+          }
+        }
+        for (i, value) in self.extension_range.iter().enumerate() {
+          for path in value.missing_fields() {
+            missing.push(format!("{}extension_range[{}].{}", prefix, i, path));
+          }
+        }
+        for (i, value) in self.oneof_decl.iter().enumerate() {
+          for path in value.missing_fields() {
+            missing.push(format!("{}oneof_decl[{}].{}", prefix, i, path));
+          }
+        }
+        match self.options {
+          Some(ref value) => {
+            for path in value.missing_fields() {
+              missing.push(format!("{}options.{}", prefix, path));
+            }
+          }
+          None => ()
+        }
+        for (i, value) in self.reserved_range.iter().enumerate() {
+          for path in value.missing_fields() {
+            missing.push(format!("{}reserved_range[{}].{}", prefix, i, path));
+          }
+        }
+      }
+    }
+
+    impl ProtobufEncode for DescriptorProto {
+      fn Encode(&self, writer: &mut dyn Writer) -> Result<(), EncodeError> {
+        for value in self.name.iter() {
+          EncodeRawField(writer, 1, value.as_bytes())?;
+        }
+        for value in self.field.iter() {
+          EncodeMessageField(writer, 2, value)?;
+        }
+        for value in self.nested_type.iter() {
+          EncodeMessageField(writer, 3, value)?;
+        }
+        for value in self.enum_type.iter() {
+          EncodeMessageField(writer, 4, value)?;
+        }
+        for value in self.extension_range.iter() {
+          EncodeMessageField(writer, 5, value)?;
+        }
+        for value in self.extension.iter() {
+          EncodeMessageField(writer, 6, value)?;
+        }
+        for value in self.options.iter() {
+          EncodeMessageField(writer, 7, value)?;
+        }
+        for value in self.oneof_decl.iter() {
+          EncodeMessageField(writer, 8, value)?;
+        }
+        for value in self.reserved_range.iter() {
+          EncodeMessageField(writer, 9, value)?;
+        }
+        for value in self.reserved_name.iter() {
+          EncodeRawField(writer, 10, value.as_bytes())?;
+        }
+        for unknown in self.unknown_fields.iter() {
+          EncodeTaggedValue(writer, unknown)?;
+        }
+        Ok(())
+      }
+    }
+
+    /// Describes a field within a message.
+    pub struct FieldDescriptorProto {
+      name: Option<String>,
+      number: Option<i32>,
+      label: Option<field_descriptor_proto::Label>,
+      /// If type_name is set, this need not be set.  If both this and type_name
+      /// are set, this must be either TYPE_ENUM or TYPE_MESSAGE.
+      type_: Option<field_descriptor_proto::Type>,
+      /// For message and enum types, this is the name of the type.  If the name
+      /// starts with a '.', it is fully-qualified.  Otherwise, C++-like scoping
+      /// rules are used to find the type (i.e. first the nested types within this
+      /// message are searched, then within the parent, on up to the root
+      /// namespace).
+      type_name: Option<String>,
+      /// For extensions, this is the name of the type being extended.  It is
+      /// resolved in the same manner as type_name.
+      extendee: Option<String>,
+      /// For numeric types, contains the original text representation of the value.
+      /// For booleans, "true" or "false".
+      /// For strings, contains the default text contents (not escaped in any way).
+      /// For bytes, contains the C escaped value.  All bytes >= 128 are escaped.
+      /// TODO(kenton):  Base-64 encode?
+      default_value: Option<String>,
+      /// If set, gives the index of a oneof in the containing type's oneof_decl
+      /// list.  This field is a member of that oneof.
+      oneof_index: Option<i32>,
+      /// JSON name of this field. The value is set by protocol compiler. The user
+      /// can set it explicitly in the .proto file with the json_name option.
+      json_name: Option<String>,
+      options: Option<FieldOptions>,
+      /// If true, this is a proto3 "optional". When a proto3 field is optional, it
+      /// tracks presence regardless of field type.
+      ///
+      /// When proto3_optional is true, this field must belong to a oneof to signal
+      /// to old proto3 clients that presence is tracked for this field. This oneof
+      /// is known as a "synthetic" oneof, and this field must be its sole member.
+      proto3_optional: Option<bool>,
+      unknown_fields: Vec<TaggedValue>,
+    }
+
+    impl FieldDescriptorProto {
+      pub const fn new() -> FieldDescriptorProto {
+        FieldDescriptorProto {
+          name: None,
+          number: None,
+          label: None,
+          type_: None,
+          type_name: None,
+          extendee: None,
+          default_value: None,
+          oneof_index: None,
+          json_name: None,
+          options: None,
+          proto3_optional: None,
+          unknown_fields: Vec::new(),
+        }
+      }
+
+      pub fn default_instance() -> &'static FieldDescriptorProto {
+        static INSTANCE: FieldDescriptorProto = FieldDescriptorProto::new();
+        &INSTANCE
+      }
+
+      pub fn has_name(&self) -> bool {
+        self.name.is_some()
+      }
+
+      pub fn get_name(&self) -> &str {
+        self.name.as_deref().unwrap_or("")
+      }
+
+      pub fn set_name(&mut self, value: String) {
+        self.name = Some(value);
+      }
+
+      pub fn clear_name(&mut self) {
+        self.name = None;
+      }
+
+      pub fn mut_name(&mut self) -> &mut String {
+        self.name.get_or_insert_with(|| String::from(""))
+      }
+
+      pub fn take_name(&mut self) -> String {
+        self.name.take().unwrap_or_else(|| String::from(""))
+      }
+
+      pub fn has_number(&self) -> bool {
+        self.number.is_some()
+      }
+
+      pub fn get_number(&self) -> i32 {
+        self.number.unwrap_or(0)
+      }
+
+      pub fn set_number(&mut self, value: i32) {
+        self.number = Some(value);
+      }
+
+      pub fn clear_number(&mut self) {
+        self.number = None;
+      }
+
+      pub fn mut_number(&mut self) -> &mut i32 {
+        self.number.get_or_insert(0)
+      }
+
+      pub fn take_number(&mut self) -> i32 {
+        self.number.take().unwrap_or(0)
+      }
+
+      pub fn has_label(&self) -> bool {
+        self.label.is_some()
+      }
+
+      pub fn get_label(&self) -> field_descriptor_proto::Label {
+        self.label.unwrap_or(field_descriptor_proto::LabelOptional)
+      }
+
+      pub fn set_label(&mut self, value: field_descriptor_proto::Label) {
+        self.label = Some(value);
+      }
+
+      pub fn clear_label(&mut self) {
+        self.label = None;
+      }
+
+      pub fn mut_label(&mut self) -> &mut field_descriptor_proto::Label {
+        self.label.get_or_insert(field_descriptor_proto::LabelOptional)
+      }
+
+      pub fn take_label(&mut self) -> field_descriptor_proto::Label {
+        self.label.take().unwrap_or(field_descriptor_proto::LabelOptional)
+      }
+
+      pub fn has_type(&self) -> bool {
+        self.type_.is_some()
+      }
+
+      pub fn get_type(&self) -> field_descriptor_proto::Type {
+        self.type_.unwrap_or(field_descriptor_proto::TypeDouble)
+      }
+
+      pub fn set_type(&mut self, value: field_descriptor_proto::Type) {
+        self.type_ = Some(value);
+      }
+
+      pub fn clear_type(&mut self) {
+        self.type_ = None;
+      }
+
+      pub fn mut_type(&mut self) -> &mut field_descriptor_proto::Type {
+        self.type_.get_or_insert(field_descriptor_proto::TypeDouble)
+      }
+
+      pub fn take_type(&mut self) -> field_descriptor_proto::Type {
+        self.type_.take().unwrap_or(field_descriptor_proto::TypeDouble)
+      }
+
+      pub fn has_type_name(&self) -> bool {
+        self.type_name.is_some()
+      }
+
+      pub fn get_type_name(&self) -> &str {
+        self.type_name.as_deref().unwrap_or("")
+      }
+
+      pub fn set_type_name(&mut self, value: String) {
+        self.type_name = Some(value);
+      }
+
+      pub fn clear_type_name(&mut self) {
+        self.type_name = None;
+      }
+
+      pub fn mut_type_name(&mut self) -> &mut String {
+        self.type_name.get_or_insert_with(|| String::from(""))
+      }
+
+      pub fn take_type_name(&mut self) -> String {
+        self.type_name.take().unwrap_or_else(|| String::from(""))
+      }
+
+      pub fn has_extendee(&self) -> bool {
+        self.extendee.is_some()
+      }
+
+      pub fn get_extendee(&self) -> &str {
+        self.extendee.as_deref().unwrap_or("")
+      }
+
+      pub fn set_extendee(&mut self, value: String) {
+        self.extendee = Some(value);
+      }
+
+      pub fn clear_extendee(&mut self) {
+        self.extendee = None;
+      }
+
+      pub fn mut_extendee(&mut self) -> &mut String {
+        self.extendee.get_or_insert_with(|| String::from(""))
+      }
+
+      pub fn take_extendee(&mut self) -> String {
+        self.extendee.take().unwrap_or_else(|| String::from(""))
+      }
+
+      pub fn has_default_value(&self) -> bool {
+        self.default_value.is_some()
+      }
+
+      pub fn get_default_value(&self) -> &str {
+        self.default_value.as_deref().unwrap_or("")
+      }
+
+      pub fn set_default_value(&mut self, value: String) {
+        self.default_value = Some(value);
+      }
+
+      pub fn clear_default_value(&mut self) {
+        self.default_value = None;
+      }
+
+      pub fn mut_default_value(&mut self) -> &mut String {
+        self.default_value.get_or_insert_with(|| String::from(""))
+      }
+
+      pub fn take_default_value(&mut self) -> String {
+        self.default_value.take().unwrap_or_else(|| String::from(""))
+      }
+
+      pub fn has_oneof_index(&self) -> bool {
+        self.oneof_index.is_some()
+      }
+
+      pub fn get_oneof_index(&self) -> i32 {
+        self.oneof_index.unwrap_or(0)
+      }
+
+      pub fn set_oneof_index(&mut self, value: i32) {
+        self.oneof_index = Some(value);
+      }
+
+      pub fn clear_oneof_index(&mut self) {
+        self.oneof_index = None;
+      }
+
+      pub fn mut_oneof_index(&mut self) -> &mut i32 {
+        self.oneof_index.get_or_insert(0)
+      }
+
+      pub fn take_oneof_index(&mut self) -> i32 {
+        self.oneof_index.take().unwrap_or(0)
+      }
+
+      pub fn has_json_name(&self) -> bool {
+        self.json_name.is_some()
+      }
+
+      pub fn get_json_name(&self) -> &str {
+        self.json_name.as_deref().unwrap_or("")
+      }
+
+      pub fn set_json_name(&mut self, value: String) {
+        self.json_name = Some(value);
+      }
+
+      pub fn clear_json_name(&mut self) {
+        self.json_name = None;
+      }
+
+      pub fn mut_json_name(&mut self) -> &mut String {
+        self.json_name.get_or_insert_with(|| String::from(""))
+      }
+
+      pub fn take_json_name(&mut self) -> String {
+        self.json_name.take().unwrap_or_else(|| String::from(""))
+      }
+
+      pub fn has_options(&self) -> bool {
+        self.options.is_some()
+      }
+
+      pub fn get_options(&self) -> &FieldOptions {
+        match self.options { Some(ref value) => value, None => FieldOptions::default_instance() }
+      }
+
+      pub fn set_options(&mut self, value: FieldOptions) {
+        self.options = Some(value);
+      }
+
+      pub fn clear_options(&mut self) {
+        self.options = None;
+      }
+
+      pub fn mut_options(&mut self) -> &mut FieldOptions {
+        self.options.get_or_insert_with(FieldOptions::new)
+      }
+
+      pub fn take_options(&mut self) -> FieldOptions {
+        self.options.take().unwrap_or_else(FieldOptions::new)
+      }
+
+      pub fn has_proto3_optional(&self) -> bool {
+        self.proto3_optional.is_some()
+      }
+
+      pub fn get_proto3_optional(&self) -> bool {
+        self.proto3_optional.unwrap_or(false)
+      }
+
+      pub fn set_proto3_optional(&mut self, value: bool) {
+        self.proto3_optional = Some(value);
+      }
+
+      pub fn clear_proto3_optional(&mut self) {
+        self.proto3_optional = None;
+      }
+
+      pub fn mut_proto3_optional(&mut self) -> &mut bool {
+        self.proto3_optional.get_or_insert(false)
+      }
+
+      pub fn take_proto3_optional(&mut self) -> bool {
+        self.proto3_optional.take().unwrap_or(false)
+      }
+
+      // Fields this message's schema doesn't know, and enum numbers outside
+      // the enum, in the order they were decoded.
+      pub fn get_unknown_fields(&self) -> &[TaggedValue] {
+        &self.unknown_fields
+      }
+    }
+
+    pub mod field_descriptor_proto {
+      #[allow(unused_imports)]
+      use ::protobuf::{
+        Protobuf, Reader, TagIter, TaggedValue,
+        DecodeZigZag32, DecodeZigZag64, DecodeFloat, DecodeDouble,
+        DecodePackedVarints, DecodePackedFixed32, DecodePackedFixed64,
+        ProtobufEncode, Writer, EncodeError,
+        EncodeTaggedValue, EncodeMessageField, EncodeRawField,
+        EncodeVarintField, EncodeFixed32Field, EncodeFixed64Field,
+        EncodeZigZag32, EncodeZigZag64, EncodeFloat, EncodeDouble,
+      };
+      #[allow(unused_imports)]
+      use ::protobuf::prelude::*;
+
+      #[derive(Clone, Copy, PartialEq, Eq, Debug)]
+      pub enum Type {
+        /// 0 is reserved for errors.
+        /// Order is weird for historical reasons.
+        TypeDouble = 1,
+        TypeFloat = 2,
+        /// Not ZigZag encoded.  Negative numbers take 10 bytes.  Use TYPE_SINT64 if
+        /// negative values are likely.
+        TypeInt64 = 3,
+        TypeUint64 = 4,
+        /// Not ZigZag encoded.  Negative numbers take 10 bytes.  Use TYPE_SINT32 if
+        /// negative values are likely.
+        TypeInt32 = 5,
+        TypeFixed64 = 6,
+        TypeFixed32 = 7,
+        TypeBool = 8,
+        TypeString = 9,
+        /// Tag-delimited aggregate.
+        TypeGroup = 10,
+        /// Length-delimited aggregate.
+        TypeMessage = 11,
+        /// New in version 2.
+        TypeBytes = 12,
+        TypeUint32 = 13,
+        TypeEnum = 14,
+        TypeSfixed32 = 15,
+        TypeSfixed64 = 16,
+        /// Uses ZigZag encoding.
+        TypeSint32 = 17,
+        /// Uses ZigZag encoding.
+        TypeSint64 = 18,
+      }
+
+      pub use self::Type::{TypeDouble, TypeFloat, TypeInt64, TypeUint64, TypeInt32, TypeFixed64, TypeFixed32, TypeBool, TypeString, TypeGroup, TypeMessage, TypeBytes, TypeUint32, TypeEnum, TypeSfixed32, TypeSfixed64, TypeSint32, TypeSint64};
+
+      impl Type {
+        pub fn from_i32(value: i32) -> Option<Type> {
+          match value {
+            1 => Some(TypeDouble),
+            2 => Some(TypeFloat),
+            3 => Some(TypeInt64),
+            4 => Some(TypeUint64),
+            5 => Some(TypeInt32),
+            6 => Some(TypeFixed64),
+            7 => Some(TypeFixed32),
+            8 => Some(TypeBool),
+            9 => Some(TypeString),
+            10 => Some(TypeGroup),
+            11 => Some(TypeMessage),
+            12 => Some(TypeBytes),
+            13 => Some(TypeUint32),
+            14 => Some(TypeEnum),
+            15 => Some(TypeSfixed32),
+            16 => Some(TypeSfixed64),
+            17 => Some(TypeSint32),
+            18 => Some(TypeSint64),
+            _ => None
+          }
+        }
+
+        pub fn value(&self) -> i32 {
+          *self as i32
+        }
+
+        pub fn name(&self) -> &'static str {
+          match *self {
+            TypeDouble => "TYPE_DOUBLE",
+            TypeFloat => "TYPE_FLOAT",
+            TypeInt64 => "TYPE_INT64",
+            TypeUint64 => "TYPE_UINT64",
+            TypeInt32 => "TYPE_INT32",
+            TypeFixed64 => "TYPE_FIXED64",
+            TypeFixed32 => "TYPE_FIXED32",
+            TypeBool => "TYPE_BOOL",
+            TypeString => "TYPE_STRING",
+            TypeGroup => "TYPE_GROUP",
+            TypeMessage => "TYPE_MESSAGE",
+            TypeBytes => "TYPE_BYTES",
+            TypeUint32 => "TYPE_UINT32",
+            TypeEnum => "TYPE_ENUM",
+            TypeSfixed32 => "TYPE_SFIXED32",
+            TypeSfixed64 => "TYPE_SFIXED64",
+            TypeSint32 => "TYPE_SINT32",
+            TypeSint64 => "TYPE_SINT64",
+          }
+        }
+
+        pub fn from_name(name: &str) -> Option<Type> {
+          match name {
+            "TYPE_DOUBLE" => Some(TypeDouble),
+            "TYPE_FLOAT" => Some(TypeFloat),
+            "TYPE_INT64" => Some(TypeInt64),
+            "TYPE_UINT64" => Some(TypeUint64),
+            "TYPE_INT32" => Some(TypeInt32),
+            "TYPE_FIXED64" => Some(TypeFixed64),
+            "TYPE_FIXED32" => Some(TypeFixed32),
+            "TYPE_BOOL" => Some(TypeBool),
+            "TYPE_STRING" => Some(TypeString),
+            "TYPE_GROUP" => Some(TypeGroup),
+            "TYPE_MESSAGE" => Some(TypeMessage),
+            "TYPE_BYTES" => Some(TypeBytes),
+            "TYPE_UINT32" => Some(TypeUint32),
+            "TYPE_ENUM" => Some(TypeEnum),
+            "TYPE_SFIXED32" => Some(TypeSfixed32),
+            "TYPE_SFIXED64" => Some(TypeSfixed64),
+            "TYPE_SINT32" => Some(TypeSint32),
+            "TYPE_SINT64" => Some(TypeSint64),
+            _ => None
+          }
+        }
+      }
+
+      impl Default for Type {
+        fn default() -> Type {
+          TypeDouble
+        }
+      }
+
+      #[derive(Clone, Copy, PartialEq, Eq, Debug)]
+      pub enum Label {
+        /// 0 is reserved for errors
+        LabelOptional = 1,
+        LabelRequired = 2,
+        /// TODO(sanjay): Should we add LABEL_MAP?
+        LabelRepeated = 3,
+      }
+
+      pub use self::Label::{LabelOptional, LabelRequired, LabelRepeated};
+
+      impl Label {
+        pub fn from_i32(value: i32) -> Option<Label> {
+          match value {
+            1 => Some(LabelOptional),
+            2 => Some(LabelRequired),
+            3 => Some(LabelRepeated),
+            _ => None
+          }
+        }
+
+        pub fn value(&self) -> i32 {
+          *self as i32
+        }
+
+        pub fn name(&self) -> &'static str {
+          match *self {
+            LabelOptional => "LABEL_OPTIONAL",
+            LabelRequired => "LABEL_REQUIRED",
+            LabelRepeated => "LABEL_REPEATED",
+          }
+        }
+
+        pub fn from_name(name: &str) -> Option<Label> {
+          match name {
+            "LABEL_OPTIONAL" => Some(LabelOptional),
+            "LABEL_REQUIRED" => Some(LabelRequired),
+            "LABEL_REPEATED" => Some(LabelRepeated),
+            _ => None
+          }
+        }
+      }
+
+      impl Default for Label {
+        fn default() -> Label {
+          LabelOptional
+        }
+      }
+
+    }
+
+    impl Protobuf for FieldDescriptorProto {
+      fn DecodePartial(&mut self, reader: &mut dyn Reader) -> bool {
+        let mut tags = TagIter::new(reader);
+        for tag_option in tags.by_ref() {
+          match tag_option {
+            TaggedValue::Raw(1, value) => {
+              match String::from_utf8(value.into_vec()) {
+                Ok(value) => self.set_name(value),
+                Err(_) => return false
+              }
+            }
+            TaggedValue::Varint(3, value) => {
+              self.set_number(value as i32);
+            }
+            TaggedValue::Varint(4, value) => {
+              match field_descriptor_proto::Label::from_i32(value as i32) {
+                Some(value) => self.set_label(value),
+                None => self.unknown_fields.push(TaggedValue::Varint(4, value))
+              }
+            }
+            TaggedValue::Varint(5, value) => {
+              match field_descriptor_proto::Type::from_i32(value as i32) {
+                Some(value) => self.set_type(value),
+                None => self.unknown_fields.push(TaggedValue::Varint(5, value))
+              }
+            }
+            TaggedValue::Raw(6, value) => {
+              match String::from_utf8(value.into_vec()) {
+                Ok(value) => self.set_type_name(value),
+                Err(_) => return false
+              }
+            }
+            TaggedValue::Raw(2, value) => {
+              match String::from_utf8(value.into_vec()) {
+                Ok(value) => self.set_extendee(value),
+                Err(_) => return false
+              }
+            }
+            TaggedValue::Raw(7, value) => {
+              match String::from_utf8(value.into_vec()) {
+                Ok(value) => self.set_default_value(value),
+                Err(_) => return false
+              }
+            }
+            TaggedValue::Varint(9, value) => {
+              self.set_oneof_index(value as i32);
+            }
+            TaggedValue::Raw(10, value) => {
+              match String::from_utf8(value.into_vec()) {
+                Ok(value) => self.set_json_name(value),
+                Err(_) => return false
+              }
+            }
+            TaggedValue::Raw(8, value) => {
+              let mut reader = value;
+              if !self.mut_options().DecodePartial(&mut reader) {
+                return false;
+              }
+            }
+            TaggedValue::Varint(17, value) => {
+              self.set_proto3_optional(value != 0);
+            }
+            unknown => self.unknown_fields.push(unknown)
+          }
+        }
+        !tags.is_malformed()
+      }
+
+      fn AppendMissingFields(&self, prefix: &str, missing: &mut Vec<String>) {
+        match self.options {
+          Some(ref value) => {
+            for path in value.missing_fields() {
+              missing.push(format!("{}options.{}", prefix, path));
+            }
+          }
+          None => ()
+        }
+      }
+    }
+
+    impl ProtobufEncode for FieldDescriptorProto {
+      fn Encode(&self, writer: &mut dyn Writer) -> Result<(), EncodeError> {
+        for value in self.name.iter() {
+          EncodeRawField(writer, 1, value.as_bytes())?;
+        }
+        for value in self.extendee.iter() {
+          EncodeRawField(writer, 2, value.as_bytes())?;
+        }
+        for value in self.number.iter() {
+          EncodeVarintField(writer, 3, *value as i64 as u64)?;
+        }
+        for value in self.label.iter() {
+          EncodeVarintField(writer, 4, value.value() as i64 as u64)?;
+        }
+        for value in self.type_.iter() {
+          EncodeVarintField(writer, 5, value.value() as i64 as u64)?;
+        }
+        for value in self.type_name.iter() {
+          EncodeRawField(writer, 6, value.as_bytes())?;
+        }
+        for value in self.default_value.iter() {
+          EncodeRawField(writer, 7, value.as_bytes())?;
+        }
+        for value in self.options.iter() {
+          EncodeMessageField(writer, 8, value)?;
+        }
+        for value in self.oneof_index.iter() {
+          EncodeVarintField(writer, 9, *value as i64 as u64)?;
+        }
+        for value in self.json_name.iter() {
+          EncodeRawField(writer, 10, value.as_bytes())?;
+        }
+        for value in self.proto3_optional.iter() {
+          EncodeVarintField(writer, 17, *value as u64)?;
+        }
+        for unknown in self.unknown_fields.iter() {
+          EncodeTaggedValue(writer, unknown)?;
+        }
+        Ok(())
+      }
+    }
+
+    /// Describes a oneof.
+    pub struct OneofDescriptorProto {
+      name: Option<String>,
+      options: Option<OneofOptions>,
+      unknown_fields: Vec<TaggedValue>,
+    }
+
+    impl OneofDescriptorProto {
+      pub const fn new() -> OneofDescriptorProto {
+        OneofDescriptorProto {
+          name: None,
+          options: None,
+          unknown_fields: Vec::new(),
+        }
+      }
+
+      pub fn default_instance() -> &'static OneofDescriptorProto {
+        static INSTANCE: OneofDescriptorProto = OneofDescriptorProto::new();
+        &INSTANCE
+      }
+
+      pub fn has_name(&self) -> bool {
+        self.name.is_some()
+      }
+
+      pub fn get_name(&self) -> &str {
+        self.name.as_deref().unwrap_or("")
+      }
+
+      pub fn set_name(&mut self, value: String) {
+        self.name = Some(value);
+      }
+
+      pub fn clear_name(&mut self) {
+        self.name = None;
+      }
+
+      pub fn mut_name(&mut self) -> &mut String {
+        self.name.get_or_insert_with(|| String::from(""))
+      }
+
+      pub fn take_name(&mut self) -> String {
+        self.name.take().unwrap_or_else(|| String::from(""))
+      }
+
+      pub fn has_options(&self) -> bool {
+        self.options.is_some()
+      }
+
+      pub fn get_options(&self) -> &OneofOptions {
+        match self.options { Some(ref value) => value, None => OneofOptions::default_instance() }
+      }
+
+      pub fn set_options(&mut self, value: OneofOptions) {
+        self.options = Some(value);
+      }
+
+      pub fn clear_options(&mut self) {
+        self.options = None;
+      }
+
+      pub fn mut_options(&mut self) -> &mut OneofOptions {
+        self.options.get_or_insert_with(OneofOptions::new)
+      }
+
+      pub fn take_options(&mut self) -> OneofOptions {
+        self.options.take().unwrap_or_else(OneofOptions::new)
+      }
+
+      // Fields this message's schema doesn't know, and enum numbers outside
+      // the enum, in the order they were decoded.
+      pub fn get_unknown_fields(&self) -> &[TaggedValue] {
+        &self.unknown_fields
+      }
+    }
+
+    impl Protobuf for OneofDescriptorProto {
+      fn DecodePartial(&mut self, reader: &mut dyn Reader) -> bool {
+        let mut tags = TagIter::new(reader);
+        for tag_option in tags.by_ref() {
+          match tag_option {
+            TaggedValue::Raw(1, value) => {
+              match String::from_utf8(value.into_vec()) {
+                Ok(value) => self.set_name(value),
+                Err(_) => return false
+              }
+            }
+            TaggedValue::Raw(2, value) => {
+              let mut reader = value;
+              if !self.mut_options().DecodePartial(&mut reader) {
+                return false;
+              }
+            }
+            unknown => self.unknown_fields.push(unknown)
+          }
+        }
+        !tags.is_malformed()
+      }
+
+      fn AppendMissingFields(&self, prefix: &str, missing: &mut Vec<String>) {
+        match self.options {
+          Some(ref value) => {
+            for path in value.missing_fields() {
+              missing.push(format!("{}options.{}", prefix, path));
+            }
+          }
+          None => ()
+        }
+      }
+    }
+
+    impl ProtobufEncode for OneofDescriptorProto {
+      fn Encode(&self, writer: &mut dyn Writer) -> Result<(), EncodeError> {
+        for value in self.name.iter() {
+          EncodeRawField(writer, 1, value.as_bytes())?;
+        }
+        for value in self.options.iter() {
+          EncodeMessageField(writer, 2, value)?;
+        }
+        for unknown in self.unknown_fields.iter() {
+          EncodeTaggedValue(writer, unknown)?;
+        }
+        Ok(())
+      }
+    }
+
+    /// Describes an enum type.
+    pub struct EnumDescriptorProto {
+      name: Option<String>,
+      value: Vec<EnumValueDescriptorProto>,
+      options: Option<EnumOptions>,
+      /// Range of reserved numeric values. Reserved numeric values may not be used
+      /// by enum values in the same enum declaration. Reserved ranges may not
+      /// overlap.
+      reserved_range: Vec<enum_descriptor_proto::EnumReservedRange>,
+      /// Reserved enum value names, which may not be reused. A given name may only
+      /// be reserved once.
+      reserved_name: Vec<String>,
+      unknown_fields: Vec<TaggedValue>,
+    }
+
+    impl EnumDescriptorProto {
+      pub const fn new() -> EnumDescriptorProto {
+        EnumDescriptorProto {
+          name: None,
+          value: Vec::new(),
+          options: None,
+          reserved_range: Vec::new(),
+          reserved_name: Vec::new(),
+          unknown_fields: Vec::new(),
+        }
+      }
+
+      pub fn default_instance() -> &'static EnumDescriptorProto {
+        static INSTANCE: EnumDescriptorProto = EnumDescriptorProto::new();
+        &INSTANCE
+      }
+
+      pub fn has_name(&self) -> bool {
+        self.name.is_some()
+      }
+
+      pub fn get_name(&self) -> &str {
+        self.name.as_deref().unwrap_or("")
+      }
+
+      pub fn set_name(&mut self, value: String) {
+        self.name = Some(value);
+      }
+
+      pub fn clear_name(&mut self) {
+        self.name = None;
+      }
+
+      pub fn mut_name(&mut self) -> &mut String {
+        self.name.get_or_insert_with(|| String::from(""))
+      }
+
+      pub fn take_name(&mut self) -> String {
+        self.name.take().unwrap_or_else(|| String::from(""))
+      }
+
+      pub fn get_value(&self) -> &[EnumValueDescriptorProto] {
+        &self.value
+      }
+
+      pub fn set_value(&mut self, value: Vec<EnumValueDescriptorProto>) {
+        self.value = value;
+      }
+
+      pub fn clear_value(&mut self) {
+        self.value.clear();
+      }
+
+      pub fn mut_value(&mut self) -> &mut Vec<EnumValueDescriptorProto> {
+        &mut self.value
+      }
+
+      pub fn take_value(&mut self) -> Vec<EnumValueDescriptorProto> {
+        core::mem::take(&mut self.value)
+      }
+
+      pub fn add_value(&mut self, value: EnumValueDescriptorProto) {
+        self.value.push(value);
+      }
+
+      pub fn value_len(&self) -> usize {
+        self.value.len()
+      }
+
+      pub fn has_options(&self) -> bool {
+        self.options.is_some()
+      }
+
+      pub fn get_options(&self) -> &EnumOptions {
+        match self.options { Some(ref value) => value, None => EnumOptions::default_instance() }
+      }
+
+      pub fn set_options(&mut self, value: EnumOptions) {
+        self.options = Some(value);
+      }
+
+      pub fn clear_options(&mut self) {
+        self.options = None;
+      }
+
+      pub fn mut_options(&mut self) -> &mut EnumOptions {
+        self.options.get_or_insert_with(EnumOptions::new)
+      }
+
+      pub fn take_options(&mut self) -> EnumOptions {
+        self.options.take().unwrap_or_else(EnumOptions::new)
+      }
+
+      pub fn get_reserved_range(&self) -> &[enum_descriptor_proto::EnumReservedRange] {
+        &self.reserved_range
+      }
+
+      pub fn set_reserved_range(&mut self, value: Vec<enum_descriptor_proto::EnumReservedRange>) {
+        self.reserved_range = value;
+      }
+
+      pub fn clear_reserved_range(&mut self) {
+        self.reserved_range.clear();
+      }
+
+      pub fn mut_reserved_range(&mut self) -> &mut Vec<enum_descriptor_proto::EnumReservedRange> {
+        &mut self.reserved_range
+      }
+
+      pub fn take_reserved_range(&mut self) -> Vec<enum_descriptor_proto::EnumReservedRange> {
+        core::mem::take(&mut self.reserved_range)
+      }
+
+      pub fn add_reserved_range(&mut self, value: enum_descriptor_proto::EnumReservedRange) {
+        self.reserved_range.push(value);
+      }
+
+      pub fn reserved_range_len(&self) -> usize {
+        self.reserved_range.len()
+      }
+
+      pub fn get_reserved_name(&self) -> &[String] {
+        &self.reserved_name
+      }
+
+      pub fn set_reserved_name(&mut self, value: Vec<String>) {
+        self.reserved_name = value;
+      }
+
+      pub fn clear_reserved_name(&mut self) {
+        self.reserved_name.clear();
+      }
+
+      pub fn mut_reserved_name(&mut self) -> &mut Vec<String> {
+        &mut self.reserved_name
+      }
+
+      pub fn take_reserved_name(&mut self) -> Vec<String> {
+        core::mem::take(&mut self.reserved_name)
+      }
+
+      pub fn add_reserved_name(&mut self, value: String) {
+        self.reserved_name.push(value);
+      }
+
+      pub fn reserved_name_len(&self) -> usize {
+        self.reserved_name.len()
+      }
+
+      // Fields this message's schema doesn't know, and enum numbers outside
+      // the enum, in the order they were decoded.
+      pub fn get_unknown_fields(&self) -> &[TaggedValue] {
+        &self.unknown_fields
+      }
+    }
+
+    pub mod enum_descriptor_proto {
+      #[allow(unused_imports)]
+      use ::protobuf::{
+        Protobuf, Reader, TagIter, TaggedValue,
+        DecodeZigZag32, DecodeZigZag64, DecodeFloat, DecodeDouble,
+        DecodePackedVarints, DecodePackedFixed32, DecodePackedFixed64,
+        ProtobufEncode, Writer, EncodeError,
+        EncodeTaggedValue, EncodeMessageField, EncodeRawField,
+        EncodeVarintField, EncodeFixed32Field, EncodeFixed64Field,
+        EncodeZigZag32, EncodeZigZag64, EncodeFloat, EncodeDouble,
+      };
+      #[allow(unused_imports)]
+      use ::protobuf::prelude::*;
+
+      /// Range of reserved numeric values. Reserved values may not be used by
+      /// entries in the same enum. Reserved ranges may not overlap.
+      ///
+      /// Note that this is distinct from DescriptorProto.ReservedRange in that it
+      /// is inclusive such that it can appropriately represent the entire int32
+      /// domain.
+      pub struct EnumReservedRange {
+        /// Inclusive.
+        start: Option<i32>,
+        /// Inclusive.
+        end: Option<i32>,
+        unknown_fields: Vec<TaggedValue>,
+      }
+
+      impl EnumReservedRange {
+        pub const fn new() -> EnumReservedRange {
+          EnumReservedRange {
+            start: None,
+            end: None,
+            unknown_fields: Vec::new(),
+          }
+        }
+
+        pub fn default_instance() -> &'static EnumReservedRange {
+          static INSTANCE: EnumReservedRange = EnumReservedRange::new();
+          &INSTANCE
+        }
+
+        pub fn has_start(&self) -> bool {
+          self.start.is_some()
+        }
+
+        pub fn get_start(&self) -> i32 {
+          self.start.unwrap_or(0)
+        }
+
+        pub fn set_start(&mut self, value: i32) {
+          self.start = Some(value);
+        }
+
+        pub fn clear_start(&mut self) {
+          self.start = None;
+        }
+
+        pub fn mut_start(&mut self) -> &mut i32 {
+          self.start.get_or_insert(0)
+        }
+
+        pub fn take_start(&mut self) -> i32 {
+          self.start.take().unwrap_or(0)
+        }
+
+        pub fn has_end(&self) -> bool {
+          self.end.is_some()
+        }
+
+        pub fn get_end(&self) -> i32 {
+          self.end.unwrap_or(0)
+        }
+
+        pub fn set_end(&mut self, value: i32) {
+          self.end = Some(value);
+        }
+
+        pub fn clear_end(&mut self) {
+          self.end = None;
+        }
+
+        pub fn mut_end(&mut self) -> &mut i32 {
+          self.end.get_or_insert(0)
+        }
+
+        pub fn take_end(&mut self) -> i32 {
+          self.end.take().unwrap_or(0)
+        }
+
+        // Fields this message's schema doesn't know, and enum numbers outside
+        // the enum, in the order they were decoded.
+        pub fn get_unknown_fields(&self) -> &[TaggedValue] {
+          &self.unknown_fields
+        }
+      }
+
+      impl Protobuf for EnumReservedRange {
+        fn DecodePartial(&mut self, reader: &mut dyn Reader) -> bool {
+          let mut tags = TagIter::new(reader);
+          for tag_option in tags.by_ref() {
+            match tag_option {
+              TaggedValue::Varint(1, value) => {
+                self.set_start(value as i32);
+              }
+              TaggedValue::Varint(2, value) => {
+                self.set_end(value as i32);
+              }
+              unknown => self.unknown_fields.push(unknown)
+            }
+          }
+          !tags.is_malformed()
+        }
+
+        fn AppendMissingFields(&self, _prefix: &str, _missing: &mut Vec<String>) {
+        }
+      }
+
+      impl ProtobufEncode for EnumReservedRange {
+        fn Encode(&self, writer: &mut dyn Writer) -> Result<(), EncodeError> {
+          for value in self.start.iter() {
+            EncodeVarintField(writer, 1, *value as i64 as u64)?;
+          }
+          for value in self.end.iter() {
+            EncodeVarintField(writer, 2, *value as i64 as u64)?;
+          }
+          for unknown in self.unknown_fields.iter() {
+            EncodeTaggedValue(writer, unknown)?;
+          }
+          Ok(())
+        }
+      }
+
+    }
+
+    impl Protobuf for EnumDescriptorProto {
+      fn DecodePartial(&mut self, reader: &mut dyn Reader) -> bool {
+        let mut tags = TagIter::new(reader);
+        for tag_option in tags.by_ref() {
+          match tag_option {
+            TaggedValue::Raw(1, value) => {
+              match String::from_utf8(value.into_vec()) {
+                Ok(value) => self.set_name(value),
+                Err(_) => return false
+              }
+            }
+            TaggedValue::Raw(2, value) => {
+              let mut reader = value;
+              let mut message = EnumValueDescriptorProto::new();
+              if !message.DecodePartial(&mut reader) {
+                return false;
+              }
+              self.add_value(message);
+            }
+            TaggedValue::Raw(3, value) => {
+              let mut reader = value;
+              if !self.mut_options().DecodePartial(&mut reader) {
+                return false;
+              }
+            }
+            TaggedValue::Raw(4, value) => {
+              let mut reader: &[u8] = &value;
+              let mut message = enum_descriptor_proto::EnumReservedRange::new();
+              if !message.DecodePartial(&mut reader) {
+                return false;
+              }
+              self.add_reserved_range(message);
+            }
+            TaggedValue::Raw(5, value) => {
+              match String::from_utf8(value.into_vec()) {
+                Ok(value) => self.add_reserved_name(value),
+                Err(_) => return false
+              }
+            }
+            unknown => self.unknown_fields.push(unknown)
+          }
+        }
+        !tags.is_malformed()
+      }
+
+      fn AppendMissingFields(&self, prefix: &str, missing: &mut Vec<String>) {
+        for (i, value) in self.value.iter().enumerate() {
+          for path in value.missing_fields() {
+            missing.push(format!("{}value[{}].{}", prefix, i, path));
+          }
+        }
+        match self.options {
+          Some(ref value) => {
+            for path in value.missing_fields() {
+              missing.push(format!("{}options.{}", prefix, path));
+            }
+          }
+          None => ()
+        }
+        for (i, value) in self.reserved_range.iter().enumerate() {
+          for path in value.missing_fields() {
+            missing.push(format!("{}reserved_range[{}].{}", prefix, i, path));
+          }
+        }
+      }
+    }
+
+    impl ProtobufEncode for EnumDescriptorProto {
+      fn Encode(&self, writer: &mut dyn Writer) -> Result<(), EncodeError> {
+        for value in self.name.iter() {
+          EncodeRawField(writer, 1, value.as_bytes())?;
+        }
+        for value in self.value.iter() {
+          EncodeMessageField(writer, 2, value)?;
+        }
+        for value in self.options.iter() {
+          EncodeMessageField(writer, 3, value)?;
+        }
+        for value in self.reserved_range.iter() {
+          EncodeMessageField(writer, 4, value)?;
+        }
+        for value in self.reserved_name.iter() {
+          EncodeRawField(writer, 5, value.as_bytes())?;
+        }
+        for unknown in self.unknown_fields.iter() {
+          EncodeTaggedValue(writer, unknown)?;
+        }
+        Ok(())
+      }
+    }
+
+    /// Describes a value within an enum.
+    pub struct EnumValueDescriptorProto {
+      name: Option<String>,
+      number: Option<i32>,
+      options: Option<EnumValueOptions>,
+      unknown_fields: Vec<TaggedValue>,
+    }
+
+    impl EnumValueDescriptorProto {
+      pub const fn new() -> EnumValueDescriptorProto {
+        EnumValueDescriptorProto {
+          name: None,
+          number: None,
+          options: None,
+          unknown_fields: Vec::new(),
+        }
+      }
+
+      pub fn default_instance() -> &'static EnumValueDescriptorProto {
+        static INSTANCE: EnumValueDescriptorProto = EnumValueDescriptorProto::new();
+        &INSTANCE
+      }
+
+      pub fn has_name(&self) -> bool {
+        self.name.is_some()
+      }
+
+      pub fn get_name(&self) -> &str {
+        self.name.as_deref().unwrap_or("")
+      }
+
+      pub fn set_name(&mut self, value: String) {
+        self.name = Some(value);
+      }
+
+      pub fn clear_name(&mut self) {
+        self.name = None;
+      }
+
+      pub fn mut_name(&mut self) -> &mut String {
+        self.name.get_or_insert_with(|| String::from(""))
+      }
+
+      pub fn take_name(&mut self) -> String {
+        self.name.take().unwrap_or_else(|| String::from(""))
+      }
+
+      pub fn has_number(&self) -> bool {
+        self.number.is_some()
+      }
+
+      pub fn get_number(&self) -> i32 {
+        self.number.unwrap_or(0)
+      }
+
+      pub fn set_number(&mut self, value: i32) {
+        self.number = Some(value);
+      }
+
+      pub fn clear_number(&mut self) {
+        self.number = None;
+      }
+
+      pub fn mut_number(&mut self) -> &mut i32 {
+        self.number.get_or_insert(0)
+      }
+
+      pub fn take_number(&mut self) -> i32 {
+        self.number.take().unwrap_or(0)
+      }
+
+      pub fn has_options(&self) -> bool {
+        self.options.is_some()
+      }
+
+      pub fn get_options(&self) -> &EnumValueOptions {
+        match self.options { Some(ref value) => value, None => EnumValueOptions::default_instance() }
+      }
+
+      pub fn set_options(&mut self, value: EnumValueOptions) {
+        self.options = Some(value);
+      }
+
+      pub fn clear_options(&mut self) {
+        self.options = None;
+      }
+
+      pub fn mut_options(&mut self) -> &mut EnumValueOptions {
+        self.options.get_or_insert_with(EnumValueOptions::new)
+      }
+
+      pub fn take_options(&mut self) -> EnumValueOptions {
+        self.options.take().unwrap_or_else(EnumValueOptions::new)
+      }
+
+      // Fields this message's schema doesn't know, and enum numbers outside
+      // the enum, in the order they were decoded.
+      pub fn get_unknown_fields(&self) -> &[TaggedValue] {
+        &self.unknown_fields
+      }
+    }
+
+    impl Protobuf for EnumValueDescriptorProto {
+      fn DecodePartial(&mut self, reader: &mut dyn Reader) -> bool {
+        let mut tags = TagIter::new(reader);
+        for tag_option in tags.by_ref() {
+          match tag_option {
+            TaggedValue::Raw(1, value) => {
+              match String::from_utf8(value.into_vec()) {
+                Ok(value) => self.set_name(value),
+                Err(_) => return false
+              }
+            }
+            TaggedValue::Varint(2, value) => {
+              self.set_number(value as i32);
+            }
+            TaggedValue::Raw(3, value) => {
+              let mut reader = value;
+              if !self.mut_options().DecodePartial(&mut reader) {
+                return false;
+              }
+            }
+            unknown => self.unknown_fields.push(unknown)
+          }
+        }
+        !tags.is_malformed()
+      }
+
+      fn AppendMissingFields(&self, prefix: &str, missing: &mut Vec<String>) {
+        match self.options {
+          Some(ref value) => {
+            for path in value.missing_fields() {
+              missing.push(format!("{}options.{}", prefix, path));
+            }
+          }
+          None => ()
+        }
+      }
+    }
+
+    impl ProtobufEncode for EnumValueDescriptorProto {
+      fn Encode(&self, writer: &mut dyn Writer) -> Result<(), EncodeError> {
+        for value in self.name.iter() {
+          EncodeRawField(writer, 1, value.as_bytes())?;
+        }
+        for value in self.number.iter() {
+          EncodeVarintField(writer, 2, *value as i64 as u64)?;
+        }
+        for value in self.options.iter() {
+          EncodeMessageField(writer, 3, value)?;
+        }
+        for unknown in self.unknown_fields.iter() {
+          EncodeTaggedValue(writer, unknown)?;
+        }
+        Ok(())
+      }
+    }
+
+    /// Describes a service.
+    pub struct ServiceDescriptorProto {
+      name: Option<String>,
+      method: Vec<MethodDescriptorProto>,
+      options: Option<ServiceOptions>,
+      unknown_fields: Vec<TaggedValue>,
+    }
+
+    impl ServiceDescriptorProto {
+      pub const fn new() -> ServiceDescriptorProto {
+        ServiceDescriptorProto {
+          name: None,
+          method: Vec::new(),
+          options: None,
+          unknown_fields: Vec::new(),
+        }
+      }
+
+      pub fn default_instance() -> &'static ServiceDescriptorProto {
+        static INSTANCE: ServiceDescriptorProto = ServiceDescriptorProto::new();
+        &INSTANCE
+      }
+
+      pub fn has_name(&self) -> bool {
+        self.name.is_some()
+      }
+
+      pub fn get_name(&self) -> &str {
+        self.name.as_deref().unwrap_or("")
+      }
+
+      pub fn set_name(&mut self, value: String) {
+        self.name = Some(value);
+      }
+
+      pub fn clear_name(&mut self) {
+        self.name = None;
+      }
+
+      pub fn mut_name(&mut self) -> &mut String {
+        self.name.get_or_insert_with(|| String::from(""))
+      }
+
+      pub fn take_name(&mut self) -> String {
+        self.name.take().unwrap_or_else(|| String::from(""))
+      }
+
+      pub fn get_method(&self) -> &[MethodDescriptorProto] {
+        &self.method
+      }
+
+      pub fn set_method(&mut self, value: Vec<MethodDescriptorProto>) {
+        self.method = value;
+      }
+
+      pub fn clear_method(&mut self) {
+        self.method.clear();
+      }
+
+      pub fn mut_method(&mut self) -> &mut Vec<MethodDescriptorProto> {
+        &mut self.method
+      }
+
+      pub fn take_method(&mut self) -> Vec<MethodDescriptorProto> {
+        core::mem::take(&mut self.method)
+      }
+
+      pub fn add_method(&mut self, value: MethodDescriptorProto) {
+        self.method.push(value);
+      }
+
+      pub fn method_len(&self) -> usize {
+        self.method.len()
+      }
+
+      pub fn has_options(&self) -> bool {
+        self.options.is_some()
+      }
+
+      pub fn get_options(&self) -> &ServiceOptions {
+        match self.options { Some(ref value) => value, None => ServiceOptions::default_instance() }
+      }
+
+      pub fn set_options(&mut self, value: ServiceOptions) {
+        self.options = Some(value);
+      }
+
+      pub fn clear_options(&mut self) {
+        self.options = None;
+      }
+
+      pub fn mut_options(&mut self) -> &mut ServiceOptions {
+        self.options.get_or_insert_with(ServiceOptions::new)
+      }
+
+      pub fn take_options(&mut self) -> ServiceOptions {
+        self.options.take().unwrap_or_else(ServiceOptions::new)
+      }
+
+      // Fields this message's schema doesn't know, and enum numbers outside
+      // the enum, in the order they were decoded.
+      pub fn get_unknown_fields(&self) -> &[TaggedValue] {
+        &self.unknown_fields
+      }
+    }
+
+    impl Protobuf for ServiceDescriptorProto {
+      fn DecodePartial(&mut self, reader: &mut dyn Reader) -> bool {
+        let mut tags = TagIter::new(reader);
+        for tag_option in tags.by_ref() {
+          match tag_option {
+            TaggedValue::Raw(1, value) => {
+              match String::from_utf8(value.into_vec()) {
+                Ok(value) => self.set_name(value),
+                Err(_) => return false
+              }
+            }
+            TaggedValue::Raw(2, value) => {
+              let mut reader = value;
+              let mut message = MethodDescriptorProto::new();
+              if !message.DecodePartial(&mut reader) {
+                return false;
+              }
+              self.add_method(message);
+            }
+            TaggedValue::Raw(3, value) => {
+              let mut reader = value;
+              if !self.mut_options().DecodePartial(&mut reader) {
+                return false;
+              }
+            }
+            unknown => self.unknown_fields.push(unknown)
+          }
+        }
+        !tags.is_malformed()
+      }
+
+      fn AppendMissingFields(&self, prefix: &str, missing: &mut Vec<String>) {
+        for (i, value) in self.method.iter().enumerate() {
+          for path in value.missing_fields() {
+            missing.push(format!("{}method[{}].{}", prefix, i, path));
+          }
+        }
+        match self.options {
+          Some(ref value) => {
+            for path in value.missing_fields() {
+              missing.push(format!("{}options.{}", prefix, path));
+            }
+          }
+          None => ()
+        }
+      }
+    }
+
+    impl ProtobufEncode for ServiceDescriptorProto {
+      fn Encode(&self, writer: &mut dyn Writer) -> Result<(), EncodeError> {
+        for value in self.name.iter() {
+          EncodeRawField(writer, 1, value.as_bytes())?;
+        }
+        for value in self.method.iter() {
+          EncodeMessageField(writer, 2, value)?;
+        }
+        for value in self.options.iter() {
+          EncodeMessageField(writer, 3, value)?;
+        }
+        for unknown in self.unknown_fields.iter() {
+          EncodeTaggedValue(writer, unknown)?;
+        }
+        Ok(())
+      }
+    }
+
+    /// Describes a method of a service.
+    pub struct MethodDescriptorProto {
+      name: Option<String>,
+      /// Input and output type names.  These are resolved in the same way as
+      /// FieldDescriptorProto.type_name, but must refer to a message type.
+      input_type: Option<String>,
+      output_type: Option<String>,
+      options: Option<MethodOptions>,
+      /// Identifies if client streams multiple client messages
+      client_streaming: Option<bool>,
+      /// Identifies if server streams multiple server messages
+      server_streaming: Option<bool>,
+      unknown_fields: Vec<TaggedValue>,
+    }
+
+    impl MethodDescriptorProto {
+      pub const fn new() -> MethodDescriptorProto {
+        MethodDescriptorProto {
+          name: None,
+          input_type: None,
+          output_type: None,
+          options: None,
+          client_streaming: None,
+          server_streaming: None,
+          unknown_fields: Vec::new(),
+        }
+      }
+
+      pub fn default_instance() -> &'static MethodDescriptorProto {
+        static INSTANCE: MethodDescriptorProto = MethodDescriptorProto::new();
+        &INSTANCE
+      }
+
+      pub fn has_name(&self) -> bool {
+        self.name.is_some()
+      }
+
+      pub fn get_name(&self) -> &str {
+        self.name.as_deref().unwrap_or("")
+      }
+
+      pub fn set_name(&mut self, value: String) {
+        self.name = Some(value);
+      }
+
+      pub fn clear_name(&mut self) {
+        self.name = None;
+      }
+
+      pub fn mut_name(&mut self) -> &mut String {
+        self.name.get_or_insert_with(|| String::from(""))
+      }
+
+      pub fn take_name(&mut self) -> String {
+        self.name.take().unwrap_or_else(|| String::from(""))
+      }
+
+      pub fn has_input_type(&self) -> bool {
+        self.input_type.is_some()
+      }
+
+      pub fn get_input_type(&self) -> &str {
+        self.input_type.as_deref().unwrap_or("")
+      }
+
+      pub fn set_input_type(&mut self, value: String) {
+        self.input_type = Some(value);
+      }
+
+      pub fn clear_input_type(&mut self) {
+        self.input_type = None;
+      }
+
+      pub fn mut_input_type(&mut self) -> &mut String {
+        self.input_type.get_or_insert_with(|| String::from(""))
+      }
+
+      pub fn take_input_type(&mut self) -> String {
+        self.input_type.take().unwrap_or_else(|| String::from(""))
+      }
+
+      pub fn has_output_type(&self) -> bool {
+        self.output_type.is_some()
+      }
+
+      pub fn get_output_type(&self) -> &str {
+        self.output_type.as_deref().unwrap_or("")
+      }
+
+      pub fn set_output_type(&mut self, value: String) {
+        self.output_type = Some(value);
+      }
+
+      pub fn clear_output_type(&mut self) {
+        self.output_type = None;
+      }
+
+      pub fn mut_output_type(&mut self) -> &mut String {
+        self.output_type.get_or_insert_with(|| String::from(""))
+      }
+
+      pub fn take_output_type(&mut self) -> String {
+        self.output_type.take().unwrap_or_else(|| String::from(""))
+      }
+
+      pub fn has_options(&self) -> bool {
+        self.options.is_some()
+      }
+
+      pub fn get_options(&self) -> &MethodOptions {
+        match self.options { Some(ref value) => value, None => MethodOptions::default_instance() }
+      }
+
+      pub fn set_options(&mut self, value: MethodOptions) {
+        self.options = Some(value);
+      }
+
+      pub fn clear_options(&mut self) {
+        self.options = None;
+      }
+
+      pub fn mut_options(&mut self) -> &mut MethodOptions {
+        self.options.get_or_insert_with(MethodOptions::new)
+      }
+
+      pub fn take_options(&mut self) -> MethodOptions {
+        self.options.take().unwrap_or_else(MethodOptions::new)
+      }
+
+      pub fn has_client_streaming(&self) -> bool {
+        self.client_streaming.is_some()
+      }
+
+      pub fn get_client_streaming(&self) -> bool {
+        self.client_streaming.unwrap_or(false)
+      }
+
+      pub fn set_client_streaming(&mut self, value: bool) {
+        self.client_streaming = Some(value);
+      }
+
+      pub fn clear_client_streaming(&mut self) {
+        self.client_streaming = None;
+      }
+
+      pub fn mut_client_streaming(&mut self) -> &mut bool {
+        self.client_streaming.get_or_insert(false)
+      }
+
+      pub fn take_client_streaming(&mut self) -> bool {
+        self.client_streaming.take().unwrap_or(false)
+      }
+
+      pub fn has_server_streaming(&self) -> bool {
+        self.server_streaming.is_some()
+      }
+
+      pub fn get_server_streaming(&self) -> bool {
+        self.server_streaming.unwrap_or(false)
+      }
+
+      pub fn set_server_streaming(&mut self, value: bool) {
+        self.server_streaming = Some(value);
+      }
+
+      pub fn clear_server_streaming(&mut self) {
+        self.server_streaming = None;
+      }
+
+      pub fn mut_server_streaming(&mut self) -> &mut bool {
+        self.server_streaming.get_or_insert(false)
+      }
+
+      pub fn take_server_streaming(&mut self) -> bool {
+        self.server_streaming.take().unwrap_or(false)
+      }
+
+      // Fields this message's schema doesn't know, and enum numbers outside
+      // the enum, in the order they were decoded.
+      pub fn get_unknown_fields(&self) -> &[TaggedValue] {
+        &self.unknown_fields
+      }
+    }
+
+    impl Protobuf for MethodDescriptorProto {
+      fn DecodePartial(&mut self, reader: &mut dyn Reader) -> bool {
+        let mut tags = TagIter::new(reader);
+        for tag_option in tags.by_ref() {
+          match tag_option {
+            TaggedValue::Raw(1, value) => {
+              match String::from_utf8(value.into_vec()) {
+                Ok(value) => self.set_name(value),
+                Err(_) => return false
+              }
+            }
+            TaggedValue::Raw(2, value) => {
+              match String::from_utf8(value.into_vec()) {
+                Ok(value) => self.set_input_type(value),
+                Err(_) => return false
+              }
+            }
+            TaggedValue::Raw(3, value) => {
+              match String::from_utf8(value.into_vec()) {
+                Ok(value) => self.set_output_type(value),
+                Err(_) => return false
+              }
+            }
+            TaggedValue::Raw(4, value) => {
+              let mut reader = value;
+              if !self.mut_options().DecodePartial(&mut reader) {
+                return false;
+              }
+            }
+            TaggedValue::Varint(5, value) => {
+              self.set_client_streaming(value != 0);
+            }
+            TaggedValue::Varint(6, value) => {
+              self.set_server_streaming(value != 0);
+            }
+            unknown => self.unknown_fields.push(unknown)
+          }
+        }
+        !tags.is_malformed()
+      }
+
+      fn AppendMissingFields(&self, prefix: &str, missing: &mut Vec<String>) {
+        match self.options {
+          Some(ref value) => {
+            for path in value.missing_fields() {
+              missing.push(format!("{}options.{}", prefix, path));
+            }
+          }
+          None => ()
+        }
+      }
+    }
+
+    impl ProtobufEncode for MethodDescriptorProto {
+      fn Encode(&self, writer: &mut dyn Writer) -> Result<(), EncodeError> {
+        for value in self.name.iter() {
+          EncodeRawField(writer, 1, value.as_bytes())?;
+        }
+        for value in self.input_type.iter() {
+          EncodeRawField(writer, 2, value.as_bytes())?;
+        }
+        for value in self.output_type.iter() {
+          EncodeRawField(writer, 3, value.as_bytes())?;
+        }
+        for value in self.options.iter() {
+          EncodeMessageField(writer, 4, value)?;
+        }
+        for value in self.client_streaming.iter() {
+          EncodeVarintField(writer, 5, *value as u64)?;
+        }
+        for value in self.server_streaming.iter() {
+          EncodeVarintField(writer, 6, *value as u64)?;
+        }
+        for unknown in self.unknown_fields.iter() {
+          EncodeTaggedValue(writer, unknown)?;
+        }
+        Ok(())
+      }
+    }
+
+    pub struct FileOptions {
+      /// Sets the Java package where classes generated from this .proto will be
+      /// placed.  By default, the proto package is used, but this is often
+      /// inappropriate because proto packages do not normally start with backwards
+      /// domain names.
+      java_package: Option<String>,
+      /// If set, all the classes from the .proto file are wrapped in a single
+      /// outer class with the given name.  This applies to both Proto1
+      /// (equivalent to the old "--one_java_file" option) and Proto2 (where
+      /// a .proto always translates to a single class, but you may want to
+      /// explicitly choose the class name).
+      java_outer_classname: Option<String>,
+      /// If set true, then the Java code generator will generate a separate .java
+      /// file for each top-level message, enum, and service defined in the .proto
+      /// file.  Thus, these types will *not* be nested inside the outer class
+      /// named by java_outer_classname.  However, the outer class will still be
+      /// generated to contain the file's getDescriptor() method as well as any
+      /// top-level extensions defined in the file.
+      java_multiple_files: Option<bool>,
+      /// If set true, then the Java code generator will generate equals() and
+      /// hashCode() methods for all messages defined in the .proto file. This is
+      /// purely a speed optimization, as the AbstractMessage base class includes
+      /// reflection-based implementations of these methods.
+      java_generate_equals_and_hash: Option<bool>,
+      /// If set true, then the Java2 code generator will generate code that
+      /// throws an exception whenever an attempt is made to assign a non-UTF-8
+      /// byte sequence to a string field.
+      /// Message reflection will do the same.
+      /// However, an extension field still accepts non-UTF-8 byte sequences.
+      /// This option has no effect on when used with the lite runtime.
+      java_string_check_utf8: Option<bool>,
+      optimize_for: Option<file_options::OptimizeMode>,
+      /// Sets the Go package where structs generated from this .proto will be
+      /// placed.  There is no default.
+      go_package: Option<String>,
+      /// Should generic services be generated in each language?  "Generic" services
+      /// are not specific to any particular RPC system.  They are generated by the
+      /// main code generators in each language (without additional plugins).
+      /// Generic services were the only kind of service generation supported by
+      /// early versions of proto2.
+      ///
+      /// Generic services are now considered deprecated in favor of using plugins
+      /// that generate code specific to your particular RPC system.  Therefore,
+      /// these default to false.  Old code which depends on generic services should
+      /// explicitly set them to true.
+      cc_generic_services: Option<bool>,
+      java_generic_services: Option<bool>,
+      py_generic_services: Option<bool>,
+      php_generic_services: Option<bool>,
+      /// Is this file deprecated?
+      /// Depending on the target platform, this can emit Deprecated annotations
+      /// for everything in the file, or it will be completely ignored; in the very
+      /// least, this is a formalization for deprecating files.
+      deprecated: Option<bool>,
+      /// Enables the use of arenas for the proto messages in this file. This applies
+      /// only to generated classes for C++.
+      cc_enable_arenas: Option<bool>,
+      /// Sets the objective c class prefix which is prepended to all objective c
+      /// generated classes from this .proto. There is no default.
+      objc_class_prefix: Option<String>,
+      /// Namespace for generated classes; defaults to the package.
+      csharp_namespace: Option<String>,
+      /// By default Swift generators will take the proto package and CamelCase it
+      /// replacing '.' with underscore and use that to prefix the types/symbols
+      /// defined. When this options is provided, they will use this value instead
+      /// to prefix the types/symbols defined.
+      swift_prefix: Option<String>,
+      /// Sets the php class prefix which is prepended to all php generated classes
+      /// from this .proto. Default is empty.
+      php_class_prefix: Option<String>,
+      /// Use this option to change the namespace of php generated classes. Default
+      /// is empty. When this option is empty, the package name will be used for
+      /// determining the namespace.
+      php_namespace: Option<String>,
+      /// Use this option to change the namespace of php generated metadata classes.
+      /// Default is empty. When this option is empty, the proto package name will be
+      /// used for determining the namespace.
+      php_metadata_namespace: Option<String>,
+      /// Use this option to change the package of ruby generated classes. Default
+      /// is empty. When this option is not set, the package name will be used for
+      /// determining the ruby package.
+      ruby_package: Option<String>,
+      /// The parser stores options it doesn't recognize here. See above.
+      uninterpreted_option: Vec<UninterpretedOption>,
+      unknown_fields: Vec<TaggedValue>,
+    }
+
+    impl FileOptions {
+      pub const fn new() -> FileOptions {
+        FileOptions {
+          java_package: None,
+          java_outer_classname: None,
+          java_multiple_files: None,
+          java_generate_equals_and_hash: None,
+          java_string_check_utf8: None,
+          optimize_for: None,
+          go_package: None,
+          cc_generic_services: None,
+          java_generic_services: None,
+          py_generic_services: None,
+          php_generic_services: None,
+          deprecated: None,
+          cc_enable_arenas: None,
+          objc_class_prefix: None,
+          csharp_namespace: None,
+          swift_prefix: None,
+          php_class_prefix: None,
+          php_namespace: None,
+          php_metadata_namespace: None,
+          ruby_package: None,
+          uninterpreted_option: Vec::new(),
+          unknown_fields: Vec::new(),
+        }
+      }
+
+      pub fn default_instance() -> &'static FileOptions {
+        static INSTANCE: FileOptions = FileOptions::new();
+        &INSTANCE
+      }
+
+      pub fn has_java_package(&self) -> bool {
+        self.java_package.is_some()
+      }
+
+      pub fn get_java_package(&self) -> &str {
+        self.java_package.as_deref().unwrap_or("")
+      }
+
+      pub fn set_java_package(&mut self, value: String) {
+        self.java_package = Some(value);
+      }
+
+      pub fn clear_java_package(&mut self) {
+        self.java_package = None;
+      }
+
+      pub fn mut_java_package(&mut self) -> &mut String {
+        self.java_package.get_or_insert_with(|| String::from(""))
+      }
+
+      pub fn take_java_package(&mut self) -> String {
+        self.java_package.take().unwrap_or_else(|| String::from(""))
+      }
+
+      pub fn has_java_outer_classname(&self) -> bool {
+        self.java_outer_classname.is_some()
+      }
+
+      pub fn get_java_outer_classname(&self) -> &str {
+        self.java_outer_classname.as_deref().unwrap_or("")
+      }
+
+      pub fn set_java_outer_classname(&mut self, value: String) {
+        self.java_outer_classname = Some(value);
+      }
+
+      pub fn clear_java_outer_classname(&mut self) {
+        self.java_outer_classname = None;
+      }
+
+      pub fn mut_java_outer_classname(&mut self) -> &mut String {
+        self.java_outer_classname.get_or_insert_with(|| String::from(""))
+      }
+
+      pub fn take_java_outer_classname(&mut self) -> String {
+        self.java_outer_classname.take().unwrap_or_else(|| String::from(""))
+      }
+
+      pub fn has_java_multiple_files(&self) -> bool {
+        self.java_multiple_files.is_some()
+      }
+
+      pub fn get_java_multiple_files(&self) -> bool {
+        self.java_multiple_files.unwrap_or(false)
+      }
+
+      pub fn set_java_multiple_files(&mut self, value: bool) {
+        self.java_multiple_files = Some(value);
+      }
+
+      pub fn clear_java_multiple_files(&mut self) {
+        self.java_multiple_files = None;
+      }
+
+      pub fn mut_java_multiple_files(&mut self) -> &mut bool {
+        self.java_multiple_files.get_or_insert(false)
+      }
+
+      pub fn take_java_multiple_files(&mut self) -> bool {
+        self.java_multiple_files.take().unwrap_or(false)
+      }
+
+      pub fn has_java_generate_equals_and_hash(&self) -> bool {
+        self.java_generate_equals_and_hash.is_some()
+      }
+
+      pub fn get_java_generate_equals_and_hash(&self) -> bool {
+        self.java_generate_equals_and_hash.unwrap_or(false)
+      }
+
+      pub fn set_java_generate_equals_and_hash(&mut self, value: bool) {
+        self.java_generate_equals_and_hash = Some(value);
+      }
+
+      pub fn clear_java_generate_equals_and_hash(&mut self) {
+        self.java_generate_equals_and_hash = None;
+      }
+
+      pub fn mut_java_generate_equals_and_hash(&mut self) -> &mut bool {
+        self.java_generate_equals_and_hash.get_or_insert(false)
+      }
+
+      pub fn take_java_generate_equals_and_hash(&mut self) -> bool {
+        self.java_generate_equals_and_hash.take().unwrap_or(false)
+      }
+
+      pub fn has_java_string_check_utf8(&self) -> bool {
+        self.java_string_check_utf8.is_some()
+      }
+
+      pub fn get_java_string_check_utf8(&self) -> bool {
+        self.java_string_check_utf8.unwrap_or(false)
+      }
+
+      pub fn set_java_string_check_utf8(&mut self, value: bool) {
+        self.java_string_check_utf8 = Some(value);
+      }
+
+      pub fn clear_java_string_check_utf8(&mut self) {
+        self.java_string_check_utf8 = None;
+      }
+
+      pub fn mut_java_string_check_utf8(&mut self) -> &mut bool {
+        self.java_string_check_utf8.get_or_insert(false)
+      }
+
+      pub fn take_java_string_check_utf8(&mut self) -> bool {
+        self.java_string_check_utf8.take().unwrap_or(false)
+      }
+
+      pub fn has_optimize_for(&self) -> bool {
+        self.optimize_for.is_some()
+      }
+
+      pub fn get_optimize_for(&self) -> file_options::OptimizeMode {
+        self.optimize_for.unwrap_or(file_options::Speed)
+      }
+
+      pub fn set_optimize_for(&mut self, value: file_options::OptimizeMode) {
+        self.optimize_for = Some(value);
+      }
+
+      pub fn clear_optimize_for(&mut self) {
+        self.optimize_for = None;
+      }
+
+      pub fn mut_optimize_for(&mut self) -> &mut file_options::OptimizeMode {
+        self.optimize_for.get_or_insert(file_options::Speed)
+      }
+
+      pub fn take_optimize_for(&mut self) -> file_options::OptimizeMode {
+        self.optimize_for.take().unwrap_or(file_options::Speed)
+      }
+
+      pub fn has_go_package(&self) -> bool {
+        self.go_package.is_some()
+      }
+
+      pub fn get_go_package(&self) -> &str {
+        self.go_package.as_deref().unwrap_or("")
+      }
+
+      pub fn set_go_package(&mut self, value: String) {
+        self.go_package = Some(value);
+      }
+
+      pub fn clear_go_package(&mut self) {
+        self.go_package = None;
+      }
+
+      pub fn mut_go_package(&mut self) -> &mut String {
+        self.go_package.get_or_insert_with(|| String::from(""))
+      }
+
+      pub fn take_go_package(&mut self) -> String {
+        self.go_package.take().unwrap_or_else(|| String::from(""))
+      }
+
+      pub fn has_cc_generic_services(&self) -> bool {
+        self.cc_generic_services.is_some()
+      }
+
+      pub fn get_cc_generic_services(&self) -> bool {
+        self.cc_generic_services.unwrap_or(false)
+      }
+
+      pub fn set_cc_generic_services(&mut self, value: bool) {
+        self.cc_generic_services = Some(value);
+      }
+
+      pub fn clear_cc_generic_services(&mut self) {
+        self.cc_generic_services = None;
+      }
+
+      pub fn mut_cc_generic_services(&mut self) -> &mut bool {
+        self.cc_generic_services.get_or_insert(false)
+      }
+
+      pub fn take_cc_generic_services(&mut self) -> bool {
+        self.cc_generic_services.take().unwrap_or(false)
+      }
+
+      pub fn has_java_generic_services(&self) -> bool {
+        self.java_generic_services.is_some()
+      }
+
+      pub fn get_java_generic_services(&self) -> bool {
+        self.java_generic_services.unwrap_or(false)
+      }
+
+      pub fn set_java_generic_services(&mut self, value: bool) {
+        self.java_generic_services = Some(value);
+      }
+
+      pub fn clear_java_generic_services(&mut self) {
+        self.java_generic_services = None;
+      }
+
+      pub fn mut_java_generic_services(&mut self) -> &mut bool {
+        self.java_generic_services.get_or_insert(false)
+      }
+
+      pub fn take_java_generic_services(&mut self) -> bool {
+        self.java_generic_services.take().unwrap_or(false)
+      }
+
+      pub fn has_py_generic_services(&self) -> bool {
+        self.py_generic_services.is_some()
+      }
+
+      pub fn get_py_generic_services(&self) -> bool {
+        self.py_generic_services.unwrap_or(false)
+      }
+
+      pub fn set_py_generic_services(&mut self, value: bool) {
+        self.py_generic_services = Some(value);
+      }
+
+      pub fn clear_py_generic_services(&mut self) {
+        self.py_generic_services = None;
+      }
+
+      pub fn mut_py_generic_services(&mut self) -> &mut bool {
+        self.py_generic_services.get_or_insert(false)
+      }
+
+      pub fn take_py_generic_services(&mut self) -> bool {
+        self.py_generic_services.take().unwrap_or(false)
+      }
+
+      pub fn has_php_generic_services(&self) -> bool {
+        self.php_generic_services.is_some()
+      }
+
+      pub fn get_php_generic_services(&self) -> bool {
+        self.php_generic_services.unwrap_or(false)
+      }
+
+      pub fn set_php_generic_services(&mut self, value: bool) {
+        self.php_generic_services = Some(value);
+      }
+
+      pub fn clear_php_generic_services(&mut self) {
+        self.php_generic_services = None;
+      }
+
+      pub fn mut_php_generic_services(&mut self) -> &mut bool {
+        self.php_generic_services.get_or_insert(false)
+      }
+
+      pub fn take_php_generic_services(&mut self) -> bool {
+        self.php_generic_services.take().unwrap_or(false)
+      }
+
+      pub fn has_deprecated(&self) -> bool {
+        self.deprecated.is_some()
+      }
+
+      pub fn get_deprecated(&self) -> bool {
+        self.deprecated.unwrap_or(false)
+      }
+
+      pub fn set_deprecated(&mut self, value: bool) {
+        self.deprecated = Some(value);
+      }
+
+      pub fn clear_deprecated(&mut self) {
+        self.deprecated = None;
+      }
+
+      pub fn mut_deprecated(&mut self) -> &mut bool {
+        self.deprecated.get_or_insert(false)
+      }
+
+      pub fn take_deprecated(&mut self) -> bool {
+        self.deprecated.take().unwrap_or(false)
+      }
+
+      pub fn has_cc_enable_arenas(&self) -> bool {
+        self.cc_enable_arenas.is_some()
+      }
+
+      pub fn get_cc_enable_arenas(&self) -> bool {
+        self.cc_enable_arenas.unwrap_or(true)
+      }
+
+      pub fn set_cc_enable_arenas(&mut self, value: bool) {
+        self.cc_enable_arenas = Some(value);
+      }
+
+      pub fn clear_cc_enable_arenas(&mut self) {
+        self.cc_enable_arenas = None;
+      }
+
+      pub fn mut_cc_enable_arenas(&mut self) -> &mut bool {
+        self.cc_enable_arenas.get_or_insert(true)
+      }
+
+      pub fn take_cc_enable_arenas(&mut self) -> bool {
+        self.cc_enable_arenas.take().unwrap_or(true)
+      }
+
+      pub fn has_objc_class_prefix(&self) -> bool {
+        self.objc_class_prefix.is_some()
+      }
+
+      pub fn get_objc_class_prefix(&self) -> &str {
+        self.objc_class_prefix.as_deref().unwrap_or("")
+      }
+
+      pub fn set_objc_class_prefix(&mut self, value: String) {
+        self.objc_class_prefix = Some(value);
+      }
+
+      pub fn clear_objc_class_prefix(&mut self) {
+        self.objc_class_prefix = None;
+      }
+
+      pub fn mut_objc_class_prefix(&mut self) -> &mut String {
+        self.objc_class_prefix.get_or_insert_with(|| String::from(""))
+      }
+
+      pub fn take_objc_class_prefix(&mut self) -> String {
+        self.objc_class_prefix.take().unwrap_or_else(|| String::from(""))
+      }
+
+      pub fn has_csharp_namespace(&self) -> bool {
+        self.csharp_namespace.is_some()
+      }
+
+      pub fn get_csharp_namespace(&self) -> &str {
+        self.csharp_namespace.as_deref().unwrap_or("")
+      }
+
+      pub fn set_csharp_namespace(&mut self, value: String) {
+        self.csharp_namespace = Some(value);
+      }
+
+      pub fn clear_csharp_namespace(&mut self) {
+        self.csharp_namespace = None;
+      }
+
+      pub fn mut_csharp_namespace(&mut self) -> &mut String {
+        self.csharp_namespace.get_or_insert_with(|| String::from(""))
+      }
+
+      pub fn take_csharp_namespace(&mut self) -> String {
+        self.csharp_namespace.take().unwrap_or_else(|| String::from(""))
+      }
+
+      pub fn has_swift_prefix(&self) -> bool {
+        self.swift_prefix.is_some()
+      }
+
+      pub fn get_swift_prefix(&self) -> &str {
+        self.swift_prefix.as_deref().unwrap_or("")
+      }
+
+      pub fn set_swift_prefix(&mut self, value: String) {
+        self.swift_prefix = Some(value);
+      }
+
+      pub fn clear_swift_prefix(&mut self) {
+        self.swift_prefix = None;
+      }
+
+      pub fn mut_swift_prefix(&mut self) -> &mut String {
+        self.swift_prefix.get_or_insert_with(|| String::from(""))
+      }
+
+      pub fn take_swift_prefix(&mut self) -> String {
+        self.swift_prefix.take().unwrap_or_else(|| String::from(""))
+      }
+
+      pub fn has_php_class_prefix(&self) -> bool {
+        self.php_class_prefix.is_some()
+      }
+
+      pub fn get_php_class_prefix(&self) -> &str {
+        self.php_class_prefix.as_deref().unwrap_or("")
+      }
+
+      pub fn set_php_class_prefix(&mut self, value: String) {
+        self.php_class_prefix = Some(value);
+      }
+
+      pub fn clear_php_class_prefix(&mut self) {
+        self.php_class_prefix = None;
+      }
+
+      pub fn mut_php_class_prefix(&mut self) -> &mut String {
+        self.php_class_prefix.get_or_insert_with(|| String::from(""))
+      }
+
+      pub fn take_php_class_prefix(&mut self) -> String {
+        self.php_class_prefix.take().unwrap_or_else(|| String::from(""))
+      }
+
+      pub fn has_php_namespace(&self) -> bool {
+        self.php_namespace.is_some()
+      }
+
+      pub fn get_php_namespace(&self) -> &str {
+        self.php_namespace.as_deref().unwrap_or("")
+      }
+
+      pub fn set_php_namespace(&mut self, value: String) {
+        self.php_namespace = Some(value);
+      }
+
+      pub fn clear_php_namespace(&mut self) {
+        self.php_namespace = None;
+      }
+
+      pub fn mut_php_namespace(&mut self) -> &mut String {
+        self.php_namespace.get_or_insert_with(|| String::from(""))
+      }
+
+      pub fn take_php_namespace(&mut self) -> String {
+        self.php_namespace.take().unwrap_or_else(|| String::from(""))
+      }
+
+      pub fn has_php_metadata_namespace(&self) -> bool {
+        self.php_metadata_namespace.is_some()
+      }
+
+      pub fn get_php_metadata_namespace(&self) -> &str {
+        self.php_metadata_namespace.as_deref().unwrap_or("")
+      }
+
+      pub fn set_php_metadata_namespace(&mut self, value: String) {
+        self.php_metadata_namespace = Some(value);
+      }
+
+      pub fn clear_php_metadata_namespace(&mut self) {
+        self.php_metadata_namespace = None;
+      }
+
+      pub fn mut_php_metadata_namespace(&mut self) -> &mut String {
+        self.php_metadata_namespace.get_or_insert_with(|| String::from(""))
+      }
+
+      pub fn take_php_metadata_namespace(&mut self) -> String {
+        self.php_metadata_namespace.take().unwrap_or_else(|| String::from(""))
+      }
+
+      pub fn has_ruby_package(&self) -> bool {
+        self.ruby_package.is_some()
+      }
+
+      pub fn get_ruby_package(&self) -> &str {
+        self.ruby_package.as_deref().unwrap_or("")
+      }
+
+      pub fn set_ruby_package(&mut self, value: String) {
+        self.ruby_package = Some(value);
+      }
+
+      pub fn clear_ruby_package(&mut self) {
+        self.ruby_package = None;
+      }
+
+      pub fn mut_ruby_package(&mut self) -> &mut String {
+        self.ruby_package.get_or_insert_with(|| String::from(""))
+      }
+
+      pub fn take_ruby_package(&mut self) -> String {
+        self.ruby_package.take().unwrap_or_else(|| String::from(""))
+      }
+
+      pub fn get_uninterpreted_option(&self) -> &[UninterpretedOption] {
+        &self.uninterpreted_option
+      }
+
+      pub fn set_uninterpreted_option(&mut self, value: Vec<UninterpretedOption>) {
+        self.uninterpreted_option = value;
+      }
+
+      pub fn clear_uninterpreted_option(&mut self) {
+        self.uninterpreted_option.clear();
+      }
+
+      pub fn mut_uninterpreted_option(&mut self) -> &mut Vec<UninterpretedOption> {
+        &mut self.uninterpreted_option
+      }
+
+      pub fn take_uninterpreted_option(&mut self) -> Vec<UninterpretedOption> {
+        core::mem::take(&mut self.uninterpreted_option)
+      }
+
+      pub fn add_uninterpreted_option(&mut self, value: UninterpretedOption) {
+        self.uninterpreted_option.push(value);
+      }
+
+      pub fn uninterpreted_option_len(&self) -> usize {
+        self.uninterpreted_option.len()
+      }
+
+      // Fields this message's schema doesn't know, and enum numbers outside
+      // the enum, in the order they were decoded.
+      pub fn get_unknown_fields(&self) -> &[TaggedValue] {
+        &self.unknown_fields
+      }
+    }
+
+    pub mod file_options {
+      #[allow(unused_imports)]
+      use ::protobuf::{
+        Protobuf, Reader, TagIter, TaggedValue,
+        DecodeZigZag32, DecodeZigZag64, DecodeFloat, DecodeDouble,
+        DecodePackedVarints, DecodePackedFixed32, DecodePackedFixed64,
+        ProtobufEncode, Writer, EncodeError,
+        EncodeTaggedValue, EncodeMessageField, EncodeRawField,
+        EncodeVarintField, EncodeFixed32Field, EncodeFixed64Field,
+        EncodeZigZag32, EncodeZigZag64, EncodeFloat, EncodeDouble,
+      };
+      #[allow(unused_imports)]
+      use ::protobuf::prelude::*;
+
+      /// Generated classes can be optimized for speed or code size.
+      #[derive(Clone, Copy, PartialEq, Eq, Debug)]
+      pub enum OptimizeMode {
+        /// Generate complete code for parsing, serialization,
+        Speed = 1,
+        /// etc.
+        ///
+        /// Use ReflectionOps to implement these methods.
+        CodeSize = 2,
+        /// Generate code using MessageLite and the lite runtime.
+        LiteRuntime = 3,
+      }
+
+      pub use self::OptimizeMode::{Speed, CodeSize, LiteRuntime};
+
+      impl OptimizeMode {
+        pub fn from_i32(value: i32) -> Option<OptimizeMode> {
+          match value {
+            1 => Some(Speed),
+            2 => Some(CodeSize),
+            3 => Some(LiteRuntime),
+            _ => None
+          }
+        }
+
+        pub fn value(&self) -> i32 {
+          *self as i32
+        }
+
+        pub fn name(&self) -> &'static str {
+          match *self {
+            Speed => "SPEED",
+            CodeSize => "CODE_SIZE",
+            LiteRuntime => "LITE_RUNTIME",
+          }
+        }
+
+        pub fn from_name(name: &str) -> Option<OptimizeMode> {
+          match name {
+            "SPEED" => Some(Speed),
+            "CODE_SIZE" => Some(CodeSize),
+            "LITE_RUNTIME" => Some(LiteRuntime),
+            _ => None
+          }
+        }
+      }
+
+      impl Default for OptimizeMode {
+        fn default() -> OptimizeMode {
+          Speed
+        }
+      }
+
+    }
+
+    impl Protobuf for FileOptions {
+      fn DecodePartial(&mut self, reader: &mut dyn Reader) -> bool {
+        let mut tags = TagIter::new(reader);
+        for tag_option in tags.by_ref() {
+          match tag_option {
+            TaggedValue::Raw(1, value) => {
+              match String::from_utf8(value.into_vec()) {
+                Ok(value) => self.set_java_package(value),
+                Err(_) => return false
+              }
+            }
+            TaggedValue::Raw(8, value) => {
+              match String::from_utf8(value.into_vec()) {
+                Ok(value) => self.set_java_outer_classname(value),
+                Err(_) => return false
+              }
+            }
+            TaggedValue::Varint(10, value) => {
+              self.set_java_multiple_files(value != 0);
+            }
+            TaggedValue::Varint(20, value) => {
+              self.set_java_generate_equals_and_hash(value != 0);
+            }
+            TaggedValue::Varint(27, value) => {
+              self.set_java_string_check_utf8(value != 0);
+            }
+            TaggedValue::Varint(9, value) => {
+              match file_options::OptimizeMode::from_i32(value as i32) {
+                Some(value) => self.set_optimize_for(value),
+                None => self.unknown_fields.push(TaggedValue::Varint(9, value))
+              }
+            }
+            TaggedValue::Raw(11, value) => {
+              match String::from_utf8(value.into_vec()) {
+                Ok(value) => self.set_go_package(value),
+                Err(_) => return false
+              }
+            }
+            TaggedValue::Varint(16, value) => {
+              self.set_cc_generic_services(value != 0);
+            }
+            TaggedValue::Varint(17, value) => {
+              self.set_java_generic_services(value != 0);
+            }
+            TaggedValue::Varint(18, value) => {
+              self.set_py_generic_services(value != 0);
+            }
+            TaggedValue::Varint(42, value) => {
+              self.set_php_generic_services(value != 0);
+            }
+            TaggedValue::Varint(23, value) => {
+              self.set_deprecated(value != 0);
+            }
+            TaggedValue::Varint(31, value) => {
+              self.set_cc_enable_arenas(value != 0);
+            }
+            TaggedValue::Raw(36, value) => {
+              match String::from_utf8(value.into_vec()) {
+                Ok(value) => self.set_objc_class_prefix(value),
+                Err(_) => return false
+              }
+            }
+            TaggedValue::Raw(37, value) => {
+              match String::from_utf8(value.into_vec()) {
+                Ok(value) => self.set_csharp_namespace(value),
+                Err(_) => return false
+              }
+            }
+            TaggedValue::Raw(39, value) => {
+              match String::from_utf8(value.into_vec()) {
+                Ok(value) => self.set_swift_prefix(value),
+                Err(_) => return false
+              }
+            }
+            TaggedValue::Raw(40, value) => {
+              match String::from_utf8(value.into_vec()) {
+                Ok(value) => self.set_php_class_prefix(value),
+                Err(_) => return false
+              }
+            }
+            TaggedValue::Raw(41, value) => {
+              match String::from_utf8(value.into_vec()) {
+                Ok(value) => self.set_php_namespace(value),
+                Err(_) => return false
+              }
+            }
+            TaggedValue::Raw(44, value) => {
+              match String::from_utf8(value.into_vec()) {
+                Ok(value) => self.set_php_metadata_namespace(value),
+                Err(_) => return false
+              }
+            }
+            TaggedValue::Raw(45, value) => {
+              match String::from_utf8(value.into_vec()) {
+                Ok(value) => self.set_ruby_package(value),
+                Err(_) => return false
+              }
+            }
+            TaggedValue::Raw(999, value) => {
+              let mut reader = value;
+              let mut message = UninterpretedOption::new();
+              if !message.DecodePartial(&mut reader) {
+                return false;
+              }
+              self.add_uninterpreted_option(message);
+            }
+            unknown => self.unknown_fields.push(unknown)
+          }
+        }
+        !tags.is_malformed()
+      }
+
+      fn AppendMissingFields(&self, prefix: &str, missing: &mut Vec<String>) {
+        for (i, value) in self.uninterpreted_option.iter().enumerate() {
+          for path in value.missing_fields() {
+            missing.push(format!("{}uninterpreted_option[{}].{}", prefix, i, path));
+          }
+        }
+      }
+    }
+
+    impl ProtobufEncode for FileOptions {
+      fn Encode(&self, writer: &mut dyn Writer) -> Result<(), EncodeError> {
+        for value in self.java_package.iter() {
+          EncodeRawField(writer, 1, value.as_bytes())?;
+        }
+        for value in self.java_outer_classname.iter() {
+          EncodeRawField(writer, 8, value.as_bytes())?;
+        }
+        for value in self.optimize_for.iter() {
+          EncodeVarintField(writer, 9, value.value() as i64 as u64)?;
+        }
+        for value in self.java_multiple_files.iter() {
+          EncodeVarintField(writer, 10, *value as u64)?;
+        }
+        for value in self.go_package.iter() {
+          EncodeRawField(writer, 11, value.as_bytes())?;
+        }
+        for value in self.cc_generic_services.iter() {
+          EncodeVarintField(writer, 16, *value as u64)?;
+        }
+        for value in self.java_generic_services.iter() {
+          EncodeVarintField(writer, 17, *value as u64)?;
+        }
+        for value in self.py_generic_services.iter() {
+          EncodeVarintField(writer, 18, *value as u64)?;
+        }
+        for value in self.java_generate_equals_and_hash.iter() {
+          EncodeVarintField(writer, 20, *value as u64)?;
+        }
+        for value in self.deprecated.iter() {
+          EncodeVarintField(writer, 23, *value as u64)?;
+        }
+        for value in self.java_string_check_utf8.iter() {
+          EncodeVarintField(writer, 27, *value as u64)?;
+        }
+        for value in self.cc_enable_arenas.iter() {
+          EncodeVarintField(writer, 31, *value as u64)?;
+        }
+        for value in self.objc_class_prefix.iter() {
+          EncodeRawField(writer, 36, value.as_bytes())?;
+        }
+        for value in self.csharp_namespace.iter() {
+          EncodeRawField(writer, 37, value.as_bytes())?;
+        }
+        for value in self.swift_prefix.iter() {
+          EncodeRawField(writer, 39, value.as_bytes())?;
+        }
+        for value in self.php_class_prefix.iter() {
+          EncodeRawField(writer, 40, value.as_bytes())?;
+        }
+        for value in self.php_namespace.iter() {
+          EncodeRawField(writer, 41, value.as_bytes())?;
+        }
+        for value in self.php_generic_services.iter() {
+          EncodeVarintField(writer, 42, *value as u64)?;
+        }
+        for value in self.php_metadata_namespace.iter() {
+          EncodeRawField(writer, 44, value.as_bytes())?;
+        }
+        for value in self.ruby_package.iter() {
+          EncodeRawField(writer, 45, value.as_bytes())?;
+        }
+        for value in self.uninterpreted_option.iter() {
+          EncodeMessageField(writer, 999, value)?;
+        }
+        for unknown in self.unknown_fields.iter() {
+          EncodeTaggedValue(writer, unknown)?;
+        }
+        Ok(())
+      }
+    }
+
+    pub struct MessageOptions {
+      /// Set true to use the old proto1 MessageSet wire format for extensions.
+      /// This is provided for backwards-compatibility with the MessageSet wire
+      /// format.  You should not use this for any other reason:  It's less
+      /// efficient, has fewer features, and is more complicated.
+      ///
+      /// The message must be defined exactly as follows:
+      ///   message Foo {
+      ///     option message_set_wire_format = true;
+      ///     extensions 4 to max;
+      ///   }
+      /// Note that the message cannot have any defined fields; MessageSets only
+      /// have extensions.
+      ///
+      /// All extensions of your type must be singular messages; e.g. they cannot
+      /// be int32s, enums, or repeated messages.
+      ///
+      /// Because this is an option, the above two restrictions are not enforced by
+      /// the protocol compiler.
+      message_set_wire_format: Option<bool>,
+      /// Disables the generation of the standard "descriptor()" accessor, which can
+      /// conflict with a field of the same name.  This is meant to make migration
+      /// from proto1 easier; new code should avoid fields named "descriptor".
+      no_standard_descriptor_accessor: Option<bool>,
+      /// Is this message deprecated?
+      /// Depending on the target platform, this can emit Deprecated annotations
+      /// for the message, or it will be completely ignored; in the very least,
+      /// this is a formalization for deprecating messages.
+      deprecated: Option<bool>,
+      /// Whether the message is an automatically generated map entry type for the
+      /// maps field.
+      ///
+      /// For maps fields:
+      ///     map<KeyType, ValueType> map_field = 1;
+      /// The parsed descriptor looks like:
+      ///     message MapFieldEntry {
+      ///         option map_entry = true;
+      ///         optional KeyType key = 1;
+      ///         optional ValueType value = 2;
+      ///     }
+      ///     repeated MapFieldEntry map_field = 1;
+      ///
+      /// Implementations may choose not to generate the map_entry=true message, but
+      /// use a native map in the target language to hold the keys and values.
+      ///
+      /// NOTE: Do not set the option in .proto files. Always use the maps syntax
+      /// instead. The option should only be implicitly set by the proto compiler
+      /// parser.
+      map_entry: Option<bool>,
+      /// The parser stores options it doesn't recognize here. See above.
+      uninterpreted_option: Vec<UninterpretedOption>,
+      unknown_fields: Vec<TaggedValue>,
+    }
+
+    impl MessageOptions {
+      pub const fn new() -> MessageOptions {
+        MessageOptions {
+          message_set_wire_format: None,
+          no_standard_descriptor_accessor: None,
+          deprecated: None,
+          map_entry: None,
+          uninterpreted_option: Vec::new(),
+          unknown_fields: Vec::new(),
+        }
+      }
+
+      pub fn default_instance() -> &'static MessageOptions {
+        static INSTANCE: MessageOptions = MessageOptions::new();
+        &INSTANCE
+      }
+
+      pub fn has_message_set_wire_format(&self) -> bool {
+        self.message_set_wire_format.is_some()
+      }
+
+      pub fn get_message_set_wire_format(&self) -> bool {
+        self.message_set_wire_format.unwrap_or(false)
+      }
+
+      pub fn set_message_set_wire_format(&mut self, value: bool) {
+        self.message_set_wire_format = Some(value);
+      }
+
+      pub fn clear_message_set_wire_format(&mut self) {
+        self.message_set_wire_format = None;
+      }
+
+      pub fn mut_message_set_wire_format(&mut self) -> &mut bool {
+        self.message_set_wire_format.get_or_insert(false)
+      }
+
+      pub fn take_message_set_wire_format(&mut self) -> bool {
+        self.message_set_wire_format.take().unwrap_or(false)
+      }
+
+      pub fn has_no_standard_descriptor_accessor(&self) -> bool {
+        self.no_standard_descriptor_accessor.is_some()
+      }
+
+      pub fn get_no_standard_descriptor_accessor(&self) -> bool {
+        self.no_standard_descriptor_accessor.unwrap_or(false)
+      }
+
+      pub fn set_no_standard_descriptor_accessor(&mut self, value: bool) {
+        self.no_standard_descriptor_accessor = Some(value);
+      }
+
+      pub fn clear_no_standard_descriptor_accessor(&mut self) {
+        self.no_standard_descriptor_accessor = None;
+      }
+
+      pub fn mut_no_standard_descriptor_accessor(&mut self) -> &mut bool {
+        self.no_standard_descriptor_accessor.get_or_insert(false)
+      }
+
+      pub fn take_no_standard_descriptor_accessor(&mut self) -> bool {
+        self.no_standard_descriptor_accessor.take().unwrap_or(false)
+      }
+
+      pub fn has_deprecated(&self) -> bool {
+        self.deprecated.is_some()
+      }
+
+      pub fn get_deprecated(&self) -> bool {
+        self.deprecated.unwrap_or(false)
+      }
+
+      pub fn set_deprecated(&mut self, value: bool) {
+        self.deprecated = Some(value);
+      }
+
+      pub fn clear_deprecated(&mut self) {
+        self.deprecated = None;
+      }
+
+      pub fn mut_deprecated(&mut self) -> &mut bool {
+        self.deprecated.get_or_insert(false)
+      }
+
+      pub fn take_deprecated(&mut self) -> bool {
+        self.deprecated.take().unwrap_or(false)
+      }
+
+      pub fn has_map_entry(&self) -> bool {
+        self.map_entry.is_some()
+      }
+
+      pub fn get_map_entry(&self) -> bool {
+        self.map_entry.unwrap_or(false)
+      }
+
+      pub fn set_map_entry(&mut self, value: bool) {
+        self.map_entry = Some(value);
+      }
+
+      pub fn clear_map_entry(&mut self) {
+        self.map_entry = None;
+      }
+
+      pub fn mut_map_entry(&mut self) -> &mut bool {
+        self.map_entry.get_or_insert(false)
+      }
+
+      pub fn take_map_entry(&mut self) -> bool {
+        self.map_entry.take().unwrap_or(false)
+      }
+
+      pub fn get_uninterpreted_option(&self) -> &[UninterpretedOption] {
+        &self.uninterpreted_option
+      }
+
+      pub fn set_uninterpreted_option(&mut self, value: Vec<UninterpretedOption>) {
+        self.uninterpreted_option = value;
+      }
+
+      pub fn clear_uninterpreted_option(&mut self) {
+        self.uninterpreted_option.clear();
+      }
+
+      pub fn mut_uninterpreted_option(&mut self) -> &mut Vec<UninterpretedOption> {
+        &mut self.uninterpreted_option
+      }
+
+      pub fn take_uninterpreted_option(&mut self) -> Vec<UninterpretedOption> {
+        core::mem::take(&mut self.uninterpreted_option)
+      }
+
+      pub fn add_uninterpreted_option(&mut self, value: UninterpretedOption) {
+        self.uninterpreted_option.push(value);
+      }
+
+      pub fn uninterpreted_option_len(&self) -> usize {
+        self.uninterpreted_option.len()
+      }
+
+      // Fields this message's schema doesn't know, and enum numbers outside
+      // the enum, in the order they were decoded.
+      pub fn get_unknown_fields(&self) -> &[TaggedValue] {
+        &self.unknown_fields
+      }
+    }
+
+    impl Protobuf for MessageOptions {
+      fn DecodePartial(&mut self, reader: &mut dyn Reader) -> bool {
+        let mut tags = TagIter::new(reader);
+        for tag_option in tags.by_ref() {
+          match tag_option {
+            TaggedValue::Varint(1, value) => {
+              self.set_message_set_wire_format(value != 0);
+            }
+            TaggedValue::Varint(2, value) => {
+              self.set_no_standard_descriptor_accessor(value != 0);
+            }
+            TaggedValue::Varint(3, value) => {
+              self.set_deprecated(value != 0);
+            }
+            TaggedValue::Varint(7, value) => {
+              self.set_map_entry(value != 0);
+            }
+            TaggedValue::Raw(999, value) => {
+              let mut reader = value;
+              let mut message = UninterpretedOption::new();
+              if !message.DecodePartial(&mut reader) {
+                return false;
+              }
+              self.add_uninterpreted_option(message);
+            }
+            unknown => self.unknown_fields.push(unknown)
+          }
+        }
+        !tags.is_malformed()
+      }
+
+      fn AppendMissingFields(&self, prefix: &str, missing: &mut Vec<String>) {
+        for (i, value) in self.uninterpreted_option.iter().enumerate() {
+          for path in value.missing_fields() {
+            missing.push(format!("{}uninterpreted_option[{}].{}", prefix, i, path));
+          }
+        }
+      }
+    }
+
+    impl ProtobufEncode for MessageOptions {
+      fn Encode(&self, writer: &mut dyn Writer) -> Result<(), EncodeError> {
+        for value in self.message_set_wire_format.iter() {
+          EncodeVarintField(writer, 1, *value as u64)?;
+        }
+        for value in self.no_standard_descriptor_accessor.iter() {
+          EncodeVarintField(writer, 2, *value as u64)?;
+        }
+        for value in self.deprecated.iter() {
+          EncodeVarintField(writer, 3, *value as u64)?;
+        }
+        for value in self.map_entry.iter() {
+          EncodeVarintField(writer, 7, *value as u64)?;
+        }
+        for value in self.uninterpreted_option.iter() {
+          EncodeMessageField(writer, 999, value)?;
+        }
+        for unknown in self.unknown_fields.iter() {
+          EncodeTaggedValue(writer, unknown)?;
+        }
+        Ok(())
+      }
+    }
+
+    pub struct FieldOptions {
+      /// The ctype option instructs the C++ code generator to use a different
+      /// representation of the field than it normally would.  See the specific
+      /// options below.  This option is not yet implemented in the open source
+      /// release -- sorry, we'll try to include it in a future version!
+      ctype: Option<field_options::CType>,
+      /// The packed option can be enabled for repeated primitive fields to enable
+      /// a more efficient representation on the wire. Rather than repeatedly
+      /// writing the tag and type for each element, the entire array is encoded as
+      /// a single length-delimited blob.
+      packed: Option<bool>,
+      /// The jstype option determines the JavaScript type used for values of the
+      /// field.  The option is permitted only for 64 bit integral and fixed types
+      /// (int64, uint64, sint64, fixed64, sfixed64).  A field with jstype JS_STRING
+      /// is represented as JavaScript string, which avoids loss of precision that
+      /// can happen when a large value is converted to a floating point JavaScript.
+      /// Specifying JS_NUMBER for the jstype causes the generated JavaScript code to
+      /// use the JavaScript "number" type.  The behavior of the default option
+      /// JS_NORMAL is implementation dependent.
+      jstype: Option<field_options::JsType>,
+      /// Should this field be parsed lazily?  Lazy applies only to message-type
+      /// fields.  It means that when the outer message is initially parsed, the
+      /// inner message's contents will not be parsed but instead stored in encoded
+      /// form.  The inner message will actually be parsed when it is first accessed.
+      ///
+      /// This is only a hint.  Implementations are free to choose whether to use
+      /// eager or lazy parsing regardless of the value of this option.  However,
+      /// setting this option true suggests that the protocol author believes that
+      /// using lazy parsing on this field is worth the additional bookkeeping
+      /// overhead typically needed to implement it.
+      ///
+      /// This option does not affect the public interface of any generated code;
+      /// all method signatures remain the same.  Furthermore, thread-safety of the
+      /// interface is not affected by this option; const methods remain safe to
+      /// call from multiple threads concurrently, while non-const methods continue
+      /// to require exclusive access.
+      ///
+      ///
+      /// Note that implementations may choose not to check required fields within
+      /// a lazy sub-message.  That is, calling IsInitialized() on the outher message
+      /// may return true even if the inner message has missing required fields.
+      /// This is necessary because otherwise the inner message would have to be
+      /// parsed in order to perform the check, defeating the purpose of lazy
+      /// parsing.  An implementation which chooses not to check required fields
+      /// must be consistent about it.  That is, for any particular sub-message, the
+      /// implementation must either *always* check its required fields, or *never*
+      /// check its required fields, regardless of whether or not the message has
+      /// been parsed.
+      lazy: Option<bool>,
+      /// unverified_lazy does no correctness checks on the byte stream. This should
+      /// only be used where lazy with verification is prohibitive for performance
+      /// reasons.
+      unverified_lazy: Option<bool>,
+      /// Is this field deprecated?
+      /// Depending on the target platform, this can emit Deprecated annotations
+      /// for accessors, or it will be completely ignored; in the very least, this
+      /// is a formalization for deprecating fields.
+      deprecated: Option<bool>,
+      /// EXPERIMENTAL.  DO NOT USE.
+      /// For "map" fields, the name of the field in the enclosed type that
+      /// is the key for this map.  For example, suppose we have:
+      ///   message Item {
+      ///     required string name = 1;
+      ///     required string value = 2;
+      ///   }
+      ///   message Config {
+      ///     repeated Item items = 1 [experimental_map_key="name"];
+      ///   }
+      /// In this situation, the map key for Item will be set to "name".
+      /// TODO: Fully-implement this, then remove the "experimental_" prefix.
+      experimental_map_key: Option<String>,
+      /// For Google-internal migration only. Do not use.
+      weak: Option<bool>,
+      /// Indicate that the field value should not be printed out when using debug
+      /// formats, e.g. when the field contains sensitive credentials.
+      debug_redact: Option<bool>,
+      /// The parser stores options it doesn't recognize here. See above.
+      uninterpreted_option: Vec<UninterpretedOption>,
+      unknown_fields: Vec<TaggedValue>,
+    }
+
+    impl FieldOptions {
+      pub const fn new() -> FieldOptions {
+        FieldOptions {
+          ctype: None,
+          packed: None,
+          jstype: None,
+          lazy: None,
+          unverified_lazy: None,
+          deprecated: None,
+          experimental_map_key: None,
+          weak: None,
+          debug_redact: None,
+          uninterpreted_option: Vec::new(),
+          unknown_fields: Vec::new(),
+        }
+      }
+
+      pub fn default_instance() -> &'static FieldOptions {
+        static INSTANCE: FieldOptions = FieldOptions::new();
+        &INSTANCE
+      }
+
+      pub fn has_ctype(&self) -> bool {
+        self.ctype.is_some()
+      }
+
+      pub fn get_ctype(&self) -> field_options::CType {
+        self.ctype.unwrap_or(field_options::String)
+      }
+
+      pub fn set_ctype(&mut self, value: field_options::CType) {
+        self.ctype = Some(value);
+      }
+
+      pub fn clear_ctype(&mut self) {
+        self.ctype = None;
+      }
+
+      pub fn mut_ctype(&mut self) -> &mut field_options::CType {
+        self.ctype.get_or_insert(field_options::String)
+      }
+
+      pub fn take_ctype(&mut self) -> field_options::CType {
+        self.ctype.take().unwrap_or(field_options::String)
+      }
+
+      pub fn has_packed(&self) -> bool {
+        self.packed.is_some()
+      }
+
+      pub fn get_packed(&self) -> bool {
+        self.packed.unwrap_or(false)
+      }
+
+      pub fn set_packed(&mut self, value: bool) {
+        self.packed = Some(value);
+      }
+
+      pub fn clear_packed(&mut self) {
+        self.packed = None;
+      }
+
+      pub fn mut_packed(&mut self) -> &mut bool {
+        self.packed.get_or_insert(false)
+      }
+
+      pub fn take_packed(&mut self) -> bool {
+        self.packed.take().unwrap_or(false)
+      }
+
+      pub fn has_jstype(&self) -> bool {
+        self.jstype.is_some()
+      }
+
+      pub fn get_jstype(&self) -> field_options::JsType {
+        self.jstype.unwrap_or(field_options::JsNormal)
+      }
+
+      pub fn set_jstype(&mut self, value: field_options::JsType) {
+        self.jstype = Some(value);
+      }
+
+      pub fn clear_jstype(&mut self) {
+        self.jstype = None;
+      }
+
+      pub fn mut_jstype(&mut self) -> &mut field_options::JsType {
+        self.jstype.get_or_insert(field_options::JsNormal)
+      }
+
+      pub fn take_jstype(&mut self) -> field_options::JsType {
+        self.jstype.take().unwrap_or(field_options::JsNormal)
+      }
+
+      pub fn has_lazy(&self) -> bool {
+        self.lazy.is_some()
+      }
+
+      pub fn get_lazy(&self) -> bool {
+        self.lazy.unwrap_or(false)
+      }
+
+      pub fn set_lazy(&mut self, value: bool) {
+        self.lazy = Some(value);
+      }
+
+      pub fn clear_lazy(&mut self) {
+        self.lazy = None;
+      }
+
+      pub fn mut_lazy(&mut self) -> &mut bool {
+        self.lazy.get_or_insert(false)
+      }
+
+      pub fn take_lazy(&mut self) -> bool {
+        self.lazy.take().unwrap_or(false)
+      }
+
+      pub fn has_unverified_lazy(&self) -> bool {
+        self.unverified_lazy.is_some()
+      }
+
+      pub fn get_unverified_lazy(&self) -> bool {
+        self.unverified_lazy.unwrap_or(false)
+      }
+
+      pub fn set_unverified_lazy(&mut self, value: bool) {
+        self.unverified_lazy = Some(value);
+      }
+
+      pub fn clear_unverified_lazy(&mut self) {
+        self.unverified_lazy = None;
+      }
+
+      pub fn mut_unverified_lazy(&mut self) -> &mut bool {
+        self.unverified_lazy.get_or_insert(false)
+      }
+
+      pub fn take_unverified_lazy(&mut self) -> bool {
+        self.unverified_lazy.take().unwrap_or(false)
+      }
+
+      pub fn has_deprecated(&self) -> bool {
+        self.deprecated.is_some()
+      }
+
+      pub fn get_deprecated(&self) -> bool {
+        self.deprecated.unwrap_or(false)
+      }
+
+      pub fn set_deprecated(&mut self, value: bool) {
+        self.deprecated = Some(value);
+      }
+
+      pub fn clear_deprecated(&mut self) {
+        self.deprecated = None;
+      }
+
+      pub fn mut_deprecated(&mut self) -> &mut bool {
+        self.deprecated.get_or_insert(false)
+      }
+
+      pub fn take_deprecated(&mut self) -> bool {
+        self.deprecated.take().unwrap_or(false)
+      }
+
+      pub fn has_experimental_map_key(&self) -> bool {
+        self.experimental_map_key.is_some()
+      }
+
+      pub fn get_experimental_map_key(&self) -> &str {
+        self.experimental_map_key.as_deref().unwrap_or("")
+      }
+
+      pub fn set_experimental_map_key(&mut self, value: String) {
+        self.experimental_map_key = Some(value);
+      }
+
+      pub fn clear_experimental_map_key(&mut self) {
+        self.experimental_map_key = None;
+      }
+
+      pub fn mut_experimental_map_key(&mut self) -> &mut String {
+        self.experimental_map_key.get_or_insert_with(|| String::from(""))
+      }
+
+      pub fn take_experimental_map_key(&mut self) -> String {
+        self.experimental_map_key.take().unwrap_or_else(|| String::from(""))
+      }
+
+      pub fn has_weak(&self) -> bool {
+        self.weak.is_some()
+      }
+
+      pub fn get_weak(&self) -> bool {
+        self.weak.unwrap_or(false)
+      }
+
+      pub fn set_weak(&mut self, value: bool) {
+        self.weak = Some(value);
+      }
+
+      pub fn clear_weak(&mut self) {
+        self.weak = None;
+      }
+
+      pub fn mut_weak(&mut self) -> &mut bool {
+        self.weak.get_or_insert(false)
+      }
+
+      pub fn take_weak(&mut self) -> bool {
+        self.weak.take().unwrap_or(false)
+      }
+
+      pub fn has_debug_redact(&self) -> bool {
+        self.debug_redact.is_some()
+      }
+
+      pub fn get_debug_redact(&self) -> bool {
+        self.debug_redact.unwrap_or(false)
+      }
+
+      pub fn set_debug_redact(&mut self, value: bool) {
+        self.debug_redact = Some(value);
+      }
+
+      pub fn clear_debug_redact(&mut self) {
+        self.debug_redact = None;
+      }
+
+      pub fn mut_debug_redact(&mut self) -> &mut bool {
+        self.debug_redact.get_or_insert(false)
+      }
+
+      pub fn take_debug_redact(&mut self) -> bool {
+        self.debug_redact.take().unwrap_or(false)
+      }
+
+      pub fn get_uninterpreted_option(&self) -> &[UninterpretedOption] {
+        &self.uninterpreted_option
+      }
+
+      pub fn set_uninterpreted_option(&mut self, value: Vec<UninterpretedOption>) {
+        self.uninterpreted_option = value;
+      }
+
+      pub fn clear_uninterpreted_option(&mut self) {
+        self.uninterpreted_option.clear();
+      }
+
+      pub fn mut_uninterpreted_option(&mut self) -> &mut Vec<UninterpretedOption> {
+        &mut self.uninterpreted_option
+      }
+
+      pub fn take_uninterpreted_option(&mut self) -> Vec<UninterpretedOption> {
+        core::mem::take(&mut self.uninterpreted_option)
+      }
+
+      pub fn add_uninterpreted_option(&mut self, value: UninterpretedOption) {
+        self.uninterpreted_option.push(value);
+      }
+
+      pub fn uninterpreted_option_len(&self) -> usize {
+        self.uninterpreted_option.len()
+      }
+
+      // Fields this message's schema doesn't know, and enum numbers outside
+      // the enum, in the order they were decoded.
+      pub fn get_unknown_fields(&self) -> &[TaggedValue] {
+        &self.unknown_fields
+      }
+    }
+
+    pub mod field_options {
+      #[allow(unused_imports)]
+      use ::protobuf::{
+        Protobuf, Reader, TagIter, TaggedValue,
+        DecodeZigZag32, DecodeZigZag64, DecodeFloat, DecodeDouble,
+        DecodePackedVarints, DecodePackedFixed32, DecodePackedFixed64,
+        ProtobufEncode, Writer, EncodeError,
+        EncodeTaggedValue, EncodeMessageField, EncodeRawField,
+        EncodeVarintField, EncodeFixed32Field, EncodeFixed64Field,
+        EncodeZigZag32, EncodeZigZag64, EncodeFloat, EncodeDouble,
+      };
+      #[allow(unused_imports)]
+      use ::protobuf::prelude::*;
+
+      #[derive(Clone, Copy, PartialEq, Eq, Debug)]
+      pub enum CType {
+        /// Default mode.
+        String = 0,
+        Cord = 1,
+        StringPiece = 2,
+      }
+
+      pub use self::CType::{String, Cord, StringPiece};
+
+      impl CType {
+        pub fn from_i32(value: i32) -> Option<CType> {
+          match value {
+            0 => Some(String),
+            1 => Some(Cord),
+            2 => Some(StringPiece),
+            _ => None
+          }
+        }
+
+        pub fn value(&self) -> i32 {
+          *self as i32
+        }
+
+        pub fn name(&self) -> &'static str {
+          match *self {
+            String => "STRING",
+            Cord => "CORD",
+            StringPiece => "STRING_PIECE",
+          }
+        }
+
+        pub fn from_name(name: &str) -> Option<CType> {
+          match name {
+            "STRING" => Some(String),
+            "CORD" => Some(Cord),
+            "STRING_PIECE" => Some(StringPiece),
+            _ => None
+          }
+        }
+      }
+
+      impl Default for CType {
+        fn default() -> CType {
+          String
+        }
+      }
+
+      #[derive(Clone, Copy, PartialEq, Eq, Debug)]
+      pub enum JsType {
+        /// Use the default type.
+        JsNormal = 0,
+        /// Use JavaScript strings.
+        JsString = 1,
+        /// Use JavaScript numbers.
+        JsNumber = 2,
+      }
+
+      pub use self::JsType::{JsNormal, JsString, JsNumber};
+
+      impl JsType {
+        pub fn from_i32(value: i32) -> Option<JsType> {
+          match value {
+            0 => Some(JsNormal),
+            1 => Some(JsString),
+            2 => Some(JsNumber),
+            _ => None
+          }
+        }
+
+        pub fn value(&self) -> i32 {
+          *self as i32
+        }
+
+        pub fn name(&self) -> &'static str {
+          match *self {
+            JsNormal => "JS_NORMAL",
+            JsString => "JS_STRING",
+            JsNumber => "JS_NUMBER",
+          }
+        }
+
+        pub fn from_name(name: &str) -> Option<JsType> {
+          match name {
+            "JS_NORMAL" => Some(JsNormal),
+            "JS_STRING" => Some(JsString),
+            "JS_NUMBER" => Some(JsNumber),
+            _ => None
+          }
+        }
+      }
+
+      impl Default for JsType {
+        fn default() -> JsType {
+          JsNormal
+        }
+      }
+
+    }
+
+    impl Protobuf for FieldOptions {
+      fn DecodePartial(&mut self, reader: &mut dyn Reader) -> bool {
+        let mut tags = TagIter::new(reader);
+        for tag_option in tags.by_ref() {
+          match tag_option {
+            TaggedValue::Varint(1, value) => {
+              match field_options::CType::from_i32(value as i32) {
+                Some(value) => self.set_ctype(value),
+                None => self.unknown_fields.push(TaggedValue::Varint(1, value))
+              }
+            }
+            TaggedValue::Varint(2, value) => {
+              self.set_packed(value != 0);
+            }
+            TaggedValue::Varint(6, value) => {
+              match field_options::JsType::from_i32(value as i32) {
+                Some(value) => self.set_jstype(value),
+                None => self.unknown_fields.push(TaggedValue::Varint(6, value))
+              }
+            }
+            TaggedValue::Varint(5, value) => {
+              self.set_lazy(value != 0);
+            }
+            TaggedValue::Varint(15, value) => {
+              self.set_unverified_lazy(value != 0);
+            }
+            TaggedValue::Varint(3, value) => {
+              self.set_deprecated(value != 0);
+            }
+            TaggedValue::Raw(9, value) => {
+              match String::from_utf8(value.into_vec()) {
+                Ok(value) => self.set_experimental_map_key(value),
+                Err(_) => return false
+              }
+            }
+            TaggedValue::Varint(10, value) => {
+              self.set_weak(value != 0);
+            }
+            TaggedValue::Varint(16, value) => {
+              self.set_debug_redact(value != 0);
+            }
+            TaggedValue::Raw(999, value) => {
+              let mut reader = value;
+              let mut message = UninterpretedOption::new();
+              if !message.DecodePartial(&mut reader) {
+                return false;
+              }
+              self.add_uninterpreted_option(message);
+            }
+            unknown => self.unknown_fields.push(unknown)
+          }
+        }
+        !tags.is_malformed()
+      }
+
+      fn AppendMissingFields(&self, prefix: &str, missing: &mut Vec<String>) {
+        for (i, value) in self.uninterpreted_option.iter().enumerate() {
+          for path in value.missing_fields() {
+            missing.push(format!("{}uninterpreted_option[{}].{}", prefix, i, path));
+          }
+        }
+      }
+    }
+
+    impl ProtobufEncode for FieldOptions {
+      fn Encode(&self, writer: &mut dyn Writer) -> Result<(), EncodeError> {
+        for value in self.ctype.iter() {
+          EncodeVarintField(writer, 1, value.value() as i64 as u64)?;
+        }
+        for value in self.packed.iter() {
+          EncodeVarintField(writer, 2, *value as u64)?;
+        }
+        for value in self.deprecated.iter() {
+          EncodeVarintField(writer, 3, *value as u64)?;
+        }
+        for value in self.lazy.iter() {
+          EncodeVarintField(writer, 5, *value as u64)?;
+        }
+        for value in self.jstype.iter() {
+          EncodeVarintField(writer, 6, value.value() as i64 as u64)?;
+        }
+        for value in self.experimental_map_key.iter() {
+          EncodeRawField(writer, 9, value.as_bytes())?;
+        }
+        for value in self.weak.iter() {
+          EncodeVarintField(writer, 10, *value as u64)?;
+        }
+        for value in self.unverified_lazy.iter() {
+          EncodeVarintField(writer, 15, *value as u64)?;
+        }
+        for value in self.debug_redact.iter() {
+          EncodeVarintField(writer, 16, *value as u64)?;
+        }
+        for value in self.uninterpreted_option.iter() {
+          EncodeMessageField(writer, 999, value)?;
+        }
+        for unknown in self.unknown_fields.iter() {
+          EncodeTaggedValue(writer, unknown)?;
+        }
+        Ok(())
+      }
+    }
+
+    pub struct EnumOptions {
+      /// Set this option to false to disallow mapping different tag names to a same
+      /// value.
+      allow_alias: Option<bool>,
+      /// Is this enum deprecated?
+      /// Depending on the target platform, this can emit Deprecated annotations
+      /// for the enum, or it will be completely ignored; in the very least, this
+      /// is a formalization for deprecating enums.
+      deprecated: Option<bool>,
+      /// The parser stores options it doesn't recognize here. See above.
+      uninterpreted_option: Vec<UninterpretedOption>,
+      unknown_fields: Vec<TaggedValue>,
+    }
+
+    impl EnumOptions {
+      pub const fn new() -> EnumOptions {
+        EnumOptions {
+          allow_alias: None,
+          deprecated: None,
+          uninterpreted_option: Vec::new(),
+          unknown_fields: Vec::new(),
+        }
+      }
+
+      pub fn default_instance() -> &'static EnumOptions {
+        static INSTANCE: EnumOptions = EnumOptions::new();
+        &INSTANCE
+      }
+
+      pub fn has_allow_alias(&self) -> bool {
+        self.allow_alias.is_some()
+      }
+
+      pub fn get_allow_alias(&self) -> bool {
+        self.allow_alias.unwrap_or(true)
+      }
+
+      pub fn set_allow_alias(&mut self, value: bool) {
+        self.allow_alias = Some(value);
+      }
+
+      pub fn clear_allow_alias(&mut self) {
+        self.allow_alias = None;
+      }
+
+      pub fn mut_allow_alias(&mut self) -> &mut bool {
+        self.allow_alias.get_or_insert(true)
+      }
+
+      pub fn take_allow_alias(&mut self) -> bool {
+        self.allow_alias.take().unwrap_or(true)
+      }
+
+      pub fn has_deprecated(&self) -> bool {
+        self.deprecated.is_some()
+      }
+
+      pub fn get_deprecated(&self) -> bool {
+        self.deprecated.unwrap_or(false)
+      }
+
+      pub fn set_deprecated(&mut self, value: bool) {
+        self.deprecated = Some(value);
+      }
+
+      pub fn clear_deprecated(&mut self) {
+        self.deprecated = None;
+      }
+
+      pub fn mut_deprecated(&mut self) -> &mut bool {
+        self.deprecated.get_or_insert(false)
+      }
+
+      pub fn take_deprecated(&mut self) -> bool {
+        self.deprecated.take().unwrap_or(false)
+      }
+
+      pub fn get_uninterpreted_option(&self) -> &[UninterpretedOption] {
+        &self.uninterpreted_option
+      }
+
+      pub fn set_uninterpreted_option(&mut self, value: Vec<UninterpretedOption>) {
+        self.uninterpreted_option = value;
+      }
+
+      pub fn clear_uninterpreted_option(&mut self) {
+        self.uninterpreted_option.clear();
+      }
+
+      pub fn mut_uninterpreted_option(&mut self) -> &mut Vec<UninterpretedOption> {
+        &mut self.uninterpreted_option
+      }
+
+      pub fn take_uninterpreted_option(&mut self) -> Vec<UninterpretedOption> {
+        core::mem::take(&mut self.uninterpreted_option)
+      }
+
+      pub fn add_uninterpreted_option(&mut self, value: UninterpretedOption) {
+        self.uninterpreted_option.push(value);
+      }
+
+      pub fn uninterpreted_option_len(&self) -> usize {
+        self.uninterpreted_option.len()
+      }
+
+      // Fields this message's schema doesn't know, and enum numbers outside
+      // the enum, in the order they were decoded.
+      pub fn get_unknown_fields(&self) -> &[TaggedValue] {
+        &self.unknown_fields
+      }
+    }
+
+    impl Protobuf for EnumOptions {
+      fn DecodePartial(&mut self, reader: &mut dyn Reader) -> bool {
+        let mut tags = TagIter::new(reader);
+        for tag_option in tags.by_ref() {
+          match tag_option {
+            TaggedValue::Varint(2, value) => {
+              self.set_allow_alias(value != 0);
+            }
+            TaggedValue::Varint(3, value) => {
+              self.set_deprecated(value != 0);
+            }
+            TaggedValue::Raw(999, value) => {
+              let mut reader = value;
+              let mut message = UninterpretedOption::new();
+              if !message.DecodePartial(&mut reader) {
+                return false;
+              }
+              self.add_uninterpreted_option(message);
+            }
+            unknown => self.unknown_fields.push(unknown)
+          }
+        }
+        !tags.is_malformed()
+      }
+
+      fn AppendMissingFields(&self, prefix: &str, missing: &mut Vec<String>) {
+        for (i, value) in self.uninterpreted_option.iter().enumerate() {
+          for path in value.missing_fields() {
+            missing.push(format!("{}uninterpreted_option[{}].{}", prefix, i, path));
+          }
+        }
+      }
+    }
+
+    impl ProtobufEncode for EnumOptions {
+      fn Encode(&self, writer: &mut dyn Writer) -> Result<(), EncodeError> {
+        for value in self.allow_alias.iter() {
+          EncodeVarintField(writer, 2, *value as u64)?;
+        }
+        for value in self.deprecated.iter() {
+          EncodeVarintField(writer, 3, *value as u64)?;
+        }
+        for value in self.uninterpreted_option.iter() {
+          EncodeMessageField(writer, 999, value)?;
+        }
+        for unknown in self.unknown_fields.iter() {
+          EncodeTaggedValue(writer, unknown)?;
+        }
+        Ok(())
+      }
+    }
+
+    pub struct OneofOptions {
+      /// The parser stores options it doesn't recognize here. See above.
+      uninterpreted_option: Vec<UninterpretedOption>,
+      unknown_fields: Vec<TaggedValue>,
+    }
+
+    impl OneofOptions {
+      pub const fn new() -> OneofOptions {
+        OneofOptions {
+          uninterpreted_option: Vec::new(),
+          unknown_fields: Vec::new(),
+        }
+      }
+
+      pub fn default_instance() -> &'static OneofOptions {
+        static INSTANCE: OneofOptions = OneofOptions::new();
+        &INSTANCE
+      }
+
+      pub fn get_uninterpreted_option(&self) -> &[UninterpretedOption] {
+        &self.uninterpreted_option
+      }
+
+      pub fn set_uninterpreted_option(&mut self, value: Vec<UninterpretedOption>) {
+        self.uninterpreted_option = value;
+      }
+
+      pub fn clear_uninterpreted_option(&mut self) {
+        self.uninterpreted_option.clear();
+      }
+
+      pub fn mut_uninterpreted_option(&mut self) -> &mut Vec<UninterpretedOption> {
+        &mut self.uninterpreted_option
+      }
+
+      pub fn take_uninterpreted_option(&mut self) -> Vec<UninterpretedOption> {
+        core::mem::take(&mut self.uninterpreted_option)
+      }
+
+      pub fn add_uninterpreted_option(&mut self, value: UninterpretedOption) {
+        self.uninterpreted_option.push(value);
+      }
+
+      pub fn uninterpreted_option_len(&self) -> usize {
+        self.uninterpreted_option.len()
+      }
+
+      // Fields this message's schema doesn't know, and enum numbers outside
+      // the enum, in the order they were decoded.
+      pub fn get_unknown_fields(&self) -> &[TaggedValue] {
+        &self.unknown_fields
+      }
+    }
+
+    impl Protobuf for OneofOptions {
+      fn DecodePartial(&mut self, reader: &mut dyn Reader) -> bool {
+        let mut tags = TagIter::new(reader);
+        for tag_option in tags.by_ref() {
+          match tag_option {
+            TaggedValue::Raw(999, value) => {
+              let mut reader = value;
+              let mut message = UninterpretedOption::new();
+              if !message.DecodePartial(&mut reader) {
+                return false;
+              }
+              self.add_uninterpreted_option(message);
+            }
+            unknown => self.unknown_fields.push(unknown)
+          }
+        }
+        !tags.is_malformed()
+      }
+
+      fn AppendMissingFields(&self, prefix: &str, missing: &mut Vec<String>) {
+        for (i, value) in self.uninterpreted_option.iter().enumerate() {
+          for path in value.missing_fields() {
+            missing.push(format!("{}uninterpreted_option[{}].{}", prefix, i, path));
+          }
+        }
+      }
+    }
+
+    impl ProtobufEncode for OneofOptions {
+      fn Encode(&self, writer: &mut dyn Writer) -> Result<(), EncodeError> {
+        for value in self.uninterpreted_option.iter() {
+          EncodeMessageField(writer, 999, value)?;
+        }
+        for unknown in self.unknown_fields.iter() {
+          EncodeTaggedValue(writer, unknown)?;
+        }
+        Ok(())
+      }
+    }
+
+    pub struct EnumValueOptions {
+      /// Is this enum value deprecated?
+      /// Depending on the target platform, this can emit Deprecated annotations
+      /// for the enum value, or it will be completely ignored; in the very least,
+      /// this is a formalization for deprecating enum values.
+      deprecated: Option<bool>,
+      /// Indicate that fields annotated with this enum value should not be printed
+      /// out when using debug formats, e.g. when the field contains sensitive
+      /// credentials.
+      debug_redact: Option<bool>,
+      /// The parser stores options it doesn't recognize here. See above.
+      uninterpreted_option: Vec<UninterpretedOption>,
+      unknown_fields: Vec<TaggedValue>,
+    }
+
+    impl EnumValueOptions {
+      pub const fn new() -> EnumValueOptions {
+        EnumValueOptions {
+          deprecated: None,
+          debug_redact: None,
+          uninterpreted_option: Vec::new(),
+          unknown_fields: Vec::new(),
+        }
+      }
+
+      pub fn default_instance() -> &'static EnumValueOptions {
+        static INSTANCE: EnumValueOptions = EnumValueOptions::new();
+        &INSTANCE
+      }
+
+      pub fn has_deprecated(&self) -> bool {
+        self.deprecated.is_some()
+      }
+
+      pub fn get_deprecated(&self) -> bool {
+        self.deprecated.unwrap_or(false)
+      }
+
+      pub fn set_deprecated(&mut self, value: bool) {
+        self.deprecated = Some(value);
+      }
+
+      pub fn clear_deprecated(&mut self) {
+        self.deprecated = None;
+      }
+
+      pub fn mut_deprecated(&mut self) -> &mut bool {
+        self.deprecated.get_or_insert(false)
+      }
+
+      pub fn take_deprecated(&mut self) -> bool {
+        self.deprecated.take().unwrap_or(false)
+      }
+
+      pub fn has_debug_redact(&self) -> bool {
+        self.debug_redact.is_some()
+      }
+
+      pub fn get_debug_redact(&self) -> bool {
+        self.debug_redact.unwrap_or(false)
+      }
+
+      pub fn set_debug_redact(&mut self, value: bool) {
+        self.debug_redact = Some(value);
+      }
+
+      pub fn clear_debug_redact(&mut self) {
+        self.debug_redact = None;
+      }
+
+      pub fn mut_debug_redact(&mut self) -> &mut bool {
+        self.debug_redact.get_or_insert(false)
+      }
+
+      pub fn take_debug_redact(&mut self) -> bool {
+        self.debug_redact.take().unwrap_or(false)
+      }
+
+      pub fn get_uninterpreted_option(&self) -> &[UninterpretedOption] {
+        &self.uninterpreted_option
+      }
+
+      pub fn set_uninterpreted_option(&mut self, value: Vec<UninterpretedOption>) {
+        self.uninterpreted_option = value;
+      }
+
+      pub fn clear_uninterpreted_option(&mut self) {
+        self.uninterpreted_option.clear();
+      }
+
+      pub fn mut_uninterpreted_option(&mut self) -> &mut Vec<UninterpretedOption> {
+        &mut self.uninterpreted_option
+      }
+
+      pub fn take_uninterpreted_option(&mut self) -> Vec<UninterpretedOption> {
+        core::mem::take(&mut self.uninterpreted_option)
+      }
+
+      pub fn add_uninterpreted_option(&mut self, value: UninterpretedOption) {
+        self.uninterpreted_option.push(value);
+      }
+
+      pub fn uninterpreted_option_len(&self) -> usize {
+        self.uninterpreted_option.len()
+      }
+
+      // Fields this message's schema doesn't know, and enum numbers outside
+      // the enum, in the order they were decoded.
+      pub fn get_unknown_fields(&self) -> &[TaggedValue] {
+        &self.unknown_fields
+      }
+    }
+
+    impl Protobuf for EnumValueOptions {
+      fn DecodePartial(&mut self, reader: &mut dyn Reader) -> bool {
+        let mut tags = TagIter::new(reader);
+        for tag_option in tags.by_ref() {
+          match tag_option {
+            TaggedValue::Varint(1, value) => {
+              self.set_deprecated(value != 0);
+            }
+            TaggedValue::Varint(3, value) => {
+              self.set_debug_redact(value != 0);
+            }
+            TaggedValue::Raw(999, value) => {
+              let mut reader = value;
+              let mut message = UninterpretedOption::new();
+              if !message.DecodePartial(&mut reader) {
+                return false;
+              }
+              self.add_uninterpreted_option(message);
+            }
+            unknown => self.unknown_fields.push(unknown)
+          }
+        }
+        !tags.is_malformed()
+      }
+
+      fn AppendMissingFields(&self, prefix: &str, missing: &mut Vec<String>) {
+        for (i, value) in self.uninterpreted_option.iter().enumerate() {
+          for path in value.missing_fields() {
+            missing.push(format!("{}uninterpreted_option[{}].{}", prefix, i, path));
+          }
+        }
+      }
+    }
+
+    impl ProtobufEncode for EnumValueOptions {
+      fn Encode(&self, writer: &mut dyn Writer) -> Result<(), EncodeError> {
+        for value in self.deprecated.iter() {
+          EncodeVarintField(writer, 1, *value as u64)?;
+        }
+        for value in self.debug_redact.iter() {
+          EncodeVarintField(writer, 3, *value as u64)?;
+        }
+        for value in self.uninterpreted_option.iter() {
+          EncodeMessageField(writer, 999, value)?;
+        }
+        for unknown in self.unknown_fields.iter() {
+          EncodeTaggedValue(writer, unknown)?;
+        }
+        Ok(())
+      }
+    }
+
+    pub struct ServiceOptions {
+      /// Is this service deprecated?
+      /// Depending on the target platform, this can emit Deprecated annotations
+      /// for the service, or it will be completely ignored; in the very least,
+      /// this is a formalization for deprecating services.
+      deprecated: Option<bool>,
+      /// The parser stores options it doesn't recognize here. See above.
+      uninterpreted_option: Vec<UninterpretedOption>,
+      unknown_fields: Vec<TaggedValue>,
+    }
+
+    impl ServiceOptions {
+      pub const fn new() -> ServiceOptions {
+        ServiceOptions {
+          deprecated: None,
+          uninterpreted_option: Vec::new(),
+          unknown_fields: Vec::new(),
+        }
+      }
+
+      pub fn default_instance() -> &'static ServiceOptions {
+        static INSTANCE: ServiceOptions = ServiceOptions::new();
+        &INSTANCE
+      }
+
+      pub fn has_deprecated(&self) -> bool {
+        self.deprecated.is_some()
+      }
+
+      pub fn get_deprecated(&self) -> bool {
+        self.deprecated.unwrap_or(false)
+      }
+
+      pub fn set_deprecated(&mut self, value: bool) {
+        self.deprecated = Some(value);
+      }
+
+      pub fn clear_deprecated(&mut self) {
+        self.deprecated = None;
+      }
+
+      pub fn mut_deprecated(&mut self) -> &mut bool {
+        self.deprecated.get_or_insert(false)
+      }
+
+      pub fn take_deprecated(&mut self) -> bool {
+        self.deprecated.take().unwrap_or(false)
+      }
+
+      pub fn get_uninterpreted_option(&self) -> &[UninterpretedOption] {
+        &self.uninterpreted_option
+      }
+
+      pub fn set_uninterpreted_option(&mut self, value: Vec<UninterpretedOption>) {
+        self.uninterpreted_option = value;
+      }
+
+      pub fn clear_uninterpreted_option(&mut self) {
+        self.uninterpreted_option.clear();
+      }
+
+      pub fn mut_uninterpreted_option(&mut self) -> &mut Vec<UninterpretedOption> {
+        &mut self.uninterpreted_option
+      }
+
+      pub fn take_uninterpreted_option(&mut self) -> Vec<UninterpretedOption> {
+        core::mem::take(&mut self.uninterpreted_option)
+      }
+
+      pub fn add_uninterpreted_option(&mut self, value: UninterpretedOption) {
+        self.uninterpreted_option.push(value);
+      }
+
+      pub fn uninterpreted_option_len(&self) -> usize {
+        self.uninterpreted_option.len()
+      }
+
+      // Fields this message's schema doesn't know, and enum numbers outside
+      // the enum, in the order they were decoded.
+      pub fn get_unknown_fields(&self) -> &[TaggedValue] {
+        &self.unknown_fields
+      }
+    }
+
+    impl Protobuf for ServiceOptions {
+      fn DecodePartial(&mut self, reader: &mut dyn Reader) -> bool {
+        let mut tags = TagIter::new(reader);
+        for tag_option in tags.by_ref() {
+          match tag_option {
+            TaggedValue::Varint(33, value) => {
+              self.set_deprecated(value != 0);
+            }
+            TaggedValue::Raw(999, value) => {
+              let mut reader = value;
+              let mut message = UninterpretedOption::new();
+              if !message.DecodePartial(&mut reader) {
+                return false;
+              }
+              self.add_uninterpreted_option(message);
+            }
+            unknown => self.unknown_fields.push(unknown)
+          }
+        }
+        !tags.is_malformed()
+      }
+
+      fn AppendMissingFields(&self, prefix: &str, missing: &mut Vec<String>) {
+        for (i, value) in self.uninterpreted_option.iter().enumerate() {
+          for path in value.missing_fields() {
+            missing.push(format!("{}uninterpreted_option[{}].{}", prefix, i, path));
+          }
+        }
+      }
+    }
+
+    impl ProtobufEncode for ServiceOptions {
+      fn Encode(&self, writer: &mut dyn Writer) -> Result<(), EncodeError> {
+        for value in self.deprecated.iter() {
+          EncodeVarintField(writer, 33, *value as u64)?;
+        }
+        for value in self.uninterpreted_option.iter() {
+          EncodeMessageField(writer, 999, value)?;
+        }
+        for unknown in self.unknown_fields.iter() {
+          EncodeTaggedValue(writer, unknown)?;
+        }
+        Ok(())
+      }
+    }
+
+    pub struct MethodOptions {
+      /// Is this method deprecated?
+      /// Depending on the target platform, this can emit Deprecated annotations
+      /// for the method, or it will be completely ignored; in the very least,
+      /// this is a formalization for deprecating methods.
+      deprecated: Option<bool>,
+      idempotency_level: Option<method_options::IdempotencyLevel>,
+      /// The parser stores options it doesn't recognize here. See above.
+      uninterpreted_option: Vec<UninterpretedOption>,
+      unknown_fields: Vec<TaggedValue>,
+    }
+
+    impl MethodOptions {
+      pub const fn new() -> MethodOptions {
+        MethodOptions {
+          deprecated: None,
+          idempotency_level: None,
+          uninterpreted_option: Vec::new(),
+          unknown_fields: Vec::new(),
+        }
+      }
+
+      pub fn default_instance() -> &'static MethodOptions {
+        static INSTANCE: MethodOptions = MethodOptions::new();
+        &INSTANCE
+      }
+
+      pub fn has_deprecated(&self) -> bool {
+        self.deprecated.is_some()
+      }
+
+      pub fn get_deprecated(&self) -> bool {
+        self.deprecated.unwrap_or(false)
+      }
+
+      pub fn set_deprecated(&mut self, value: bool) {
+        self.deprecated = Some(value);
+      }
+
+      pub fn clear_deprecated(&mut self) {
+        self.deprecated = None;
+      }
+
+      pub fn mut_deprecated(&mut self) -> &mut bool {
+        self.deprecated.get_or_insert(false)
+      }
+
+      pub fn take_deprecated(&mut self) -> bool {
+        self.deprecated.take().unwrap_or(false)
+      }
+
+      pub fn has_idempotency_level(&self) -> bool {
+        self.idempotency_level.is_some()
+      }
+
+      pub fn get_idempotency_level(&self) -> method_options::IdempotencyLevel {
+        self.idempotency_level.unwrap_or(method_options::IdempotencyUnknown)
+      }
+
+      pub fn set_idempotency_level(&mut self, value: method_options::IdempotencyLevel) {
+        self.idempotency_level = Some(value);
+      }
+
+      pub fn clear_idempotency_level(&mut self) {
+        self.idempotency_level = None;
+      }
+
+      pub fn mut_idempotency_level(&mut self) -> &mut method_options::IdempotencyLevel {
+        self.idempotency_level.get_or_insert(method_options::IdempotencyUnknown)
+      }
+
+      pub fn take_idempotency_level(&mut self) -> method_options::IdempotencyLevel {
+        self.idempotency_level.take().unwrap_or(method_options::IdempotencyUnknown)
+      }
+
+      pub fn get_uninterpreted_option(&self) -> &[UninterpretedOption] {
+        &self.uninterpreted_option
+      }
+
+      pub fn set_uninterpreted_option(&mut self, value: Vec<UninterpretedOption>) {
+        self.uninterpreted_option = value;
+      }
+
+      pub fn clear_uninterpreted_option(&mut self) {
+        self.uninterpreted_option.clear();
+      }
+
+      pub fn mut_uninterpreted_option(&mut self) -> &mut Vec<UninterpretedOption> {
+        &mut self.uninterpreted_option
+      }
+
+      pub fn take_uninterpreted_option(&mut self) -> Vec<UninterpretedOption> {
+        core::mem::take(&mut self.uninterpreted_option)
+      }
+
+      pub fn add_uninterpreted_option(&mut self, value: UninterpretedOption) {
+        self.uninterpreted_option.push(value);
+      }
+
+      pub fn uninterpreted_option_len(&self) -> usize {
+        self.uninterpreted_option.len()
+      }
+
+      // Fields this message's schema doesn't know, and enum numbers outside
+      // the enum, in the order they were decoded.
+      pub fn get_unknown_fields(&self) -> &[TaggedValue] {
+        &self.unknown_fields
+      }
+    }
+
+    pub mod method_options {
+      #[allow(unused_imports)]
+      use ::protobuf::{
+        Protobuf, Reader, TagIter, TaggedValue,
+        DecodeZigZag32, DecodeZigZag64, DecodeFloat, DecodeDouble,
+        DecodePackedVarints, DecodePackedFixed32, DecodePackedFixed64,
+        ProtobufEncode, Writer, EncodeError,
+        EncodeTaggedValue, EncodeMessageField, EncodeRawField,
+        EncodeVarintField, EncodeFixed32Field, EncodeFixed64Field,
+        EncodeZigZag32, EncodeZigZag64, EncodeFloat, EncodeDouble,
+      };
+      #[allow(unused_imports)]
+      use ::protobuf::prelude::*;
+
+      /// Is this method side-effect-free (or safe in HTTP parlance), or idempotent,
+      /// or neither? HTTP based RPC implementation may choose GET verb for safe
+      /// methods, and PUT verb for idempotent methods instead of the default POST.
+      #[derive(Clone, Copy, PartialEq, Eq, Debug)]
+      pub enum IdempotencyLevel {
+        IdempotencyUnknown = 0,
+        /// implies idempotent
+        NoSideEffects = 1,
+        /// idempotent, but may have side effects
+        Idempotent = 2,
+      }
+
+      pub use self::IdempotencyLevel::{IdempotencyUnknown, NoSideEffects, Idempotent};
+
+      impl IdempotencyLevel {
+        pub fn from_i32(value: i32) -> Option<IdempotencyLevel> {
+          match value {
+            0 => Some(IdempotencyUnknown),
+            1 => Some(NoSideEffects),
+            2 => Some(Idempotent),
+            _ => None
+          }
+        }
+
+        pub fn value(&self) -> i32 {
+          *self as i32
+        }
+
+        pub fn name(&self) -> &'static str {
+          match *self {
+            IdempotencyUnknown => "IDEMPOTENCY_UNKNOWN",
+            NoSideEffects => "NO_SIDE_EFFECTS",
+            Idempotent => "IDEMPOTENT",
+          }
+        }
+
+        pub fn from_name(name: &str) -> Option<IdempotencyLevel> {
+          match name {
+            "IDEMPOTENCY_UNKNOWN" => Some(IdempotencyUnknown),
+            "NO_SIDE_EFFECTS" => Some(NoSideEffects),
+            "IDEMPOTENT" => Some(Idempotent),
+            _ => None
+          }
+        }
+      }
+
+      impl Default for IdempotencyLevel {
+        fn default() -> IdempotencyLevel {
+          IdempotencyUnknown
+        }
+      }
+
+    }
+
+    impl Protobuf for MethodOptions {
+      fn DecodePartial(&mut self, reader: &mut dyn Reader) -> bool {
+        let mut tags = TagIter::new(reader);
+        for tag_option in tags.by_ref() {
+          match tag_option {
+            TaggedValue::Varint(33, value) => {
+              self.set_deprecated(value != 0);
+            }
+            TaggedValue::Varint(34, value) => {
+              match method_options::IdempotencyLevel::from_i32(value as i32) {
+                Some(value) => self.set_idempotency_level(value),
+                None => self.unknown_fields.push(TaggedValue::Varint(34, value))
+              }
+            }
+            TaggedValue::Raw(999, value) => {
+              let mut reader = value;
+              let mut message = UninterpretedOption::new();
+              if !message.DecodePartial(&mut reader) {
+                return false;
+              }
+              self.add_uninterpreted_option(message);
+            }
+            unknown => self.unknown_fields.push(unknown)
+          }
+        }
+        !tags.is_malformed()
+      }
+
+      fn AppendMissingFields(&self, prefix: &str, missing: &mut Vec<String>) {
+        for (i, value) in self.uninterpreted_option.iter().enumerate() {
+          for path in value.missing_fields() {
+            missing.push(format!("{}uninterpreted_option[{}].{}", prefix, i, path));
+          }
+        }
+      }
+    }
+
+    impl ProtobufEncode for MethodOptions {
+      fn Encode(&self, writer: &mut dyn Writer) -> Result<(), EncodeError> {
+        for value in self.deprecated.iter() {
+          EncodeVarintField(writer, 33, *value as u64)?;
+        }
+        for value in self.idempotency_level.iter() {
+          EncodeVarintField(writer, 34, value.value() as i64 as u64)?;
+        }
+        for value in self.uninterpreted_option.iter() {
+          EncodeMessageField(writer, 999, value)?;
+        }
+        for unknown in self.unknown_fields.iter() {
+          EncodeTaggedValue(writer, unknown)?;
+        }
+        Ok(())
+      }
+    }
+
+    /// A message representing a option the parser does not recognize. This only
+    /// appears in options protos created by the compiler::Parser class.
+    /// DescriptorPool resolves these when building Descriptor objects. Therefore,
+    /// options protos in descriptor objects (e.g. returned by Descriptor::options(),
+    /// or produced by Descriptor::CopyTo()) will never have UninterpretedOptions
+    /// in them.
+    pub struct UninterpretedOption {
+      name: Vec<uninterpreted_option::NamePart>,
+      /// The value of the uninterpreted option, in whatever type the tokenizer
+      /// identified it as during parsing. Exactly one of these should be set.
+      identifier_value: Option<String>,
+      positive_int_value: Option<u64>,
+      negative_int_value: Option<i64>,
+      double_value: Option<f64>,
+      string_value: Option<Vec<u8>>,
+      aggregate_value: Option<String>,
+      unknown_fields: Vec<TaggedValue>,
+    }
+
+    impl UninterpretedOption {
+      pub const fn new() -> UninterpretedOption {
+        UninterpretedOption {
+          name: Vec::new(),
+          identifier_value: None,
+          positive_int_value: None,
+          negative_int_value: None,
+          double_value: None,
+          string_value: None,
+          aggregate_value: None,
+          unknown_fields: Vec::new(),
+        }
+      }
+
+      pub fn default_instance() -> &'static UninterpretedOption {
+        static INSTANCE: UninterpretedOption = UninterpretedOption::new();
+        &INSTANCE
+      }
+
+      pub fn get_name(&self) -> &[uninterpreted_option::NamePart] {
+        &self.name
+      }
+
+      pub fn set_name(&mut self, value: Vec<uninterpreted_option::NamePart>) {
+        self.name = value;
+      }
+
+      pub fn clear_name(&mut self) {
+        self.name.clear();
+      }
+
+      pub fn mut_name(&mut self) -> &mut Vec<uninterpreted_option::NamePart> {
+        &mut self.name
+      }
+
+      pub fn take_name(&mut self) -> Vec<uninterpreted_option::NamePart> {
+        core::mem::take(&mut self.name)
+      }
+
+      pub fn add_name(&mut self, value: uninterpreted_option::NamePart) {
+        self.name.push(value);
+      }
+
+      pub fn name_len(&self) -> usize {
+        self.name.len()
+      }
+
+      pub fn has_identifier_value(&self) -> bool {
+        self.identifier_value.is_some()
+      }
+
+      pub fn get_identifier_value(&self) -> &str {
+        self.identifier_value.as_deref().unwrap_or("")
+      }
+
+      pub fn set_identifier_value(&mut self, value: String) {
+        self.identifier_value = Some(value);
+      }
+
+      pub fn clear_identifier_value(&mut self) {
+        self.identifier_value = None;
+      }
+
+      pub fn mut_identifier_value(&mut self) -> &mut String {
+        self.identifier_value.get_or_insert_with(|| String::from(""))
+      }
+
+      pub fn take_identifier_value(&mut self) -> String {
+        self.identifier_value.take().unwrap_or_else(|| String::from(""))
+      }
+
+      pub fn has_positive_int_value(&self) -> bool {
+        self.positive_int_value.is_some()
+      }
+
+      pub fn get_positive_int_value(&self) -> u64 {
+        self.positive_int_value.unwrap_or(0)
+      }
+
+      pub fn set_positive_int_value(&mut self, value: u64) {
+        self.positive_int_value = Some(value);
+      }
+
+      pub fn clear_positive_int_value(&mut self) {
+        self.positive_int_value = None;
+      }
+
+      pub fn mut_positive_int_value(&mut self) -> &mut u64 {
+        self.positive_int_value.get_or_insert(0)
+      }
+
+      pub fn take_positive_int_value(&mut self) -> u64 {
+        self.positive_int_value.take().unwrap_or(0)
+      }
+
+      pub fn has_negative_int_value(&self) -> bool {
+        self.negative_int_value.is_some()
+      }
+
+      pub fn get_negative_int_value(&self) -> i64 {
+        self.negative_int_value.unwrap_or(0)
+      }
+
+      pub fn set_negative_int_value(&mut self, value: i64) {
+        self.negative_int_value = Some(value);
+      }
+
+      pub fn clear_negative_int_value(&mut self) {
+        self.negative_int_value = None;
+      }
+
+      pub fn mut_negative_int_value(&mut self) -> &mut i64 {
+        self.negative_int_value.get_or_insert(0)
+      }
+
+      pub fn take_negative_int_value(&mut self) -> i64 {
+        self.negative_int_value.take().unwrap_or(0)
+      }
+
+      pub fn has_double_value(&self) -> bool {
+        self.double_value.is_some()
+      }
+
+      pub fn get_double_value(&self) -> f64 {
+        self.double_value.unwrap_or(0.0)
+      }
+
+      pub fn set_double_value(&mut self, value: f64) {
+        self.double_value = Some(value);
+      }
+
+      pub fn clear_double_value(&mut self) {
+        self.double_value = None;
+      }
+
+      pub fn mut_double_value(&mut self) -> &mut f64 {
+        self.double_value.get_or_insert(0.0)
+      }
+
+      pub fn take_double_value(&mut self) -> f64 {
+        self.double_value.take().unwrap_or(0.0)
+      }
+
+      pub fn has_string_value(&self) -> bool {
+        self.string_value.is_some()
+      }
+
+      pub fn get_string_value(&self) -> &[u8] {
+        self.string_value.as_deref().unwrap_or(b"")
+      }
+
+      pub fn set_string_value(&mut self, value: Vec<u8>) {
+        self.string_value = Some(value);
+      }
+
+      pub fn clear_string_value(&mut self) {
+        self.string_value = None;
+      }
+
+      pub fn mut_string_value(&mut self) -> &mut Vec<u8> {
+        self.string_value.get_or_insert_with(|| b"".to_vec())
+      }
+
+      pub fn take_string_value(&mut self) -> Vec<u8> {
+        self.string_value.take().unwrap_or_else(|| b"".to_vec())
+      }
+
+      pub fn has_aggregate_value(&self) -> bool {
+        self.aggregate_value.is_some()
+      }
+
+      pub fn get_aggregate_value(&self) -> &str {
+        self.aggregate_value.as_deref().unwrap_or("")
+      }
+
+      pub fn set_aggregate_value(&mut self, value: String) {
+        self.aggregate_value = Some(value);
+      }
+
+      pub fn clear_aggregate_value(&mut self) {
+        self.aggregate_value = None;
+      }
+
+      pub fn mut_aggregate_value(&mut self) -> &mut String {
+        self.aggregate_value.get_or_insert_with(|| String::from(""))
+      }
+
+      pub fn take_aggregate_value(&mut self) -> String {
+        self.aggregate_value.take().unwrap_or_else(|| String::from(""))
+      }
+
+      // Fields this message's schema doesn't know, and enum numbers outside
+      // the enum, in the order they were decoded.
+      pub fn get_unknown_fields(&self) -> &[TaggedValue] {
+        &self.unknown_fields
+      }
+    }
+
+    pub mod uninterpreted_option {
+      #[allow(unused_imports)]
+      use ::protobuf::{
+        Protobuf, Reader, TagIter, TaggedValue,
+        DecodeZigZag32, DecodeZigZag64, DecodeFloat, DecodeDouble,
+        DecodePackedVarints, DecodePackedFixed32, DecodePackedFixed64,
+        ProtobufEncode, Writer, EncodeError,
+        EncodeTaggedValue, EncodeMessageField, EncodeRawField,
+        EncodeVarintField, EncodeFixed32Field, EncodeFixed64Field,
+        EncodeZigZag32, EncodeZigZag64, EncodeFloat, EncodeDouble,
+      };
+      #[allow(unused_imports)]
+      use ::protobuf::prelude::*;
+
+      /// The name of the uninterpreted option.  Each string represents a segment in
+      /// a dot-separated name.  is_extension is true iff a segment represents an
+      /// extension (denoted with parentheses in options specs in .proto files).
+      /// E.g.,{ ["foo", false], ["bar.baz", true], ["qux", false] } represents
+      /// "foo.(bar.baz).qux".
+      pub struct NamePart {
+        name_part: Option<String>,
+        is_extension: Option<bool>,
+        unknown_fields: Vec<TaggedValue>,
+      }
+
+      impl NamePart {
+        pub const fn new() -> NamePart {
+          NamePart {
+            name_part: None,
+            is_extension: None,
+            unknown_fields: Vec::new(),
+          }
+        }
+
+        pub fn default_instance() -> &'static NamePart {
+          static INSTANCE: NamePart = NamePart::new();
+          &INSTANCE
+        }
+
+        pub fn has_name_part(&self) -> bool {
+          self.name_part.is_some()
+        }
+
+        pub fn get_name_part(&self) -> &str {
+          self.name_part.as_deref().unwrap_or("")
+        }
+
+        pub fn set_name_part(&mut self, value: String) {
+          self.name_part = Some(value);
+        }
+
+        pub fn clear_name_part(&mut self) {
+          self.name_part = None;
+        }
+
+        pub fn mut_name_part(&mut self) -> &mut String {
+          self.name_part.get_or_insert_with(|| String::from(""))
+        }
+
+        pub fn take_name_part(&mut self) -> String {
+          self.name_part.take().unwrap_or_else(|| String::from(""))
+        }
+
+        pub fn has_is_extension(&self) -> bool {
+          self.is_extension.is_some()
+        }
+
+        pub fn get_is_extension(&self) -> bool {
+          self.is_extension.unwrap_or(false)
+        }
+
+        pub fn set_is_extension(&mut self, value: bool) {
+          self.is_extension = Some(value);
+        }
+
+        pub fn clear_is_extension(&mut self) {
+          self.is_extension = None;
+        }
+
+        pub fn mut_is_extension(&mut self) -> &mut bool {
+          self.is_extension.get_or_insert(false)
+        }
+
+        pub fn take_is_extension(&mut self) -> bool {
+          self.is_extension.take().unwrap_or(false)
+        }
+
+        // Fields this message's schema doesn't know, and enum numbers outside
+        // the enum, in the order they were decoded.
+        pub fn get_unknown_fields(&self) -> &[TaggedValue] {
+          &self.unknown_fields
+        }
+      }
+
+      impl Protobuf for NamePart {
+        fn DecodePartial(&mut self, reader: &mut dyn Reader) -> bool {
+          let mut tags = TagIter::new(reader);
+          for tag_option in tags.by_ref() {
+            match tag_option {
+              TaggedValue::Raw(1, value) => {
+                match String::from_utf8(value.into_vec()) {
+                  Ok(value) => self.set_name_part(value),
+                  Err(_) => return false
+                }
+              }
+              TaggedValue::Varint(2, value) => {
+                self.set_is_extension(value != 0);
+              }
+              unknown => self.unknown_fields.push(unknown)
+            }
+          }
+          !tags.is_malformed()
+        }
+
+        fn AppendMissingFields(&self, prefix: &str, missing: &mut Vec<String>) {
+          if self.name_part.is_none() {
+            missing.push(format!("{}name_part", prefix));
+          }
+          if self.is_extension.is_none() {
+            missing.push(format!("{}is_extension", prefix));
+          }
+        }
+      }
+
+      impl ProtobufEncode for NamePart {
+        fn Encode(&self, writer: &mut dyn Writer) -> Result<(), EncodeError> {
+          for value in self.name_part.iter() {
+            EncodeRawField(writer, 1, value.as_bytes())?;
+          }
+          for value in self.is_extension.iter() {
+            EncodeVarintField(writer, 2, *value as u64)?;
+          }
+          for unknown in self.unknown_fields.iter() {
+            EncodeTaggedValue(writer, unknown)?;
+          }
+          Ok(())
+        }
+      }
+
+    }
+
+    impl Protobuf for UninterpretedOption {
+      fn DecodePartial(&mut self, reader: &mut dyn Reader) -> bool {
+        let mut tags = TagIter::new(reader);
+        for tag_option in tags.by_ref() {
+          match tag_option {
+            TaggedValue::Raw(2, value) => {
+              let mut reader: &[u8] = &value;
+              let mut message = uninterpreted_option::NamePart::new();
+              if !message.DecodePartial(&mut reader) {
+                return false;
+              }
+              self.add_name(message);
+            }
+            TaggedValue::Raw(3, value) => {
+              match String::from_utf8(value.into_vec()) {
+                Ok(value) => self.set_identifier_value(value),
+                Err(_) => return false
+              }
+            }
+            TaggedValue::Varint(4, value) => {
+              self.set_positive_int_value(value);
+            }
+            TaggedValue::Varint(5, value) => {
+              self.set_negative_int_value(value as i64);
+            }
+            TaggedValue::Fixed64(6, value) => {
+              self.set_double_value(DecodeDouble(value));
+            }
+            TaggedValue::Raw(7, value) => {
+              self.set_string_value(value.into_vec());
+            }
+            TaggedValue::Raw(8, value) => {
+              match String::from_utf8(value.into_vec()) {
+                Ok(value) => self.set_aggregate_value(value),
+                Err(_) => return false
+              }
+            }
+            unknown => self.unknown_fields.push(unknown)
+          }
+        }
+        !tags.is_malformed()
+      }
+
+      fn AppendMissingFields(&self, prefix: &str, missing: &mut Vec<String>) {
+        for (i, value) in self.name.iter().enumerate() {
+          for path in value.missing_fields() {
+            missing.push(format!("{}name[{}].{}", prefix, i, path));
+          }
+        }
+      }
+    }
+
+    impl ProtobufEncode for UninterpretedOption {
+      fn Encode(&self, writer: &mut dyn Writer) -> Result<(), EncodeError> {
+        for value in self.name.iter() {
+          EncodeMessageField(writer, 2, value)?;
+        }
+        for value in self.identifier_value.iter() {
+          EncodeRawField(writer, 3, value.as_bytes())?;
+        }
+        for value in self.positive_int_value.iter() {
+          EncodeVarintField(writer, 4, *value)?;
+        }
+        for value in self.negative_int_value.iter() {
+          EncodeVarintField(writer, 5, *value as u64)?;
+        }
+        for value in self.double_value.iter() {
+          EncodeFixed64Field(writer, 6, EncodeDouble(*value))?;
+        }
+        for value in self.string_value.iter() {
+          EncodeRawField(writer, 7, value)?;
+        }
+        for value in self.aggregate_value.iter() {
+          EncodeRawField(writer, 8, value.as_bytes())?;
+        }
+        for unknown in self.unknown_fields.iter() {
+          EncodeTaggedValue(writer, unknown)?;
+        }
+        Ok(())
+      }
+    }
+
+    /// Encapsulates information about the original source file from which a
+    /// FileDescriptorProto was generated.
+    pub struct SourceCodeInfo {
+      /// A Location identifies a piece of source code in a .proto file which
+      /// corresponds to a particular definition.  This information is intended
+      /// to be useful to IDEs, code indexers, documentation generators, and similar
+      /// tools.
+      ///
+      /// For example, say we have a file like:
+      ///   message Foo {
+      ///     optional string foo = 1;
+      ///   }
+      /// Let's look at just the field definition:
+      ///   optional string foo = 1;
+      ///   ^       ^^     ^^  ^  ^^^
+      ///   a       bc     de  f  ghi
+      /// We have the following locations:
+      ///   span   path               represents
+      ///   [a,i)  [ 4, 0, 2, 0 ]     The whole field definition.
+      ///   [a,b)  [ 4, 0, 2, 0, 4 ]  The label (optional).
+      ///   [c,d)  [ 4, 0, 2, 0, 5 ]  The type (string).
+      ///   [e,f)  [ 4, 0, 2, 0, 1 ]  The name (foo).
+      ///   [g,h)  [ 4, 0, 2, 0, 3 ]  The number (1).
+      ///
+      /// Notes:
+      /// - A location may refer to a repeated field itself (i.e. not to any
+      ///   particular index within it).  This is used whenever a set of elements are
+      ///   logically enclosed in a single code segment.  For example, an entire
+      ///   extend block (possibly containing multiple extension definitions) will
+      ///   have an outer location whose path refers to the "extensions" repeated
+      ///   field without an index.
+      /// - Multiple locations may have the same path.  This happens when a single
+      ///   logical declaration is spread out across multiple places.  The most
+      ///   obvious example is the "extend" block again -- there may be multiple
+      ///   extend blocks in the same scope, each of which will have the same path.
+      /// - A location's span is not always a subset of its parent's span.  For
+      ///   example, the "extendee" of an extension declaration appears at the
+      ///   beginning of the "extend" block and is shared by all extensions within
+      ///   the block.
+      /// - Just because a location's span is a subset of some other location's span
+      ///   does not mean that it is a descendent.  For example, a "group" defines
+      ///   both a type and a field in a single declaration.  Thus, the locations
+      ///   corresponding to the type and field and their components will overlap.
+      /// - Code which tries to interpret locations should probably be designed to
+      ///   ignore those that it doesn't understand, as more types of locations could
+      ///   be recorded in the future.
+      location: Vec<source_code_info::Location>,
+      unknown_fields: Vec<TaggedValue>,
+    }
+
+    impl SourceCodeInfo {
+      pub const fn new() -> SourceCodeInfo {
+        SourceCodeInfo {
+          location: Vec::new(),
+          unknown_fields: Vec::new(),
+        }
+      }
+
+      pub fn default_instance() -> &'static SourceCodeInfo {
+        static INSTANCE: SourceCodeInfo = SourceCodeInfo::new();
+        &INSTANCE
+      }
+
+      pub fn get_location(&self) -> &[source_code_info::Location] {
+        &self.location
+      }
+
+      pub fn set_location(&mut self, value: Vec<source_code_info::Location>) {
+        self.location = value;
+      }
+
+      pub fn clear_location(&mut self) {
+        self.location.clear();
+      }
+
+      pub fn mut_location(&mut self) -> &mut Vec<source_code_info::Location> {
+        &mut self.location
+      }
+
+      pub fn take_location(&mut self) -> Vec<source_code_info::Location> {
+        core::mem::take(&mut self.location)
+      }
+
+      pub fn add_location(&mut self, value: source_code_info::Location) {
+        self.location.push(value);
+      }
+
+      pub fn location_len(&self) -> usize {
+        self.location.len()
+      }
+
+      // Fields this message's schema doesn't know, and enum numbers outside
+      // the enum, in the order they were decoded.
+      pub fn get_unknown_fields(&self) -> &[TaggedValue] {
+        &self.unknown_fields
+      }
+    }
+
+    pub mod source_code_info {
+      #[allow(unused_imports)]
+      use ::protobuf::{
+        Protobuf, Reader, TagIter, TaggedValue,
+        DecodeZigZag32, DecodeZigZag64, DecodeFloat, DecodeDouble,
+        DecodePackedVarints, DecodePackedFixed32, DecodePackedFixed64,
+        ProtobufEncode, Writer, EncodeError,
+        EncodeTaggedValue, EncodeMessageField, EncodeRawField,
+        EncodeVarintField, EncodeFixed32Field, EncodeFixed64Field,
+        EncodeZigZag32, EncodeZigZag64, EncodeFloat, EncodeDouble,
+      };
+      #[allow(unused_imports)]
+      use ::protobuf::prelude::*;
+
+      pub struct Location {
+        /// Identifies which part of the FileDescriptorProto was defined at this
+        /// location.
+        ///
+        /// Each element is a field number or an index.  They form a path from
+        /// the root FileDescriptorProto to the place where the definition.  For
+        /// example, this path:
+        ///   [ 4, 3, 2, 7, 1 ]
+        /// refers to:
+        ///   file.message_type(3)  // 4, 3
+        ///       .field(7)         // 2, 7
+        ///       .name()           // 1
+        /// This is because FileDescriptorProto.message_type has field number 4:
+        ///   repeated DescriptorProto message_type = 4;
+        /// and DescriptorProto.field has field number 2:
+        ///   repeated FieldDescriptorProto field = 2;
+        /// and FieldDescriptorProto.name has field number 1:
+        ///   optional string name = 1;
+        ///
+        /// Thus, the above path gives the location of a field name.  If we removed
+        /// the last element:
+        ///   [ 4, 3, 2, 7 ]
+        /// this path refers to the whole field declaration (from the beginning
+        /// of the label to the terminating semicolon).
+        path: Vec<i32>,
+        /// Always has exactly three or four elements: start line, start column,
+        /// end line (optional, otherwise assumed same as start line), end column.
+        /// These are packed into a single field for efficiency.  Note that line
+        /// and column numbers are zero-based -- typically you will want to add
+        /// 1 to each before displaying to a user.
+        span: Vec<i32>,
+        /// If this SourceCodeInfo represents a complete declaration, these are any
+        /// comments appearing before and after the declaration which appear to be
+        /// attached to the declaration.
+        ///
+        /// A series of line comments appearing on consecutive lines, with no other
+        /// tokens appearing on those lines, will be treated as a single comment.
+        ///
+        /// Only the comment content is provided; comment markers (e.g. //) are
+        /// stripped out.  For block comments, leading whitespace and an asterisk
+        /// will be stripped from the beginning of each line other than the first.
+        /// Newlines are included in the output.
+        ///
+        /// Examples:
+        ///
+        ///   optional int32 foo = 1;  // Comment attached to foo.
+        ///   // Comment attached to bar.
+        ///   optional int32 bar = 2;
+        ///
+        ///   optional string baz = 3;
+        ///   // Comment attached to baz.
+        ///   // Another line attached to baz.
+        ///
+        ///   // Comment attached to qux.
+        ///   //
+        ///   // Another line attached to qux.
+        ///   optional double qux = 4;
+        ///
+        ///   optional string corge = 5;
+        ///   /* Block comment attached
+        ///    * to corge.  Leading asterisks
+        ///    * will be removed. */
+        ///   /* Block comment attached to
+        ///    * grault. */
+        ///   optional int32 grault = 6;
+        leading_comments: Option<String>,
+        trailing_comments: Option<String>,
+        unknown_fields: Vec<TaggedValue>,
+      }
+
+      impl Location {
+        pub const fn new() -> Location {
+          Location {
+            path: Vec::new(),
+            span: Vec::new(),
+            leading_comments: None,
+            trailing_comments: None,
+            unknown_fields: Vec::new(),
+          }
+        }
+
+        pub fn default_instance() -> &'static Location {
+          static INSTANCE: Location = Location::new();
+          &INSTANCE
+        }
+
+        pub fn get_path(&self) -> &[i32] {
+          &self.path
+        }
+
+        pub fn set_path(&mut self, value: Vec<i32>) {
+          self.path = value;
+        }
+
+        pub fn clear_path(&mut self) {
+          self.path.clear();
+        }
+
+        pub fn mut_path(&mut self) -> &mut Vec<i32> {
+          &mut self.path
+        }
+
+        pub fn take_path(&mut self) -> Vec<i32> {
+          core::mem::take(&mut self.path)
+        }
+
+        pub fn add_path(&mut self, value: i32) {
+          self.path.push(value);
+        }
+
+        pub fn path_len(&self) -> usize {
+          self.path.len()
+        }
+
+        pub fn get_span(&self) -> &[i32] {
+          &self.span
+        }
+
+        pub fn set_span(&mut self, value: Vec<i32>) {
+          self.span = value;
+        }
+
+        pub fn clear_span(&mut self) {
+          self.span.clear();
+        }
+
+        pub fn mut_span(&mut self) -> &mut Vec<i32> {
+          &mut self.span
+        }
+
+        pub fn take_span(&mut self) -> Vec<i32> {
+          core::mem::take(&mut self.span)
+        }
+
+        pub fn add_span(&mut self, value: i32) {
+          self.span.push(value);
+        }
+
+        pub fn span_len(&self) -> usize {
+          self.span.len()
+        }
+
+        pub fn has_leading_comments(&self) -> bool {
+          self.leading_comments.is_some()
+        }
+
+        pub fn get_leading_comments(&self) -> &str {
+          self.leading_comments.as_deref().unwrap_or("")
+        }
+
+        pub fn set_leading_comments(&mut self, value: String) {
+          self.leading_comments = Some(value);
+        }
+
+        pub fn clear_leading_comments(&mut self) {
+          self.leading_comments = None;
+        }
+
+        pub fn mut_leading_comments(&mut self) -> &mut String {
+          self.leading_comments.get_or_insert_with(|| String::from(""))
+        }
+
+        pub fn take_leading_comments(&mut self) -> String {
+          self.leading_comments.take().unwrap_or_else(|| String::from(""))
+        }
+
+        pub fn has_trailing_comments(&self) -> bool {
+          self.trailing_comments.is_some()
+        }
+
+        pub fn get_trailing_comments(&self) -> &str {
+          self.trailing_comments.as_deref().unwrap_or("")
+        }
+
+        pub fn set_trailing_comments(&mut self, value: String) {
+          self.trailing_comments = Some(value);
+        }
+
+        pub fn clear_trailing_comments(&mut self) {
+          self.trailing_comments = None;
+        }
+
+        pub fn mut_trailing_comments(&mut self) -> &mut String {
+          self.trailing_comments.get_or_insert_with(|| String::from(""))
+        }
+
+        pub fn take_trailing_comments(&mut self) -> String {
+          self.trailing_comments.take().unwrap_or_else(|| String::from(""))
+        }
+
+        // Fields this message's schema doesn't know, and enum numbers outside
+        // the enum, in the order they were decoded.
+        pub fn get_unknown_fields(&self) -> &[TaggedValue] {
+          &self.unknown_fields
+        }
+      }
+
+      impl Protobuf for Location {
+        fn DecodePartial(&mut self, reader: &mut dyn Reader) -> bool {
+          let mut tags = TagIter::new(reader);
+          for tag_option in tags.by_ref() {
+            match tag_option {
+              TaggedValue::Varint(1, value) => {
+                self.add_path(value as i32);
+              }
+              TaggedValue::Raw(1, packed) => {
+                match DecodePackedVarints(&packed) {
+                  Some(values) => {
+                    for value in values.iter() {
+                      self.add_path(*value as i32);
+                    }
+                  }
+                  None => return false
+                }
+              }
+              TaggedValue::Varint(2, value) => {
+                self.add_span(value as i32);
+              }
+              TaggedValue::Raw(2, packed) => {
+                match DecodePackedVarints(&packed) {
+                  Some(values) => {
+                    for value in values.iter() {
+                      self.add_span(*value as i32);
+                    }
+                  }
+                  None => return false
+                }
+              }
+              TaggedValue::Raw(3, value) => {
+                match String::from_utf8(value.into_vec()) {
+                  Ok(value) => self.set_leading_comments(value),
+                  Err(_) => return false
+                }
+              }
+              TaggedValue::Raw(4, value) => {
+                match String::from_utf8(value.into_vec()) {
+                  Ok(value) => self.set_trailing_comments(value),
+                  Err(_) => return false
+                }
+              }
+              unknown => self.unknown_fields.push(unknown)
+            }
+          }
+          !tags.is_malformed()
+        }
+
+        fn AppendMissingFields(&self, _prefix: &str, _missing: &mut Vec<String>) {
+        }
+      }
+
+      impl ProtobufEncode for Location {
+        fn Encode(&self, writer: &mut dyn Writer) -> Result<(), EncodeError> {
+          for value in self.path.iter() {
+            EncodeVarintField(writer, 1, *value as i64 as u64)?;
+          }
+          for value in self.span.iter() {
+            EncodeVarintField(writer, 2, *value as i64 as u64)?;
+          }
+          for value in self.leading_comments.iter() {
+            EncodeRawField(writer, 3, value.as_bytes())?;
+          }
+          for value in self.trailing_comments.iter() {
+            EncodeRawField(writer, 4, value.as_bytes())?;
+          }
+          for unknown in self.unknown_fields.iter() {
+            EncodeTaggedValue(writer, unknown)?;
+          }
+          Ok(())
+        }
+      }
+
+    }
+
+    impl Protobuf for SourceCodeInfo {
+      fn DecodePartial(&mut self, reader: &mut dyn Reader) -> bool {
+        let mut tags = TagIter::new(reader);
+        for tag_option in tags.by_ref() {
+          match tag_option {
+            TaggedValue::Raw(1, value) => {
+              let mut reader: &[u8] = &value;
+              let mut message = source_code_info::Location::new();
+              if !message.DecodePartial(&mut reader) {
+                return false;
+              }
+              self.add_location(message);
+            }
+            unknown => self.unknown_fields.push(unknown)
+          }
+        }
+        !tags.is_malformed()
+      }
+
+      fn AppendMissingFields(&self, prefix: &str, missing: &mut Vec<String>) {
+        for (i, value) in self.location.iter().enumerate() {
+          for path in value.missing_fields() {
+            missing.push(format!("{}location[{}].{}", prefix, i, path));
+          }
+        }
+      }
+    }
+
+    impl ProtobufEncode for SourceCodeInfo {
+      fn Encode(&self, writer: &mut dyn Writer) -> Result<(), EncodeError> {
+        for value in self.location.iter() {
+          EncodeMessageField(writer, 1, value)?;
+        }
+        for unknown in self.unknown_fields.iter() {
+          EncodeTaggedValue(writer, unknown)?;
+        }
+        Ok(())
+      }
+    }
+
+  }
+}
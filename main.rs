@@ -0,0 +1,6 @@
+// The protoc plugin and command line, both in protoc-gen-rust.rs so that
+// the library's Codegen shares them.
+
+fn main() {
+  protoc_gen_rust::main()
+}
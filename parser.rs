@@ -0,0 +1,2328 @@
+// Parses .proto files into the FileDescriptorProtos protoc would send the
+// plugin, so that code can be generated without protoc installed. The
+// tokenizer and parser follow protoc's own: comments are attached to
+// declarations by the same rules, and the SourceCodeInfo spans count lines
+// and columns the same way.
+
+use std::collections::HashMap;
+use std::fmt;
+use std::fs;
+use std::mem::replace;
+use std::path::PathBuf;
+use crate::descriptor::google::protobuf::{FileDescriptorProto, DescriptorProto, FieldDescriptorProto};
+use crate::descriptor::google::protobuf::{OneofDescriptorProto, EnumDescriptorProto, EnumValueDescriptorProto};
+use crate::descriptor::google::protobuf::{ServiceDescriptorProto, MethodDescriptorProto, UninterpretedOption};
+use crate::descriptor::google::protobuf::{FileOptions, MessageOptions, FieldOptions, OneofOptions};
+use crate::descriptor::google::protobuf::{EnumOptions, EnumValueOptions, ServiceOptions, MethodOptions};
+use crate::descriptor::google::protobuf::descriptor_proto::{ExtensionRange, ReservedRange};
+use crate::descriptor::google::protobuf::enum_descriptor_proto::EnumReservedRange;
+use crate::descriptor::google::protobuf::file_options::OptimizeMode;
+use crate::descriptor::google::protobuf::field_options::{CType, JsType};
+use crate::descriptor::google::protobuf::method_options::IdempotencyLevel;
+use crate::descriptor::google::protobuf::uninterpreted_option::NamePart;
+use crate::descriptor::google::protobuf::source_code_info::Location;
+use crate::descriptor::google::protobuf::field_descriptor_proto::Type;
+use crate::descriptor::google::protobuf::field_descriptor_proto::{TypeDouble, TypeFloat, TypeInt64, TypeUint64};
+use crate::descriptor::google::protobuf::field_descriptor_proto::{TypeInt32, TypeFixed64, TypeFixed32, TypeBool};
+use crate::descriptor::google::protobuf::field_descriptor_proto::{TypeString, TypeGroup, TypeMessage, TypeBytes};
+use crate::descriptor::google::protobuf::field_descriptor_proto::{TypeUint32, TypeEnum, TypeSfixed32, TypeSfixed64};
+use crate::descriptor::google::protobuf::field_descriptor_proto::{TypeSint32, TypeSint64};
+use crate::descriptor::google::protobuf::field_descriptor_proto::{LabelOptional, LabelRequired, LabelRepeated};
+use crate::printer::{c_escape, default_json_name, kMaxFieldNumber, kMaxEnumNumber};
+use crate::{unescape_c_bytes, package_scope, visible_files};
+
+// Where parsing failed. Lines and columns count from 1; errors about a whole
+// file, such as it not being found, have line 0.
+#[derive(Debug)]
+pub struct ParseError {
+  pub file: String,
+  pub line: usize,
+  pub column: usize,
+  pub message: String
+}
+
+impl fmt::Display for ParseError {
+  fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+    if self.line == 0 {
+      write!(f, "{}: {}", self.file, self.message)
+    } else {
+      write!(f, "{}:{}:{}: {}", self.file, self.line, self.column, self.message)
+    }
+  }
+}
+
+#[derive(PartialEq, Clone)]
+enum TokenType {
+  TokenStart,
+  TokenEnd,
+  TokenIdentifier,
+  TokenInteger,
+  TokenFloat,
+  TokenString,
+  TokenSymbol
+}
+use self::TokenType::*;
+
+#[derive(Clone)]
+struct Token {
+  kind: TokenType,
+  text: String,
+  // Zero-based, as in SourceCodeInfo spans.
+  line: usize,
+  column: usize,
+  end_column: usize
+}
+
+fn is_letter(c: char) -> bool {
+  c.is_ascii_alphabetic() || c == '_'
+}
+
+fn is_digit(c: char) -> bool {
+  c.is_ascii_digit()
+}
+
+fn is_inline_whitespace(c: char) -> bool {
+  c == ' ' || c == '\t' || c == '\r' || c == '\x0b' || c == '\x0c'
+}
+
+// The value of an integer literal: decimal, hex ("0x1f") or octal ("017").
+fn parse_integer(text: &str) -> Option<u64> {
+  if text.starts_with("0x") || text.starts_with("0X") {
+    u64::from_str_radix(&text[2..], 16).ok()
+  } else if text.len() > 1 && text.starts_with("0") {
+    u64::from_str_radix(&text[1..], 8).ok()
+  } else {
+    text.parse().ok()
+  }
+}
+
+// A float literal without the "f" suffix protoc allows.
+fn float_text(text: &str) -> &str {
+  if text.ends_with("f") || text.ends_with("F") {
+    &text[..text.len() - 1]
+  } else {
+    text
+  }
+}
+
+#[test]
+fn test_parse_integer() {
+  assert_eq!(parse_integer("42"), Some(42));
+  assert_eq!(parse_integer("0x1F"), Some(31));
+  assert_eq!(parse_integer("017"), Some(15));
+  assert_eq!(parse_integer("0"), Some(0));
+  assert_eq!(parse_integer("18446744073709551616"), None);
+}
+
+// The comments met between two tokens, sorted the way protoc's
+// CommentCollector does it.
+struct CommentCollector {
+  buffer: String,
+  has_comment: bool,
+  is_line_comment: bool,
+  can_attach_to_previous: bool,
+  trailing: String
+}
+
+impl CommentCollector {
+  // Line comments on consecutive lines form one comment.
+  fn add_line_comment(&mut self, text: &str) {
+    if self.has_comment && !self.is_line_comment {
+      self.flush();
+    }
+    self.buffer.push_str(text);
+    self.has_comment = true;
+    self.is_line_comment = true;
+  }
+
+  fn add_block_comment(&mut self, text: &str) {
+    self.flush();
+    self.buffer.push_str(text);
+    self.has_comment = true;
+    self.is_line_comment = false;
+  }
+
+  fn clear(&mut self) {
+    self.buffer = String::new();
+    self.has_comment = false;
+  }
+
+  // A finished comment trails the previous token if it is the first one
+  // after it and nothing separates them; any other is detached and dropped.
+  fn flush(&mut self) {
+    if !self.has_comment {
+      return;
+    }
+    if self.can_attach_to_previous {
+      self.trailing.push_str(&self.buffer);
+      self.can_attach_to_previous = false;
+    }
+    self.clear();
+  }
+
+  // The trailing comment, and the one left over to lead the next token.
+  fn finish(self) -> (String, String) {
+    let leading = if self.has_comment { self.buffer } else { String::new() };
+    (self.trailing, leading)
+  }
+}
+
+struct Tokenizer<'a> {
+  file_name: &'a str,
+  input: &'a [u8],
+  pos: usize,
+  line: usize,
+  column: usize,
+  current: Token,
+  previous: Token
+}
+
+impl<'a> Tokenizer<'a> {
+  fn new(file_name: &'a str, input: &'a [u8]) -> Tokenizer<'a> {
+    let start = Token { kind: TokenStart, text: String::new(), line: 0, column: 0, end_column: 0 };
+    Tokenizer {
+      file_name,
+      input,
+      pos: 0,
+      line: 0,
+      column: 0,
+      current: start.clone(),
+      previous: start
+    }
+  }
+
+  fn error_at(&self, line: usize, column: usize, message: &str) -> ParseError {
+    ParseError { file: self.file_name.to_owned(), line: line + 1, column: column + 1, message: message.to_owned() }
+  }
+
+  fn error_here(&self, message: &str) -> ParseError {
+    self.error_at(self.line, self.column, message)
+  }
+
+  fn at_end(&self) -> bool {
+    self.pos >= self.input.len()
+  }
+
+  fn peek(&self, offset: usize) -> char {
+    if self.pos + offset < self.input.len() {
+      self.input[self.pos + offset] as char
+    } else {
+      '\0'
+    }
+  }
+
+  fn looking_at(&self, text: &str) -> bool {
+    self.input[self.pos..].starts_with(text.as_bytes())
+  }
+
+  // Columns advance to the next multiple of 8 at a tab, as in protoc.
+  fn next_char(&mut self) {
+    match self.peek(0) {
+      '\n' => {
+        self.line += 1;
+        self.column = 0;
+      }
+      '\t' => self.column += 8 - self.column % 8,
+      _ => self.column += 1
+    }
+    self.pos += 1;
+  }
+
+  fn try_consume(&mut self, c: char) -> bool {
+    if !self.at_end() && self.peek(0) == c {
+      self.next_char();
+      true
+    } else {
+      false
+    }
+  }
+
+  fn skip_inline_whitespace(&mut self) {
+    while !self.at_end() && is_inline_whitespace(self.peek(0)) {
+      self.next_char();
+    }
+  }
+
+  // Tokens and comments start and end at ASCII characters, so the input
+  // between them is still UTF-8.
+  fn text_from(&self, start: usize) -> String {
+    String::from_utf8(self.input[start..self.pos].to_vec()).unwrap()
+  }
+
+  // The text after "//", up to and including the newline.
+  fn consume_line_comment(&mut self) -> String {
+    self.next_char();
+    self.next_char();
+    let start = self.pos;
+    while !self.at_end() && self.peek(0) != '\n' {
+      self.next_char();
+    }
+    self.try_consume('\n');
+    self.text_from(start)
+  }
+
+  // The text between "/*" and "*/", without the whitespace and asterisk
+  // that start each line after the first.
+  fn consume_block_comment(&mut self) -> Result<String, ParseError> {
+    let (line, column) = (self.line, self.column);
+    self.next_char();
+    self.next_char();
+    let mut content = String::new();
+    let mut start = self.pos;
+    loop {
+      if self.at_end() {
+        return Err(self.error_at(line, column, "End-of-file inside block comment."));
+      }
+      if self.looking_at("*/") {
+        content.push_str(&self.text_from(start));
+        self.next_char();
+        self.next_char();
+        return Ok(content);
+      }
+      if !self.try_consume('\n') {
+        self.next_char();
+        continue;
+      }
+      content.push_str(&self.text_from(start));
+      self.skip_inline_whitespace();
+      if self.looking_at("*/") {
+        self.next_char();
+        self.next_char();
+        return Ok(content);
+      }
+      self.try_consume('*');
+      start = self.pos;
+    }
+  }
+
+  // Moves to the next token, skipping whitespace and comments.
+  fn next(&mut self) -> Result<(), ParseError> {
+    loop {
+      while !self.at_end() && (is_inline_whitespace(self.peek(0)) || self.peek(0) == '\n') {
+        self.next_char();
+      }
+      if self.looking_at("//") {
+        self.consume_line_comment();
+      } else if self.looking_at("/*") {
+        self.consume_block_comment()?;
+      } else {
+        break;
+      }
+    }
+    self.read_token()
+  }
+
+  // Tokenizer::NextWithComments: moves to the next token and returns the
+  // comment trailing the current one and the comment leading the next.
+  fn next_with_comments(&mut self) -> Result<(String, String), ParseError> {
+    let mut collector = CommentCollector {
+      buffer: String::new(),
+      has_comment: false,
+      is_line_comment: false,
+      can_attach_to_previous: true,
+      trailing: String::new()
+    };
+    if self.current.kind == TokenStart {
+      if self.input[self.pos..].starts_with(b"\xef\xbb\xbf") {
+        self.pos += 3;
+      }
+      collector.can_attach_to_previous = false;
+    } else {
+      // A comment on the same line belongs to the token before it.
+      self.skip_inline_whitespace();
+      if self.looking_at("//") {
+        let comment = self.consume_line_comment();
+        collector.add_line_comment(&comment);
+        collector.flush();
+      } else if self.looking_at("/*") {
+        let comment = self.consume_block_comment()?;
+        collector.add_block_comment(&comment);
+        self.skip_inline_whitespace();
+        if !self.try_consume('\n') {
+          // The next token is on the same line; the comment could belong
+          // to either, so it goes to neither.
+          collector.clear();
+          self.next()?;
+          return Ok(collector.finish());
+        }
+        collector.flush();
+      } else if !self.try_consume('\n') {
+        self.next()?;
+        return Ok(collector.finish());
+      }
+    }
+
+    loop {
+      self.skip_inline_whitespace();
+      if self.looking_at("//") {
+        let comment = self.consume_line_comment();
+        collector.add_line_comment(&comment);
+      } else if self.looking_at("/*") {
+        let comment = self.consume_block_comment()?;
+        collector.add_block_comment(&comment);
+        self.skip_inline_whitespace();
+        self.try_consume('\n');
+      } else if self.try_consume('\n') {
+        // A blank line ends a comment and detaches what follows from the
+        // previous token.
+        collector.flush();
+        collector.can_attach_to_previous = false;
+      } else {
+        self.next()?;
+        let text = self.current.text.as_str();
+        // Nothing follows at the end of a scope for a comment to lead.
+        if self.current.kind == TokenEnd || text == "}" || text == "]" || text == ")" {
+          collector.flush();
+        }
+        return Ok(collector.finish());
+      }
+    }
+  }
+
+  fn read_token(&mut self) -> Result<(), ParseError> {
+    let (start, line, column) = (self.pos, self.line, self.column);
+    let c = self.peek(0);
+    let kind = if self.at_end() {
+      TokenEnd
+    } else if is_letter(c) {
+      while is_letter(self.peek(0)) || is_digit(self.peek(0)) {
+        self.next_char();
+      }
+      TokenIdentifier
+    } else if is_digit(c) || (c == '.' && is_digit(self.peek(1))) {
+      self.consume_number()?
+    } else if c == '"' || c == '\'' {
+      self.consume_string(c)?;
+      TokenString
+    } else if !(' '..='~').contains(&c) {
+      return Err(self.error_here("Invalid character in input."));
+    } else {
+      self.next_char();
+      TokenSymbol
+    };
+    let token = Token { kind, text: self.text_from(start), line, column, end_column: self.column };
+    self.previous = replace(&mut self.current, token);
+    Ok(())
+  }
+
+  fn consume_digits(&mut self, radix: u32) {
+    while self.peek(0).is_digit(radix) {
+      self.next_char();
+    }
+  }
+
+  fn consume_number(&mut self) -> Result<TokenType, ParseError> {
+    let mut kind = TokenInteger;
+    if self.looking_at("0x") || self.looking_at("0X") {
+      self.next_char();
+      self.next_char();
+      if !self.peek(0).is_ascii_hexdigit() {
+        return Err(self.error_here("\"0x\" must be followed by hex digits."));
+      }
+      self.consume_digits(16);
+    } else if self.peek(0) == '0' && is_digit(self.peek(1)) {
+      self.consume_digits(8);
+      if is_digit(self.peek(0)) {
+        return Err(self.error_here("Numbers starting with leading zero must be in octal."));
+      }
+    } else {
+      self.consume_digits(10);
+      if self.try_consume('.') {
+        kind = TokenFloat;
+        self.consume_digits(10);
+      }
+      if self.try_consume('e') || self.try_consume('E') {
+        kind = TokenFloat;
+        let _ = self.try_consume('-') || self.try_consume('+');
+        if !is_digit(self.peek(0)) {
+          return Err(self.error_here("\"e\" must be followed by exponent."));
+        }
+        self.consume_digits(10);
+      }
+      if kind == TokenFloat {
+        let _ = self.try_consume('f') || self.try_consume('F');
+      }
+    }
+    if is_letter(self.peek(0)) {
+      return Err(self.error_here("Need space between number and identifier."));
+    }
+    Ok(kind)
+  }
+
+  // Escapes are checked when the parser decodes the literal.
+  fn consume_string(&mut self, delimiter: char) -> Result<(), ParseError> {
+    self.next_char();
+    loop {
+      if self.at_end() {
+        return Err(self.error_here("Unexpected end of string."));
+      }
+      match self.peek(0) {
+        '\n' => return Err(self.error_here("String literals cannot cross line boundaries.")),
+        '\\' => {
+          self.next_char();
+          if !self.at_end() && self.peek(0) != '\n' {
+            self.next_char();
+          }
+        }
+        c if c == delimiter => {
+          self.next_char();
+          return Ok(());
+        }
+        _ => self.next_char()
+      }
+    }
+  }
+}
+
+// An option as written: its name parts, true for "(extension)" ones, and
+// its value.
+struct OptionAssignment {
+  name: Vec<(String, bool)>,
+  value: OptionValue,
+  line: usize,
+  column: usize
+}
+
+enum OptionValue {
+  IdentifierValue(String),
+  PositiveIntValue(u64),
+  NegativeIntValue(i64),
+  DoubleValue(f64),
+  StringValue(Vec<u8>),
+  AggregateValue(String)
+}
+use self::OptionValue::*;
+
+impl OptionAssignment {
+  fn display_name(&self) -> String {
+    self.name.iter().map(|&(ref part, is_extension)| {
+      if is_extension { format!("({})", part) } else { part.clone() }
+    }).collect::<Vec<String>>().join(".")
+  }
+
+  // The name of a built-in option; custom ones give None.
+  fn builtin_name(&self) -> Option<&str> {
+    if self.name.len() != 1 {
+      return None;
+    }
+    let (ref name, is_extension) = self.name[0];
+    if is_extension { None } else { Some(name.as_str()) }
+  }
+
+  fn bool_value(&self) -> Result<bool, String> {
+    match self.value {
+      IdentifierValue(ref value) if value == "true" => Ok(true),
+      IdentifierValue(ref value) if value == "false" => Ok(false),
+      _ => Err(format!("Value must be \"true\" or \"false\" for boolean option \"{}\".", self.display_name()))
+    }
+  }
+
+  fn string_value(&self) -> Result<String, String> {
+    match self.value {
+      StringValue(ref bytes) => match String::from_utf8(bytes.clone()) {
+        Ok(value) => Ok(value),
+        Err(_) => Err(format!("Value for string option \"{}\" must be valid UTF-8.", self.display_name()))
+      },
+      _ => Err(format!("Value must be quoted string for string option \"{}\".", self.display_name()))
+    }
+  }
+
+  fn enum_value<T>(&self, from_name: impl Fn(&str) -> Option<T>) -> Result<T, String> {
+    match self.value {
+      IdentifierValue(ref value) => match from_name(value) {
+        Some(result) => Ok(result),
+        None => Err(format!("Enum type for option \"{}\" has no value named \"{}\".", self.display_name(), value))
+      },
+      _ => Err(format!("Value must be identifier for enum-valued option \"{}\".", self.display_name()))
+    }
+  }
+
+  // Custom options are left for whoever defines them to interpret.
+  fn to_uninterpreted(&self) -> UninterpretedOption {
+    let mut option = UninterpretedOption::new();
+    for &(ref name, is_extension) in self.name.iter() {
+      let mut part = NamePart::new();
+      part.set_name_part(name.clone());
+      part.set_is_extension(is_extension);
+      option.add_name(part);
+    }
+    match self.value {
+      IdentifierValue(ref value) => option.set_identifier_value(value.clone()),
+      PositiveIntValue(value) => option.set_positive_int_value(value),
+      NegativeIntValue(value) => option.set_negative_int_value(value),
+      DoubleValue(value) => option.set_double_value(value),
+      StringValue(ref value) => option.set_string_value(value.clone()),
+      AggregateValue(ref value) => option.set_aggregate_value(value.clone())
+    }
+    option
+  }
+}
+
+fn unknown_option(name: &str) -> Result<(), String> {
+  Err(format!("Option \"{}\" unknown.", name))
+}
+
+fn set_file_option(options: &mut FileOptions, option: &OptionAssignment) -> Result<(), String> {
+  match option.builtin_name() {
+    Some("java_package") => options.set_java_package(option.string_value()?),
+    Some("java_outer_classname") => options.set_java_outer_classname(option.string_value()?),
+    Some("java_multiple_files") => options.set_java_multiple_files(option.bool_value()?),
+    Some("java_generate_equals_and_hash") => options.set_java_generate_equals_and_hash(option.bool_value()?),
+    Some("optimize_for") => options.set_optimize_for(option.enum_value(OptimizeMode::from_name)?),
+    Some("go_package") => options.set_go_package(option.string_value()?),
+    Some("cc_generic_services") => options.set_cc_generic_services(option.bool_value()?),
+    Some("java_generic_services") => options.set_java_generic_services(option.bool_value()?),
+    Some("py_generic_services") => options.set_py_generic_services(option.bool_value()?),
+    Some("php_generic_services") => options.set_php_generic_services(option.bool_value()?),
+    Some("java_string_check_utf8") => options.set_java_string_check_utf8(option.bool_value()?),
+    Some("deprecated") => options.set_deprecated(option.bool_value()?),
+    Some("cc_enable_arenas") => options.set_cc_enable_arenas(option.bool_value()?),
+    Some("objc_class_prefix") => options.set_objc_class_prefix(option.string_value()?),
+    Some("csharp_namespace") => options.set_csharp_namespace(option.string_value()?),
+    Some("swift_prefix") => options.set_swift_prefix(option.string_value()?),
+    Some("php_class_prefix") => options.set_php_class_prefix(option.string_value()?),
+    Some("php_namespace") => options.set_php_namespace(option.string_value()?),
+    Some("php_metadata_namespace") => options.set_php_metadata_namespace(option.string_value()?),
+    Some("ruby_package") => options.set_ruby_package(option.string_value()?),
+    Some(name) => return unknown_option(name),
+    None => options.add_uninterpreted_option(option.to_uninterpreted())
+  }
+  Ok(())
+}
+
+fn set_message_option(options: &mut MessageOptions, option: &OptionAssignment) -> Result<(), String> {
+  match option.builtin_name() {
+    Some("message_set_wire_format") => options.set_message_set_wire_format(option.bool_value()?),
+    Some("no_standard_descriptor_accessor") => options.set_no_standard_descriptor_accessor(option.bool_value()?),
+    Some("deprecated") => options.set_deprecated(option.bool_value()?),
+    Some("map_entry") => return Err("map_entry should not be set explicitly. Use map<KeyType, ValueType> instead.".to_string()),
+    Some(name) => return unknown_option(name),
+    None => options.add_uninterpreted_option(option.to_uninterpreted())
+  }
+  Ok(())
+}
+
+fn set_field_option(options: &mut FieldOptions, option: &OptionAssignment) -> Result<(), String> {
+  match option.builtin_name() {
+    Some("ctype") => options.set_ctype(option.enum_value(CType::from_name)?),
+    Some("packed") => options.set_packed(option.bool_value()?),
+    Some("jstype") => options.set_jstype(option.enum_value(JsType::from_name)?),
+    Some("lazy") => options.set_lazy(option.bool_value()?),
+    Some("unverified_lazy") => options.set_unverified_lazy(option.bool_value()?),
+    Some("deprecated") => options.set_deprecated(option.bool_value()?),
+    Some("experimental_map_key") => options.set_experimental_map_key(option.string_value()?),
+    Some("weak") => options.set_weak(option.bool_value()?),
+    Some("debug_redact") => options.set_debug_redact(option.bool_value()?),
+    Some(name) => return unknown_option(name),
+    None => options.add_uninterpreted_option(option.to_uninterpreted())
+  }
+  Ok(())
+}
+
+fn set_oneof_option(options: &mut OneofOptions, option: &OptionAssignment) -> Result<(), String> {
+  match option.builtin_name() {
+    Some(name) => return unknown_option(name),
+    None => options.add_uninterpreted_option(option.to_uninterpreted())
+  }
+  Ok(())
+}
+
+fn set_enum_option(options: &mut EnumOptions, option: &OptionAssignment) -> Result<(), String> {
+  match option.builtin_name() {
+    Some("allow_alias") => options.set_allow_alias(option.bool_value()?),
+    Some("deprecated") => options.set_deprecated(option.bool_value()?),
+    Some(name) => return unknown_option(name),
+    None => options.add_uninterpreted_option(option.to_uninterpreted())
+  }
+  Ok(())
+}
+
+fn set_enum_value_option(options: &mut EnumValueOptions, option: &OptionAssignment) -> Result<(), String> {
+  match option.builtin_name() {
+    Some("deprecated") => options.set_deprecated(option.bool_value()?),
+    Some("debug_redact") => options.set_debug_redact(option.bool_value()?),
+    Some(name) => return unknown_option(name),
+    None => options.add_uninterpreted_option(option.to_uninterpreted())
+  }
+  Ok(())
+}
+
+fn set_service_option(options: &mut ServiceOptions, option: &OptionAssignment) -> Result<(), String> {
+  match option.builtin_name() {
+    Some("deprecated") => options.set_deprecated(option.bool_value()?),
+    Some(name) => return unknown_option(name),
+    None => options.add_uninterpreted_option(option.to_uninterpreted())
+  }
+  Ok(())
+}
+
+fn set_method_option(options: &mut MethodOptions, option: &OptionAssignment) -> Result<(), String> {
+  match option.builtin_name() {
+    Some("deprecated") => options.set_deprecated(option.bool_value()?),
+    Some("idempotency_level") => options.set_idempotency_level(option.enum_value(IdempotencyLevel::from_name)?),
+    Some(name) => return unknown_option(name),
+    None => options.add_uninterpreted_option(option.to_uninterpreted())
+  }
+  Ok(())
+}
+
+static kScalarTypes: &[(&str, Type)] = &[
+  ("double", TypeDouble), ("float", TypeFloat), ("int64", TypeInt64), ("uint64", TypeUint64),
+  ("int32", TypeInt32), ("fixed64", TypeFixed64), ("fixed32", TypeFixed32), ("bool", TypeBool),
+  ("string", TypeString), ("bytes", TypeBytes), ("uint32", TypeUint32), ("sfixed32", TypeSfixed32),
+  ("sfixed64", TypeSfixed64), ("sint32", TypeSint32), ("sint64", TypeSint64)
+];
+
+// A field's type as written: a scalar, or the still unresolved name of a
+// message or enum.
+enum TypeReference {
+  ScalarType(Type),
+  NamedType(String)
+}
+use self::TypeReference::*;
+
+fn set_field_type(field: &mut FieldDescriptorProto, field_type: TypeReference) {
+  match field_type {
+    ScalarType(scalar) => field.set_type(scalar),
+    NamedType(name) => field.set_type_name(name)
+  }
+}
+
+// Where a field is declared, which decides the labels it may have.
+#[derive(PartialEq, Eq)]
+enum FieldScope {
+  MessageField,
+  OneofField,
+  ExtensionField
+}
+use self::FieldScope::*;
+
+// protoc names the entry type of "map<K, V> foo_bar" "FooBarEntry".
+fn map_entry_name(field_name: &str) -> String {
+  let mut result = String::new();
+  let mut capitalize = true;
+  for c in field_name.chars() {
+    if c == '_' {
+      capitalize = true;
+    } else if capitalize {
+      result.push(c.to_ascii_uppercase());
+      capitalize = false;
+    } else {
+      result.push(c);
+    }
+  }
+  result.push_str("Entry");
+  result
+}
+
+fn map_entry_field(name: &str, number: i32, field_type: TypeReference) -> FieldDescriptorProto {
+  let mut field = FieldDescriptorProto::new();
+  field.set_name(name.to_owned());
+  field.set_number(number);
+  field.set_label(LabelOptional);
+  set_field_type(&mut field, field_type);
+  field
+}
+
+// protoc gives each proto3 optional field a oneof of its own, after the
+// declared ones, so that readers unaware of proto3 optional still see its
+// presence. The name avoids those of the other fields and oneofs.
+fn add_synthetic_oneofs(message: &mut DescriptorProto) {
+  let mut names: Vec<String> = message.get_field().iter().map(|field| field.get_name().to_owned()).collect();
+  names.extend(message.get_oneof_decl().iter().map(|oneof| oneof.get_name().to_owned()));
+  let mut fields = message.take_field();
+  for field in fields.iter_mut().filter(|field| field.get_proto3_optional()) {
+    let mut name = if field.get_name().starts_with("_") { field.get_name().to_owned() } else { format!("_{}", field.get_name()) };
+    while names.contains(&name) {
+      name = format!("X{}", name);
+    }
+    names.push(name.clone());
+    field.set_oneof_index(message.oneof_decl_len() as i32);
+    let mut oneof = OneofDescriptorProto::new();
+    oneof.set_name(name);
+    message.add_oneof_decl(oneof);
+  }
+  message.set_field(fields);
+}
+
+fn child_path(path: &[i32], suffix: &[i32]) -> Vec<i32> {
+  let mut result = path.to_owned();
+  result.extend_from_slice(suffix);
+  result
+}
+
+struct Parser<'a> {
+  tokenizer: Tokenizer<'a>,
+  proto3: bool,
+  // The comment leading the next declaration. It is collected at the end
+  // of the previous one, the only place protoc looks for comments.
+  upcoming_doc_comments: String,
+  locations: Vec<Location>
+}
+
+impl<'a> Parser<'a> {
+  fn error(&self, message: &str) -> ParseError {
+    let token = &self.tokenizer.current;
+    self.tokenizer.error_at(token.line, token.column, message)
+  }
+
+  fn option_result(&self, option: &OptionAssignment, result: Result<(), String>) -> Result<(), ParseError> {
+    result.map_err(|message| self.tokenizer.error_at(option.line, option.column, &message))
+  }
+
+  fn at_end(&self) -> bool {
+    self.tokenizer.current.kind == TokenEnd
+  }
+
+  fn looking_at(&self, text: &str) -> bool {
+    self.tokenizer.current.kind != TokenString && self.tokenizer.current.text == text
+  }
+
+  fn looking_at_kind(&self, kind: TokenType) -> bool {
+    self.tokenizer.current.kind == kind
+  }
+
+  fn next(&mut self) -> Result<(), ParseError> {
+    self.tokenizer.next()
+  }
+
+  fn try_consume(&mut self, text: &str) -> Result<bool, ParseError> {
+    if !self.looking_at(text) {
+      return Ok(false);
+    }
+    self.next()?;
+    Ok(true)
+  }
+
+  fn expect(&mut self, text: &str, message: &str) -> Result<(), ParseError> {
+    if self.try_consume(text)? {
+      Ok(())
+    } else {
+      Err(self.error(message))
+    }
+  }
+
+  fn consume(&mut self, text: &str) -> Result<(), ParseError> {
+    self.expect(text, &format!("Expected \"{}\".", text))
+  }
+
+  fn consume_identifier(&mut self, message: &str) -> Result<String, ParseError> {
+    if !self.looking_at_kind(TokenIdentifier) {
+      return Err(self.error(message));
+    }
+    let text = self.tokenizer.current.text.clone();
+    self.next()?;
+    Ok(text)
+  }
+
+  // A dotted name such as a package, "a.b.c".
+  fn consume_dotted_name(&mut self, message: &str) -> Result<String, ParseError> {
+    let mut name = self.consume_identifier(message)?;
+    while self.try_consume(".")? {
+      name.push('.');
+      name.push_str(&self.consume_identifier(message)?);
+    }
+    Ok(name)
+  }
+
+  // An integer literal no greater than `max`.
+  fn consume_integer(&mut self, max: u64, message: &str) -> Result<u64, ParseError> {
+    if !self.looking_at_kind(TokenInteger) {
+      return Err(self.error(message));
+    }
+    let value = match parse_integer(&self.tokenizer.current.text) {
+      Some(value) if value <= max => value,
+      _ => return Err(self.error("Integer out of range."))
+    };
+    self.next()?;
+    Ok(value)
+  }
+
+  // An integer literal with an optional "-", within i32.
+  fn consume_signed_integer(&mut self, message: &str) -> Result<i32, ParseError> {
+    let negative = self.try_consume("-")?;
+    let max = kMaxEnumNumber as u64;
+    let value = self.consume_integer(if negative { max + 1 } else { max }, message)?;
+    Ok(if negative { -(value as i64) as i32 } else { value as i32 })
+  }
+
+  // One or more adjacent string literals, concatenated and unescaped.
+  fn consume_string(&mut self, message: &str) -> Result<Vec<u8>, ParseError> {
+    if !self.looking_at_kind(TokenString) {
+      return Err(self.error(message));
+    }
+    let mut result = Vec::new();
+    while self.looking_at_kind(TokenString) {
+      let escaped = {
+        let text = &self.tokenizer.current.text;
+        text[1..text.len() - 1].to_string()
+      };
+      match unescape_c_bytes(&escaped) {
+        Ok(bytes) => result.extend(bytes),
+        Err(error) => return Err(self.error(&format!("Invalid string literal: {}.", error)))
+      }
+      self.next()?;
+    }
+    Ok(result)
+  }
+
+  fn consume_utf8_string(&mut self, message: &str) -> Result<String, ParseError> {
+    let not_utf8 = self.error("String must be valid UTF-8.");
+    match String::from_utf8(self.consume_string(message)?) {
+      Ok(text) => Ok(text),
+      Err(_) => Err(not_utf8)
+    }
+  }
+
+  // LocationRecorder: a SourceCodeInfo location for `path`, starting at the
+  // current token. `end_location` ends it after the last token consumed.
+  fn start_location(&mut self, path: Vec<i32>) -> usize {
+    let (line, column) = (self.tokenizer.current.line, self.tokenizer.current.column);
+    self.start_location_at(path, line, column)
+  }
+
+  fn start_location_at(&mut self, path: Vec<i32>, line: usize, column: usize) -> usize {
+    let mut location = Location::new();
+    location.set_path(path);
+    location.set_span(vec![line as i32, column as i32]);
+    self.locations.push(location);
+    self.locations.len() - 1
+  }
+
+  // Spans on one line leave out the end line.
+  fn end_location(&mut self, index: usize) {
+    let end_line = self.tokenizer.previous.line as i32;
+    let end_column = self.tokenizer.previous.end_column as i32;
+    let location = &mut self.locations[index];
+    if location.get_span()[0] != end_line {
+      location.add_span(end_line);
+    }
+    location.add_span(end_column);
+  }
+
+  // Parser::TryConsumeEndOfDeclaration: consumes the ";" or "{" ending a
+  // declaration, or the "}" closing a block. The comments around it are the
+  // declaration's, if it has a location.
+  fn end_declaration(&mut self, text: &str, location: Option<usize>) -> Result<bool, ParseError> {
+    if !self.looking_at(text) {
+      return Ok(false);
+    }
+    let (trailing, upcoming) = self.tokenizer.next_with_comments()?;
+    let leading = replace(&mut self.upcoming_doc_comments, upcoming);
+    if let Some(index) = location {
+      let location = &mut self.locations[index];
+      if !leading.is_empty() {
+        location.set_leading_comments(leading);
+      }
+      if !trailing.is_empty() {
+        location.set_trailing_comments(trailing);
+      }
+    }
+    Ok(true)
+  }
+
+  fn consume_end_of_declaration(&mut self, text: &str, location: Option<usize>) -> Result<(), ParseError> {
+    if self.end_declaration(text, location)? {
+      Ok(())
+    } else {
+      Err(self.error(&format!("Expected \"{}\".", text)))
+    }
+  }
+
+  fn parse_file(&mut self, file: &mut FileDescriptorProto) -> Result<(), ParseError> {
+    let (_, upcoming) = self.tokenizer.next_with_comments()?;
+    self.upcoming_doc_comments = upcoming;
+    if self.looking_at("syntax") {
+      let location = self.start_location(vec![12]);
+      self.next()?;
+      self.consume("=")?;
+      let unknown = self.error("Unrecognized syntax identifier.  This parser only recognizes \"proto2\" and \"proto3\".");
+      let syntax = self.consume_utf8_string("Expected syntax identifier.")?;
+      match syntax.as_str() {
+        "proto2" => (),
+        "proto3" => {
+          self.proto3 = true;
+          file.set_syntax(syntax.clone());
+        }
+        _ => return Err(unknown)
+      }
+      self.consume_end_of_declaration(";", Some(location))?;
+      self.end_location(location);
+    }
+    while !self.at_end() {
+      self.parse_top_level_statement(file)?;
+    }
+    file.mut_source_code_info().set_location(std::mem::take(&mut self.locations));
+    Ok(())
+  }
+
+  fn parse_top_level_statement(&mut self, file: &mut FileDescriptorProto) -> Result<(), ParseError> {
+    if self.end_declaration(";", None)? {
+      // An empty statement.
+      Ok(())
+    } else if self.looking_at("message") {
+      let message = self.parse_message_definition(vec![4, file.message_type_len() as i32])?;
+      file.add_message_type(message);
+      Ok(())
+    } else if self.looking_at("enum") {
+      let descriptor = self.parse_enum_definition(vec![5, file.enum_type_len() as i32])?;
+      file.add_enum_type(descriptor);
+      Ok(())
+    } else if self.looking_at("service") {
+      let service = self.parse_service_definition(vec![6, file.service_len() as i32])?;
+      file.add_service(service);
+      Ok(())
+    } else if self.looking_at("extend") {
+      let mut extensions = file.take_extension();
+      let mut messages = file.take_message_type();
+      let result = self.parse_extend(&mut extensions, &mut messages, &[7], &[4]);
+      file.set_extension(extensions);
+      file.set_message_type(messages);
+      result
+    } else if self.looking_at("import") {
+      self.parse_import(file)
+    } else if self.looking_at("package") {
+      self.parse_package(file)
+    } else if self.looking_at("option") {
+      let option = self.parse_option_statement()?;
+      self.option_result(&option, set_file_option(file.mut_options(), &option))
+    } else {
+      Err(self.error("Expected top-level statement (e.g. \"message\")."))
+    }
+  }
+
+  fn parse_import(&mut self, file: &mut FileDescriptorProto) -> Result<(), ParseError> {
+    let index = file.dependency_len() as i32;
+    let location = self.start_location(vec![3, index]);
+    self.consume("import")?;
+    if self.try_consume("public")? {
+      file.add_public_dependency(index);
+    } else if self.try_consume("weak")? {
+      file.add_weak_dependency(index);
+    }
+    file.add_dependency(self.consume_utf8_string("Expected a string naming the file to import.")?);
+    self.consume_end_of_declaration(";", Some(location))?;
+    self.end_location(location);
+    Ok(())
+  }
+
+  fn parse_package(&mut self, file: &mut FileDescriptorProto) -> Result<(), ParseError> {
+    if file.has_package() {
+      return Err(self.error("Multiple package definitions."));
+    }
+    let location = self.start_location(vec![2]);
+    self.consume("package")?;
+    file.set_package(self.consume_dotted_name("Expected identifier.")?);
+    self.consume_end_of_declaration(";", Some(location))?;
+    self.end_location(location);
+    Ok(())
+  }
+
+  // "option name = value;" in any scope; the caller applies it.
+  fn parse_option_statement(&mut self) -> Result<OptionAssignment, ParseError> {
+    self.consume("option")?;
+    let option = self.parse_option_assignment()?;
+    self.consume_end_of_declaration(";", None)?;
+    Ok(option)
+  }
+
+  // "name = value", where the name is dotted and may have "(extension)"
+  // parts.
+  fn parse_option_assignment(&mut self) -> Result<OptionAssignment, ParseError> {
+    let (line, column) = (self.tokenizer.current.line, self.tokenizer.current.column);
+    let mut name = Vec::new();
+    loop {
+      if self.try_consume("(")? {
+        let mut part = String::new();
+        if self.try_consume(".")? {
+          part.push('.');
+        }
+        part.push_str(&self.consume_dotted_name("Expected identifier.")?);
+        self.consume(")")?;
+        name.push((part, true));
+      } else {
+        name.push((self.consume_identifier("Expected identifier.")?, false));
+      }
+      if !self.try_consume(".")? {
+        break;
+      }
+    }
+    self.consume("=")?;
+    let value = self.parse_option_value()?;
+    Ok(OptionAssignment { name, value, line, column })
+  }
+
+  fn parse_option_value(&mut self) -> Result<OptionValue, ParseError> {
+    if self.looking_at("{") {
+      let aggregate = self.parse_aggregate()?;
+      return Ok(AggregateValue(aggregate));
+    }
+    let negative = self.try_consume("-")?;
+    let token = self.tokenizer.current.clone();
+    match token.kind {
+      TokenIdentifier => {
+        if negative {
+          return Err(self.error("Invalid '-' symbol before identifier."));
+        }
+        self.next()?;
+        Ok(IdentifierValue(token.text))
+      }
+      TokenInteger => {
+        let max = if negative { 1 << 63 } else { u64::MAX };
+        let value = self.consume_integer(max, "Expected integer.")?;
+        Ok(if negative { NegativeIntValue(-(value as i64)) } else { PositiveIntValue(value) })
+      }
+      TokenFloat => {
+        self.next()?;
+        let value: f64 = float_text(&token.text).parse().unwrap();
+        Ok(DoubleValue(if negative { -value } else { value }))
+      }
+      TokenString => {
+        if negative {
+          return Err(self.error("Invalid '-' symbol before string."));
+        }
+        Ok(StringValue(self.consume_string("Expected string.")?))
+      }
+      _ => Err(self.error("Expected option value."))
+    }
+  }
+
+  // An aggregate option value, stored as its tokens separated by spaces
+  // without the enclosing braces.
+  fn parse_aggregate(&mut self) -> Result<String, ParseError> {
+    self.consume("{")?;
+    let mut depth = 1;
+    let mut value = String::new();
+    while !self.at_end() {
+      if self.looking_at("{") {
+        depth += 1;
+      } else if self.looking_at("}") {
+        depth -= 1;
+        if depth == 0 {
+          self.next()?;
+          return Ok(value);
+        }
+      }
+      if !value.is_empty() {
+        value.push(' ');
+      }
+      value.push_str(&self.tokenizer.current.text);
+      self.next()?;
+    }
+    Err(self.error("Unexpected end of stream while parsing aggregate value."))
+  }
+
+  fn parse_message_definition(&mut self, path: Vec<i32>) -> Result<DescriptorProto, ParseError> {
+    let location = self.start_location(path.clone());
+    self.consume("message")?;
+    let mut message = DescriptorProto::new();
+    message.set_name(self.consume_identifier("Expected message name.")?);
+    self.parse_message_block(&mut message, path, location)?;
+    self.end_location(location);
+    Ok(message)
+  }
+
+  // The braces of a message or group and what is in them.
+  fn parse_message_block(&mut self, message: &mut DescriptorProto, path: Vec<i32>, location: usize)
+      -> Result<(), ParseError> {
+    self.consume_end_of_declaration("{", Some(location))?;
+    while !self.end_declaration("}", None)? {
+      if self.at_end() {
+        return Err(self.error("Reached end of input in message definition (missing '}')."));
+      }
+      self.parse_message_statement(message, &path)?;
+    }
+    add_synthetic_oneofs(message);
+    Ok(())
+  }
+
+  fn parse_message_statement(&mut self, message: &mut DescriptorProto, path: &[i32]) -> Result<(), ParseError> {
+    if self.end_declaration(";", None)? {
+      Ok(())
+    } else if self.looking_at("message") {
+      let nested = self.parse_message_definition(child_path(path, &[3, message.nested_type_len() as i32]))?;
+      message.add_nested_type(nested);
+      Ok(())
+    } else if self.looking_at("enum") {
+      let nested = self.parse_enum_definition(child_path(path, &[4, message.enum_type_len() as i32]))?;
+      message.add_enum_type(nested);
+      Ok(())
+    } else if self.looking_at("extensions") {
+      self.parse_extensions(message)
+    } else if self.looking_at("reserved") {
+      self.parse_reserved(message)
+    } else if self.looking_at("extend") {
+      let mut extensions = message.take_extension();
+      let mut nested = message.take_nested_type();
+      let result = self.parse_extend(&mut extensions, &mut nested, &child_path(path, &[6]), &child_path(path, &[3]));
+      message.set_extension(extensions);
+      message.set_nested_type(nested);
+      result
+    } else if self.looking_at("option") {
+      let option = self.parse_option_statement()?;
+      self.option_result(&option, set_message_option(message.mut_options(), &option))
+    } else if self.looking_at("oneof") {
+      self.parse_oneof(message, path)
+    } else {
+      let field_path = child_path(path, &[2, message.field_len() as i32]);
+      let mut nested = message.take_nested_type();
+      let result = self.parse_field(field_path, MessageField, &mut nested, &child_path(path, &[3]));
+      message.set_nested_type(nested);
+      message.add_field(result?);
+      Ok(())
+    }
+  }
+
+  // A field in a message, oneof or extend block. The types of groups and
+  // maps are added to `nested`, whose path is `nested_path`.
+  fn parse_field(&mut self, path: Vec<i32>, scope: FieldScope, nested: &mut Vec<DescriptorProto>, nested_path: &[i32])
+      -> Result<FieldDescriptorProto, ParseError> {
+    let (line, column) = (self.tokenizer.current.line, self.tokenizer.current.column);
+    let location = self.start_location(path);
+    let mut field = FieldDescriptorProto::new();
+    let label = match self.tokenizer.current.text.as_str() {
+      "optional" => Some(LabelOptional),
+      "required" => Some(LabelRequired),
+      "repeated" => Some(LabelRepeated),
+      _ => None
+    };
+    match label {
+      Some(_) if scope == OneofField => {
+        return Err(self.error("Fields in oneofs must not have labels (required / optional / repeated)."));
+      }
+      Some(LabelRequired) if self.proto3 => return Err(self.error("Required fields are not allowed in proto3.")),
+      Some(label) => {
+        self.next()?;
+        field.set_label(label);
+        if self.proto3 && label == LabelOptional {
+          field.set_proto3_optional(true);
+        }
+      }
+      None => ()
+    }
+
+    let mut map_types = None;
+    if self.looking_at("map") {
+      self.next()?;
+      if self.try_consume("<")? {
+        if scope == OneofField {
+          return Err(self.error("Map fields are not allowed in oneofs."));
+        }
+        if scope == ExtensionField {
+          return Err(self.error("Map fields are not allowed to be extensions."));
+        }
+        if label.is_some() {
+          return Err(self.error("Field labels (required/optional/repeated) are not allowed on map fields."));
+        }
+        let key = self.parse_type()?;
+        match key {
+          ScalarType(TypeDouble) | ScalarType(TypeFloat) | ScalarType(TypeBytes) | NamedType(_) => {
+            return Err(self.error("Key in map fields cannot be float/double, bytes or message types."));
+          }
+          _ => ()
+        }
+        self.consume(",")?;
+        let value = self.parse_type()?;
+        self.consume(">")?;
+        field.set_label(LabelRepeated);
+        map_types = Some((key, value));
+      } else {
+        // A message or enum named "map".
+        field.set_type_name("map".to_string());
+      }
+    } else if self.looking_at("group") {
+      if self.proto3 {
+        return Err(self.error("Group syntax is no longer supported in proto3."));
+      }
+      self.next()?;
+      field.set_type(TypeGroup);
+    } else {
+      set_field_type(&mut field, self.parse_type()?);
+    }
+    if !field.has_label() {
+      if !self.proto3 && scope != OneofField {
+        return Err(self.error("Expected \"required\", \"optional\", or \"repeated\"."));
+      }
+      field.set_label(LabelOptional);
+    }
+
+    let name_error = self.error("Group names must start with a capital letter.");
+    field.set_name(self.consume_identifier("Expected field name.")?);
+    self.expect("=", "Missing field number.")?;
+    // Any i32 parses; the resolver checks the number as protoc's
+    // DescriptorBuilder does.
+    field.set_number(self.consume_integer(kMaxEnumNumber as u64, "Expected field number.")? as i32);
+    self.parse_field_options(&mut field)?;
+
+    if let Some((key, value)) = map_types {
+      let entry_name = map_entry_name(field.get_name());
+      let mut entry = DescriptorProto::new();
+      entry.set_name(entry_name.clone());
+      entry.mut_options().set_map_entry(true);
+      entry.add_field(map_entry_field("key", 1, key));
+      entry.add_field(map_entry_field("value", 2, value));
+      nested.push(entry);
+      field.set_type_name(entry_name);
+    }
+
+    if field.get_type() == TypeGroup {
+      // The field is named after the group, in lower case.
+      let group_name = field.get_name().to_owned();
+      if !group_name.starts_with(|c: char| c.is_ascii_uppercase()) {
+        return Err(name_error);
+      }
+      field.set_name(group_name.to_ascii_lowercase());
+      field.set_type_name(group_name.clone());
+      let group_path = child_path(nested_path, &[nested.len() as i32]);
+      let group_location = self.start_location_at(group_path.clone(), line, column);
+      let mut group = DescriptorProto::new();
+      group.set_name(group_name);
+      self.parse_message_block(&mut group, group_path, group_location)?;
+      self.end_location(group_location);
+      nested.push(group);
+    } else {
+      self.consume_end_of_declaration(";", Some(location))?;
+    }
+    self.end_location(location);
+    Ok(field)
+  }
+
+  // A scalar type or a possibly qualified type name.
+  fn parse_type(&mut self) -> Result<TypeReference, ParseError> {
+    for &(name, scalar) in kScalarTypes.iter() {
+      if self.looking_at(name) {
+        self.next()?;
+        return Ok(ScalarType(scalar));
+      }
+    }
+    let mut name = String::new();
+    if self.try_consume(".")? {
+      name.push('.');
+    }
+    name.push_str(&self.consume_dotted_name("Expected type name.")?);
+    Ok(NamedType(name))
+  }
+
+  fn parse_message_type(&mut self) -> Result<String, ParseError> {
+    match self.parse_type()? {
+      NamedType(name) => Ok(name),
+      ScalarType(_) => Err(self.error("Expected message type."))
+    }
+  }
+
+  // "[name = value, ...]" after a field. `default` and `json_name` aren't
+  // options but fields of the descriptor.
+  fn parse_field_options(&mut self, field: &mut FieldDescriptorProto) -> Result<(), ParseError> {
+    if !self.try_consume("[")? {
+      return Ok(());
+    }
+    loop {
+      if self.looking_at("default") {
+        self.parse_default_assignment(field)?;
+      } else if self.looking_at("json_name") {
+        if field.has_json_name() {
+          return Err(self.error("Already set option \"json_name\"."));
+        }
+        self.next()?;
+        self.consume("=")?;
+        field.set_json_name(self.consume_utf8_string("Expected string for JSON name.")?);
+      } else {
+        let option = self.parse_option_assignment()?;
+        self.option_result(&option, set_field_option(field.mut_options(), &option))?;
+      }
+      if !self.try_consume(",")? {
+        break;
+      }
+    }
+    self.consume("]")
+  }
+
+  // The default is stored the way protoc stores it: numbers in decimal,
+  // strings unescaped and bytes C-escaped.
+  fn parse_default_assignment(&mut self, field: &mut FieldDescriptorProto) -> Result<(), ParseError> {
+    if field.has_default_value() {
+      return Err(self.error("Already set option \"default\"."));
+    }
+    if self.proto3 {
+      return Err(self.error("Explicit default values are not allowed in proto3."));
+    }
+    self.next()?;
+    self.consume("=")?;
+    if !field.has_type() {
+      // A message or enum type, unknown until names are resolved; an enum
+      // value name is checked by the generator.
+      let value = self.tokenizer.current.text.clone();
+      self.next()?;
+      field.set_default_value(value);
+      return Ok(());
+    }
+    let value = match field.get_type() {
+      TypeFloat | TypeDouble => {
+        let negative = self.try_consume("-")?;
+        let token = self.tokenizer.current.clone();
+        let value = match token.kind {
+          TokenInteger => match parse_integer(&token.text) {
+            Some(value) => value.to_string(),
+            None => return Err(self.error("Integer out of range."))
+          },
+          TokenFloat => float_text(&token.text).to_owned(),
+          TokenIdentifier if token.text == "inf" || token.text == "nan" => token.text.clone(),
+          _ => return Err(self.error("Expected number."))
+        };
+        self.next()?;
+        if negative { format!("-{}", value) } else { value }
+      }
+      TypeInt32 | TypeSint32 | TypeSfixed32 => self.consume_signed_default(kMaxEnumNumber as u64)?,
+      TypeInt64 | TypeSint64 | TypeSfixed64 => self.consume_signed_default((1 << 63) - 1)?,
+      TypeUint32 | TypeFixed32 | TypeUint64 | TypeFixed64 => {
+        if self.looking_at("-") {
+          return Err(self.error("Unsigned field can't have negative default value."));
+        }
+        let max = match field.get_type() {
+          TypeUint32 | TypeFixed32 => 0xffffffff,
+          _ => u64::MAX
+        };
+        self.consume_integer(max, "Expected integer.")?.to_string()
+      }
+      TypeBool => {
+        if self.try_consume("true")? {
+          "true".to_string()
+        } else if self.try_consume("false")? {
+          "false".to_string()
+        } else {
+          return Err(self.error("Expected \"true\" or \"false\"."));
+        }
+      }
+      TypeString => self.consume_utf8_string("Expected string.")?,
+      TypeBytes => c_escape(&self.consume_string("Expected string.")?),
+      TypeEnum => self.consume_identifier("Expected enum identifier.")?,
+      TypeMessage | TypeGroup => return Err(self.error("Messages can't have default values."))
+    };
+    field.set_default_value(value);
+    Ok(())
+  }
+
+  fn consume_signed_default(&mut self, max: u64) -> Result<String, ParseError> {
+    let negative = self.try_consume("-")?;
+    let value = self.consume_integer(if negative { max + 1 } else { max }, "Expected integer.")?;
+    Ok(if negative { format!("-{}", value) } else { value.to_string() })
+  }
+
+  fn parse_oneof(&mut self, message: &mut DescriptorProto, path: &[i32]) -> Result<(), ParseError> {
+    let index = message.oneof_decl_len() as i32;
+    let location = self.start_location(child_path(path, &[8, index]));
+    self.consume("oneof")?;
+    let mut oneof = OneofDescriptorProto::new();
+    oneof.set_name(self.consume_identifier("Expected oneof name.")?);
+    self.consume_end_of_declaration("{", Some(location))?;
+    loop {
+      if self.at_end() {
+        return Err(self.error("Reached end of input in oneof definition (missing '}')."));
+      }
+      if self.looking_at("option") {
+        let option = self.parse_option_statement()?;
+        self.option_result(&option, set_oneof_option(oneof.mut_options(), &option))?;
+      } else {
+        let field_path = child_path(path, &[2, message.field_len() as i32]);
+        let mut nested = message.take_nested_type();
+        let result = self.parse_field(field_path, OneofField, &mut nested, &child_path(path, &[3]));
+        message.set_nested_type(nested);
+        let mut field = result?;
+        field.set_oneof_index(index);
+        message.add_field(field);
+      }
+      if self.end_declaration("}", None)? {
+        break;
+      }
+    }
+    self.end_location(location);
+    message.add_oneof_decl(oneof);
+    Ok(())
+  }
+
+  // "extensions 100 to 199, 500 to max;" The ranges are stored with their
+  // end exclusive.
+  fn parse_extensions(&mut self, message: &mut DescriptorProto) -> Result<(), ParseError> {
+    self.consume("extensions")?;
+    loop {
+      let (start, end) = self.parse_field_range("Expected field number range.")?;
+      let mut range = ExtensionRange::new();
+      range.set_start(start);
+      range.set_end(end + 1);
+      message.add_extension_range(range);
+      if !self.try_consume(",")? {
+        break;
+      }
+    }
+    if self.looking_at("[") {
+      return Err(self.error("Extension range options are not supported."));
+    }
+    self.consume_end_of_declaration(";", None)
+  }
+
+  // "reserved 2, 9 to 11;" or "reserved \"foo\", \"bar\";"
+  fn parse_reserved(&mut self, message: &mut DescriptorProto) -> Result<(), ParseError> {
+    self.consume("reserved")?;
+    if self.looking_at_kind(TokenString) {
+      loop {
+        message.add_reserved_name(self.consume_utf8_string("Expected field name.")?);
+        if !self.try_consume(",")? {
+          break;
+        }
+      }
+    } else {
+      loop {
+        let (start, end) = self.parse_field_range("Expected field name or number range.")?;
+        let mut range = ReservedRange::new();
+        range.set_start(start);
+        range.set_end(end + 1);
+        message.add_reserved_range(range);
+        if !self.try_consume(",")? {
+          break;
+        }
+      }
+    }
+    self.consume_end_of_declaration(";", None)
+  }
+
+  // "N", "N to M" or "N to max", as inclusive bounds.
+  fn parse_field_range(&mut self, message: &str) -> Result<(i32, i32), ParseError> {
+    let start_error = self.error("Field numbers must be positive integers.");
+    let start = self.consume_integer(kMaxEnumNumber as u64, message)? as i32;
+    if start <= 0 {
+      return Err(start_error);
+    }
+    let end = if !self.try_consume("to")? {
+      start
+    } else if self.try_consume("max")? {
+      kMaxFieldNumber
+    } else {
+      let end_error = self.error("Range end must not be less than its start.");
+      let end = self.consume_integer(kMaxEnumNumber as u64, "Expected integer.")? as i32;
+      if end < start {
+        return Err(end_error);
+      }
+      end
+    };
+    Ok((start, end))
+  }
+
+  // Enum ranges may be negative and are stored with their end inclusive.
+  fn parse_enum_range(&mut self) -> Result<(i32, i32), ParseError> {
+    let start = self.consume_signed_integer("Expected enum value or number range.")?;
+    let end = if !self.try_consume("to")? {
+      start
+    } else if self.try_consume("max")? {
+      kMaxEnumNumber
+    } else {
+      let end_error = self.error("Range end must not be less than its start.");
+      let end = self.consume_signed_integer("Expected integer.")?;
+      if end < start {
+        return Err(end_error);
+      }
+      end
+    };
+    Ok((start, end))
+  }
+
+  // Extension fields go to `extensions` and the types of groups among them
+  // to `nested`, with `extensions_path` and `nested_path` as their paths.
+  fn parse_extend(&mut self, extensions: &mut Vec<FieldDescriptorProto>, nested: &mut Vec<DescriptorProto>,
+                  extensions_path: &[i32], nested_path: &[i32]) -> Result<(), ParseError> {
+    let location = self.start_location(extensions_path.to_owned());
+    self.consume("extend")?;
+    let extendee = self.parse_message_type()?;
+    self.consume_end_of_declaration("{", Some(location))?;
+    loop {
+      if self.at_end() {
+        return Err(self.error("Reached end of input in extend definition (missing '}')."));
+      }
+      let path = child_path(extensions_path, &[extensions.len() as i32]);
+      let mut field = self.parse_field(path, ExtensionField, nested, nested_path)?;
+      field.set_extendee(extendee.clone());
+      extensions.push(field);
+      if self.end_declaration("}", None)? {
+        break;
+      }
+    }
+    self.end_location(location);
+    Ok(())
+  }
+
+  fn parse_enum_definition(&mut self, path: Vec<i32>) -> Result<EnumDescriptorProto, ParseError> {
+    let location = self.start_location(path.clone());
+    let start_error = self.error("The first enum value must be zero in proto3.");
+    self.consume("enum")?;
+    let mut descriptor = EnumDescriptorProto::new();
+    descriptor.set_name(self.consume_identifier("Expected enum name.")?);
+    self.consume_end_of_declaration("{", Some(location))?;
+    while !self.end_declaration("}", None)? {
+      if self.at_end() {
+        return Err(self.error("Reached end of input in enum definition (missing '}')."));
+      }
+      if self.end_declaration(";", None)? {
+        continue;
+      }
+      if self.looking_at("option") {
+        let option = self.parse_option_statement()?;
+        self.option_result(&option, set_enum_option(descriptor.mut_options(), &option))?;
+      } else if self.looking_at("reserved") {
+        self.parse_enum_reserved(&mut descriptor)?;
+      } else {
+        let value = self.parse_enum_constant(child_path(&path, &[2, descriptor.value_len() as i32]))?;
+        descriptor.add_value(value);
+      }
+    }
+    self.end_location(location);
+    if self.proto3 && descriptor.value_len() > 0 && descriptor.get_value()[0].get_number() != 0 {
+      return Err(start_error);
+    }
+    Ok(descriptor)
+  }
+
+  fn parse_enum_reserved(&mut self, descriptor: &mut EnumDescriptorProto) -> Result<(), ParseError> {
+    self.consume("reserved")?;
+    if self.looking_at_kind(TokenString) {
+      loop {
+        descriptor.add_reserved_name(self.consume_utf8_string("Expected enum value.")?);
+        if !self.try_consume(",")? {
+          break;
+        }
+      }
+    } else {
+      loop {
+        let (start, end) = self.parse_enum_range()?;
+        let mut range = EnumReservedRange::new();
+        range.set_start(start);
+        range.set_end(end);
+        descriptor.add_reserved_range(range);
+        if !self.try_consume(",")? {
+          break;
+        }
+      }
+    }
+    self.consume_end_of_declaration(";", None)
+  }
+
+  fn parse_enum_constant(&mut self, path: Vec<i32>) -> Result<EnumValueDescriptorProto, ParseError> {
+    let location = self.start_location(path);
+    let mut value = EnumValueDescriptorProto::new();
+    value.set_name(self.consume_identifier("Expected enum constant name.")?);
+    self.expect("=", "Missing numeric value for enum constant.")?;
+    value.set_number(self.consume_signed_integer("Expected integer.")?);
+    if self.try_consume("[")? {
+      loop {
+        let option = self.parse_option_assignment()?;
+        self.option_result(&option, set_enum_value_option(value.mut_options(), &option))?;
+        if !self.try_consume(",")? {
+          break;
+        }
+      }
+      self.consume("]")?;
+    }
+    self.consume_end_of_declaration(";", Some(location))?;
+    self.end_location(location);
+    Ok(value)
+  }
+
+  fn parse_service_definition(&mut self, path: Vec<i32>) -> Result<ServiceDescriptorProto, ParseError> {
+    let location = self.start_location(path.clone());
+    self.consume("service")?;
+    let mut service = ServiceDescriptorProto::new();
+    service.set_name(self.consume_identifier("Expected service name.")?);
+    self.consume_end_of_declaration("{", Some(location))?;
+    while !self.end_declaration("}", None)? {
+      if self.at_end() {
+        return Err(self.error("Reached end of input in service definition (missing '}')."));
+      }
+      if self.end_declaration(";", None)? {
+        continue;
+      }
+      if self.looking_at("option") {
+        let option = self.parse_option_statement()?;
+        self.option_result(&option, set_service_option(service.mut_options(), &option))?;
+      } else {
+        let method = self.parse_method(child_path(&path, &[2, service.method_len() as i32]))?;
+        service.add_method(method);
+      }
+    }
+    self.end_location(location);
+    Ok(service)
+  }
+
+  // "rpc Name(Request) returns (stream Response);" with an optional block
+  // of options in place of the ";".
+  fn parse_method(&mut self, path: Vec<i32>) -> Result<MethodDescriptorProto, ParseError> {
+    let location = self.start_location(path);
+    self.consume("rpc")?;
+    let mut method = MethodDescriptorProto::new();
+    method.set_name(self.consume_identifier("Expected method name.")?);
+    self.consume("(")?;
+    if self.try_consume("stream")? {
+      method.set_client_streaming(true);
+    }
+    method.set_input_type(self.parse_message_type()?);
+    self.consume(")")?;
+    self.consume("returns")?;
+    self.consume("(")?;
+    if self.try_consume("stream")? {
+      method.set_server_streaming(true);
+    }
+    method.set_output_type(self.parse_message_type()?);
+    self.consume(")")?;
+    if self.looking_at("{") {
+      self.consume_end_of_declaration("{", Some(location))?;
+      while !self.end_declaration("}", None)? {
+        if self.at_end() {
+          return Err(self.error("Reached end of input in method options (missing '}')."));
+        }
+        if self.end_declaration(";", None)? {
+          continue;
+        }
+        let option = self.parse_option_statement()?;
+        self.option_result(&option, set_method_option(method.mut_options(), &option))?;
+      }
+    } else {
+      self.consume_end_of_declaration(";", Some(location))?;
+    }
+    self.end_location(location);
+    Ok(method)
+  }
+}
+
+// Parses the source of the file `name`. Type names are left as written;
+// `resolve_files` qualifies them once the imported files are known.
+pub fn parse(name: &str, source: &str) -> Result<FileDescriptorProto, ParseError> {
+  let mut parser = Parser {
+    tokenizer: Tokenizer::new(name, source.as_bytes()),
+    proto3: false,
+    upcoming_doc_comments: String::new(),
+    locations: Vec::new()
+  };
+  let mut file = FileDescriptorProto::new();
+  file.set_name(name.to_owned());
+  parser.parse_file(&mut file)?;
+  Ok(file)
+}
+
+// An error about the declaration at `path`, placed where its location
+// starts.
+fn error_at_path(file_name: &str, locations: &[Location], path: &[i32], message: String) -> ParseError {
+  let (line, column) = match locations.iter().find(|location| location.get_path() == path) {
+    Some(location) if location.span_len() >= 2 => (location.get_span()[0] as usize + 1, location.get_span()[1] as usize + 1),
+    _ => (0, 0)
+  };
+  ParseError { file: file_name.to_owned(), line, column, message }
+}
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum SymbolKind {
+  PackageSymbol,
+  MessageSymbol,
+  EnumSymbol,
+  ServiceSymbol
+}
+use self::SymbolKind::*;
+
+fn add_message_symbols(symbols: &mut HashMap<String, SymbolKind>, scope: &str, messages: &[DescriptorProto]) {
+  for message in messages.iter() {
+    let name = format!("{}.{}", scope, message.get_name());
+    for nested in message.get_enum_type().iter() {
+      symbols.insert(format!("{}.{}", name, nested.get_name()), EnumSymbol);
+    }
+    add_message_symbols(symbols, &name, message.get_nested_type());
+    symbols.insert(name, MessageSymbol);
+  }
+}
+
+// The extension ranges of every message, by full name, as [start, end).
+fn add_extension_ranges(ranges: &mut HashMap<String, Vec<(i32, i32)>>, scope: &str, messages: &[DescriptorProto]) {
+  for message in messages.iter() {
+    let name = format!("{}.{}", scope, message.get_name());
+    add_extension_ranges(ranges, &name, message.get_nested_type());
+    let extension_ranges = message.get_extension_range().iter().map(|range| (range.get_start(), range.get_end()));
+    ranges.insert(name, extension_ranges.collect());
+  }
+}
+
+fn add_file_symbols(symbols: &mut HashMap<String, SymbolKind>, proto: &FileDescriptorProto) {
+  if proto.has_package() {
+    let mut package = String::new();
+    for part in proto.get_package().split('.') {
+      package = format!("{}.{}", package, part);
+      symbols.insert(package.clone(), PackageSymbol);
+    }
+  }
+  let scope = package_scope(proto);
+  add_message_symbols(symbols, &scope, proto.get_message_type());
+  for descriptor in proto.get_enum_type().iter() {
+    symbols.insert(format!("{}.{}", scope, descriptor.get_name()), EnumSymbol);
+  }
+  for service in proto.get_service().iter() {
+    symbols.insert(format!("{}.{}", scope, service.get_name()), ServiceSymbol);
+  }
+}
+
+// Field numbers protoc keeps for the library's own use.
+static kFirstReservedNumber: i32 = 19000;
+static kLastReservedNumber: i32 = 19999;
+
+// Qualifies the type names of one file with the types it can see, and
+// checks its fields as protoc's DescriptorBuilder does.
+struct Resolver {
+  file_name: String,
+  symbols: HashMap<String, SymbolKind>,
+  extension_ranges: HashMap<String, Vec<(i32, i32)>>,
+  locations: Vec<Location>
+}
+
+impl Resolver {
+  fn error(&self, path: &[i32], message: &str) -> ParseError {
+    error_at_path(&self.file_name, &self.locations, path, message.to_string())
+  }
+
+  // protoc's name lookup: a relative name is looked for in `scope`, then in
+  // each enclosing scope. Once its first component is found, the rest must
+  // be in there.
+  fn lookup(&self, name: &str, scope: &str) -> Option<(String, SymbolKind)> {
+    if name.starts_with(".") {
+      return self.symbols.get(name).map(|&kind| (name.to_owned(), kind));
+    }
+    let first = match name.find('.') {
+      Some(index) => &name[..index],
+      None => name
+    };
+    let mut scope = scope.to_owned();
+    loop {
+      let candidate = format!("{}.{}", scope, first);
+      match self.symbols.get(&candidate) {
+        Some(&kind) if first.len() == name.len() => return Some((candidate, kind)),
+        Some(&PackageSymbol) | Some(&MessageSymbol) => {
+          let full_name = format!("{}.{}", scope, name);
+          return self.symbols.get(&full_name).map(|&kind| (full_name.clone(), kind));
+        }
+        _ => ()
+      }
+      if scope.is_empty() {
+        return None;
+      }
+      scope = match scope.rfind('.') {
+        Some(index) => scope[..index].to_owned(),
+        None => String::new()
+      };
+    }
+  }
+
+  fn resolve_message_type(&self, name: &str, scope: &str, path: &[i32]) -> Result<String, ParseError> {
+    match self.lookup(name, scope) {
+      Some((full_name, MessageSymbol)) => Ok(full_name),
+      Some(_) => Err(self.error(path, &format!("\"{}\" is not a message type.", name))),
+      None => Err(self.error(path, &format!("\"{}\" is not defined.", name)))
+    }
+  }
+
+  fn resolve_field(&self, field: &mut FieldDescriptorProto, scope: &str, path: &[i32]) -> Result<(), ParseError> {
+    if field.has_type_name() {
+      let (full_name, kind) = match self.lookup(field.get_type_name(), scope) {
+        Some(found) => found,
+        None => return Err(self.error(path, &format!("\"{}\" is not defined.", field.get_type_name())))
+      };
+      match kind {
+        MessageSymbol if !field.has_type() => field.set_type(TypeMessage),
+        MessageSymbol if field.get_type() == TypeGroup => (),
+        EnumSymbol if !field.has_type() => field.set_type(TypeEnum),
+        _ => return Err(self.error(path, &format!("\"{}\" is not a type.", field.get_type_name())))
+      }
+      field.set_type_name(full_name);
+    }
+    if field.has_extendee() {
+      let extendee = self.resolve_message_type(field.get_extendee(), scope, path)?;
+      field.set_extendee(extendee);
+    }
+    if field.has_default_value() && field.get_type() == TypeMessage {
+      return Err(self.error(path, "Messages can't have default values."));
+    }
+    if !field.has_json_name() {
+      field.set_json_name(default_json_name(field.get_name()));
+    }
+    self.check_field(field, path)
+  }
+
+  // What holds for any field or extension once its type is known.
+  fn check_field(&self, field: &FieldDescriptorProto, path: &[i32]) -> Result<(), ParseError> {
+    let number = field.get_number();
+    if number <= 0 {
+      return Err(self.error(path, "Field numbers must be positive integers."));
+    }
+    if number > kMaxFieldNumber {
+      return Err(self.error(path, &format!("Field numbers cannot be greater than {}.", kMaxFieldNumber)));
+    }
+    if number >= kFirstReservedNumber && number <= kLastReservedNumber {
+      return Err(self.error(path, &format!("Field numbers {} through {} are reserved for the protocol buffer library \
+                                            implementation.", kFirstReservedNumber, kLastReservedNumber)));
+    }
+    if field.has_extendee() {
+      let ranges = self.extension_ranges.get(field.get_extendee()).map(|ranges| ranges.as_slice()).unwrap_or(&[]);
+      if !ranges.iter().any(|&(start, end)| start <= number && number < end) {
+        return Err(self.error(path, &format!("\"{}\" does not declare {} as an extension number.",
+                                             field.get_extendee().trim_start_matches('.'), number)));
+      }
+    }
+    let packable = field.get_label() == LabelRepeated &&
+      !matches!(field.get_type(), TypeString | TypeBytes | TypeMessage | TypeGroup);
+    if field.get_options().has_packed() && !packable {
+      return Err(self.error(path, "[packed = true] can only be specified for repeated primitive fields."));
+    }
+    Ok(())
+  }
+
+  // What holds among the fields of `message`, whose full name is
+  // `full_name`: numbers and names are used once, and reserved ones not at
+  // all.
+  fn check_message(&self, message: &DescriptorProto, full_name: &str, path: &[i32]) -> Result<(), ParseError> {
+    let full_name = full_name.trim_start_matches('.');
+    let fields = message.get_field();
+    for (i, field) in fields.iter().enumerate() {
+      let field_path = child_path(path, &[2, i as i32]);
+      let (name, number) = (field.get_name(), field.get_number());
+      if let Some(other) = fields[..i].iter().find(|other| other.get_number() == number) {
+        return Err(self.error(&field_path, &format!("Field number {} has already been used in \"{}\" by field \"{}\".",
+                                                    number, full_name, other.get_name())));
+      }
+      if message.get_reserved_range().iter().any(|range| range.get_start() <= number && number < range.get_end()) {
+        return Err(self.error(&field_path, &format!("Field \"{}\" uses reserved number {}.", name, number)));
+      }
+      if message.get_reserved_name().iter().any(|reserved| reserved == name) {
+        return Err(self.error(&field_path, &format!("Field name \"{}\" is reserved.", name)));
+      }
+    }
+    // Fields, nested types and oneofs all name things in the message's scope.
+    let mut names: Vec<(&str, Vec<i32>)> = Vec::new();
+    names.extend(fields.iter().enumerate().map(|(i, field)| (field.get_name(), child_path(path, &[2, i as i32]))));
+    names.extend(message.get_nested_type().iter().enumerate()
+                 .map(|(i, nested)| (nested.get_name(), child_path(path, &[3, i as i32]))));
+    names.extend(message.get_enum_type().iter().enumerate()
+                 .map(|(i, nested)| (nested.get_name(), child_path(path, &[4, i as i32]))));
+    names.extend(message.get_oneof_decl().iter().enumerate()
+                 .map(|(i, oneof)| (oneof.get_name(), child_path(path, &[8, i as i32]))));
+    for (i, (name, name_path)) in names.iter().enumerate() {
+      if names[..i].iter().any(|(other, _)| other == name) {
+        return Err(self.error(name_path, &format!("\"{}\" is already defined in \"{}\".", name, full_name)));
+      }
+    }
+    Ok(())
+  }
+
+  fn resolve_message(&self, message: &mut DescriptorProto, scope: &str, path: &[i32]) -> Result<(), ParseError> {
+    let full_name = format!("{}.{}", scope, message.get_name());
+    for (i, field) in message.mut_field().iter_mut().enumerate() {
+      self.resolve_field(field, &full_name, &child_path(path, &[2, i as i32]))?;
+    }
+    self.check_message(message, &full_name, path)?;
+    for (i, extension) in message.mut_extension().iter_mut().enumerate() {
+      self.resolve_field(extension, &full_name, &child_path(path, &[6, i as i32]))?;
+    }
+    for (i, nested) in message.mut_nested_type().iter_mut().enumerate() {
+      self.resolve_message(nested, &full_name, &child_path(path, &[3, i as i32]))?;
+    }
+    Ok(())
+  }
+
+  fn resolve_file(&self, file: &mut FileDescriptorProto) -> Result<(), ParseError> {
+    let scope = package_scope(file);
+    for (i, message) in file.mut_message_type().iter_mut().enumerate() {
+      self.resolve_message(message, &scope, &[4, i as i32])?;
+    }
+    for (i, extension) in file.mut_extension().iter_mut().enumerate() {
+      self.resolve_field(extension, &scope, &[7, i as i32])?;
+    }
+    for (i, service) in file.mut_service().iter_mut().enumerate() {
+      let service_scope = format!("{}.{}", scope, service.get_name());
+      for (j, method) in service.mut_method().iter_mut().enumerate() {
+        let path = [6, i as i32, 2, j as i32];
+        let input_type = self.resolve_message_type(method.get_input_type(), &service_scope, &path)?;
+        method.set_input_type(input_type);
+        let output_type = self.resolve_message_type(method.get_output_type(), &service_scope, &path)?;
+        method.set_output_type(output_type);
+      }
+    }
+    Ok(())
+  }
+}
+
+// Qualifies the type names in `files`, which must include everything they
+// import, and fills in the json_names protoc reports to plugins.
+pub fn resolve_files(files: &mut [FileDescriptorProto]) -> Result<(), ParseError> {
+  for i in 0..files.len() {
+    let mut symbols = HashMap::new();
+    let mut extension_ranges = HashMap::new();
+    let visible = visible_files(files, files[i].get_name());
+    for proto in files.iter().filter(|proto| visible.iter().any(|name| name.as_str() == proto.get_name())) {
+      add_file_symbols(&mut symbols, proto);
+      add_extension_ranges(&mut extension_ranges, &package_scope(proto), proto.get_message_type());
+    }
+    let file = &mut files[i];
+    let resolver = Resolver {
+      file_name: file.get_name().to_owned(),
+      symbols,
+      extension_ranges,
+      locations: file.mut_source_code_info().take_location()
+    };
+    let result = resolver.resolve_file(file);
+    file.mut_source_code_info().set_location(resolver.locations);
+    result?;
+  }
+  Ok(())
+}
+
+struct Loader<'a> {
+  include_paths: &'a [PathBuf],
+  files: Vec<FileDescriptorProto>,
+  // The files being loaded, each imported by the one before.
+  loading: Vec<String>
+}
+
+// Where the file imported as `name` is: under the first of `include_paths`
+// that has it.
+pub fn find_file(name: &str, include_paths: &[PathBuf]) -> Option<PathBuf> {
+  include_paths.iter().map(|dir| dir.join(name)).find(|path| path.exists())
+}
+
+impl<'a> Loader<'a> {
+
+  // Loads `name` after the files it imports.
+  fn load(&mut self, name: &str) -> Result<(), ParseError> {
+    if self.files.iter().any(|file| file.get_name() == name) {
+      return Ok(());
+    }
+    let file_error = |message: String| ParseError { file: name.to_owned(), line: 0, column: 0, message };
+    let path = match find_file(name, self.include_paths) {
+      Some(path) => path,
+      None => return Err(file_error("File not found.".to_string()))
+    };
+    let source = match fs::read_to_string(&path) {
+      Ok(source) => source,
+      Err(error) => return Err(file_error(error.to_string()))
+    };
+    let file = parse(name, &source)?;
+    self.loading.push(name.to_owned());
+    for (i, dependency) in file.get_dependency().iter().enumerate() {
+      let path = [3, i as i32];
+      if self.loading.contains(dependency) {
+        let cycle = self.loading.join(" -> ");
+        return Err(error_at_path(name, file.get_source_code_info().get_location(), &path,
+                                 format!("File recursively imports itself: {} -> {}", cycle, dependency)));
+      }
+      if find_file(dependency, self.include_paths).is_none() {
+        return Err(error_at_path(name, file.get_source_code_info().get_location(), &path,
+                                 format!("Import \"{}\" was not found.", dependency)));
+      }
+      self.load(dependency)?;
+    }
+    self.loading.pop();
+    self.files.push(file);
+    Ok(())
+  }
+}
+
+// Loads `files`, named as they would be imported, from the first of
+// `include_paths` that has them, along with everything they import. The
+// files come back as protoc lists them in a CodeGeneratorRequest:
+// dependencies first, resolved, with json_names set.
+pub fn load_files(files: &[String], include_paths: &[PathBuf]) -> Result<Vec<FileDescriptorProto>, ParseError> {
+  let mut loader = Loader { include_paths, files: Vec::new(), loading: Vec::new() };
+  for name in files.iter() {
+    loader.load(name)?;
+  }
+  let mut loaded = loader.files;
+  resolve_files(&mut loaded)?;
+  Ok(loaded)
+}
+
+#[cfg(test)]
+fn parse_error(source: &str) -> String {
+  let result = parse("bad.proto", source).and_then(|file| {
+    let mut files = vec![file];
+    resolve_files(&mut files).map(|_| ())
+  });
+  match result {
+    Ok(()) => panic!("{} parsed", source),
+    Err(error) => error.to_string()
+  }
+}
+
+#[test]
+fn test_parse_file() {
+  let source = "// Raft messages.
+syntax = \"proto3\";
+
+package raft;
+
+import public \"common.proto\";
+
+option optimize_for = LITE_RUNTIME;
+
+// A replicated log entry.
+message Entry {
+  uint64 term = 1;  // Election term.
+  optional string command = 2;
+  map<string, Entry> children = 3;
+  oneof body {
+    int32 vote = 4;
+    bytes append = 5;
+  }
+  reserved 6, 8 to 10;
+  reserved \"old\";
+  enum Kind {
+    NOP = 0;
+    SET = -1 [deprecated = true];
+  }
+  Kind kind = 7 [json_name = \"type\", (custom.opt) = -3];
+}
+
+service Raft {
+  rpc Replicate(stream Entry) returns (Entry);
+}
+";
+  let mut files = vec![parse("raft.proto", source).unwrap()];
+  resolve_files(&mut files).unwrap();
+  let file = &files[0];
+  assert_eq!(file.get_syntax(), "proto3");
+  assert_eq!(file.get_package(), "raft");
+  assert_eq!(file.get_dependency(), &["common.proto".to_string()]);
+  assert_eq!(file.get_public_dependency(), &[0]);
+  assert_eq!(file.get_options().get_optimize_for(), OptimizeMode::from_name("LITE_RUNTIME").unwrap());
+
+  let entry = &file.get_message_type()[0];
+  let fields = entry.get_field();
+  let names: Vec<&str> = fields.iter().map(|field| field.get_name()).collect();
+  assert_eq!(names, vec!["term", "command", "children", "vote", "append", "kind"]);
+  assert_eq!(fields[0].get_type(), TypeUint64);
+  assert_eq!(fields[0].get_label(), LabelOptional);
+  assert!(!fields[0].has_oneof_index());
+  assert_eq!(fields[0].get_json_name(), "term");
+  assert!(fields[1].get_proto3_optional());
+  assert_eq!(fields[1].get_oneof_index(), 1);
+  assert_eq!(fields[2].get_label(), LabelRepeated);
+  assert_eq!(fields[2].get_type_name(), ".raft.Entry.ChildrenEntry");
+  assert_eq!(fields[3].get_oneof_index(), 0);
+  assert_eq!(fields[4].get_oneof_index(), 0);
+  assert_eq!(fields[5].get_type(), TypeEnum);
+  assert_eq!(fields[5].get_type_name(), ".raft.Entry.Kind");
+  assert_eq!(fields[5].get_json_name(), "type");
+  let custom = &fields[5].get_options().get_uninterpreted_option()[0];
+  assert_eq!(custom.get_name()[0].get_name_part(), "custom.opt");
+  assert!(custom.get_name()[0].get_is_extension());
+  assert_eq!(custom.get_negative_int_value(), -3);
+
+  let oneofs: Vec<&str> = entry.get_oneof_decl().iter().map(|oneof| oneof.get_name()).collect();
+  assert_eq!(oneofs, vec!["body", "_command"]);
+  let map_entry = &entry.get_nested_type()[0];
+  assert_eq!(map_entry.get_name(), "ChildrenEntry");
+  assert!(map_entry.get_options().get_map_entry());
+  assert_eq!(map_entry.get_field()[0].get_type(), TypeString);
+  assert_eq!(map_entry.get_field()[1].get_type_name(), ".raft.Entry");
+  let reserved: Vec<(i32, i32)> = entry.get_reserved_range().iter().map(|r| (r.get_start(), r.get_end())).collect();
+  assert_eq!(reserved, vec![(6, 7), (8, 11)]);
+  assert_eq!(entry.get_reserved_name(), &["old".to_string()]);
+  let kind = &entry.get_enum_type()[0];
+  assert_eq!(kind.get_value()[1].get_number(), -1);
+  assert!(kind.get_value()[1].get_options().get_deprecated());
+
+  let method = &file.get_service()[0].get_method()[0];
+  assert!(method.get_client_streaming());
+  assert!(!method.get_server_streaming());
+  assert_eq!(method.get_input_type(), ".raft.Entry");
+
+  let locations = file.get_source_code_info().get_location();
+  let find = |path: &[i32]| locations.iter().find(|location| location.get_path() == path).unwrap();
+  assert_eq!(find(&[4, 0]).get_leading_comments(), " A replicated log entry.\n");
+  assert_eq!(find(&[4, 0]).get_span(), &[10, 0, 25, 1]);
+  assert_eq!(find(&[4, 0, 2, 0]).get_trailing_comments(), " Election term.\n");
+  assert_eq!(find(&[4, 0, 2, 0]).get_span(), &[11, 2, 18]);
+  assert_eq!(find(&[12]).get_leading_comments(), " Raft messages.\n");
+}
+
+// Comments attach the way protoc attaches them.
+#[test]
+fn test_comments() {
+  let source = "message A {
+  optional int32 foo = 1;  // Comment attached to foo.
+  // Comment attached to bar.
+  optional int32 bar = 2;
+
+  optional string baz = 3;
+  // Comment attached to baz.
+  // Another line attached to baz.
+
+  // Comment attached to qux.
+  //
+  // Another line attached to qux.
+  optional double qux = 4;
+
+  // Detached comment.  This is not attached to qux or corge
+  // because there are blank lines separating it from both.
+
+  optional string corge = 5;
+  /* Block comment attached
+   * to corge.  Leading asterisks
+   * will be removed. */
+  /* Block comment attached to
+   * grault. */
+  optional int32 grault = 6;
+}
+";
+  let file = parse("comments.proto", source).unwrap();
+  let locations = file.get_source_code_info().get_location();
+  let comments = |index: i32| {
+    let location = locations.iter().find(|location| location.get_path() == [4, 0, 2, index]).unwrap();
+    (location.get_leading_comments().to_owned(), location.get_trailing_comments().to_owned())
+  };
+  assert_eq!(comments(0), (String::new(), " Comment attached to foo.\n".to_string()));
+  assert_eq!(comments(1), (" Comment attached to bar.\n".to_string(), String::new()));
+  assert_eq!(comments(2), (String::new(), " Comment attached to baz.\n Another line attached to baz.\n".to_string()));
+  assert_eq!(comments(3), (" Comment attached to qux.\n\n Another line attached to qux.\n".to_string(), String::new()));
+  assert_eq!(comments(4), (String::new(), " Block comment attached\n to corge.  Leading asterisks\n will be removed. ".to_string()));
+  assert_eq!(comments(5), (" Block comment attached to\n grault. ".to_string(), String::new()));
+}
+
+#[test]
+fn test_parse_errors() {
+  assert_eq!(parse_error("syntax = \"proto3\";\nmessage Foo {\n  int32 = 1;\n}\n"),
+             "bad.proto:3:9: Expected field name.".to_string());
+  assert_eq!(parse_error("message Foo {\n  required string name = 1 [default = 5];\n}\n"),
+             "bad.proto:2:39: Expected string.".to_string());
+  assert_eq!(parse_error("message Foo {"),
+             "bad.proto:1:14: Reached end of input in message definition (missing '}').".to_string());
+  assert_eq!(parse_error("syntax = \"proto3\";\nenum E {\n  A = 1;\n}\n"),
+             "bad.proto:2:1: The first enum value must be zero in proto3.".to_string());
+  assert_eq!(parse_error("option java_package = \"a\nb\";"),
+             "bad.proto:1:25: String literals cannot cross line boundaries.".to_string());
+  assert_eq!(parse_error("message A {\n\toptional int32 a = 0x;\n}"),
+             "bad.proto:2:30: \"0x\" must be followed by hex digits.".to_string());
+  assert_eq!(parse_error("message A { optional B b = 1; }"),
+             "bad.proto:1:13: \"B\" is not defined.".to_string());
+  assert_eq!(parse_error("message A { optional int32 a = 1 [packd = true]; }"),
+             "bad.proto:1:35: Option \"packd\" unknown.".to_string());
+}
+
+// Schemas that parse but that protoc's DescriptorBuilder rejects, each
+// reported where the offending field starts.
+#[test]
+fn test_descriptor_errors() {
+  assert_eq!(parse_error("message A {\n  optional int32 a = 1;\n  optional int32 b = 1;\n}\n"),
+             "bad.proto:3:3: Field number 1 has already been used in \"A\" by field \"a\".");
+  assert_eq!(parse_error("message A {\n  optional int32 a = 1;\n  optional string a = 2;\n}\n"),
+             "bad.proto:3:3: \"a\" is already defined in \"A\".");
+  assert_eq!(parse_error("message A {\n  optional int32 a = 0;\n}\n"),
+             "bad.proto:2:3: Field numbers must be positive integers.");
+  assert_eq!(parse_error("message A {\n  optional int32 a = 536870912;\n}\n"),
+             "bad.proto:2:3: Field numbers cannot be greater than 536870911.");
+  assert_eq!(parse_error("message A {\n  optional int32 a = 19500;\n}\n"),
+             "bad.proto:2:3: Field numbers 19000 through 19999 are reserved for the protocol buffer library \
+              implementation.");
+  assert_eq!(parse_error("message A {\n  reserved 2 to 4;\n  optional int32 a = 3;\n}\n"),
+             "bad.proto:3:3: Field \"a\" uses reserved number 3.");
+  assert_eq!(parse_error("message A {\n  reserved \"a\";\n  optional int32 a = 1;\n}\n"),
+             "bad.proto:3:3: Field name \"a\" is reserved.");
+  assert_eq!(parse_error("message A {\n  extensions 100 to 199;\n}\nextend A {\n  optional int32 a = 200;\n}\n"),
+             "bad.proto:5:3: \"A\" does not declare 200 as an extension number.");
+  assert_eq!(parse_error("message A {\n  optional int32 a = 1 [packed = true];\n}\n"),
+             "bad.proto:2:3: [packed = true] can only be specified for repeated primitive fields.");
+  assert_eq!(parse_error("message A {\n  repeated string a = 1 [packed = true];\n}\n"),
+             "bad.proto:2:3: [packed = true] can only be specified for repeated primitive fields.");
+}
+
+// What the printer writes parses back to the same descriptor.
+#[test]
+fn test_parse_printed_file() {
+  use crate::printer::print_file;
+
+  let source = "syntax = \"proto2\";
+
+package raft;
+
+option java_package = \"org.raft\";
+option cc_enable_arenas = true;
+option csharp_namespace = \"Raft\";
+
+enum State {
+  option allow_alias = true;
+  FOLLOWER = 0;
+  LEADER = 1;
+  PRIMARY = 1;
+  reserved 10 to max;
+}
+
+message Entries {
+  required uint64 term = 1;
+  optional string command = 4 [default = \"say \\\"hi\\\"\", json_name = \"cmd\"];
+  optional bytes raw = 10 [default = \"\\000\\377\"];
+  optional double ratio = 11 [default = -inf];
+  optional int64 id = 12 [jstype = JS_STRING, debug_redact = true];
+  oneof body {
+    int32 vote = 5;
+    bytes append = 6 [deprecated = true];
+  }
+  map<string, uint64> peers = 7;
+  optional group Snapshot = 8 {
+    required uint64 index = 1;
+  }
+  optional .raft.State state = 9 [(raft.tag) = \"x\"];
+  extensions 100 to max;
+  reserved 2, 20 to 29;
+  reserved \"old\";
+}
+
+extend .raft.Entries {
+  optional string origin = 100;
+}
+
+service Raft {
+  rpc Replicate(.raft.Entries) returns (stream .raft.Entries) {
+    option deprecated = true;
+    option idempotency_level = NO_SIDE_EFFECTS;
+  }
+}
+";
+  let mut files = vec![parse("raft.proto", source).unwrap()];
+  resolve_files(&mut files).unwrap();
+  assert_eq!(print_file(&files[0]), source.to_owned());
+}
+
+// Parsing log_entry.proto gives what protoc sent in the captured request,
+// except for the json_names protoc leaves out when nobody asks for them.
+#[test]
+fn test_load_files_like_protoc() {
+  use protobuf::{Protobuf, ProtobufEncode, IoReader};
+  use crate::plugin::google::protobuf::compiler::CodeGeneratorRequest;
+
+  fn encode(file: &mut FileDescriptorProto) -> Vec<u8> {
+    file.clear_source_code_info();
+    for message in file.mut_message_type().iter_mut() {
+      for field in message.mut_field().iter_mut() {
+        field.clear_json_name();
+      }
+    }
+    let mut writer = Vec::new();
+    assert!(file.Encode(&mut writer).is_ok());
+    writer
+  }
+
+  let mut request = CodeGeneratorRequest::new();
+  assert!(request.Decode(&mut IoReader::new(fs::File::open("testdata/CodeGenRequest.pbf").unwrap())).is_ok());
+  let mut loaded = load_files(request.get_file_to_generate(), &[PathBuf::from(".")]).unwrap();
+  assert_eq!(loaded.len(), request.proto_file_len());
+  let mut expected = request.take_proto_file();
+  let comment = |file: &FileDescriptorProto| {
+    let locations = file.get_source_code_info().get_location();
+    locations.iter().find(|location| location.get_path() == [4, 0, 2, 3]).unwrap().get_trailing_comments().to_owned()
+  };
+  assert_eq!(comment(&loaded[0]), comment(&expected[0]));
+  assert_eq!(encode(&mut loaded[0]), encode(&mut expected[0]));
+
+  match load_files(&["proto/missing.proto".to_string()], &[PathBuf::from(".")]) {
+    Ok(_) => panic!("loaded a missing file"),
+    Err(error) => assert_eq!(error.to_string(), "proto/missing.proto: File not found.")
+  }
+}
@@ -0,0 +1,773 @@
+// Turns a FileDescriptorProto back into .proto source, for recovering the
+// schemas of descriptor sets embedded in binaries. Type names are printed
+// fully qualified, the way descriptors store them, so the output parses
+// without resolving them against the package.
+
+use protobuf::{Protobuf, DecodeError, TaggedValue};
+use crate::descriptor::google::protobuf::{FileDescriptorSet, FileDescriptorProto, DescriptorProto, FieldDescriptorProto};
+use crate::descriptor::google::protobuf::{EnumDescriptorProto, ServiceDescriptorProto, UninterpretedOption};
+use crate::descriptor::google::protobuf::{FileOptions, MessageOptions, FieldOptions, OneofOptions};
+use crate::descriptor::google::protobuf::{EnumOptions, EnumValueOptions, ServiceOptions, MethodOptions};
+use crate::descriptor::google::protobuf::field_descriptor_proto::{TypeString, TypeBytes, TypeMessage, TypeEnum, TypeGroup};
+use crate::descriptor::google::protobuf::field_descriptor_proto::{LabelOptional, LabelRequired, LabelRepeated};
+
+// Field numbers stop at 2^29 - 1 and enum numbers at the end of i32;
+// ranges reaching them are written "to max".
+pub(crate) static kMaxFieldNumber: i32 = 536870911;
+pub(crate) static kMaxEnumNumber: i32 = 2147483647;
+
+// Prints every file of an encoded FileDescriptorSet, as protoc's
+// --descriptor_set_out writes it, each with its name.
+pub fn print_descriptor_set(data: &[u8]) -> Result<Vec<(String, String)>, DecodeError> {
+  let mut set = FileDescriptorSet::new();
+  set.Decode(&mut &data[..])?;
+  Ok(set.get_file().iter().map(|file| (file.get_name().to_string(), print_file(file))).collect())
+}
+
+// Prints one encoded FileDescriptorProto, the form generated code usually
+// embeds its schema in.
+pub fn print_file_descriptor(data: &[u8]) -> Result<String, DecodeError> {
+  let mut proto = FileDescriptorProto::new();
+  proto.Decode(&mut &data[..])?;
+  Ok(print_file(&proto))
+}
+
+pub(crate) fn print_file(proto: &FileDescriptorProto) -> String {
+  let mut printer = ProtoPrinter {
+    proto3: proto.get_syntax() == "proto3",
+    indent: 0,
+    buf: String::new()
+  };
+  printer.print_file(proto);
+  printer.buf
+}
+
+// Escapes `bytes` for a .proto string literal, as protoc's CEscape does;
+// it is also how protoc stores the default of a bytes field.
+pub(crate) fn c_escape(bytes: &[u8]) -> String {
+  let mut result = String::new();
+  for &b in bytes.iter() {
+    match b as char {
+      '\n' => result.push_str("\\n"),
+      '\r' => result.push_str("\\r"),
+      '\t' => result.push_str("\\t"),
+      '"' => result.push_str("\\\""),
+      '\'' => result.push_str("\\'"),
+      '\\' => result.push_str("\\\\"),
+      ' '..='~' => result.push(b as char),
+      _ => result.push_str(&format!("\\{:03o}", b))
+    }
+  }
+  result
+}
+
+// Quotes `bytes` as a .proto string literal.
+fn quote(bytes: &[u8]) -> String {
+  format!("\"{}\"", c_escape(bytes))
+}
+
+#[test]
+fn test_quote() {
+  assert_eq!(quote(b"plain"), "\"plain\"");
+  assert_eq!(quote(b"a \"b\"\n"), "\"a \\\"b\\\"\\n\"");
+  assert_eq!(quote(&[0u8, 0xff]), "\"\\000\\377\"");
+}
+
+// protoc's default json_name: "foo_bar_baz" is "fooBarBaz".
+pub(crate) fn default_json_name(name: &str) -> String {
+  let mut result = String::new();
+  let mut capitalize = false;
+  for c in name.chars() {
+    if c == '_' {
+      capitalize = true;
+    } else if capitalize {
+      result.extend(c.to_uppercase());
+      capitalize = false;
+    } else {
+      result.push(c);
+    }
+  }
+  result
+}
+
+#[test]
+fn test_default_json_name() {
+  assert_eq!(default_json_name("leader_name"), "leaderName");
+  assert_eq!(default_json_name("LeaderName"), "LeaderName");
+  assert_eq!(default_json_name("a__b_"), "aB");
+}
+
+// The value of an option as it is written in a .proto file.
+type OptionList = Vec<(String, String)>;
+
+fn push_bool(options: &mut OptionList, name: &str, present: bool, value: bool) {
+  if present {
+    options.push((name.to_string(), value.to_string()));
+  }
+}
+
+fn push_string(options: &mut OptionList, name: &str, present: bool, value: &str) {
+  if present {
+    options.push((name.to_string(), quote(value.as_bytes())));
+  }
+}
+
+// Options the parser couldn't resolve, usually custom ones.
+fn push_uninterpreted(options: &mut OptionList, uninterpreted: &[UninterpretedOption]) {
+  for option in uninterpreted.iter() {
+    let name = option.get_name().iter().map(|part| {
+      if part.get_is_extension() {
+        format!("({})", part.get_name_part())
+      } else {
+        part.get_name_part().to_string()
+      }
+    }).collect::<Vec<String>>().join(".");
+    let value = if option.has_identifier_value() {
+      option.get_identifier_value().to_string()
+    } else if option.has_positive_int_value() {
+      option.get_positive_int_value().to_string()
+    } else if option.has_negative_int_value() {
+      option.get_negative_int_value().to_string()
+    } else if option.has_double_value() {
+      option.get_double_value().to_string()
+    } else if option.has_string_value() {
+      quote(option.get_string_value())
+    } else {
+      format!("{{ {} }}", option.get_aggregate_value())
+    };
+    options.push((name, value));
+  }
+}
+
+// Option fields this descriptor.proto doesn't name, from a newer protoc or
+// custom options compiled in, as "(number) = value": the output no longer
+// parses, but it doesn't lose them quietly either.
+fn push_unknown(options: &mut OptionList, unknown_fields: &[TaggedValue]) {
+  for value in unknown_fields.iter() {
+    let (number, value) = match *value {
+      TaggedValue::Varint(number, value) => (number, value.to_string()),
+      TaggedValue::Fixed64(number, value) => (number, format!("0x{:016x}", value)),
+      TaggedValue::Fixed32(number, value) => (number, format!("0x{:08x}", value)),
+      TaggedValue::Raw(number, ref data) => (number, quote(data)),
+      TaggedValue::StartGroup | TaggedValue::EndGroup => continue
+    };
+    options.push((format!("({})", number), value));
+  }
+}
+
+fn file_options(options: &FileOptions) -> OptionList {
+  let mut result = Vec::new();
+  push_string(&mut result, "java_package", options.has_java_package(), options.get_java_package());
+  push_string(&mut result, "java_outer_classname", options.has_java_outer_classname(),
+              options.get_java_outer_classname());
+  push_bool(&mut result, "java_multiple_files", options.has_java_multiple_files(), options.get_java_multiple_files());
+  push_bool(&mut result, "java_generate_equals_and_hash", options.has_java_generate_equals_and_hash(),
+            options.get_java_generate_equals_and_hash());
+  if options.has_optimize_for() {
+    result.push(("optimize_for".to_string(), options.get_optimize_for().name().to_string()));
+  }
+  push_string(&mut result, "go_package", options.has_go_package(), options.get_go_package());
+  push_bool(&mut result, "cc_generic_services", options.has_cc_generic_services(), options.get_cc_generic_services());
+  push_bool(&mut result, "java_generic_services", options.has_java_generic_services(),
+            options.get_java_generic_services());
+  push_bool(&mut result, "py_generic_services", options.has_py_generic_services(), options.get_py_generic_services());
+  push_bool(&mut result, "php_generic_services", options.has_php_generic_services(),
+            options.get_php_generic_services());
+  push_bool(&mut result, "java_string_check_utf8", options.has_java_string_check_utf8(),
+            options.get_java_string_check_utf8());
+  push_bool(&mut result, "deprecated", options.has_deprecated(), options.get_deprecated());
+  push_bool(&mut result, "cc_enable_arenas", options.has_cc_enable_arenas(), options.get_cc_enable_arenas());
+  push_string(&mut result, "objc_class_prefix", options.has_objc_class_prefix(), options.get_objc_class_prefix());
+  push_string(&mut result, "csharp_namespace", options.has_csharp_namespace(), options.get_csharp_namespace());
+  push_string(&mut result, "swift_prefix", options.has_swift_prefix(), options.get_swift_prefix());
+  push_string(&mut result, "php_class_prefix", options.has_php_class_prefix(), options.get_php_class_prefix());
+  push_string(&mut result, "php_namespace", options.has_php_namespace(), options.get_php_namespace());
+  push_string(&mut result, "php_metadata_namespace", options.has_php_metadata_namespace(),
+              options.get_php_metadata_namespace());
+  push_string(&mut result, "ruby_package", options.has_ruby_package(), options.get_ruby_package());
+  push_uninterpreted(&mut result, options.get_uninterpreted_option());
+  push_unknown(&mut result, options.get_unknown_fields());
+  result
+}
+
+// map_entry isn't listed: map entries are printed as map fields.
+fn message_options(options: &MessageOptions) -> OptionList {
+  let mut result = Vec::new();
+  push_bool(&mut result, "message_set_wire_format", options.has_message_set_wire_format(),
+            options.get_message_set_wire_format());
+  push_bool(&mut result, "no_standard_descriptor_accessor", options.has_no_standard_descriptor_accessor(),
+            options.get_no_standard_descriptor_accessor());
+  push_bool(&mut result, "deprecated", options.has_deprecated(), options.get_deprecated());
+  push_uninterpreted(&mut result, options.get_uninterpreted_option());
+  push_unknown(&mut result, options.get_unknown_fields());
+  result
+}
+
+fn field_options(options: &FieldOptions) -> OptionList {
+  let mut result = Vec::new();
+  if options.has_ctype() {
+    result.push(("ctype".to_string(), options.get_ctype().name().to_string()));
+  }
+  push_bool(&mut result, "packed", options.has_packed(), options.get_packed());
+  if options.has_jstype() {
+    result.push(("jstype".to_string(), options.get_jstype().name().to_string()));
+  }
+  push_bool(&mut result, "lazy", options.has_lazy(), options.get_lazy());
+  push_bool(&mut result, "unverified_lazy", options.has_unverified_lazy(), options.get_unverified_lazy());
+  push_bool(&mut result, "deprecated", options.has_deprecated(), options.get_deprecated());
+  push_string(&mut result, "experimental_map_key", options.has_experimental_map_key(),
+              options.get_experimental_map_key());
+  push_bool(&mut result, "weak", options.has_weak(), options.get_weak());
+  push_bool(&mut result, "debug_redact", options.has_debug_redact(), options.get_debug_redact());
+  push_uninterpreted(&mut result, options.get_uninterpreted_option());
+  push_unknown(&mut result, options.get_unknown_fields());
+  result
+}
+
+fn oneof_options(options: &OneofOptions) -> OptionList {
+  let mut result = Vec::new();
+  push_uninterpreted(&mut result, options.get_uninterpreted_option());
+  push_unknown(&mut result, options.get_unknown_fields());
+  result
+}
+
+fn enum_options(options: &EnumOptions) -> OptionList {
+  let mut result = Vec::new();
+  push_bool(&mut result, "allow_alias", options.has_allow_alias(), options.get_allow_alias());
+  push_bool(&mut result, "deprecated", options.has_deprecated(), options.get_deprecated());
+  push_uninterpreted(&mut result, options.get_uninterpreted_option());
+  push_unknown(&mut result, options.get_unknown_fields());
+  result
+}
+
+fn enum_value_options(options: &EnumValueOptions) -> OptionList {
+  let mut result = Vec::new();
+  push_bool(&mut result, "deprecated", options.has_deprecated(), options.get_deprecated());
+  push_bool(&mut result, "debug_redact", options.has_debug_redact(), options.get_debug_redact());
+  push_uninterpreted(&mut result, options.get_uninterpreted_option());
+  push_unknown(&mut result, options.get_unknown_fields());
+  result
+}
+
+fn service_options(options: &ServiceOptions) -> OptionList {
+  let mut result = Vec::new();
+  push_bool(&mut result, "deprecated", options.has_deprecated(), options.get_deprecated());
+  push_uninterpreted(&mut result, options.get_uninterpreted_option());
+  push_unknown(&mut result, options.get_unknown_fields());
+  result
+}
+
+fn method_options(options: &MethodOptions) -> OptionList {
+  let mut result = Vec::new();
+  push_bool(&mut result, "deprecated", options.has_deprecated(), options.get_deprecated());
+  if options.has_idempotency_level() {
+    result.push(("idempotency_level".to_string(), options.get_idempotency_level().name().to_string()));
+  }
+  push_uninterpreted(&mut result, options.get_uninterpreted_option());
+  push_unknown(&mut result, options.get_unknown_fields());
+  result
+}
+
+// " [a = 1, b = 2]", or nothing without options.
+fn bracketed(options: &OptionList) -> String {
+  if options.is_empty() {
+    return String::new();
+  }
+  let items = options.iter().map(|(name, value)| format!("{} = {}", name, value)).collect::<Vec<String>>();
+  format!(" [{}]", items.join(", "))
+}
+
+// "reserved 2, 9 to 11, 100 to max;" from ranges whose end is `end_offset`
+// past the last number reserved.
+fn ranges(ranges: &[(i32, i32)], end_offset: i32, max: i32) -> String {
+  ranges.iter().map(|&(start, end)| {
+    let last = end - end_offset;
+    if last == start {
+      start.to_string()
+    } else if last == max {
+      format!("{} to max", start)
+    } else {
+      format!("{} to {}", start, last)
+    }
+  }).collect::<Vec<String>>().join(", ")
+}
+
+fn field_type(field: &FieldDescriptorProto) -> String {
+  match field.get_type() {
+    TypeMessage | TypeEnum | TypeGroup => field.get_type_name().to_string(),
+    other => other.to_proto_str()
+  }
+}
+
+// The nested type among `nested`, declared in `scope`, that `type_name`
+// names.
+fn find_nested<'a>(nested: &'a [DescriptorProto], scope: &str, type_name: &str) -> Option<&'a DescriptorProto> {
+  nested.iter().find(|message| format!("{}.{}", scope, message.get_name()) == type_name)
+}
+
+struct ProtoPrinter {
+  proto3: bool,
+  indent: usize,
+  buf: String
+}
+
+impl ProtoPrinter {
+  fn line(&mut self, line: &str) {
+    if !line.is_empty() {
+      for _ in 0..self.indent {
+        self.buf.push_str("  ");
+      }
+    }
+    self.buf.push_str(line);
+    self.buf.push('\n');
+  }
+
+  fn option_statements(&mut self, options: OptionList) {
+    for (name, value) in options.iter() {
+      self.line(&format!("option {} = {};", name, value));
+    }
+  }
+
+  fn print_file(&mut self, proto: &FileDescriptorProto) {
+    self.line(&format!("syntax = \"{}\";", if self.proto3 { "proto3" } else { "proto2" }));
+    if proto.has_package() {
+      self.line("");
+      self.line(&format!("package {};", proto.get_package()));
+    }
+    if proto.dependency_len() > 0 {
+      self.line("");
+    }
+    for (i, dependency) in proto.get_dependency().iter().enumerate() {
+      let index = i as i32;
+      let modifier = if proto.get_public_dependency().contains(&index) {
+        "public "
+      } else if proto.get_weak_dependency().contains(&index) {
+        "weak "
+      } else {
+        ""
+      };
+      self.line(&format!("import {}{};", modifier, quote(dependency.as_bytes())));
+    }
+    let options = file_options(proto.get_options());
+    if !options.is_empty() {
+      self.line("");
+      self.option_statements(options);
+    }
+
+    let package_scope = if proto.has_package() { format!(".{}", proto.get_package()) } else { String::new() };
+    let scope = package_scope.as_str();
+    for enum_type in proto.get_enum_type().iter() {
+      self.line("");
+      self.print_enum(enum_type);
+    }
+    for message in proto.get_message_type().iter() {
+      if self.is_group_type(proto.get_extension(), scope, message) {
+        continue;
+      }
+      self.line("");
+      self.print_message(message, scope);
+    }
+    if proto.extension_len() > 0 {
+      self.line("");
+      self.print_extensions(proto.get_extension(), proto.get_message_type(), scope);
+    }
+    for service in proto.get_service().iter() {
+      self.line("");
+      self.print_service(service);
+    }
+  }
+
+  // Group types are printed with the field declaring them.
+  fn is_group_type(&self, fields: &[FieldDescriptorProto], scope: &str, message: &DescriptorProto) -> bool {
+    let full_name = format!("{}.{}", scope, message.get_name());
+    fields.iter().any(|field| field.get_type() == TypeGroup && field.get_type_name() == full_name)
+  }
+
+  fn print_message(&mut self, message: &DescriptorProto, scope: &str) {
+    self.line(&format!("message {} {{", message.get_name()));
+    self.indent += 1;
+    self.print_message_body(message, scope);
+    self.indent -= 1;
+    self.line("}");
+  }
+
+  fn print_message_body(&mut self, message: &DescriptorProto, scope: &str) {
+    let message_scope = format!("{}.{}", scope, message.get_name());
+    let full_name = message_scope.as_str();
+    self.option_statements(message_options(message.get_options()));
+
+    // A oneof is printed where its first field is. The oneof of a proto3
+    // optional field only exists for old readers and isn't printed.
+    let mut printed_oneofs: Vec<i32> = Vec::new();
+    for field in message.get_field().iter() {
+      let in_oneof = field.has_oneof_index() && !field.get_proto3_optional() &&
+        (field.get_oneof_index() as usize) < message.oneof_decl_len();
+      if !in_oneof {
+        self.print_field(field, message.get_nested_type(), full_name, false);
+        continue;
+      }
+      let index = field.get_oneof_index();
+      if printed_oneofs.contains(&index) {
+        continue;
+      }
+      printed_oneofs.push(index);
+      let oneof = &message.get_oneof_decl()[index as usize];
+      self.line(&format!("oneof {} {{", oneof.get_name()));
+      self.indent += 1;
+      self.option_statements(oneof_options(oneof.get_options()));
+      for member in message.get_field().iter().filter(|f| f.has_oneof_index() && f.get_oneof_index() == index) {
+        self.print_field(member, message.get_nested_type(), full_name, true);
+      }
+      self.indent -= 1;
+      self.line("}");
+    }
+
+    if message.extension_range_len() > 0 {
+      let extension_ranges = message.get_extension_range().iter().map(|r| (r.get_start(), r.get_end())).collect::<Vec<(i32, i32)>>();
+      self.line(&format!("extensions {};", ranges(&extension_ranges, 1, kMaxFieldNumber)));
+    }
+    if message.reserved_range_len() > 0 {
+      let reserved = message.get_reserved_range().iter().map(|r| (r.get_start(), r.get_end())).collect::<Vec<(i32, i32)>>();
+      self.line(&format!("reserved {};", ranges(&reserved, 1, kMaxFieldNumber)));
+    }
+    if message.reserved_name_len() > 0 {
+      let names = message.get_reserved_name().iter().map(|name| quote(name.as_bytes())).collect::<Vec<String>>();
+      self.line(&format!("reserved {};", names.join(", ")));
+    }
+
+    for enum_type in message.get_enum_type().iter() {
+      self.print_enum(enum_type);
+    }
+    for nested in message.get_nested_type().iter() {
+      if nested.get_options().get_map_entry() || self.is_group_type(message.get_field(), full_name, nested) ||
+          self.is_group_type(message.get_extension(), full_name, nested) {
+        continue;
+      }
+      self.print_message(nested, full_name);
+    }
+    self.print_extensions(message.get_extension(), message.get_nested_type(), full_name);
+  }
+
+  // `nested` are the types declared next to the field, in `scope`, where
+  // its map entry or group type is.
+  fn print_field(&mut self, field: &FieldDescriptorProto, nested: &[DescriptorProto], scope: &str, in_oneof: bool) {
+    let label = match field.get_label() {
+      _ if in_oneof => "",
+      LabelRepeated => "repeated ",
+      LabelRequired => "required ",
+      LabelOptional if !self.proto3 || field.get_proto3_optional() => "optional ",
+      LabelOptional => ""
+    };
+
+    let mut options = Vec::new();
+    if field.has_default_value() {
+      let value = field.get_default_value();
+      let literal = match field.get_type() {
+        TypeString => quote(value.as_bytes()),
+        // Already C-escaped in the descriptor.
+        TypeBytes => format!("\"{}\"", value),
+        _ => value.to_string()
+      };
+      options.push(("default".to_string(), literal));
+    }
+    if field.has_json_name() && field.get_json_name() != default_json_name(field.get_name()) {
+      options.push(("json_name".to_string(), quote(field.get_json_name().as_bytes())));
+    }
+    options.extend(field_options(field.get_options()));
+
+    let declared = find_nested(nested, scope, field.get_type_name());
+    match declared {
+      Some(entry) if field.get_label() == LabelRepeated && entry.get_options().get_map_entry() &&
+          entry.field_len() == 2 => {
+        let key = field_type(&entry.get_field()[0]);
+        let value = field_type(&entry.get_field()[1]);
+        self.line(&format!("map<{}, {}> {} = {}{};", key, value, field.get_name(), field.get_number(),
+                          bracketed(&options)));
+      }
+      Some(group) if field.get_type() == TypeGroup => {
+        self.line(&format!("{}group {} = {}{} {{", label, group.get_name(), field.get_number(),
+                          bracketed(&options)));
+        self.indent += 1;
+        self.print_message_body(group, scope);
+        self.indent -= 1;
+        self.line("}");
+      }
+      _ => {
+        self.line(&format!("{}{} {} = {}{};", label, field_type(field), field.get_name(), field.get_number(),
+                          bracketed(&options)));
+      }
+    }
+  }
+
+  // Extensions are grouped by the message they extend, in the order each
+  // extendee first appears.
+  fn print_extensions(&mut self, extensions: &[FieldDescriptorProto], nested: &[DescriptorProto], scope: &str) {
+    let mut extendees: Vec<&str> = Vec::new();
+    for extension in extensions.iter() {
+      if !extendees.contains(&extension.get_extendee()) {
+        extendees.push(extension.get_extendee());
+      }
+    }
+    for extendee in extendees.iter() {
+      self.line(&format!("extend {} {{", *extendee));
+      self.indent += 1;
+      for extension in extensions.iter().filter(|e| e.get_extendee() == *extendee) {
+        self.print_field(extension, nested, scope, false);
+      }
+      self.indent -= 1;
+      self.line("}");
+    }
+  }
+
+  fn print_enum(&mut self, descriptor: &EnumDescriptorProto) {
+    self.line(&format!("enum {} {{", descriptor.get_name()));
+    self.indent += 1;
+    self.option_statements(enum_options(descriptor.get_options()));
+    for value in descriptor.get_value().iter() {
+      let options = enum_value_options(value.get_options());
+      self.line(&format!("{} = {}{};", value.get_name(), value.get_number(), bracketed(&options)));
+    }
+    if descriptor.reserved_range_len() > 0 {
+      let reserved = descriptor.get_reserved_range().iter().map(|r| (r.get_start(), r.get_end())).collect::<Vec<(i32, i32)>>();
+      self.line(&format!("reserved {};", ranges(&reserved, 0, kMaxEnumNumber)));
+    }
+    if descriptor.reserved_name_len() > 0 {
+      let names = descriptor.get_reserved_name().iter().map(|name| quote(name.as_bytes())).collect::<Vec<String>>();
+      self.line(&format!("reserved {};", names.join(", ")));
+    }
+    self.indent -= 1;
+    self.line("}");
+  }
+
+  fn print_service(&mut self, service: &ServiceDescriptorProto) {
+    self.line(&format!("service {} {{", service.get_name()));
+    self.indent += 1;
+    self.option_statements(service_options(service.get_options()));
+    for method in service.get_method().iter() {
+      let signature = format!("rpc {}({}{}) returns ({}{})", method.get_name(),
+                              if method.get_client_streaming() { "stream " } else { "" }, method.get_input_type(),
+                              if method.get_server_streaming() { "stream " } else { "" }, method.get_output_type());
+      let options = method_options(method.get_options());
+      if options.is_empty() {
+        self.line(&(signature + ";"));
+        continue;
+      }
+      self.line(&(signature + " {"));
+      self.indent += 1;
+      self.option_statements(options);
+      self.indent -= 1;
+      self.line("}");
+    }
+    self.indent -= 1;
+    self.line("}");
+  }
+}
+
+#[test]
+fn test_print_file() {
+  use crate::descriptor::google::protobuf::{EnumValueDescriptorProto, OneofDescriptorProto, MethodDescriptorProto};
+  use crate::descriptor::google::protobuf::descriptor_proto::{ExtensionRange, ReservedRange};
+  use crate::descriptor::google::protobuf::enum_descriptor_proto::EnumReservedRange;
+  use crate::descriptor::google::protobuf::field_descriptor_proto::{Type, Label, TypeUint64, TypeInt32};
+
+  fn field(name: &str, number: i32, label: Label, ty: Type, type_name: &str) -> FieldDescriptorProto {
+    let mut field = FieldDescriptorProto::new();
+    field.set_name(name.to_string());
+    field.set_number(number);
+    field.set_label(label);
+    field.set_type(ty);
+    if !type_name.is_empty() {
+      field.set_type_name(type_name.to_string());
+    }
+    field
+  }
+
+  let mut state = EnumDescriptorProto::new();
+  state.set_name("State".to_string());
+  state.mut_options().set_allow_alias(true);
+  for &(name, number) in [("FOLLOWER", 0), ("LEADER", 1), ("PRIMARY", 1)].iter() {
+    let mut value = EnumValueDescriptorProto::new();
+    value.set_name(name.to_string());
+    value.set_number(number);
+    state.add_value(value);
+  }
+  let mut reserved_values = EnumReservedRange::new();
+  reserved_values.set_start(10);
+  reserved_values.set_end(2147483647);
+  state.add_reserved_range(reserved_values);
+
+  let mut entry = DescriptorProto::new();
+  entry.set_name("PeersEntry".to_string());
+  entry.mut_options().set_map_entry(true);
+  entry.add_field(field("key", 1, LabelOptional, TypeString, ""));
+  entry.add_field(field("value", 2, LabelOptional, TypeUint64, ""));
+
+  let mut group = DescriptorProto::new();
+  group.set_name("Snapshot".to_string());
+  group.add_field(field("index", 1, LabelRequired, TypeUint64, ""));
+
+  let mut entries = DescriptorProto::new();
+  entries.set_name("Entries".to_string());
+  let mut command = field("command", 4, LabelOptional, TypeString, "");
+  command.set_default_value("say \"hi\"".to_string());
+  command.set_json_name("cmd".to_string());
+  entries.add_field(field("term", 1, LabelRequired, TypeUint64, ""));
+  entries.add_field(command);
+  let mut vote = field("vote", 5, LabelOptional, TypeInt32, "");
+  vote.set_oneof_index(0);
+  let mut append = field("append", 6, LabelOptional, TypeBytes, "");
+  append.set_oneof_index(0);
+  append.mut_options().set_deprecated(true);
+  entries.add_field(vote);
+  entries.add_field(append);
+  entries.add_field(field("peers", 7, LabelRepeated, TypeMessage, ".raft.Entries.PeersEntry"));
+  entries.add_field(field("snapshot", 8, LabelOptional, TypeGroup, ".raft.Entries.Snapshot"));
+  entries.add_field(field("state", 9, LabelOptional, TypeEnum, ".raft.State"));
+  let mut body = OneofDescriptorProto::new();
+  body.set_name("body".to_string());
+  entries.add_oneof_decl(body);
+  entries.add_nested_type(entry);
+  entries.add_nested_type(group);
+  let mut extensions = ExtensionRange::new();
+  extensions.set_start(100);
+  extensions.set_end(536870912);
+  entries.add_extension_range(extensions);
+  for &(start, end) in [(2, 3), (20, 30)].iter() {
+    let mut reserved = ReservedRange::new();
+    reserved.set_start(start);
+    reserved.set_end(end);
+    entries.add_reserved_range(reserved);
+  }
+  entries.add_reserved_name("old".to_string());
+
+  let mut extension = field("origin", 100, LabelOptional, TypeString, "");
+  extension.set_extendee(".raft.Entries".to_string());
+
+  let mut replicate = MethodDescriptorProto::new();
+  replicate.set_name("Replicate".to_string());
+  replicate.set_input_type(".raft.Entries".to_string());
+  replicate.set_output_type(".raft.Entries".to_string());
+  replicate.set_server_streaming(true);
+  replicate.mut_options().set_deprecated(true);
+  let mut service = ServiceDescriptorProto::new();
+  service.set_name("Raft".to_string());
+  service.add_method(replicate);
+
+  let mut proto = FileDescriptorProto::new();
+  proto.set_name("raft.proto".to_string());
+  proto.set_package("raft".to_string());
+  proto.add_dependency("common.proto".to_string());
+  proto.add_dependency("legacy.proto".to_string());
+  proto.add_public_dependency(0);
+  proto.mut_options().set_java_package("org.raft".to_string());
+  proto.add_enum_type(state);
+  proto.add_message_type(entries);
+  proto.add_extension(extension);
+  proto.add_service(service);
+
+  assert_eq!(print_file(&proto), "syntax = \"proto2\";
+
+package raft;
+
+import public \"common.proto\";
+import \"legacy.proto\";
+
+option java_package = \"org.raft\";
+
+enum State {
+  option allow_alias = true;
+  FOLLOWER = 0;
+  LEADER = 1;
+  PRIMARY = 1;
+  reserved 10 to max;
+}
+
+message Entries {
+  required uint64 term = 1;
+  optional string command = 4 [default = \"say \\\"hi\\\"\", json_name = \"cmd\"];
+  oneof body {
+    int32 vote = 5;
+    bytes append = 6 [deprecated = true];
+  }
+  map<string, uint64> peers = 7;
+  optional group Snapshot = 8 {
+    required uint64 index = 1;
+  }
+  optional .raft.State state = 9;
+  extensions 100 to max;
+  reserved 2, 20 to 29;
+  reserved \"old\";
+}
+
+extend .raft.Entries {
+  optional string origin = 100;
+}
+
+service Raft {
+  rpc Replicate(.raft.Entries) returns (stream .raft.Entries) {
+    option deprecated = true;
+  }
+}
+");
+}
+
+// Descriptor sets compiled into binaries come from newer protocs, whose
+// options this descriptor.proto may not name.
+#[test]
+fn test_print_newer_options() {
+  use crate::descriptor::google::protobuf::field_descriptor_proto::TypeInt64;
+  use crate::descriptor::google::protobuf::field_options::JsType;
+
+  let mut options = FileOptions::new();
+  // Field 50, the editions features, with a 0-length value.
+  options.Decode(&mut &[0x92, 0x03, 0x00][..]).unwrap();
+  options.set_csharp_namespace("Raft".to_string());
+  options.set_cc_enable_arenas(true);
+  let mut id = FieldDescriptorProto::new();
+  id.set_name("id".to_string());
+  id.set_number(1);
+  id.set_label(LabelOptional);
+  id.set_type(TypeInt64);
+  id.mut_options().set_jstype(JsType::from_name("JS_STRING").unwrap());
+  id.mut_options().set_unverified_lazy(true);
+  let mut message = DescriptorProto::new();
+  message.set_name("Entry".to_string());
+  message.add_field(id);
+  let mut proto = FileDescriptorProto::new();
+  proto.set_name("raft.proto".to_string());
+  proto.set_options(options);
+  proto.add_message_type(message);
+  assert_eq!(print_file(&proto), "syntax = \"proto2\";
+
+option cc_enable_arenas = true;
+option csharp_namespace = \"Raft\";
+option (50) = \"\";
+
+message Entry {
+  optional int64 id = 1 [jstype = JS_STRING, unverified_lazy = true];
+}
+");
+}
+
+#[test]
+fn test_print_descriptor_set() {
+  use protobuf::ProtobufEncode;
+
+  let mut message = DescriptorProto::new();
+  message.set_name("Empty".to_string());
+  let mut proto = FileDescriptorProto::new();
+  proto.set_name("empty.proto".to_string());
+  proto.set_package("raft".to_string());
+  proto.add_message_type(message);
+  let mut data = Vec::new();
+  proto.Encode(&mut data).unwrap();
+  let source = print_file_descriptor(&data).unwrap();
+  assert_eq!(source, "syntax = \"proto2\";\n\npackage raft;\n\nmessage Empty {\n}\n");
+
+  let mut set = FileDescriptorSet::new();
+  set.add_file(proto);
+  let mut data = Vec::new();
+  set.Encode(&mut data).unwrap();
+  assert_eq!(print_descriptor_set(&data).unwrap(), [("empty.proto".to_string(), source)]);
+  assert!(print_descriptor_set(&data[..data.len() - 1]).is_err());
+}
@@ -0,0 +1,482 @@
+// Storage for messages generated with the arena option. Decoding into an
+// Arena copies the input into it once, and takes every sub-message and
+// repeated field of the tree from it too; strings and bytes are slices of
+// the copy. Nothing in an arena is dropped on its own: dropping the arena
+// frees the whole tree at once, a chunk at a time.
+
+use alloc::alloc::{alloc, dealloc, handle_alloc_error, Layout};
+use alloc::string::String;
+use alloc::vec::Vec;
+use core::cell::Cell;
+use core::marker::PhantomData;
+use core::ops::{Deref, DerefMut};
+use core::ptr::{self, NonNull};
+use core::{fmt, mem, slice};
+use crate::io::{EncodeError, Writer};
+use crate::WireType::*;
+use crate::{DecodeError, DecodeVarintSlice, DecodeWireSlice, MalformedInput, MissingRequiredFields};
+use crate::{EncodeFixed32Field, EncodeFixed64Field, EncodeRawField, EncodeVarintField, ProtobufEncode};
+
+// The size of the first chunk of an arena made by new(). Each chunk after
+// it is twice the size of the one before.
+static kFirstChunkSize: usize = 4096;
+
+// The start of every chunk, linking it to the one allocated before it.
+struct Chunk {
+  previous: *mut Chunk,
+  layout: Layout
+}
+
+pub struct Arena {
+  // The newest chunk, whose free space runs from `next` to `end`.
+  chunk: Cell<*mut Chunk>,
+  next: Cell<*mut u8>,
+  end: Cell<*mut u8>,
+  next_size: Cell<usize>
+}
+
+// The chunks belong to the arena alone, and what is allocated in them
+// borrows it.
+unsafe impl Send for Arena {}
+
+impl Arena {
+  // Allocates nothing until it is first used.
+  pub const fn new() -> Arena {
+    Arena::with_chunk_size(kFirstChunkSize)
+  }
+
+  // An arena whose first chunk holds `capacity` bytes, so that a tree
+  // known to fit takes a single allocation.
+  pub const fn with_capacity(capacity: usize) -> Arena {
+    Arena::with_chunk_size(capacity.saturating_add(mem::size_of::<Chunk>()))
+  }
+
+  const fn with_chunk_size(size: usize) -> Arena {
+    Arena {
+      chunk: Cell::new(ptr::null_mut()),
+      next: Cell::new(ptr::null_mut()),
+      end: Cell::new(ptr::null_mut()),
+      next_size: Cell::new(size)
+    }
+  }
+
+  // Moves `value` into the arena. It is never dropped, so it may not own
+  // anything outside the arena.
+  #[allow(clippy::mut_from_ref)]
+  pub fn alloc<T>(&self, value: T) -> &mut T {
+    const { assert!(!mem::needs_drop::<T>(), "arena values are never dropped") };
+    let data = self.alloc_layout(Layout::new::<T>()).cast::<T>().as_ptr();
+    unsafe {
+      data.write(value);
+      &mut *data
+    }
+  }
+
+  #[allow(clippy::mut_from_ref)]
+  pub fn alloc_slice_copy<T: Copy>(&self, values: &[T]) -> &mut [T] {
+    let layout = Layout::for_value(values);
+    let data = self.alloc_layout(layout).cast::<T>().as_ptr();
+    unsafe {
+      ptr::copy_nonoverlapping(values.as_ptr(), data, values.len());
+      slice::from_raw_parts_mut(data, values.len())
+    }
+  }
+
+  // A copy of `value`, e.g. to set a string field to one built at run time.
+  #[allow(clippy::mut_from_ref)]
+  pub fn alloc_str(&self, value: &str) -> &mut str {
+    let bytes = self.alloc_slice_copy(value.as_bytes());
+    unsafe { core::str::from_utf8_unchecked_mut(bytes) }
+  }
+
+  fn alloc_layout(&self, layout: Layout) -> NonNull<u8> {
+    if layout.size() == 0 {
+      return unsafe { NonNull::new_unchecked(ptr::without_provenance_mut(layout.align())) };
+    }
+    let next = self.next.get();
+    let free = self.end.get() as usize - next as usize;
+    let padding = next.align_offset(layout.align());
+    if padding <= free && layout.size() <= free - padding {
+      unsafe {
+        self.next.set(next.add(padding + layout.size()));
+        return NonNull::new_unchecked(next.add(padding));
+      }
+    }
+    self.grow(layout)
+  }
+
+  // Starts a new chunk with room for `layout`, leaving what is left of the
+  // last one unused.
+  #[cold]
+  fn grow(&self, layout: Layout) -> NonNull<u8> {
+    let header = Layout::new::<Chunk>();
+    let needed = header.size().checked_add(layout.align() + layout.size());
+    let size = needed.expect("arena allocation too large").max(self.next_size.get());
+    let chunk_layout = Layout::from_size_align(size, header.align()).expect("arena allocation too large");
+    let data = unsafe { alloc(chunk_layout) };
+    if data.is_null() {
+      handle_alloc_error(chunk_layout);
+    }
+    unsafe {
+      (data as *mut Chunk).write(Chunk { previous: self.chunk.get(), layout: chunk_layout });
+      self.next.set(data.add(header.size()));
+      self.end.set(data.add(size));
+    }
+    self.chunk.set(data as *mut Chunk);
+    self.next_size.set(size.saturating_mul(2));
+    self.alloc_layout(layout)
+  }
+}
+
+impl Default for Arena {
+  fn default() -> Arena {
+    Arena::new()
+  }
+}
+
+impl Drop for Arena {
+  fn drop(&mut self) {
+    let mut chunk = self.chunk.get();
+    while !chunk.is_null() {
+      let Chunk { previous, layout } = unsafe { chunk.read() };
+      unsafe { dealloc(chunk as *mut u8, layout) };
+      chunk = previous;
+    }
+  }
+}
+
+impl fmt::Debug for Arena {
+  fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+    f.write_str("Arena")
+  }
+}
+
+// A message field's sub-message, in the arena. Like a Box, it is the only
+// pointer to its value, so like a Box it is covariant: a message of the
+// arena can be read where an empty one is borrowed from a static.
+pub struct ArenaBox<'a, T> {
+  data: NonNull<T>,
+  _marker: PhantomData<&'a T>
+}
+
+unsafe impl<T: Send> Send for ArenaBox<'_, T> {}
+unsafe impl<T: Sync> Sync for ArenaBox<'_, T> {}
+
+impl<'a, T> ArenaBox<'a, T> {
+  pub fn new_in(arena: &'a Arena, value: T) -> ArenaBox<'a, T> {
+    ArenaBox { data: NonNull::from(arena.alloc(value)), _marker: PhantomData }
+  }
+}
+
+impl<T> Deref for ArenaBox<'_, T> {
+  type Target = T;
+
+  fn deref(&self) -> &T {
+    unsafe { self.data.as_ref() }
+  }
+}
+
+impl<T> DerefMut for ArenaBox<'_, T> {
+  fn deref_mut(&mut self) -> &mut T {
+    unsafe { self.data.as_mut() }
+  }
+}
+
+impl<T: fmt::Debug> fmt::Debug for ArenaBox<'_, T> {
+  fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+    (**self).fmt(f)
+  }
+}
+
+impl<T: PartialEq> PartialEq for ArenaBox<'_, T> {
+  fn eq(&self, other: &ArenaBox<'_, T>) -> bool {
+    **self == **other
+  }
+}
+
+impl<T: ProtobufEncode> ProtobufEncode for ArenaBox<'_, T> {
+  fn Encode(&self, writer: &mut dyn Writer) -> Result<(), EncodeError> {
+    (**self).Encode(writer)
+  }
+}
+
+// A repeated field, with its elements in the arena. Growing moves them to
+// a block twice the size and leaves the old one to the arena.
+pub struct RepeatedField<'a, T> {
+  data: NonNull<T>,
+  len: usize,
+  capacity: usize,
+  _marker: PhantomData<&'a [T]>
+}
+
+unsafe impl<T: Send> Send for RepeatedField<'_, T> {}
+unsafe impl<T: Sync> Sync for RepeatedField<'_, T> {}
+
+impl<'a, T> RepeatedField<'a, T> {
+  pub const fn new() -> RepeatedField<'a, T> {
+    RepeatedField { data: NonNull::dangling(), len: 0, capacity: 0, _marker: PhantomData }
+  }
+
+  pub fn push(&mut self, arena: &'a Arena, value: T) {
+    const { assert!(!mem::needs_drop::<T>(), "arena values are never dropped") };
+    if self.len == self.capacity {
+      self.grow(arena);
+    }
+    unsafe { self.data.as_ptr().add(self.len).write(value) };
+    self.len += 1;
+  }
+
+  pub fn clear(&mut self) {
+    self.len = 0;
+  }
+
+  pub fn capacity(&self) -> usize {
+    self.capacity
+  }
+
+  fn grow(&mut self, arena: &'a Arena) {
+    let capacity = (self.capacity * 2).max(4);
+    let layout = Layout::array::<T>(capacity).expect("repeated field too large");
+    let data = arena.alloc_layout(layout).cast::<T>();
+    unsafe { ptr::copy_nonoverlapping(self.data.as_ptr(), data.as_ptr(), self.len) };
+    self.data = data;
+    self.capacity = capacity;
+  }
+}
+
+impl<T> Default for RepeatedField<'_, T> {
+  fn default() -> Self {
+    RepeatedField::new()
+  }
+}
+
+impl<T> Deref for RepeatedField<'_, T> {
+  type Target = [T];
+
+  fn deref(&self) -> &[T] {
+    unsafe { slice::from_raw_parts(self.data.as_ptr(), self.len) }
+  }
+}
+
+impl<T> DerefMut for RepeatedField<'_, T> {
+  fn deref_mut(&mut self) -> &mut [T] {
+    unsafe { slice::from_raw_parts_mut(self.data.as_ptr(), self.len) }
+  }
+}
+
+impl<T: fmt::Debug> fmt::Debug for RepeatedField<'_, T> {
+  fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+    (**self).fmt(f)
+  }
+}
+
+impl<T: PartialEq> PartialEq for RepeatedField<'_, T> {
+  fn eq(&self, other: &RepeatedField<'_, T>) -> bool {
+    **self == **other
+  }
+}
+
+// A field as ArenaTagIter reads it: a TaggedValue whose payload is a slice
+// of the input.
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum ArenaValue<'a> {
+  Varint(u64, u64),
+  Fixed64(u64, u64),
+  Raw(u64, &'a [u8]),
+  Fixed32(u64, u32)
+}
+
+pub fn EncodeArenaValue(writer: &mut dyn Writer, value: &ArenaValue) -> Result<(), EncodeError> {
+  match *value {
+    ArenaValue::Varint(tag, varint) => EncodeVarintField(writer, tag, varint),
+    ArenaValue::Fixed64(tag, fixed) => EncodeFixed64Field(writer, tag, fixed),
+    ArenaValue::Raw(tag, data) => EncodeRawField(writer, tag, data),
+    ArenaValue::Fixed32(tag, fixed) => EncodeFixed32Field(writer, tag, fixed)
+  }
+}
+
+// The fields encoded in `data`, like TagIter.
+pub struct ArenaTagIter<'a> {
+  data: &'a [u8],
+  malformed: bool
+}
+
+impl<'a> ArenaTagIter<'a> {
+  pub fn new(data: &'a [u8]) -> ArenaTagIter<'a> {
+    ArenaTagIter { data, malformed: false }
+  }
+
+  pub fn is_malformed(&self) -> bool {
+    self.malformed
+  }
+}
+
+impl<'a> Iterator for ArenaTagIter<'a> {
+  type Item = ArenaValue<'a>;
+
+  fn next(&mut self) -> Option<ArenaValue<'a>> {
+    if self.malformed || self.data.is_empty() {
+      return None;
+    }
+    match DecodeArenaValue(self.data) {
+      Some((value, length)) => {
+        self.data = &self.data[length..];
+        Some(value)
+      }
+      None => {
+        self.malformed = true;
+        None
+      }
+    }
+  }
+}
+
+// The field at the front of `data`, with the bytes it takes. Groups are
+// malformed, as for TagIter.
+fn DecodeArenaValue(data: &[u8]) -> Option<(ArenaValue<'_>, usize)> {
+  let ((wire, tag), start) = DecodeWireSlice(data)?;
+  let rest = &data[start..];
+  match wire {
+    VarintWireType => {
+      let (varint, length) = DecodeVarintSlice(rest)?;
+      Some((ArenaValue::Varint(tag, varint), start + length))
+    }
+    Fixed64WireType => {
+      let bytes = rest.get(..8)?;
+      Some((ArenaValue::Fixed64(tag, u64::from_le_bytes(bytes.try_into().unwrap())), start + 8))
+    }
+    LengthDelimWireType => {
+      let (length, prefix) = DecodeVarintSlice(rest)?;
+      let end = prefix.checked_add(usize::try_from(length).ok()?)?;
+      Some((ArenaValue::Raw(tag, rest.get(prefix..end)?), start + end))
+    }
+    Fixed32WireType => {
+      let bytes = rest.get(..4)?;
+      Some((ArenaValue::Fixed32(tag, u32::from_le_bytes(bytes.try_into().unwrap())), start + 4))
+    }
+    StartGroupWireType | EndGroupWireType => None
+  }
+}
+
+// The elements of a packed repeated field, handed to `push` in order.
+// False if `data` doesn't hold whole elements.
+pub fn UnpackVarints(data: &[u8], mut push: impl FnMut(u64)) -> bool {
+  let mut data = data;
+  while !data.is_empty() {
+    match DecodeVarintSlice(data) {
+      Some((value, length)) => {
+        push(value);
+        data = &data[length..];
+      }
+      None => return false
+    }
+  }
+  true
+}
+
+pub fn UnpackFixed32(data: &[u8], mut push: impl FnMut(u32)) -> bool {
+  let elements = data.chunks_exact(4);
+  if !elements.remainder().is_empty() {
+    return false;
+  }
+  for bytes in elements {
+    push(u32::from_le_bytes(bytes.try_into().unwrap()));
+  }
+  true
+}
+
+pub fn UnpackFixed64(data: &[u8], mut push: impl FnMut(u64)) -> bool {
+  let elements = data.chunks_exact(8);
+  if !elements.remainder().is_empty() {
+    return false;
+  }
+  for bytes in elements {
+    push(u64::from_le_bytes(bytes.try_into().unwrap()));
+  }
+  true
+}
+
+// Implemented by messages generated with the arena option, which borrow
+// everything they hold from an Arena.
+pub trait ArenaMessage<'a> {
+  // Merges the fields encoded in `data` into self, taking what they need
+  // from `arena`, without checking that required fields ended up set.
+  // Strings and bytes are slices of `data`.
+  fn DecodePartialIn(&mut self, arena: &'a Arena, data: &'a [u8]) -> bool;
+
+  // As for Protobuf.
+  fn AppendMissingFields(&self, _prefix: &str, _missing: &mut Vec<String>) {
+  }
+
+  fn missing_fields(&self) -> Vec<String> {
+    let mut missing = Vec::new();
+    self.AppendMissingFields("", &mut missing);
+    missing
+  }
+
+  fn is_initialized(&self) -> bool {
+    self.missing_fields().is_empty()
+  }
+}
+
+// Decodes `data` into `message` in the arena, copying it there first so
+// that the tree lives as long as the arena and no longer needs `data`.
+pub fn DecodeIn<'a, M: ArenaMessage<'a>>(arena: &'a Arena, data: &[u8], message: M) -> Result<&'a mut M, DecodeError> {
+  let data = &*arena.alloc_slice_copy(data);
+  let message = arena.alloc(message);
+  if !message.DecodePartialIn(arena, data) {
+    return Err(MalformedInput);
+  }
+  let missing = message.missing_fields();
+  if !missing.is_empty() {
+    return Err(MissingRequiredFields(missing));
+  }
+  Ok(message)
+}
+
+#[test]
+fn test_arena_alloc() {
+  let arena = Arena::with_capacity(64);
+  let number = arena.alloc(7u64);
+  let text = arena.alloc_str("put");
+  let bytes = arena.alloc_slice_copy(&[1u8, 2, 3]);
+  *number += 1;
+  assert_eq!((*number, &*text, &*bytes), (8, "put", &[1u8, 2, 3][..]));
+  assert_eq!(number as *mut u64 as usize % mem::align_of::<u64>(), 0);
+
+  // Past the first chunk, values still come out whole and aligned.
+  let mut field = RepeatedField::new();
+  for i in 0..1000u64 {
+    field.push(&arena, i);
+  }
+  assert_eq!(field.len(), 1000);
+  assert!(field.capacity() >= 1000);
+  assert!(field.iter().copied().eq(0..1000));
+  assert_eq!(*number, 8);
+  let empty: &mut [u64] = arena.alloc_slice_copy(&[]);
+  assert!(empty.is_empty());
+}
+
+#[test]
+fn test_arena_tag_iter() {
+  // Field 1 = 150, field 2 = "ab", field 3 = fixed32 1, then a group.
+  let data = [0x08, 0x96, 0x01, 0x12, 0x02, b'a', b'b', 0x1d, 1, 0, 0, 0, 0x23];
+  let mut tags = ArenaTagIter::new(&data);
+  assert_eq!(tags.next(), Some(ArenaValue::Varint(1, 150)));
+  assert_eq!(tags.next(), Some(ArenaValue::Raw(2, b"ab")));
+  assert_eq!(tags.next(), Some(ArenaValue::Fixed32(3, 1)));
+  assert!(!tags.is_malformed());
+  assert_eq!(tags.next(), None);
+  assert!(tags.is_malformed());
+
+  for truncated in [&data[..2], &data[..6], &data[..10]] {
+    let mut tags = ArenaTagIter::new(truncated);
+    assert!(tags.by_ref().count() < 3 && tags.is_malformed(), "{:?}", truncated);
+  }
+
+  let mut values = Vec::new();
+  assert!(UnpackVarints(&[0x96, 0x01, 0x02], |value| values.push(value)));
+  assert_eq!(values, [150, 2]);
+  assert!(!UnpackVarints(&[0x96], |_| ()));
+  assert!(!UnpackFixed32(&[1, 0, 0], |_| ()));
+}
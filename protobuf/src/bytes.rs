@@ -0,0 +1,294 @@
+// Reference-counted slices of a decoded buffer, for the bytes_type=Bytes and
+// string_type=BytesStr generator options. Decoding from a Bytes hands out
+// slices of it for length-delimited fields, so payloads are shared with the
+// input and with every clone instead of being copied. Decoding from other
+// readers gives each payload a buffer of its own, which becomes shared only
+// once a Bytes reader is made of it.
+
+use alloc::string::String;
+use alloc::sync::Arc;
+use alloc::vec::Vec;
+use core::cmp::Ordering;
+use core::fmt;
+use core::hash::{Hash, Hasher};
+use core::ops::{Deref, Range};
+use crate::io::Reader;
+
+#[derive(Clone)]
+enum Buffer {
+  // Held by this Bytes alone, as read_shared() returns a payload copied out
+  // of a reader, so that into_vec() takes it without copying and no
+  // reference count is allocated for it. Cloning and slicing copy it.
+  Owned(Vec<u8>),
+  Shared(Arc<Vec<u8>>)
+}
+
+impl Deref for Buffer {
+  type Target = Vec<u8>;
+
+  fn deref(&self) -> &Vec<u8> {
+    match *self {
+      Buffer::Owned(ref data) => data,
+      Buffer::Shared(ref data) => data
+    }
+  }
+}
+
+#[derive(Clone)]
+pub struct Bytes {
+  data: Buffer,
+  start: usize,
+  end: usize
+}
+
+impl Bytes {
+  pub fn new() -> Bytes {
+    Bytes::from(Vec::new())
+  }
+
+  pub fn copy_from_slice(data: &[u8]) -> Bytes {
+    Bytes::from(data.to_vec())
+  }
+
+  // A payload read out of a reader other than a Bytes.
+  pub(crate) fn from_owned(data: Vec<u8>) -> Bytes {
+    let end = data.len();
+    Bytes { data: Buffer::Owned(data), start: 0, end }
+  }
+
+  // The bytes at `range` of this slice, sharing its buffer unless it is
+  // owned.
+  pub fn slice(&self, range: Range<usize>) -> Bytes {
+    assert!(range.start <= range.end && range.end <= self.len());
+    match self.data {
+      Buffer::Owned(_) => Bytes::from_owned(self[range].to_vec()),
+      Buffer::Shared(ref data) => {
+        Bytes { data: Buffer::Shared(data.clone()), start: self.start + range.start, end: self.start + range.end }
+      }
+    }
+  }
+
+  // Copies only when the buffer is shared or holds more than this slice.
+  pub fn into_vec(self) -> Vec<u8> {
+    if self.start != 0 || self.end != self.data.len() {
+      return self[..].to_vec();
+    }
+    match self.data {
+      Buffer::Owned(data) => data,
+      Buffer::Shared(data) => Arc::try_unwrap(data).unwrap_or_else(|data| data.to_vec())
+    }
+  }
+}
+
+impl Default for Bytes {
+  fn default() -> Bytes {
+    Bytes::new()
+  }
+}
+
+impl Deref for Bytes {
+  type Target = [u8];
+
+  fn deref(&self) -> &[u8] {
+    &self.data[self.start..self.end]
+  }
+}
+
+impl AsRef<[u8]> for Bytes {
+  fn as_ref(&self) -> &[u8] {
+    self
+  }
+}
+
+impl From<Vec<u8>> for Bytes {
+  fn from(data: Vec<u8>) -> Bytes {
+    let end = data.len();
+    Bytes { data: Buffer::Shared(Arc::new(data)), start: 0, end }
+  }
+}
+
+impl From<&[u8]> for Bytes {
+  fn from(data: &[u8]) -> Bytes {
+    Bytes::copy_from_slice(data)
+  }
+}
+
+impl fmt::Debug for Bytes {
+  fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+    fmt::Debug::fmt(&**self, f)
+  }
+}
+
+impl PartialEq for Bytes {
+  fn eq(&self, other: &Bytes) -> bool {
+    **self == **other
+  }
+}
+
+impl Eq for Bytes {}
+
+impl PartialEq<[u8]> for Bytes {
+  fn eq(&self, other: &[u8]) -> bool {
+    **self == *other
+  }
+}
+
+impl PartialOrd for Bytes {
+  fn partial_cmp(&self, other: &Bytes) -> Option<Ordering> {
+    Some(self.cmp(other))
+  }
+}
+
+impl Ord for Bytes {
+  fn cmp(&self, other: &Bytes) -> Ordering {
+    (**self).cmp(&**other)
+  }
+}
+
+impl Hash for Bytes {
+  fn hash<H: Hasher>(&self, state: &mut H) {
+    (**self).hash(state)
+  }
+}
+
+// Reading advances past what was read; read_shared() slices rather than
+// copies.
+impl Reader for Bytes {
+  fn read_bytes(&mut self, buf: &mut [u8]) -> bool {
+    if self.len() < buf.len() {
+      return false;
+    }
+    buf.copy_from_slice(&self[..buf.len()]);
+    self.start += buf.len();
+    true
+  }
+
+  fn read_vec(&mut self, length: u64) -> Option<Vec<u8>> {
+    self.read_shared(length).map(|bytes| bytes.to_vec())
+  }
+
+  fn read_shared(&mut self, length: u64) -> Option<Bytes> {
+    if (self.len() as u64) < length {
+      return None;
+    }
+    if let Buffer::Owned(ref mut data) = self.data {
+      self.data = Buffer::Shared(Arc::new(core::mem::take(data)));
+    }
+    let shared = self.slice(0..length as usize);
+    self.start = shared.end;
+    Some(shared)
+  }
+
+  fn remaining(&self) -> Option<&[u8]> {
+    Some(self)
+  }
+
+  fn advance(&mut self, count: usize) {
+    assert!(count <= self.len());
+    self.start += count;
+  }
+}
+
+// A Bytes checked to hold UTF-8.
+#[derive(Clone, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct BytesStr {
+  bytes: Bytes
+}
+
+impl BytesStr {
+  pub fn new() -> BytesStr {
+    BytesStr::default()
+  }
+
+  // Hands `bytes` back if they aren't UTF-8.
+  pub fn from_utf8(bytes: Bytes) -> Result<BytesStr, Bytes> {
+    match core::str::from_utf8(&bytes) {
+      Ok(_) => Ok(BytesStr { bytes }),
+      Err(_) => Err(bytes)
+    }
+  }
+
+  pub fn as_bytes_shared(&self) -> &Bytes {
+    &self.bytes
+  }
+
+  pub fn into_bytes(self) -> Bytes {
+    self.bytes
+  }
+}
+
+impl Deref for BytesStr {
+  type Target = str;
+
+  fn deref(&self) -> &str {
+    // The bytes were checked by from_utf8() or came from a str.
+    unsafe { core::str::from_utf8_unchecked(&self.bytes) }
+  }
+}
+
+impl AsRef<str> for BytesStr {
+  fn as_ref(&self) -> &str {
+    self
+  }
+}
+
+impl From<String> for BytesStr {
+  fn from(string: String) -> BytesStr {
+    BytesStr { bytes: Bytes::from(string.into_bytes()) }
+  }
+}
+
+impl From<&str> for BytesStr {
+  fn from(string: &str) -> BytesStr {
+    BytesStr { bytes: Bytes::copy_from_slice(string.as_bytes()) }
+  }
+}
+
+impl fmt::Debug for BytesStr {
+  fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+    fmt::Debug::fmt(&**self, f)
+  }
+}
+
+impl fmt::Display for BytesStr {
+  fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+    fmt::Display::fmt(&**self, f)
+  }
+}
+
+#[cfg(test)]
+fn same_buffer(a: &Bytes, b: &Bytes) -> bool {
+  match (&a.data, &b.data) {
+    (Buffer::Shared(a), Buffer::Shared(b)) => Arc::ptr_eq(a, b),
+    _ => false
+  }
+}
+
+#[test]
+fn test_shared_reads() {
+  let mut reader = Bytes::from(alloc::vec![1u8, 2, 3, 4, 5, 6]);
+  let mut byte = [0u8];
+  assert!(reader.read_bytes(&mut byte));
+  let shared = reader.read_shared(3).unwrap();
+  assert_eq!(&*shared, [2, 3, 4]);
+  assert!(same_buffer(&shared, &reader));
+  assert_eq!(&*reader, [5, 6]);
+  assert!(reader.read_shared(3).is_none());
+  assert_eq!(shared.slice(1..3).into_vec(), [3, 4]);
+
+  // Read out of a slice, a payload owns its buffer, which into_vec() hands
+  // back as it is.
+  let mut input: &[u8] = &[1, 2, 3];
+  let owned = input.read_shared(2).unwrap();
+  let data = owned.as_ptr();
+  assert_eq!(owned.clone().into_vec(), [1, 2]);
+  let vec = owned.into_vec();
+  assert_eq!(vec.as_ptr(), data);
+  let mut reader = Bytes::from_owned(alloc::vec![1, 2, 3]);
+  let shared = reader.read_shared(2).unwrap();
+  assert!(same_buffer(&shared, &reader));
+
+  let text = BytesStr::from_utf8(Bytes::from(&b"raft"[..])).unwrap();
+  assert_eq!(&*text, "raft");
+  assert!(BytesStr::from_utf8(Bytes::from(&[0xffu8][..])).is_err());
+}
@@ -0,0 +1,202 @@
+// Structural differences between two messages, compared as the JSON values
+// the json generator option gives them: fields are named as in the .proto
+// file, 64-bit integers are strings and enums their value names.
+
+use alloc::format;
+use alloc::string::String;
+use alloc::vec::Vec;
+use core::fmt;
+use crate::json::{Json, ToJson};
+use crate::{MessageInfo, Reflect};
+
+#[derive(Debug, PartialEq, Clone)]
+pub enum Change {
+  Added(Json),
+  Removed(Json),
+  Changed(Json, Json)
+}
+
+#[derive(Debug, PartialEq, Clone)]
+pub struct Difference {
+  // The dotted path of the field, with the index of an element of a
+  // repeated field in brackets, e.g. "Entries[1].Term".
+  pub path: String,
+  pub change: Change
+}
+
+impl fmt::Display for Difference {
+  fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+    match self.change {
+      Change::Added(ref value) => write!(f, "+ {}: {}", self.path, value),
+      Change::Removed(ref value) => write!(f, "- {}: {}", self.path, value),
+      Change::Changed(ref from, ref to) => write!(f, "~ {}: {} -> {}", self.path, from, to)
+    }
+  }
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct DiffOptions {
+  // Compares repeated fields as multisets, reporting the elements of either
+  // side left unmatched at their index on that side.
+  pub repeated_as_sets: bool,
+  // Paths of fields to leave out, without indices: "Entries.Term" is the
+  // term of every entry.
+  pub ignored: Vec<String>,
+  // The largest difference between the values of a float or double field
+  // still taken as equal. Other numbers are compared exactly.
+  pub float_tolerance: f64
+}
+
+// The differences that turn `a` into `b`, in field name order. Their
+// reflection tells the float and double fields apart.
+pub fn DiffMessages<M: ToJson + Reflect>(a: &M, b: &M, options: &DiffOptions) -> Vec<Difference> {
+  let info = a.message_info();
+  DiffTypedJson(&a.to_json(), &b.to_json(), &|field| is_float_field(info, field), options)
+}
+
+// Compares every number exactly, knowing no field types.
+pub fn DiffJson(a: &Json, b: &Json, options: &DiffOptions) -> Vec<Difference> {
+  DiffTypedJson(a, b, &|_| false, options)
+}
+
+// `is_float` tells whether a field, named by its path without indices as
+// ignored fields are, is a float or double field.
+pub fn DiffTypedJson(a: &Json, b: &Json, is_float: &dyn Fn(&str) -> bool, options: &DiffOptions) -> Vec<Difference> {
+  let mut differences = Vec::new();
+  diff_values("", "", a, b, is_float, options, &mut differences);
+  differences
+}
+
+fn is_float_field(info: &MessageInfo, field: &str) -> bool {
+  info.field_by_path(field).is_some_and(|field| matches!(field.type_name, "float" | "double"))
+}
+
+fn join(path: &str, name: &str) -> String {
+  if path.is_empty() {
+    name.into()
+  } else {
+    format!("{}.{}", path, name)
+  }
+}
+
+// `field` is `path` without indices, as ignored fields are named.
+fn diff_values(path: &str, field: &str, a: &Json, b: &Json, is_float: &dyn Fn(&str) -> bool, options: &DiffOptions,
+               differences: &mut Vec<Difference>) {
+  match (a, b) {
+    (Json::Object(a), Json::Object(b)) => {
+      let mut names: Vec<&String> = a.keys().chain(b.keys()).collect();
+      names.sort();
+      names.dedup();
+      let empty = Json::List(Vec::new());
+      for name in names {
+        let field = join(field, name);
+        if options.ignored.contains(&field) {
+          continue;
+        }
+        let path = join(path, name);
+        // An empty repeated field is left out, so a list is compared
+        // element by element with a missing one.
+        match (a.get(name), b.get(name)) {
+          (Some(a), Some(b)) => diff_values(&path, &field, a, b, is_float, options, differences),
+          (Some(a @ Json::List(_)), None) => diff_values(&path, &field, a, &empty, is_float, options, differences),
+          (None, Some(b @ Json::List(_))) => diff_values(&path, &field, &empty, b, is_float, options, differences),
+          (Some(a), None) => differences.push(Difference { path, change: Change::Removed(a.clone()) }),
+          (None, Some(b)) => differences.push(Difference { path, change: Change::Added(b.clone()) }),
+          (None, None) => ()
+        }
+      }
+    }
+    (Json::List(a), Json::List(b)) if options.repeated_as_sets => {
+      let mut matched = alloc::vec![false; b.len()];
+      let mut removed = Vec::new();
+      for (i, a) in a.iter().enumerate() {
+        let found = (0..b.len()).find(|&j| !matched[j] && is_equal(field, a, &b[j], is_float, options));
+        match found {
+          Some(j) => matched[j] = true,
+          None => removed.push(i)
+        }
+      }
+      for i in removed {
+        differences.push(Difference { path: format!("{}[{}]", path, i), change: Change::Removed(a[i].clone()) });
+      }
+      for (j, b) in b.iter().enumerate().filter(|&(j, _)| !matched[j]) {
+        differences.push(Difference { path: format!("{}[{}]", path, j), change: Change::Added(b.clone()) });
+      }
+    }
+    (Json::List(a), Json::List(b)) => {
+      for i in 0..a.len().max(b.len()) {
+        let path = format!("{}[{}]", path, i);
+        match (a.get(i), b.get(i)) {
+          (Some(a), Some(b)) => diff_values(&path, field, a, b, is_float, options, differences),
+          (Some(a), None) => differences.push(Difference { path, change: Change::Removed(a.clone()) }),
+          (None, Some(b)) => differences.push(Difference { path, change: Change::Added(b.clone()) }),
+          (None, None) => ()
+        }
+      }
+    }
+    // NaN equals nothing, itself included, but a NaN left as it was is no
+    // change.
+    (&Json::Number(x), &Json::Number(y)) if x == y || (x.is_nan() && y.is_nan()) => (),
+    (&Json::Number(x), &Json::Number(y)) if is_float(field) && (x - y).abs() <= options.float_tolerance => (),
+    _ if a == b => (),
+    _ => differences.push(Difference { path: path.into(), change: Change::Changed(a.clone(), b.clone()) })
+  }
+}
+
+fn is_equal(field: &str, a: &Json, b: &Json, is_float: &dyn Fn(&str) -> bool, options: &DiffOptions) -> bool {
+  let mut differences = Vec::new();
+  diff_values("", field, a, b, is_float, options, &mut differences);
+  differences.is_empty()
+}
+
+#[cfg(test)]
+fn entry(index: f64, term: &str) -> Json {
+  let mut object = crate::json::Object::new();
+  object.insert("Index".into(), Json::Number(index));
+  object.insert("Term".into(), Json::String(term.into()));
+  Json::Object(object)
+}
+
+#[test]
+fn test_diff_json() {
+  let mut a = crate::json::Object::new();
+  a.insert("Entries".into(), Json::List(alloc::vec![entry(1.0, "2"), entry(2.0, "2")]));
+  a.insert("LeaderName".into(), Json::String("n1".into()));
+  let mut b = crate::json::Object::new();
+  b.insert("Entries".into(), Json::List(alloc::vec![entry(2.0, "3"), entry(1.0000001, "2"), entry(3.0, "3")]));
+  b.insert("CommitIndex".into(), Json::String("4".into()));
+  let (a, b) = (Json::Object(a), Json::Object(b));
+
+  let mut options = DiffOptions::default();
+  let lines: Vec<String> = DiffJson(&a, &b, &options).iter().map(|difference| difference.to_string()).collect();
+  assert_eq!(lines, [
+    "+ CommitIndex: \"4\"",
+    "~ Entries[0].Index: 1 -> 2",
+    "~ Entries[0].Term: \"2\" -> \"3\"",
+    "~ Entries[1].Index: 2 -> 1.0000001",
+    "+ Entries[2]: {\"Index\":3,\"Term\":\"3\"}",
+    "- LeaderName: \"n1\""
+  ]);
+
+  options.repeated_as_sets = true;
+  options.float_tolerance = 0.001;
+  options.ignored = alloc::vec!["Entries.Term".into(), "LeaderName".into()];
+  let paths: Vec<String> = DiffTypedJson(&a, &b, &|field| field == "Entries.Index", &options)
+    .into_iter().map(|difference| difference.path).collect();
+  assert_eq!(paths, ["CommitIndex", "Entries[2]"]);
+  // Not knowing Index is a float field, the tolerance doesn't apply to it.
+  let paths: Vec<String> = DiffJson(&a, &b, &options).into_iter().map(|difference| difference.path).collect();
+  assert_eq!(paths, ["CommitIndex", "Entries[0]", "Entries[1]", "Entries[2]"]);
+
+  // Removing the last entry empties the list, which is then left out.
+  let mut c = crate::json::Object::new();
+  c.insert("Entries".into(), Json::List(alloc::vec![entry(1.0, "2")]));
+  let differences = DiffJson(&Json::Object(c), &Json::Object(crate::json::Object::new()), &DiffOptions::default());
+  assert_eq!(differences, [Difference { path: "Entries[0]".into(), change: Change::Removed(entry(1.0, "2")) }]);
+
+  let nan = Json::List(alloc::vec![entry(f64::NAN, "2")]);
+  assert_eq!(DiffJson(&nan, &nan, &DiffOptions::default()), []);
+  let lines: Vec<String> = DiffJson(&nan, &Json::List(alloc::vec![entry(1.0, "2")]), &DiffOptions::default())
+    .iter().map(|difference| difference.to_string()).collect();
+  assert_eq!(lines, ["~ [0].Index: null -> 1"]);
+}
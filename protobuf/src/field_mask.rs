@@ -0,0 +1,232 @@
+// Field masks as in google.protobuf.FieldMask: sets of dotted paths such as
+// "Term" or "Entries.Index", which name fields as the .proto file does.
+// Messages generated with the field_mask option copy, merge and clear
+// fields by them.
+
+use alloc::collections::BTreeMap;
+use alloc::format;
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
+use core::fmt;
+
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub enum FieldMaskError {
+  // The path names no field: a part of it isn't a field of the message
+  // before it, or follows a field that isn't a message.
+  InvalidPath(String),
+  // Copying and merging take repeated fields whole, not elements'
+  // subfields.
+  RepeatedSubpath(String)
+}
+
+impl fmt::Display for FieldMaskError {
+  fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+    match *self {
+      FieldMaskError::InvalidPath(ref path) => write!(f, "invalid field mask path \"{}\"", path),
+      FieldMaskError::RepeatedSubpath(ref path) => write!(f, "field mask path \"{}\" goes into a repeated field", path)
+    }
+  }
+}
+
+impl core::error::Error for FieldMaskError {
+}
+
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct FieldMask {
+  // The masked fields by name, each with the mask of its subfields, or
+  // None when every field is masked.
+  fields: Option<BTreeMap<String, FieldMask>>
+}
+
+static kAll: FieldMask = FieldMask { fields: None };
+
+impl FieldMask {
+  // Masks no field.
+  pub fn new() -> FieldMask {
+    FieldMask { fields: Some(BTreeMap::new()) }
+  }
+
+  pub fn from_paths<M: FieldMasked, S: AsRef<str>>(paths: &[S]) -> Result<FieldMask, FieldMaskError> {
+    let mut mask = FieldMask::new();
+    for path in paths.iter() {
+      mask.add::<M>(path.as_ref())?;
+    }
+    Ok(mask)
+  }
+
+  // Paths separated by commas, as a FieldMask is written in JSON.
+  pub fn parse<M: FieldMasked>(paths: &str) -> Result<FieldMask, FieldMaskError> {
+    if paths.is_empty() {
+      return Ok(FieldMask::new());
+    }
+    let paths: Vec<&str> = paths.split(',').collect();
+    FieldMask::from_paths::<M, &str>(&paths)
+  }
+
+  // Masks the field at `path` of M whole. Its subpaths are dropped, and a
+  // path inside a field already masked whole changes nothing.
+  pub fn add<M: FieldMasked>(&mut self, path: &str) -> Result<(), FieldMaskError> {
+    let parts: Vec<&str> = path.split('.').collect();
+    if !M::IsMaskPath(&parts, true) {
+      return Err(FieldMaskError::InvalidPath(path.to_string()));
+    }
+    let mut mask = self;
+    for part in parts {
+      mask = match mask.fields {
+        Some(ref mut fields) => fields.entry(part.to_string()).or_insert_with(FieldMask::new),
+        None => return Ok(())
+      };
+    }
+    mask.fields = None;
+    Ok(())
+  }
+
+  // The masked paths, sorted, without any that another one covers.
+  pub fn paths(&self) -> Vec<String> {
+    let mut paths = Vec::new();
+    self.append_paths("", &mut paths);
+    paths
+  }
+
+  fn append_paths(&self, prefix: &str, paths: &mut Vec<String>) {
+    for (name, mask) in self.fields.iter().flatten() {
+      let path = format!("{}{}", prefix, name);
+      if mask.is_all() {
+        paths.push(path);
+      } else {
+        mask.append_paths(&format!("{}.", path), paths);
+      }
+    }
+  }
+
+  // The mask of the subfields of field `name` if it is masked, with every
+  // subfield masked if it is masked whole.
+  pub fn field(&self, name: &str) -> Option<&FieldMask> {
+    match self.fields {
+      Some(ref fields) => fields.get(name),
+      None => Some(&kAll)
+    }
+  }
+
+  pub fn is_all(&self) -> bool {
+    self.fields.is_none()
+  }
+
+  pub fn is_empty(&self) -> bool {
+    self.fields.as_ref().is_some_and(|fields| fields.is_empty())
+  }
+
+  // Checks the paths against the fields of M, as a mask built for another
+  // message may not fit it.
+  fn check<M: FieldMasked>(&self, through_repeated: bool) -> Result<(), FieldMaskError> {
+    for path in self.paths() {
+      let parts: Vec<&str> = path.split('.').collect();
+      if M::IsMaskPath(&parts, through_repeated) {
+        continue;
+      }
+      if !through_repeated && M::IsMaskPath(&parts, true) {
+        return Err(FieldMaskError::RepeatedSubpath(path));
+      }
+      return Err(FieldMaskError::InvalidPath(path));
+    }
+    Ok(())
+  }
+}
+
+impl Default for FieldMask {
+  fn default() -> FieldMask {
+    FieldMask::new()
+  }
+}
+
+impl fmt::Display for FieldMask {
+  fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+    f.write_str(&self.paths().join(","))
+  }
+}
+
+// Implemented by messages generated with the field_mask option.
+pub trait FieldMasked: Sized {
+  // Whether the names in `path` lead from this message to a field, each
+  // but the last naming a message field, and a repeated one only if
+  // `through_repeated`.
+  fn IsMaskPath(path: &[&str], through_repeated: bool) -> bool;
+
+  // Sets the fields in `mask` to their values in `from`. With `merge`, they
+  // are merged as decoding `from` would: fields set in `from` replace
+  // scalars, are appended to repeated fields and merge into messages.
+  fn CopyMasked(&mut self, from: &Self, mask: &FieldMask, merge: bool);
+
+  // Clears the fields outside `mask`, unknown fields included.
+  fn ClearUnmasked(&mut self, mask: &FieldMask);
+
+  fn copy_masked(&mut self, from: &Self, mask: &FieldMask) -> Result<(), FieldMaskError> {
+    mask.check::<Self>(false)?;
+    self.CopyMasked(from, mask, false);
+    Ok(())
+  }
+
+  fn merge_masked(&mut self, from: &Self, mask: &FieldMask) -> Result<(), FieldMaskError> {
+    mask.check::<Self>(false)?;
+    self.CopyMasked(from, mask, true);
+    Ok(())
+  }
+
+  // The subpaths of a repeated message field apply to every element.
+  fn clear_unmasked(&mut self, mask: &FieldMask) -> Result<(), FieldMaskError> {
+    mask.check::<Self>(true)?;
+    self.ClearUnmasked(mask);
+    Ok(())
+  }
+
+  // A copy with only the fields in `mask`, e.g. to send.
+  fn trimmed(&self, mask: &FieldMask) -> Result<Self, FieldMaskError> where Self: Clone {
+    let mut trimmed = self.clone();
+    trimmed.clear_unmasked(mask)?;
+    Ok(trimmed)
+  }
+}
+
+// A message with fields "a" and "b", and "c" repeating it.
+#[cfg(test)]
+struct Node;
+
+#[cfg(test)]
+impl FieldMasked for Node {
+  fn IsMaskPath(path: &[&str], through_repeated: bool) -> bool {
+    match path {
+      ["a"] | ["b"] => true,
+      ["c", rest @ ..] => rest.is_empty() || through_repeated && Node::IsMaskPath(rest, through_repeated),
+      _ => false
+    }
+  }
+
+  fn CopyMasked(&mut self, _from: &Node, _mask: &FieldMask, _merge: bool) {
+  }
+
+  fn ClearUnmasked(&mut self, _mask: &FieldMask) {
+  }
+}
+
+#[test]
+fn test_field_mask_paths() {
+  let mask = FieldMask::parse::<Node>("c.b,a,c.c.a,c.b").unwrap();
+  assert_eq!(mask.paths(), ["a", "c.b", "c.c.a"]);
+  assert_eq!(mask.to_string(), "a,c.b,c.c.a");
+  assert!(mask.field("a").unwrap().is_all());
+  assert!(mask.field("b").is_none());
+  assert_eq!(mask.field("c").unwrap().paths(), ["b", "c.a"]);
+
+  // A field masked whole covers its subpaths, before and after.
+  let mut mask = FieldMask::from_paths::<Node, &str>(&["c.a", "c", "c.b"]).unwrap();
+  assert_eq!(mask.paths(), ["c"]);
+  assert!(mask.add::<Node>("c.d").is_err());
+  assert!(FieldMask::parse::<Node>("").unwrap().is_empty());
+  assert_eq!(FieldMask::parse::<Node>("a,"), Err(FieldMaskError::InvalidPath(String::new())));
+  assert_eq!(FieldMask::parse::<Node>("a.b"), Err(FieldMaskError::InvalidPath("a.b".to_string())));
+
+  let mut node = Node;
+  let mask = FieldMask::parse::<Node>("a,c.b").unwrap();
+  assert_eq!(node.copy_masked(&Node, &mask), Err(FieldMaskError::RepeatedSubpath("c.b".to_string())));
+  assert!(node.clear_unmasked(&mask).is_ok());
+}
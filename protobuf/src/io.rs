@@ -0,0 +1,217 @@
+// Where messages are decoded from and encoded to. Byte slices and Vec<u8>
+// need only core and alloc; with the `std` feature, IoReader and IoWriter
+// adapt any std::io::Read or Write.
+
+use alloc::vec::Vec;
+use core::fmt;
+use crate::bytes::Bytes;
+
+#[derive(Debug)]
+pub enum EncodeError {
+  // A slice being encoded into has no room left.
+  BufferFull,
+  #[cfg(feature = "std")]
+  Io(std::io::Error)
+}
+
+impl fmt::Display for EncodeError {
+  fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+    match *self {
+      EncodeError::BufferFull => write!(f, "output buffer full"),
+      #[cfg(feature = "std")]
+      EncodeError::Io(ref error) => write!(f, "{}", error)
+    }
+  }
+}
+
+impl core::error::Error for EncodeError {
+  fn source(&self) -> Option<&(dyn core::error::Error + 'static)> {
+    match *self {
+      #[cfg(feature = "std")]
+      EncodeError::Io(ref error) => Some(error),
+      _ => None
+    }
+  }
+}
+
+pub trait Reader {
+  // Fills `buf`, or returns false if the input ends first.
+  fn read_bytes(&mut self, buf: &mut [u8]) -> bool;
+
+  // The next `length` bytes, or None if the input ends first. They are read
+  // a chunk at a time so that a corrupt length can't allocate more than the
+  // input holds.
+  fn read_vec(&mut self, length: u64) -> Option<Vec<u8>> {
+    let mut bytes = Vec::new();
+    let mut chunk = [0u8; 4096];
+    let mut remaining = length;
+    while remaining > 0 {
+      let size = remaining.min(chunk.len() as u64) as usize;
+      if !self.read_bytes(&mut chunk[..size]) {
+        return None;
+      }
+      bytes.extend_from_slice(&chunk[..size]);
+      remaining -= size as u64;
+    }
+    Some(bytes)
+  }
+  // The next `length` bytes as a Bytes, which a Bytes reader slices out of
+  // itself rather than copying. Other readers copy them into a buffer the
+  // Bytes owns, as read_vec() would.
+  fn read_shared(&mut self, length: u64) -> Option<Bytes> {
+    self.read_vec(length).map(Bytes::from_owned)
+  }
+
+  // All that is left to read, for readers holding it in memory. Varints
+  // and tags are decoded straight out of it.
+  fn remaining(&self) -> Option<&[u8]> {
+    None
+  }
+
+  // Skips `count` bytes, no more than remaining() holds.
+  fn advance(&mut self, count: usize) {
+    let mut byte = [0u8];
+    for _ in 0..count {
+      self.read_bytes(&mut byte);
+    }
+  }
+}
+
+// Reading advances the slice past what was read.
+impl Reader for &[u8] {
+  fn read_bytes(&mut self, buf: &mut [u8]) -> bool {
+    if self.len() < buf.len() {
+      return false;
+    }
+    let (head, tail) = self.split_at(buf.len());
+    buf.copy_from_slice(head);
+    *self = tail;
+    true
+  }
+
+  fn read_vec(&mut self, length: u64) -> Option<Vec<u8>> {
+    if (self.len() as u64) < length {
+      return None;
+    }
+    let (head, tail) = self.split_at(length as usize);
+    *self = tail;
+    Some(head.to_vec())
+  }
+
+  fn remaining(&self) -> Option<&[u8]> {
+    Some(self)
+  }
+
+  fn advance(&mut self, count: usize) {
+    *self = &self[count..];
+  }
+}
+
+pub trait Writer {
+  fn write_bytes(&mut self, data: &[u8]) -> Result<(), EncodeError>;
+}
+
+impl Writer for Vec<u8> {
+  fn write_bytes(&mut self, data: &[u8]) -> Result<(), EncodeError> {
+    self.extend_from_slice(data);
+    Ok(())
+  }
+}
+
+// Writing fills the slice from the front and leaves it holding the space
+// still free.
+impl Writer for &mut [u8] {
+  fn write_bytes(&mut self, data: &[u8]) -> Result<(), EncodeError> {
+    if self.len() < data.len() {
+      return Err(EncodeError::BufferFull);
+    }
+    let (head, tail) = core::mem::take(self).split_at_mut(data.len());
+    head.copy_from_slice(data);
+    *self = tail;
+    Ok(())
+  }
+}
+
+#[cfg(feature = "std")]
+pub struct IoReader<R: std::io::Read> {
+  inner: R
+}
+
+#[cfg(feature = "std")]
+impl<R: std::io::Read> IoReader<R> {
+  pub fn new(inner: R) -> IoReader<R> {
+    IoReader { inner }
+  }
+
+  pub fn into_inner(self) -> R {
+    self.inner
+  }
+}
+
+#[cfg(feature = "std")]
+impl<R: std::io::Read> Reader for IoReader<R> {
+  fn read_bytes(&mut self, buf: &mut [u8]) -> bool {
+    self.inner.read_exact(buf).is_ok()
+  }
+
+  fn read_vec(&mut self, length: u64) -> Option<Vec<u8>> {
+    use std::io::Read;
+    let mut bytes = Vec::new();
+    match (&mut self.inner).take(length).read_to_end(&mut bytes) {
+      Ok(read) if read as u64 == length => Some(bytes),
+      _ => None
+    }
+  }
+}
+
+#[cfg(feature = "std")]
+pub struct IoWriter<W: std::io::Write> {
+  inner: W
+}
+
+#[cfg(feature = "std")]
+impl<W: std::io::Write> IoWriter<W> {
+  pub fn new(inner: W) -> IoWriter<W> {
+    IoWriter { inner }
+  }
+
+  pub fn into_inner(self) -> W {
+    self.inner
+  }
+}
+
+#[cfg(feature = "std")]
+impl<W: std::io::Write> Writer for IoWriter<W> {
+  fn write_bytes(&mut self, data: &[u8]) -> Result<(), EncodeError> {
+    self.inner.write_all(data).map_err(EncodeError::Io)
+  }
+}
+
+#[test]
+fn test_slice_io() {
+  let mut reader: &[u8] = &[1, 2, 3, 4, 5];
+  let mut pair = [0u8; 2];
+  assert!(reader.read_bytes(&mut pair));
+  assert_eq!(pair, [1, 2]);
+  assert_eq!(reader.read_vec(2), Some(alloc::vec![3, 4]));
+  assert_eq!(reader.read_vec(2), None);
+
+  let mut buf = [0u8; 3];
+  let mut writer: &mut [u8] = &mut buf;
+  assert!(writer.write_bytes(&[7, 8]).is_ok());
+  assert!(matches!(writer.write_bytes(&[9, 10]), Err(EncodeError::BufferFull)));
+  assert_eq!(writer.len(), 1);
+  assert_eq!(buf, [7, 8, 0]);
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn test_io_adapters() {
+  let mut reader = IoReader::new(std::io::Cursor::new(alloc::vec![1u8, 2, 3]));
+  assert_eq!(reader.read_vec(2), Some(alloc::vec![1, 2]));
+  assert_eq!(reader.read_vec(2), None);
+
+  let mut writer = IoWriter::new(Vec::new());
+  assert!(writer.write_bytes(&[4, 5]).is_ok());
+  assert_eq!(writer.into_inner(), [4, 5]);
+}
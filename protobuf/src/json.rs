@@ -0,0 +1,133 @@
+// The JSON values generated ToJson impls build, printed compactly by
+// Display.
+
+use alloc::collections::BTreeMap;
+use alloc::string::String;
+use alloc::vec::Vec;
+use core::fmt;
+
+#[derive(Debug, PartialEq, Clone)]
+pub enum Json {
+  Null,
+  Boolean(bool),
+  Number(f64),
+  String(String),
+  List(Vec<Json>),
+  Object(Object)
+}
+
+// The members of a JSON object, sorted by name.
+pub type Object = BTreeMap<String, Json>;
+
+pub trait ToJson {
+  fn to_json(&self) -> Json;
+}
+
+impl ToJson for bool {
+  fn to_json(&self) -> Json {
+    Json::Boolean(*self)
+  }
+}
+
+impl ToJson for i32 {
+  fn to_json(&self) -> Json {
+    Json::Number(*self as f64)
+  }
+}
+
+impl ToJson for u32 {
+  fn to_json(&self) -> Json {
+    Json::Number(*self as f64)
+  }
+}
+
+impl ToJson for f32 {
+  fn to_json(&self) -> Json {
+    Json::Number(*self as f64)
+  }
+}
+
+impl ToJson for f64 {
+  fn to_json(&self) -> Json {
+    Json::Number(*self)
+  }
+}
+
+fn write_string(f: &mut fmt::Formatter, value: &str) -> fmt::Result {
+  f.write_str("\"")?;
+  for c in value.chars() {
+    match c {
+      '"' => f.write_str("\\\"")?,
+      '\\' => f.write_str("\\\\")?,
+      '\n' => f.write_str("\\n")?,
+      '\r' => f.write_str("\\r")?,
+      '\t' => f.write_str("\\t")?,
+      c if (c as u32) < 0x20 => write!(f, "\\u{:04x}", c as u32)?,
+      c => write!(f, "{}", c)?
+    }
+  }
+  f.write_str("\"")
+}
+
+impl fmt::Display for Json {
+  fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+    match *self {
+      Json::Null => f.write_str("null"),
+      Json::Boolean(value) => write!(f, "{}", value),
+      // JSON has no NaN or infinities.
+      Json::Number(value) if !value.is_finite() => f.write_str("null"),
+      Json::Number(value) => write!(f, "{}", value),
+      Json::String(ref value) => write_string(f, value),
+      Json::List(ref values) => {
+        f.write_str("[")?;
+        for (i, value) in values.iter().enumerate() {
+          if i > 0 {
+            f.write_str(",")?;
+          }
+          write!(f, "{}", value)?;
+        }
+        f.write_str("]")
+      }
+      Json::Object(ref object) => {
+        f.write_str("{")?;
+        for (i, (key, value)) in object.iter().enumerate() {
+          if i > 0 {
+            f.write_str(",")?;
+          }
+          write_string(f, key)?;
+          write!(f, ":{}", value)?;
+        }
+        f.write_str("}")
+      }
+    }
+  }
+}
+
+static kBase64Chars: &[u8] = b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789+/";
+
+// Bytes fields are base64 encoded with padding, as in proto3's JSON mapping.
+pub fn to_base64(bytes: &[u8]) -> String {
+  let mut encoded = String::new();
+  for chunk in bytes.chunks(3) {
+    let b0 = chunk[0] as usize;
+    let b1 = if chunk.len() > 1 { chunk[1] as usize } else { 0 };
+    let b2 = if chunk.len() > 2 { chunk[2] as usize } else { 0 };
+    encoded.push(kBase64Chars[b0 >> 2] as char);
+    encoded.push(kBase64Chars[((b0 & 0x3) << 4) | (b1 >> 4)] as char);
+    encoded.push(if chunk.len() > 1 { kBase64Chars[((b1 & 0xf) << 2) | (b2 >> 6)] as char } else { '=' });
+    encoded.push(if chunk.len() > 2 { kBase64Chars[b2 & 0x3f] as char } else { '=' });
+  }
+  encoded
+}
+
+#[test]
+fn test_json_display() {
+  let mut object = BTreeMap::new();
+  object.insert("name".to_string(), Json::String("a \"b\"\n".to_string()));
+  object.insert("values".to_string(), Json::List(vec![1u32.to_json(), 2.5f64.to_json(), Json::Null]));
+  object.insert("ok".to_string(), true.to_json());
+  assert_eq!(Json::Object(object).to_string(), "{\"name\":\"a \\\"b\\\"\\n\",\"ok\":true,\"values\":[1,2.5,null]}");
+  assert_eq!(to_base64(b"hello"), "aGVsbG8=");
+  assert_eq!(to_base64(b"hi!"), "aGkh");
+  assert_eq!(to_base64(b"h"), "aA==");
+}
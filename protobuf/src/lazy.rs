@@ -0,0 +1,225 @@
+// Storage for embedded message fields marked [lazy = true]. Decoding keeps
+// the encoded occurrences of the field, which are parsed the first time the
+// field is read. Until it is changed, encoding writes those occurrences back
+// as they were.
+//
+// As descriptor.proto allows, required fields inside a lazy field are never
+// checked, parsed or not. Malformed occurrences parse as far as they go,
+// since the decode that kept them has already succeeded.
+
+use alloc::boxed::Box;
+use alloc::vec::Vec;
+use core::fmt;
+use core::marker::PhantomData;
+use core::ptr;
+use core::sync::atomic::{AtomicPtr, Ordering};
+use crate::bytes::Bytes;
+use crate::Protobuf;
+
+// A value set at most once through a shared reference. Threads racing to
+// set it each build one, and all but the first drop theirs.
+struct OnceBox<T> {
+  ptr: AtomicPtr<T>,
+  _owns: PhantomData<*mut T>
+}
+
+// As for Box<T>, plus Send for Sync: a value built on any thread sharing
+// the box is dropped by its owner.
+unsafe impl<T: Send> Send for OnceBox<T> {}
+unsafe impl<T: Send + Sync> Sync for OnceBox<T> {}
+
+impl<T> OnceBox<T> {
+  const fn new() -> OnceBox<T> {
+    OnceBox { ptr: AtomicPtr::new(ptr::null_mut()), _owns: PhantomData }
+  }
+
+  fn get(&self) -> Option<&T> {
+    // The pointer only ever goes from null to a value that lives as long
+    // as the box, or is reset through &mut self.
+    unsafe { self.ptr.load(Ordering::Acquire).as_ref() }
+  }
+
+  fn get_or_init(&self, init: impl FnOnce() -> T) -> &T {
+    if let Some(value) = self.get() {
+      return value;
+    }
+    let value = Box::into_raw(Box::new(init()));
+    match self.ptr.compare_exchange(ptr::null_mut(), value, Ordering::AcqRel, Ordering::Acquire) {
+      Ok(_) => unsafe { &*value },
+      Err(first) => {
+        drop(unsafe { Box::from_raw(value) });
+        unsafe { &*first }
+      }
+    }
+  }
+
+  fn get_mut(&mut self) -> Option<&mut T> {
+    unsafe { self.ptr.get_mut().as_mut() }
+  }
+
+  fn take(&mut self) -> Option<T> {
+    let value = core::mem::replace(self.ptr.get_mut(), ptr::null_mut());
+    if value.is_null() {
+      None
+    } else {
+      Some(*unsafe { Box::from_raw(value) })
+    }
+  }
+}
+
+impl<T> Drop for OnceBox<T> {
+  fn drop(&mut self) {
+    self.take();
+  }
+}
+
+pub struct Lazy<T> {
+  // The encoded occurrences while they still are the field, or None once
+  // it has been changed since decoding.
+  raw: Option<Vec<Bytes>>,
+  parsed: OnceBox<T>,
+  parse: fn(&[Bytes]) -> T
+}
+
+impl<T> Lazy<T> {
+  pub const fn new(parse: fn(&[Bytes]) -> T) -> Lazy<T> {
+    Lazy { raw: Some(Vec::new()), parsed: OnceBox::new(), parse }
+  }
+
+  // The occurrences decoded, if the field is unchanged since.
+  pub fn raw(&self) -> Option<&[Bytes]> {
+    self.raw.as_deref()
+  }
+
+  // Keeps another decoded occurrence, or hands it back to be merged into
+  // the parsed field if that has been changed.
+  pub fn push_raw(&mut self, occurrence: Bytes) -> Result<(), Bytes> {
+    match self.raw {
+      Some(ref mut raw) => {
+        raw.push(occurrence);
+        self.parsed.take();
+        Ok(())
+      }
+      None => Err(occurrence)
+    }
+  }
+
+  pub fn get(&self) -> &T {
+    match self.raw {
+      Some(ref raw) => self.parsed.get_or_init(|| (self.parse)(raw)),
+      None => self.parsed.get().unwrap()
+    }
+  }
+
+  // Parses the field if need be; it is then encoded from what it was
+  // parsed into.
+  pub fn get_mut(&mut self) -> &mut T {
+    if let Some(raw) = self.raw.take() {
+      self.parsed.get_or_init(|| (self.parse)(&raw));
+    }
+    self.parsed.get_mut().unwrap()
+  }
+
+  pub fn set(&mut self, value: T) {
+    self.parsed.take();
+    self.parsed.get_or_init(|| value);
+    self.raw = None;
+  }
+
+  pub fn take(&mut self) -> T {
+    let empty = (self.parse)(&[]);
+    let value = core::mem::replace(self.get_mut(), empty);
+    self.clear();
+    value
+  }
+
+  pub fn clear(&mut self) {
+    self.parsed.take();
+    self.raw = Some(Vec::new());
+  }
+}
+
+impl<M> Lazy<Option<M>> {
+  // Whether the singular field is present, without parsing it.
+  pub fn is_set(&self) -> bool {
+    match self.raw {
+      Some(ref raw) => !raw.is_empty(),
+      None => self.get().is_some()
+    }
+  }
+}
+
+// Parses a singular field, merging its occurrences as eager decoding does.
+pub fn ParseLazyMessage<M: Protobuf>(raw: &[Bytes], new: fn() -> M) -> Option<M> {
+  if raw.is_empty() {
+    return None;
+  }
+  let mut message = new();
+  for occurrence in raw.iter() {
+    message.DecodePartial(&mut occurrence.clone());
+  }
+  Some(message)
+}
+
+// Parses a repeated field, one element per occurrence.
+pub fn ParseLazyMessages<M: Protobuf>(raw: &[Bytes], new: fn() -> M) -> Vec<M> {
+  raw.iter().map(|occurrence| {
+    let mut message = new();
+    message.DecodePartial(&mut occurrence.clone());
+    message
+  }).collect()
+}
+
+impl<T: Clone> Clone for Lazy<T> {
+  fn clone(&self) -> Lazy<T> {
+    let parsed = OnceBox::new();
+    if let Some(value) = self.parsed.get() {
+      parsed.get_or_init(|| value.clone());
+    }
+    Lazy { raw: self.raw.clone(), parsed, parse: self.parse }
+  }
+}
+
+impl<T: PartialEq> PartialEq for Lazy<T> {
+  fn eq(&self, other: &Lazy<T>) -> bool {
+    self.get() == other.get()
+  }
+}
+
+impl<T: Eq> Eq for Lazy<T> {}
+
+impl<T: core::hash::Hash> core::hash::Hash for Lazy<T> {
+  fn hash<H: core::hash::Hasher>(&self, state: &mut H) {
+    self.get().hash(state)
+  }
+}
+
+impl<T: fmt::Debug> fmt::Debug for Lazy<T> {
+  fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+    self.get().fmt(f)
+  }
+}
+
+#[cfg(test)]
+fn parse_total(raw: &[Bytes]) -> u32 {
+  raw.iter().map(|occurrence| occurrence.iter().map(|&byte| byte as u32).sum::<u32>()).sum()
+}
+
+#[test]
+fn test_lazy() {
+  let mut lazy: Lazy<u32> = Lazy::new(parse_total);
+  assert!(lazy.push_raw(Bytes::from(alloc::vec![1, 2])).is_ok());
+  assert_eq!(*lazy.get(), 3);
+  // A later occurrence is parsed along with the earlier ones.
+  assert!(lazy.push_raw(Bytes::from(alloc::vec![4])).is_ok());
+  assert_eq!(*lazy.get(), 7);
+  assert_eq!(lazy.raw().map(|raw| raw.len()), Some(2));
+
+  *lazy.get_mut() += 1;
+  assert!(lazy.raw().is_none());
+  assert_eq!(lazy.push_raw(Bytes::from(alloc::vec![9])), Err(Bytes::from(alloc::vec![9])));
+  assert_eq!(lazy.clone(), lazy);
+  assert_eq!(lazy.take(), 8);
+  assert_eq!(*lazy.get(), 0);
+  assert_eq!(lazy.raw(), Some(&[][..]));
+}
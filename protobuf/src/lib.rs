@@ -0,0 +1,711 @@
+// protobuf support for rust
+
+// The runtime keeps the names generated code has always called it by.
+#![allow(non_snake_case, non_upper_case_globals, clippy::enum_variant_names)]
+#![cfg_attr(not(any(feature = "std", test)), no_std)]
+
+extern crate alloc;
+
+use alloc::string::String;
+use alloc::vec::Vec;
+use core::fmt;
+
+pub mod arena;
+pub mod bytes;
+pub mod diff;
+pub mod field_mask;
+pub mod io;
+pub mod json;
+pub mod lazy;
+
+pub use arena::{Arena, ArenaBox, ArenaMessage, ArenaTagIter, ArenaValue, RepeatedField, DecodeIn, EncodeArenaValue};
+pub use arena::{UnpackVarints, UnpackFixed32, UnpackFixed64};
+pub use bytes::{Bytes, BytesStr};
+pub use diff::{Change, Difference, DiffOptions, DiffMessages, DiffJson, DiffTypedJson};
+pub use field_mask::{FieldMask, FieldMaskError, FieldMasked};
+pub use io::{Reader, Writer, EncodeError};
+#[cfg(feature = "std")]
+pub use io::{IoReader, IoWriter};
+pub use json::{Json, ToJson};
+pub use lazy::{Lazy, ParseLazyMessage, ParseLazyMessages};
+pub use DecodeError::{MalformedInput, MissingRequiredFields};
+pub use TaggedValue::{Varint, Fixed64, Raw, StartGroup, EndGroup, Fixed32};
+
+// The alloc names generated code uses, which the prelude of a no_std crate
+// lacks. Generated modules glob-import it, so their own types and enum
+// values still win over these names.
+pub mod prelude {
+  pub use alloc::{boxed::Box, format, string::{String, ToString}, vec::Vec};
+}
+
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub enum DecodeError {
+  MalformedInput,
+  // Dotted paths of the required fields left unset, e.g. "Entries[0].Term".
+  MissingRequiredFields(Vec<String>)
+}
+
+impl fmt::Display for DecodeError {
+  fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+    match *self {
+      MalformedInput => write!(f, "malformed input"),
+      MissingRequiredFields(ref missing) => write!(f, "missing required fields: {}", missing.join(", "))
+    }
+  }
+}
+
+impl core::error::Error for DecodeError {
+}
+
+pub trait Protobuf {
+  // Merges the fields in `reader` into self without checking that required
+  // fields ended up set.
+  fn DecodePartial(&mut self, reader: &mut dyn Reader) -> bool;
+
+  // Appends the paths of unset required fields, nested messages included,
+  // to `missing`, each starting with `prefix`.
+  fn AppendMissingFields(&self, _prefix: &str, _missing: &mut Vec<String>) {
+  }
+
+  fn missing_fields(&self) -> Vec<String> {
+    let mut missing = Vec::new();
+    self.AppendMissingFields("", &mut missing);
+    missing
+  }
+
+  fn is_initialized(&self) -> bool {
+    self.missing_fields().is_empty()
+  }
+
+  fn Decode(&mut self, reader: &mut dyn Reader) -> Result<(), DecodeError> {
+    if !self.DecodePartial(reader) {
+      return Err(MalformedInput);
+    }
+    let missing = self.missing_fields();
+    if !missing.is_empty() {
+      return Err(MissingRequiredFields(missing));
+    }
+    Ok(())
+  }
+}
+
+pub trait ProtobufEncode {
+  fn Encode(&self, writer: &mut dyn Writer) -> Result<(), EncodeError>;
+}
+
+// Schema information generated alongside messages by the `reflection`
+// generator option.
+pub struct FieldInfo {
+  pub name: &'static str,
+  pub number: u32,
+  // The .proto type: "uint64", "string", ... or the fully qualified name of
+  // a message or enum type.
+  pub type_name: &'static str,
+  pub repeated: bool,
+  pub required: bool,
+  // The MessageInfo of a message field's type, when it has reflection.
+  pub message: Option<fn() -> &'static MessageInfo>
+}
+
+pub struct MessageInfo {
+  pub full_name: &'static str,
+  pub fields: &'static [FieldInfo]
+}
+
+impl MessageInfo {
+  pub fn field_by_name(&self, name: &str) -> Option<&'static FieldInfo> {
+    self.fields.iter().find(|field| field.name == name)
+  }
+
+  pub fn field_by_number(&self, number: u32) -> Option<&'static FieldInfo> {
+    self.fields.iter().find(|field| field.number == number)
+  }
+
+  // The field at a dotted path of field names, e.g. "Entries.Term", going
+  // through message fields.
+  pub fn field_by_path(&self, path: &str) -> Option<&'static FieldInfo> {
+    match path.split_once('.') {
+      Some((name, rest)) => (self.field_by_name(name)?.message?)().field_by_path(rest),
+      None => self.field_by_name(path)
+    }
+  }
+}
+
+pub trait Reflect {
+  fn message_info(&self) -> &'static MessageInfo;
+}
+
+pub struct TagIter<'a> {
+  reader: &'a mut dyn Reader,
+  malformed: bool
+}
+
+impl<'a> TagIter<'a> {
+  pub fn new(reader: &'a mut dyn Reader) -> TagIter<'a> {
+    TagIter { reader, malformed: false }
+  }
+
+  // Whether iterating stopped at something other than a whole field, such
+  // as a truncated value, a group or an overlong varint, rather than at the
+  // end of the input. Decoding has failed then.
+  pub fn is_malformed(&self) -> bool {
+    self.malformed
+  }
+}
+
+static kWireMask: u64 = 0x7;
+static kMSBMask: u64 = 0x80;
+static kLS7BMask: u64  = 0x7F;
+
+#[derive(Debug, Clone, Copy)]
+enum WireType {
+  VarintWireType = 0,
+  Fixed64WireType = 1,
+  LengthDelimWireType = 2,
+  StartGroupWireType = 3,
+  EndGroupWireType = 4,
+  Fixed32WireType = 5
+}
+
+use WireType::*;
+
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub enum TaggedValue {
+  Varint(u64, u64),
+  Fixed64(u64, u64),
+  Raw(u64, Bytes),
+  StartGroup,
+  EndGroup,
+  Fixed32(u64, u32)
+}
+
+impl<'a> Iterator for TagIter<'a> {
+  type Item = TaggedValue;
+
+  fn next(&mut self) -> Option<TaggedValue> {
+    if self.malformed {
+      return None;
+    }
+    match DecodeTagged(self.reader) {
+      Ok(value) => value,
+      Err(_) => {
+        self.malformed = true;
+        None
+      }
+    }
+  }
+}
+
+
+fn IntToWireType(i: u8) -> Option<WireType> {
+  match i {
+  0 => {Some(VarintWireType)}
+  1 => {Some(Fixed64WireType)}
+  2 => {Some(LengthDelimWireType)}
+  3 => {Some(StartGroupWireType)}
+  4 => {Some(EndGroupWireType)}
+  5 => {Some(Fixed32WireType)}
+  _ => {None}
+  }
+}
+
+fn ReadByte(reader: &mut dyn Reader) -> Option<u8> {
+  let mut byte = [0u8];
+  if reader.read_bytes(&mut byte) { Some(byte[0]) } else { None }
+}
+
+fn ReadFixed32(reader: &mut dyn Reader) -> Option<u32> {
+  let mut bytes = [0u8; 4];
+  if reader.read_bytes(&mut bytes) { Some(u32::from_le_bytes(bytes)) } else { None }
+}
+
+fn ReadFixed64(reader: &mut dyn Reader) -> Option<u64> {
+  let mut bytes = [0u8; 8];
+  if reader.read_bytes(&mut bytes) { Some(u64::from_le_bytes(bytes)) } else { None }
+}
+
+// The next field, or None at the end of the input. Groups are an error:
+// they aren't generated, and skipping one means parsing it.
+fn DecodeTagged(reader: &mut dyn Reader) -> Result<Option<TaggedValue>, DecodeError> {
+  let (wire, tag) = match DecodeWire(reader)? {
+    Some(wire) => wire,
+    None => return Ok(None)
+  };
+  let value = match wire {
+    VarintWireType => DecodeVarint(reader).map(|varint| Varint(tag, varint)),
+    LengthDelimWireType => DecodeVarint(reader).and_then(|length| reader.read_shared(length)).map(|data| Raw(tag, data)),
+    Fixed64WireType => ReadFixed64(reader).map(|value| Fixed64(tag, value)),
+    Fixed32WireType => ReadFixed32(reader).map(|value| Fixed32(tag, value)),
+    StartGroupWireType | EndGroupWireType => None
+  };
+  value.map(Some).ok_or(MalformedInput)
+}
+
+#[test]
+fn test_tag_decode() {
+  let mut reader: &[u8] = &[0x08, 0x96, 0x1];
+  let tagged_val = DecodeTagged(&mut reader).unwrap().unwrap();
+  match tagged_val {
+    Varint(1, i) => {
+      assert!(i == 150);
+    }
+    _ => {
+      panic!();
+    }
+  }
+}
+
+
+// The wire type and tag of the next field, or None at the end of the
+// input. In-memory readers take the fast paths through DecodeWireSlice and
+// DecodeVarintSlice; others are read a byte at a time.
+fn DecodeWire(reader: &mut dyn Reader) -> Result<Option<(WireType, u64)>, DecodeError> {
+  if let Some(data) = reader.remaining() {
+    if data.is_empty() {
+      return Ok(None);
+    }
+    let (wire, length) = DecodeWireSlice(data).ok_or(MalformedInput)?;
+    reader.advance(length);
+    return Ok(Some(wire));
+  }
+  let read = match ReadByte(reader) {
+    Some(read) => read as u64,
+    None => return Ok(None)
+  };
+  let wire = IntToWireType((read & kWireMask) as u8).ok_or(MalformedInput)?;
+  let mut tag: u64 = (read & kLS7BMask) >> 3;
+  if (read & kMSBMask) != 0x0 {
+    tag |= DecodeVarint(reader).ok_or(MalformedInput)? << 4;
+  }
+  Ok(Some((wire, tag)))
+}
+
+fn DecodeVarint(reader: &mut dyn Reader) -> Option<u64> {
+  if let Some(data) = reader.remaining() {
+    let (value, length) = DecodeVarintSlice(data)?;
+    reader.advance(length);
+    return Some(value);
+  }
+  let mut shift = 0;
+  let mut result: u64 = 0;
+  loop {
+    let byte = ReadByte(reader)? as u64;
+    let payload = kLS7BMask & byte;
+    if shift < 64 {
+      result |= payload << shift;
+    }
+    shift += 7;
+    if (byte & kMSBMask) == 0x0 {
+      break;
+    }
+    if shift == 70 {
+      return None;
+    }
+  }
+  Some(result)
+}
+
+// The wire type and tag at the front of `data`, with the bytes they take.
+// Fields 1 to 15 take one byte and fields up to 2047 two.
+fn DecodeWireSlice(data: &[u8]) -> Option<((WireType, u64), usize)> {
+  let first = *data.first()? as u64;
+  let wire = IntToWireType((first & kWireMask) as u8)?;
+  let tag = (first & kLS7BMask) >> 3;
+  if (first & kMSBMask) == 0x0 {
+    return Some(((wire, tag), 1));
+  }
+  match data.get(1) {
+    Some(&second) if (second as u64 & kMSBMask) == 0x0 => Some(((wire, tag | (second as u64) << 4), 2)),
+    _ => {
+      let (high, length) = DecodeVarintSlice(&data[1..])?;
+      Some(((wire, tag | high << 4), length + 1))
+    }
+  }
+}
+
+// The varint at the front of `data`, with the bytes it takes, or None if it
+// is cut off or runs past the ten bytes of the longest u64. With ten bytes
+// at hand, the end of the buffer is checked once; nearer the end, at every
+// byte.
+fn DecodeVarintSlice(data: &[u8]) -> Option<(u64, usize)> {
+  match data.first() {
+    Some(&byte) if (byte as u64 & kMSBMask) == 0x0 => return Some((byte as u64, 1)),
+    _ => ()
+  }
+  if data.len() >= 10 {
+    let bytes: &[u8; 10] = data[..10].try_into().unwrap();
+    let mut result: u64 = 0;
+    for (i, &byte) in bytes.iter().enumerate() {
+      result |= (kLS7BMask & byte as u64) << (7 * i);
+      if (byte as u64 & kMSBMask) == 0x0 {
+        return Some((result, i + 1));
+      }
+    }
+    return None;
+  }
+  let mut shift = 0;
+  let mut result: u64 = 0;
+  for (i, &byte) in data.iter().enumerate() {
+    result |= (kLS7BMask & byte as u64) << shift;
+    shift += 7;
+    if (byte as u64 & kMSBMask) == 0x0 {
+      return Some((result, i + 1));
+    }
+  }
+  None
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn test_slice_decoding_matches_reader() {
+  // Reads through std::io, which has no in-memory fast path. What was read
+  // of malformed input doesn't matter, as decoding stops.
+  fn through_io<T>(data: &[u8], decode: fn(&mut dyn Reader) -> Option<T>) -> Option<(T, usize)> {
+    let mut reader = IoReader::new(data);
+    let decoded = decode(&mut reader)?;
+    Some((decoded, data.len() - reader.into_inner().len()))
+  }
+
+  let varints: [&[u8]; 7] = [
+    &[0x00], &[0x96, 0x01], &[0xff, 0xff, 0xff, 0xff, 0x0f],
+    &[0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0x01, 0x08],
+    // Overlong, past ten bytes, and cut off.
+    &[0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x01],
+    &[0x80, 0x80], &[]
+  ];
+  for data in varints.iter() {
+    let mut reader = *data;
+    let decoded = DecodeVarint(&mut reader).map(|value| (value, data.len() - reader.len()));
+    assert_eq!(decoded, through_io(data, DecodeVarint), "{:?}", data);
+  }
+  assert_eq!(DecodeVarintSlice(varints[3]), Some((u64::MAX, 10)));
+
+  let tags: [&[u8]; 6] = [&[0x08], &[0x7a], &[0xfa, 0x01], &[0xfa, 0xff, 0x03], &[0x0e], &[0x88]];
+  for data in tags.iter() {
+    let mut reader = *data;
+    let decoded = DecodeWire(&mut reader).ok().flatten().map(|(wire, tag)| (wire as u8, tag, data.len() - reader.len()));
+    let expected = through_io(data, |reader| DecodeWire(reader).ok().flatten()).map(|((wire, tag), read)| (wire as u8, tag, read));
+    assert_eq!(decoded, expected, "{:?}", data);
+  }
+  assert_eq!(DecodeWireSlice(&[0xfa, 0x01]).map(|((_, tag), length)| (tag, length)), Some((31, 2)));
+}
+
+pub fn DecodeZigZag32(n: u64) -> i32 {
+  let n = n as u32;
+  ((n >> 1) as i32) ^ (-((n & 1) as i32))
+}
+
+pub fn DecodeZigZag64(n: u64) -> i64 {
+  ((n >> 1) as i64) ^ (-((n & 1) as i64))
+}
+
+pub fn DecodeFloat(bits: u32) -> f32 {
+  f32::from_bits(bits)
+}
+
+pub fn DecodeDouble(bits: u64) -> f64 {
+  f64::from_bits(bits)
+}
+
+// Packed repeated fields arrive as a single Raw value holding the
+// concatenated elements with no per-element tags.
+pub fn DecodePackedVarints(data: &[u8]) -> Option<Vec<u64>> {
+  let mut reader = data;
+  let mut values = Vec::new();
+  while !reader.is_empty() {
+    values.push(DecodeVarint(&mut reader)?);
+  }
+  Some(values)
+}
+
+pub fn DecodePackedFixed32(data: &[u8]) -> Option<Vec<u32>> {
+  let mut reader = data;
+  let mut values = Vec::new();
+  while !reader.is_empty() {
+    values.push(ReadFixed32(&mut reader)?);
+  }
+  Some(values)
+}
+
+pub fn DecodePackedFixed64(data: &[u8]) -> Option<Vec<u64>> {
+  let mut reader = data;
+  let mut values = Vec::new();
+  while !reader.is_empty() {
+    values.push(ReadFixed64(&mut reader)?);
+  }
+  Some(values)
+}
+
+pub fn EncodeVarint(writer: &mut dyn Writer, value: u64) -> Result<(), EncodeError> {
+  let mut remaining = value;
+  while remaining > kLS7BMask {
+    writer.write_bytes(&[((remaining & kLS7BMask) | kMSBMask) as u8])?;
+    remaining >>= 7;
+  }
+  writer.write_bytes(&[remaining as u8])
+}
+
+fn EncodeTag(writer: &mut dyn Writer, tag: u64, wire: WireType) -> Result<(), EncodeError> {
+  EncodeVarint(writer, (tag << 3) | (wire as u64))
+}
+
+pub fn EncodeVarintField(writer: &mut dyn Writer, tag: u64, value: u64) -> Result<(), EncodeError> {
+  EncodeTag(writer, tag, VarintWireType)?;
+  EncodeVarint(writer, value)
+}
+
+pub fn EncodeFixed32Field(writer: &mut dyn Writer, tag: u64, value: u32) -> Result<(), EncodeError> {
+  EncodeTag(writer, tag, Fixed32WireType)?;
+  writer.write_bytes(&value.to_le_bytes())
+}
+
+pub fn EncodeFixed64Field(writer: &mut dyn Writer, tag: u64, value: u64) -> Result<(), EncodeError> {
+  EncodeTag(writer, tag, Fixed64WireType)?;
+  writer.write_bytes(&value.to_le_bytes())
+}
+
+pub fn EncodeRawField(writer: &mut dyn Writer, tag: u64, data: &[u8]) -> Result<(), EncodeError> {
+  EncodeTag(writer, tag, LengthDelimWireType)?;
+  EncodeVarint(writer, data.len() as u64)?;
+  writer.write_bytes(data)
+}
+
+// Embedded messages are length-delimited, so they are encoded to a buffer
+// first to learn their size.
+pub fn EncodeMessageField<M: ProtobufEncode>(writer: &mut dyn Writer, tag: u64, message: &M) -> Result<(), EncodeError> {
+  let mut buf = Vec::new();
+  message.Encode(&mut buf)?;
+  EncodeRawField(writer, tag, &buf)
+}
+
+pub fn EncodeTaggedValue(writer: &mut dyn Writer, value: &TaggedValue) -> Result<(), EncodeError> {
+  match *value {
+    Varint(tag, varint) => EncodeVarintField(writer, tag, varint),
+    Fixed64(tag, fixed) => EncodeFixed64Field(writer, tag, fixed),
+    Raw(tag, ref data) => EncodeRawField(writer, tag, data),
+    Fixed32(tag, fixed) => EncodeFixed32Field(writer, tag, fixed),
+    StartGroup | EndGroup => Ok(())
+  }
+}
+
+pub fn EncodeZigZag32(n: i32) -> u64 {
+  ((n << 1) ^ (n >> 31)) as u32 as u64
+}
+
+pub fn EncodeZigZag64(n: i64) -> u64 {
+  ((n << 1) ^ (n >> 63)) as u64
+}
+
+pub fn EncodeFloat(value: f32) -> u32 {
+  value.to_bits()
+}
+
+pub fn EncodeDouble(value: f64) -> u64 {
+  value.to_bits()
+}
+
+#[test]
+fn test_zigzag_decode() {
+  assert_eq!(DecodeZigZag32(0), 0);
+  assert_eq!(DecodeZigZag32(1), -1);
+  assert_eq!(DecodeZigZag32(2), 1);
+  assert_eq!(DecodeZigZag32(4294967295), -2147483648);
+  assert_eq!(DecodeZigZag64(3), -2);
+  assert_eq!(DecodeZigZag64(4), 2);
+}
+
+#[test]
+fn test_packed_decode() {
+  assert_eq!(DecodePackedVarints(&[0x03, 0x8e, 0x02, 0x9e, 0xa7, 0x05]).unwrap(),
+             vec![3, 270, 86942]);
+  assert_eq!(DecodePackedFixed32(&[0x78, 0x56, 0x34, 0x12, 0x1, 0x0, 0x0, 0x0]).unwrap(),
+             vec![0x12345678, 1]);
+  assert!(DecodePackedVarints(&[0x8e]).is_none());
+}
+
+#[test]
+fn test_zigzag_round_trip() {
+  for &n in [0i32, 1, -1, 150, -2147483648, 2147483647].iter() {
+    assert_eq!(DecodeZigZag32(EncodeZigZag32(n)), n);
+  }
+  for &n in [0i64, -3, 9223372036854775807].iter() {
+    assert_eq!(DecodeZigZag64(EncodeZigZag64(n)), n);
+  }
+}
+
+#[test]
+fn test_encode_fields() {
+  let mut writer = Vec::new();
+  assert!(EncodeVarintField(&mut writer, 1, 0x12345678).is_ok());
+  assert!(EncodeFixed64Field(&mut writer, 2, 0x12345678).is_ok());
+  assert!(EncodeRawField(&mut writer, 3, "hello, world".as_bytes()).is_ok());
+  assert!(EncodeFixed32Field(&mut writer, 4, 0x12345678).is_ok());
+  assert_eq!(writer, [
+             0x8, 0xf8, 0xac, 0xd1, 0x91,
+             0x1, 0x11, 0x78, 0x56, 0x34,
+             0x12, 0x0, 0x0, 0x0, 0x0,
+             0x1a, 0xc, 0x68, 0x65, 0x6c,
+             0x6c, 0x6f, 0x2c, 0x20, 0x77,
+             0x6f, 0x72, 0x6c, 0x64, 0x25,
+             0x78, 0x56, 0x34, 0x12]);
+}
+
+#[test]
+fn test_encode_large_tag() {
+  let mut writer = Vec::new();
+  assert!(EncodeVarintField(&mut writer, 300, 150).is_ok());
+  let mut reader = writer.as_slice();
+  match DecodeTagged(&mut reader).unwrap().unwrap() {
+    Varint(300, 150) => {}
+    _ => { panic!() }
+  }
+}
+
+#[test]
+fn test_decode_truncated() {
+  let mut reader: &[u8] = &[0x1a, 0xc, 0x68, 0x65];
+  assert_eq!(DecodeTagged(&mut reader), Err(MalformedInput));
+
+  // Cut off inside a value, a group, an unknown wire type and an overlong
+  // varint, read in memory and through std::io.
+  let inputs: [&[u8]; 6] = [
+    &[0x08], &[0x21, 0x01, 0x02], &[0x0a, 0x05, 0x01], &[0x0b], &[0x0f],
+    &[0x08, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x01]
+  ];
+  for data in inputs.iter() {
+    let mut reader = *data;
+    let mut iter = TagIter::new(&mut reader);
+    assert!(iter.next().is_none() && iter.is_malformed(), "{:?}", data);
+    let mut pair = RequiredPair{first: Some(1), second: Some(2)};
+    assert_eq!(pair.Decode(&mut &data[..]), Err(MalformedInput), "{:?}", data);
+    #[cfg(feature = "std")]
+    assert_eq!(pair.Decode(&mut IoReader::new(*data)), Err(MalformedInput), "{:?}", data);
+  }
+  let mut reader: &[u8] = &[];
+  let mut iter = TagIter::new(&mut reader);
+  assert!(iter.next().is_none() && !iter.is_malformed());
+}
+
+#[cfg(test)]
+struct RequiredPair {
+  first: Option<u64>,
+  second: Option<u64>
+}
+
+#[cfg(test)]
+impl Protobuf for RequiredPair {
+  fn DecodePartial(&mut self, reader: &mut dyn Reader) -> bool {
+    let mut tags = TagIter::new(reader);
+    for tag_option in tags.by_ref() {
+      match tag_option {
+        Varint(1, value) => self.first = Some(value),
+        Varint(2, value) => self.second = Some(value),
+        _ => ()
+      }
+    }
+    !tags.is_malformed()
+  }
+
+  fn AppendMissingFields(&self, prefix: &str, missing: &mut Vec<String>) {
+    if self.first.is_none() {
+      missing.push(format!("{}first", prefix));
+    }
+    if self.second.is_none() {
+      missing.push(format!("{}second", prefix));
+    }
+  }
+}
+
+#[test]
+fn test_decode_missing_required() {
+  let mut pair = RequiredPair{first: None, second: None};
+  let mut reader: &[u8] = &[0x10, 0x2];
+  assert_eq!(pair.Decode(&mut reader), Err(MissingRequiredFields(vec!["first".to_string()])));
+  assert!(!pair.is_initialized());
+
+  let mut partial = RequiredPair{first: None, second: None};
+  let mut reader: &[u8] = &[0x10, 0x2];
+  assert!(partial.DecodePartial(&mut reader));
+  assert_eq!(partial.second, Some(2));
+
+  let mut complete = RequiredPair{first: None, second: None};
+  let mut reader: &[u8] = &[0x8, 0x1, 0x10, 0x2];
+  assert_eq!(complete.Decode(&mut reader), Ok(()));
+  assert!(complete.is_initialized());
+}
+
+#[cfg(test)]
+struct RequiredPairs {
+  pair: Option<RequiredPair>,
+  pairs: Vec<RequiredPair>
+}
+
+#[cfg(test)]
+impl Protobuf for RequiredPairs {
+  fn DecodePartial(&mut self, _reader: &mut dyn Reader) -> bool {
+    true
+  }
+
+  fn AppendMissingFields(&self, prefix: &str, missing: &mut Vec<String>) {
+    match self.pair {
+      Some(ref value) => {
+        for path in value.missing_fields() {
+          missing.push(format!("{}pair.{}", prefix, path));
+        }
+      }
+      None => missing.push(format!("{}pair", prefix))
+    }
+    for (i, value) in self.pairs.iter().enumerate() {
+      for path in value.missing_fields() {
+        missing.push(format!("{}pairs[{}].{}", prefix, i, path));
+      }
+    }
+  }
+}
+
+#[test]
+fn test_nested_missing_fields() {
+  let mut pairs = RequiredPairs{pair: None, pairs: Vec::new()};
+  assert_eq!(pairs.missing_fields(), ["pair"]);
+
+  pairs.pair = Some(RequiredPair{first: Some(1), second: None});
+  pairs.pairs.push(RequiredPair{first: Some(1), second: Some(2)});
+  pairs.pairs.push(RequiredPair{first: None, second: Some(2)});
+  assert_eq!(pairs.missing_fields(), ["pair.second", "pairs[1].first"]);
+
+  pairs.pair = Some(RequiredPair{first: Some(1), second: Some(2)});
+  pairs.pairs.pop();
+  assert!(pairs.is_initialized());
+}
+
+#[test]
+fn test_tag_iter() {
+  let mut reader: &[u8] = &[
+             0x8, 0xf8, 0xac, 0xd1, 0x91,
+             0x1, 0x11, 0x78, 0x56, 0x34,
+             0x12, 0x0, 0x0, 0x0, 0x0,
+             0x1a, 0xc, 0x68, 0x65, 0x6c,
+             0x6c, 0x6f, 0x2c, 0x20, 0x77,
+             0x6f, 0x72, 0x6c, 0x64, 0x25,
+             0x78, 0x56, 0x34, 0x12];
+  let mut iter = TagIter::new(&mut reader);
+  match iter.next().unwrap() {
+    Varint(1, 0x12345678) => {}
+    _ => { panic!() }
+  }
+  match iter.next().unwrap() {
+    Fixed64(2, 0x12345678) => {}
+    _ => { panic!() }
+  }
+  match iter.next().unwrap() {
+    Raw(3, arr) => {
+      assert_eq!(std::str::from_utf8(&arr), Ok("hello, world"));
+    }
+    _ => { panic!() }
+  }
+  match iter.next().unwrap() {
+    Fixed32(4, 0x12345678) => {}
+    _ => { panic!() }
+  }
+  assert!(iter.next().is_none());
+}
@@ -1,729 +1,3119 @@
-extern crate protobuf;
-extern crate extra;
-extern crate collections;
-
-use std::to_str::ToStr;
-use std::io::{stdin, Reader, MemReader};
-use std::str::from_utf8;
-use protobuf::{Protobuf, TagIter, Raw, Varint};
-use collections::hashmap::{HashSet, HashMap};
-use std::iter::FromIterator;
-
-#[deriving(Show)]
-struct CodeGeneratorRequest {
-  file_to_generate: ~[~str],
-  parameter: Option<~str>,
-  proto_file: ~[FileDescriptorProto]
-}
-
-#[deriving(Show)]
-struct FileDescriptorProto {
-  name: Option<~str>,
-  package: Option<~str>,
-  message_type: ~[DescriptorProto]
-}
-
-#[deriving(Show)]
-struct DescriptorProto {
-  name: Option<~str>, // 1
-  field: ~[FieldDescriptorProto], // 2
-  nested_type: ~[DescriptorProto], // 3
-  enum_type: ~[EnumDescriptorProto] // 4
-}
-
-static orig_var: &'static str = "encoded_var";
-
-fn WireTypeForField(field: &FieldDescriptorProto) -> ~str {
-  match *field.Type.get_ref() {
-    Int32Type | Int64Type | UInt32Type | UInt64Type | SInt32Type | SInt64Type | BoolType => format!("@Varint({:s}, {:s})", *field.name.get_ref(), orig_var),
-    StringType | BytesType | MessageType => format!("Raw({:s}, {:s})", *field.name.get_ref(), orig_var),
-    _ => ~"UNKNOWN"
-  }
-}
-
-#[deriving(Show,Clone,DeepClone,Eq)]
-enum FieldDescriptorProto_Type {
-    DoubleType         = 1,
-    FloatType          = 2,
-    // Not ZigZag encoded.  Negative numbers take 10 bytes.  Use SInt64Type if
-    // negative values are likely.
-    Int64Type          = 3,
-    UInt64Type         = 4,
-    // Not ZigZag encoded.  Negative numbers take 10 bytes.  Use SInt32Type if
-    // negative values are likely.
-    Int32Type          = 5,
-    Fixed64Type        = 6,
-    Fixed32Type        = 7,
-    BoolType           = 8,
-    StringType         = 9,
-    GroupType          = 10,  // Tag-delimited aggregate.
-    MessageType        = 11,  // Length-delimited aggregate.
-
-    // New in version 2.
-    BytesType          = 12,
-    UInt32Type         = 13,
-    EnumType           = 14,
-    SFixed32Type       = 15,
-    SFixed64Type       = 16,
-    SInt32Type         = 17,  // Uses ZigZag encoding.
-    SInt64Type         = 18, 
-}
-
-
-impl FieldDescriptorProto_Type {
-  fn to_proto_str(&self) -> ~str {
-    match self {
-      &Int32Type => ~"int32",
-      &Int64Type => ~"int64",
-      &UInt32Type => ~"uint32",
-      &UInt64Type => ~"uint64",
-      &SInt32Type => ~"sint32",
-      &SInt64Type => ~"sint64",
-      &BoolType => ~"bool",
-      &StringType => ~"string",
-      &BytesType => ~"bytes",
-      &MessageType => ~"message",
-      &DoubleType => ~"double",
-      _ => fail!(self.to_str())
-    }
-  }
-}
-
-fn type_from_u64(u: u64) -> FieldDescriptorProto_Type {
-  match u {
-    1 => DoubleType,
-    2 => FloatType,
-    3 => Int64Type,
-    4 => UInt64Type,
-    5 => Int32Type,
-    6 => Fixed64Type,
-    7 => Fixed32Type,
-    8 => BoolType,
-    9 => StringType,
-    10 => GroupType,
-    11 => MessageType,
-    12 => BytesType,
-    13 => UInt32Type,
-    14 => EnumType,
-    15 => SFixed32Type,
-    16 => SFixed64Type,
-    17 => SInt32Type,
-    18 => SInt64Type,
-    _ => {fail!()},
-  }
-}
-
-#[deriving(Show)]
-enum FieldDescriptorProto_Label {
-  OptionalLabel      = 1,
-  RequiredLabel      = 2,
-  RepeatedLabel      = 3,
-}
-
-impl FieldDescriptorProto_Label {
-  fn to_proto_str(&self) -> ~str {
-    match self {
-      &OptionalLabel => ~"optional",
-      &RequiredLabel => ~"required",
-      &RepeatedLabel => ~"repeated"
-    }
-  }
-}
-
-fn label_from_u64(u: u64) -> FieldDescriptorProto_Label {
-  match u {
-    1 => OptionalLabel,
-    2 => RequiredLabel,
-    3 => RepeatedLabel,
-    _ => fail!()
-  }
-}
-
-#[deriving(Show)]
-struct FieldDescriptorProto {
-  name: Option<~str>,
-  number: Option<i32>,
-  label: Option<FieldDescriptorProto_Label>,
-  Type: Option<FieldDescriptorProto_Type>,
-  type_name: Option<~str>,
-  default_value: Option<~str>,
-}
-
-impl FieldDescriptorProto {
-  fn BuildTreeLines(&self, depth: uint) -> ~str {
-    let padding = "\t".repeat(depth);
-    let ty_proto_str = match self.Type.unwrap() {
-      EnumType => {
-        (*self.type_name.get_ref()).clone()
-      }
-      id => id.to_proto_str()
-    };
-    let default = if self.default_value.is_some() { format!(" [default = \"{:s}\"]", *self.default_value.get_ref()) } else { ~"" };
-    return format!("{:s}{:s} {:s} {:s} = {:d}{:s};",
-                   padding,
-                   (*self.label.get_ref()).to_proto_str(),
-                   ty_proto_str,
-                   (*self.name.get_ref()),
-                   *self.number.get_ref(),
-                   default);
-  }
-
-  fn fmt(&self, formatter: &mut std::fmt::Formatter) -> std::fmt::Result {
-      try!(write!(formatter.buf, "{}", self.BuildTreeLines(0)));
-      Ok(())
+// The generator behind the protoc plugin in main.rs, also used as a library
+// by build scripts; see codegen.rs.
+
+#![allow(non_snake_case, non_upper_case_globals, clippy::enum_variant_names)]
+
+use std::collections::HashMap;
+use std::io::{Read, Write};
+use std::str::FromStr;
+use protobuf::{Protobuf, ProtobufEncode};
+use descriptor::google::protobuf::{FileDescriptorProto, DescriptorProto, FieldDescriptorProto};
+use descriptor::google::protobuf::{EnumDescriptorProto, EnumValueDescriptorProto};
+use descriptor::google::protobuf::ServiceDescriptorProto;
+use descriptor::google::protobuf::file_options::LiteRuntime;
+use descriptor::google::protobuf::field_descriptor_proto::Type;
+use descriptor::google::protobuf::field_descriptor_proto::{TypeDouble, TypeFloat, TypeInt64, TypeUint64};
+use descriptor::google::protobuf::field_descriptor_proto::{TypeInt32, TypeFixed64, TypeFixed32, TypeBool};
+use descriptor::google::protobuf::field_descriptor_proto::{TypeString, TypeGroup, TypeMessage, TypeBytes};
+use descriptor::google::protobuf::field_descriptor_proto::{TypeUint32, TypeEnum, TypeSfixed32, TypeSfixed64};
+use descriptor::google::protobuf::field_descriptor_proto::{TypeSint32, TypeSint64};
+use descriptor::google::protobuf::field_descriptor_proto::{LabelOptional, LabelRequired, LabelRepeated};
+use plugin::google::protobuf::compiler::{CodeGeneratorRequest, CodeGeneratorResponse};
+use plugin::google::protobuf::compiler::code_generator_response;
+use plugin::google::protobuf::compiler::code_generator_response::FeatureProto3Optional;
+
+// The descriptor types are this generator's own output for the bundled
+// .proto files. After changing the generator, regenerate them with
+//   cargo run -- --rust_out=. \
+//     google/protobuf/descriptor.proto google/protobuf/compiler/plugin.proto
+// and rebuild, so that the plugin is built from what it now generates.
+#[path="google/protobuf/descriptor.rs"]
+#[allow(dead_code, clippy::all)]
+mod descriptor;
+#[path="google/protobuf/compiler/plugin.rs"]
+#[allow(dead_code, clippy::all)]
+mod plugin;
+mod parser;
+pub mod codegen;
+pub mod diff;
+pub mod printer;
+
+// "TYPE_SFIXED32" is written "sfixed32" in a .proto file.
+fn lower_suffix(name: &str, prefix_len: usize) -> String {
+  name[prefix_len..].to_lowercase()
+}
+
+impl Type {
+  fn to_proto_str(self) -> String {
+    lower_suffix(self.name(), "TYPE_".len())
   }
 }
 
-impl Protobuf for CodeGeneratorRequest {
-  fn Decode<'a>(&mut self, reader: &'a mut Reader) -> bool {
-    for tag_option in TagIter{reader: reader} {
-      match tag_option {
-        Raw(1, data) => {
-          self.file_to_generate.push(from_utf8(data).unwrap().to_owned());
+fn float_literal(ty: &str, declared: &str) -> Result<String, String> {
+  match declared {
+    "inf" => return Ok(format!("{}::INFINITY", ty)),
+    "-inf" => return Ok(format!("{}::NEG_INFINITY", ty)),
+    "nan" => return Ok(format!("{}::NAN", ty)),
+    _ => ()
+  }
+  if declared.parse::<f64>().is_err() || !declared.contains(|c: char| c.is_ascii_digit()) {
+    return Err(format!("\"{}\" is not a floating point number", declared));
+  }
+  if declared.contains('.') || declared.contains('e') || declared.contains('E') {
+    Ok(format!("{}{}", declared, ty))
+  } else {
+    Ok(format!("{}.0{}", declared, ty))
+  }
+}
+
+// Reverses the C escaping protoc applies to bytes defaults: the simple
+// escapes plus octal (\NNN) and hex (\xHH) sequences.
+fn unescape_c_bytes(escaped: &str) -> Result<Vec<u8>, String> {
+  let bytes = escaped.as_bytes();
+  let mut result = Vec::new();
+  let mut i = 0;
+  while i < bytes.len() {
+    let c = bytes[i];
+    i += 1;
+    if c != b'\\' {
+      result.push(c);
+      continue;
+    }
+    if i == bytes.len() {
+      return Err("trailing backslash".to_string());
+    }
+    let escape = bytes[i] as char;
+    i += 1;
+    match escape {
+      'a' => result.push(0x07),
+      'b' => result.push(0x08),
+      'f' => result.push(0x0c),
+      'n' => result.push(b'\n'),
+      'r' => result.push(b'\r'),
+      't' => result.push(b'\t'),
+      'v' => result.push(0x0b),
+      '\\' | '\'' | '"' | '?' => result.push(escape as u8),
+      '0'..='7' => {
+        let mut value = escape.to_digit(8).unwrap();
+        let mut digits = 1;
+        while digits < 3 && i < bytes.len() && (bytes[i] as char).is_digit(8) {
+          value = value * 8 + (bytes[i] as char).to_digit(8).unwrap();
+          i += 1;
+          digits += 1;
+        }
+        if value > 0xff {
+          return Err(format!("octal escape \\{:o} is out of range", value));
         }
-        Raw(2, parameter) => {
-          assert!(self.parameter.is_none());
-          self.parameter = Some(from_utf8(parameter).unwrap().to_owned());
+        result.push(value as u8);
+      }
+      'x' => {
+        let mut value = 0;
+        let mut digits = 0;
+        while digits < 2 && i < bytes.len() && (bytes[i] as char).is_ascii_hexdigit() {
+          value = value * 16 + (bytes[i] as char).to_digit(16).unwrap();
+          i += 1;
+          digits += 1;
         }
-        Raw(15, proto_file) => {
-          let mut reader = MemReader::new(proto_file);
-          let mut fd_proto = FileDescriptorProto{
-            name: None,
-            package: None,
-            message_type: ~[]
-          };
-          assert!(fd_proto.Decode(&mut reader));
-          self.proto_file.push(fd_proto);
+        if digits == 0 {
+          return Err("\\x used with no following hex digits".to_string());
         }
-        _ => fail!()
+        result.push(value as u8);
       }
+      _ => return Err(format!("unknown escape \\{}", escape))
     }
-    true
   }
+  Ok(result)
+}
+
+#[test]
+fn test_unescape_c_bytes() {
+  assert_eq!(unescape_c_bytes("abc").unwrap(), [0x61, 0x62, 0x63]);
+  assert_eq!(unescape_c_bytes("\\000\\001\\377").unwrap(), [0x0, 0x1, 0xff]);
+  assert_eq!(unescape_c_bytes("\\x7fz\\n\\\\").unwrap(), [0x7f, 0x7a, 0x0a, 0x5c]);
+  assert!(unescape_c_bytes("\\400").is_err());
+  assert!(unescape_c_bytes("\\q").is_err());
+  assert!(unescape_c_bytes("\\").is_err());
+}
+
+#[test]
+fn test_float_literal() {
+  assert_eq!(float_literal("f64", "inf").unwrap(), "f64::INFINITY");
+  assert_eq!(float_literal("f32", "-inf").unwrap(), "f32::NEG_INFINITY");
+  assert_eq!(float_literal("f64", "nan").unwrap(), "f64::NAN");
+  assert_eq!(float_literal("f32", "1").unwrap(), "1.0f32");
+  assert_eq!(float_literal("f64", "-2.5e3").unwrap(), "-2.5e3f64");
+  assert!(float_literal("f64", "one").is_err());
+  assert!(float_literal("f64", "infinity").is_err());
 }
 
 impl CodeGeneratorRequest {
-  fn to_proto_str(&self) -> ~str {
-    let mut buf = ~"";
-    if self.file_to_generate.len() > 0 {
-      buf.push_str(format!("Files to generate:\n{:s}", self.file_to_generate.connect("\n\t")));
+  // A readable dump of the request, for debugging the plugin.
+  #[allow(dead_code)]
+  fn to_proto_str(&self) -> String {
+    let mut buf = String::new();
+    if self.file_to_generate_len() > 0 {
+      buf.push_str(&format!("Files to generate:\n{}", self.get_file_to_generate().join("\n\t")));
     }
-    if self.proto_file.len() > 0 {
-      buf.push_str(format!("\n\nFile descriptor protos:\n{:s}", self.proto_file.map(|proto_file| {proto_file.to_proto_str()}).connect("\n\n")));
+    if self.proto_file_len() > 0 {
+      let files: Vec<String> = self.get_proto_file().iter().map(|proto_file| {
+        format!("// {}\n{}", proto_file.get_name(), proto_file.to_proto_str())
+      }).collect();
+      buf.push_str(&format!("\n\nFile descriptor protos:\n{}", files.join("\n")));
     }
     buf
   }
 }
 
-impl FileDescriptorProto {
-  fn to_proto_str(&self) -> ~str {
-    let mut buf = format!("File \"{:s}\":\n\n", *self.name.get_ref());
+// The name of the module a generated file forms: "foo/bar-baz.proto" gives
+// "bar_baz".
+fn rs_module_name(proto_name: &str) -> String {
+  let stem = proto_name.strip_suffix(".proto").unwrap_or(proto_name);
+  let base = match stem.rfind('/') {
+    Some(index) => &stem[index + 1..],
+    None => stem
+  };
+  base.chars().map(|c| if c.is_alphanumeric() { c } else { '_' }).collect()
+}
+
+// "foo/bar-baz.proto" generates "foo/bar_baz.rs"; the stem must be usable
+// as a Rust module name.
+fn rs_file_name(proto_name: &str) -> String {
+  let dir = match proto_name.rfind('/') {
+    Some(index) => &proto_name[..index + 1],
+    None => ""
+  };
+  format!("{}{}.rs", dir, rs_module_name(proto_name))
+}
 
-    if !self.package.is_none() {
-      buf.push_str(format!("pub mod {:s};\n", *self.package.get_ref()));
+#[test]
+fn test_rs_file_name() {
+  assert_eq!(rs_file_name("proto/log_entry.proto"), "proto/log_entry.rs");
+  assert_eq!(rs_file_name("a/b.c/raft-log.proto"), "a/b.c/raft_log.rs");
+  assert_eq!(rs_file_name("plain"), "plain.rs");
+  assert_eq!(rs_module_name("google/protobuf/descriptor.proto"), "descriptor");
+}
+
+// Nested types live in a module named after their message, and modules
+// share a namespace with structs: "FieldDescriptorProto" nests its types in
+// "field_descriptor_proto".
+fn snake_case(name: &str) -> String {
+  let chars: Vec<char> = name.chars().collect();
+  let mut result = String::new();
+  for (i, &c) in chars.iter().enumerate() {
+    if c.is_uppercase() && i > 0 {
+      let previous = chars[i - 1];
+      let next_is_lower = i + 1 < chars.len() && chars[i + 1].is_lowercase();
+      if previous.is_lowercase() || previous.is_ascii_digit() || (previous.is_uppercase() && next_is_lower) {
+        result.push('_');
+      }
     }
+    result.extend(c.to_lowercase());
+  }
+  result
+}
+
+#[test]
+fn test_snake_case() {
+  assert_eq!(snake_case("FieldDescriptorProto"), "field_descriptor_proto");
+  assert_eq!(snake_case("File"), "file");
+  assert_eq!(snake_case("HTTPRequest"), "http_request");
+  assert_eq!(snake_case("Ipv4Address"), "ipv4_address");
+  assert_eq!(snake_case("already_snake"), "already_snake");
+}
 
-    if self.message_type.len() > 0 {
-      buf.push_str(format!("\n\n{:s}\n", self.message_type.map(|message_type|{message_type.to_proto_str()}).connect("\n\n")));
+// "TYPE_DOUBLE" and "double_value" become the type and variant names
+// "TypeDouble" and "DoubleValue".
+fn camel_case(name: &str) -> String {
+  let mut result = String::new();
+  for word in snake_case(name).split('_') {
+    let mut chars = word.chars();
+    if let Some(first) = chars.next() {
+      result.extend(first.to_uppercase());
     }
-    buf
+    result.extend(chars);
   }
+  result
 }
 
-impl DescriptorProto {
-  fn BuildTreeLines(&self, depth: uint) -> ~str {
-    let padding = "\t".repeat(depth);
+#[test]
+fn test_camel_case() {
+  assert_eq!(camel_case("TYPE_SFIXED32"), "TypeSfixed32");
+  assert_eq!(camel_case("FEATURE_PROTO3_OPTIONAL"), "FeatureProto3Optional");
+  assert_eq!(camel_case("FieldDescriptorProto"), "FieldDescriptorProto");
+  assert_eq!(camel_case("HTTPRequest"), "HttpRequest");
+  assert_eq!(camel_case("log_entry"), "LogEntry");
+}
 
-    let mut buf = format!("{:s}message {:s} \\{", padding, *self.name.get_ref());
+// Reserved words of every Rust edition the generated code may be built with.
+static kRustKeywords: &[&str] = &[
+  "abstract", "alignof", "as", "async", "await", "become", "box", "break", "const", "continue",
+  "crate", "do", "dyn", "else", "enum", "extern", "false", "final", "fn", "for", "gen", "if",
+  "impl", "in", "let", "loop", "macro", "match", "mod", "move", "mut", "offsetof", "override",
+  "priv", "proc", "pub", "pure", "ref", "return", "self", "Self", "sizeof", "static", "struct",
+  "super", "trait", "true", "try", "type", "typeof", "unsafe", "unsized", "use", "virtual",
+  "where", "while", "yield"
+];
 
-    for field in self.field.iter() {
-      buf.push_str(format!("\n{:s}", field.BuildTreeLines(depth + 1)));
-    }
+// `self`, `super` and `crate` can't be written as raw identifiers, so every
+// keyword is escaped the same way, with a trailing underscore.
+fn escape_keyword(identifier: String) -> String {
+  if kRustKeywords.contains(&identifier.as_str()) {
+    identifier + "_"
+  } else {
+    identifier
+  }
+}
 
-    for nested_type in self.nested_type.iter() {
-      buf.push_str(format!("\n{:s}", nested_type.BuildTreeLines(depth + 1)));
-    }
+// Fields, methods and modules.
+fn snake_identifier(name: &str) -> String {
+  escape_keyword(snake_case(name))
+}
+
+// Messages, enums, enum variants and services.
+fn camel_identifier(name: &str) -> String {
+  escape_keyword(camel_case(name))
+}
+
+#[test]
+fn test_identifiers() {
+  assert_eq!(snake_identifier("LeaderName"), "leader_name");
+  assert_eq!(snake_identifier("type"), "type_");
+  assert_eq!(snake_identifier("Match"), "match_");
+  assert_eq!(snake_identifier("self"), "self_");
+  assert_eq!(snake_identifier("move"), "move_");
+  assert_eq!(camel_identifier("self"), "Self_");
+  assert_eq!(camel_identifier("LABEL_OPTIONAL"), "LabelOptional");
+}
+
+// The Rust name of the enum value `value_name`. Values are variants, but
+// an alias of an earlier value's number is a constant named like the
+// value in the .proto.
+fn enum_value_identifier(descriptor: &EnumDescriptorProto, value_name: &str) -> String {
+  let values = descriptor.get_value();
+  let number = match values.iter().find(|v| v.get_name() == value_name) {
+    Some(value) => value.get_number(),
+    None => return camel_identifier(value_name)
+  };
+  if values.iter().find(|v| v.get_number() == number).unwrap().get_name() == value_name {
+    camel_identifier(value_name)
+  } else {
+    value_name.to_uppercase()
+  }
+}
+
+// An extension `foo_bar` is identified by the constant FOO_BAR_FIELD_NUMBER.
+fn extension_constant(name: &str) -> String {
+  name.to_uppercase() + "_FIELD_NUMBER"
+}
 
-    buf.push_str(format!("\n{:s}\\}", padding));
-    return buf;
+// Field numbers in descriptor.proto that make up SourceCodeInfo paths.
+static kFileMessageTypeField: i32 = 4;
+static kFileEnumTypeField: i32 = 5;
+static kFileServiceField: i32 = 6;
+static kFileExtensionField: i32 = 7;
+static kMessageFieldField: i32 = 2;
+static kMessageNestedTypeField: i32 = 3;
+static kMessageEnumTypeField: i32 = 4;
+static kEnumValueField: i32 = 2;
+static kServiceMethodField: i32 = 2;
+
+// Turns a location's comments into doc comment lines: the leading comment,
+// then the trailing one, separated by an empty line.
+fn doc_comment_lines(leading: &str, trailing: &str) -> Vec<String> {
+  let mut lines = Vec::new();
+  for comment in [leading, trailing].iter() {
+    if comment.trim().is_empty() {
+      continue;
+    }
+    if !lines.is_empty() {
+      lines.push("///".to_string());
+    }
+    for line in comment.trim_end().lines() {
+      lines.push(format!("///{}", line.trim_end()));
+    }
   }
+  lines
 }
 
-impl DescriptorProto {
-  fn to_proto_str(&self) -> ~str {
-    self.BuildTreeLines(0)
+#[test]
+fn test_doc_comment_lines() {
+  assert_eq!(doc_comment_lines(" for nop-command\n", ""), ["/// for nop-command"]);
+  assert_eq!(doc_comment_lines(" First.\n\n Second.\n", " Trailing.\n"),
+             ["/// First.", "///", "/// Second.", "///", "/// Trailing."]);
+  assert!(doc_comment_lines("", "  \n").is_empty());
+}
+
+// The prefix of fully qualified type names declared in `proto_file`:
+// ".google.protobuf", or nothing without a package.
+fn package_scope(proto_file: &FileDescriptorProto) -> String {
+  if proto_file.has_package() {
+    format!(".{}", proto_file.get_package())
+  } else {
+    String::new()
   }
 }
 
-impl Protobuf for FileDescriptorProto {
-  fn Decode<'a>(&mut self, reader: &'a mut Reader) -> bool {
-    for tag_option in TagIter{reader: reader} {
-      match tag_option {
-        Raw(1, name) => {
-          assert!(self.name.is_none());
-          self.name = Some(from_utf8(name).unwrap().to_owned())
-        }
-        Raw(2, package) => {
-          assert!(self.package.is_none());
-          self.package = Some(from_utf8(package).unwrap().to_owned());
-        }
-        Raw(4, message_type) => {
-          let mut reader = MemReader::new(message_type);
-          let mut desc_proto = DescriptorProto{
-            name: None,
-            field: ~[],
-            nested_type: ~[],
-            enum_type: ~[]
-          };
-          assert!(desc_proto.Decode(&mut reader));
-          self.message_type.push(desc_proto)
+// The files whose types `proto_name` may use: itself, the files it imports
+// and, transitively, what those import publicly.
+fn visible_files(proto_files: &[FileDescriptorProto], proto_name: &str) -> Vec<String> {
+  fn find<'b>(proto_files: &'b [FileDescriptorProto], name: &str) -> Option<&'b FileDescriptorProto> {
+    proto_files.iter().find(|f| f.get_name() == name)
+  }
+
+  let mut visible = vec![proto_name.to_string()];
+  let mut pending: Vec<String> = match find(proto_files, proto_name) {
+    Some(proto) => proto.get_dependency().to_vec(),
+    None => Vec::new()
+  };
+  while let Some(name) = pending.pop() {
+    if visible.contains(&name) {
+      continue;
+    }
+    if let Some(dependency) = find(proto_files, &name) {
+      for &index in dependency.get_public_dependency().iter() {
+        if index >= 0 && (index as usize) < dependency.dependency_len() {
+          pending.push(dependency.get_dependency()[index as usize].clone());
         }
-        _ => ()
       }
     }
-    return true;
+    visible.push(name);
   }
+  visible
 }
 
-impl Protobuf for DescriptorProto {
-  fn Decode<'a>(&mut self, reader: &'a mut Reader) -> bool {
-    for tag_option in TagIter{reader: reader} {
-      match tag_option {
-        Raw(1, name) => {
-          assert!(self.name.is_none());
-          self.name = Some(from_utf8(name).unwrap().to_owned())
-        }
-        Raw(2, field) => {
-          let mut reader = MemReader::new(field);
-          let mut field_proto = FieldDescriptorProto{
-            name: None,
-            number: None,
-            label: None,
-            Type: None,
-            type_name: None,
-            default_value: None,
-          };
-          assert!(field_proto.Decode(&mut reader));
-          self.field.push(field_proto)
-        }
-        Raw(3, nested_type) => {
-          let mut reader = MemReader::new(nested_type);
-          let mut desc_proto = DescriptorProto{
-            name: None,
-            field: ~[],
-            nested_type: ~[],
-            enum_type: ~[]
-          };
-          assert!(desc_proto.Decode(&mut reader));
-          self.nested_type.push(desc_proto)
-        }
-        Raw(4, enum_type) => {
-          let mut reader = MemReader::new(enum_type);
-          let mut enum_proto = EnumDescriptorProto{
-            name: None,
-            value: ~[]
-          };
-          assert!(enum_proto.Decode(&mut reader));
-          self.enum_type.push(enum_proto);
-        }
-        _ => ()
-      }
+#[test]
+fn test_visible_files() {
+  fn file(name: &str, dependencies: &[&str], public: &[i32]) -> FileDescriptorProto {
+    let mut proto = FileDescriptorProto::new();
+    proto.set_name(name.to_string());
+    for dependency in dependencies.iter() {
+      proto.add_dependency(dependency.to_string());
     }
-    return true;
+    proto.set_public_dependency(public.to_vec());
+    proto
   }
+
+  let files = [file("a.proto", &[], &[]),
+               file("b.proto", &["a.proto"], &[0]),
+               file("c.proto", &["b.proto"], &[]),
+               file("d.proto", &["c.proto"], &[])];
+  let mut visible = visible_files(&files, "c.proto");
+  visible.sort();
+  assert_eq!(visible, ["a.proto", "b.proto", "c.proto"]);
+  // Only public imports are passed on.
+  let mut visible = visible_files(&files, "d.proto");
+  visible.sort();
+  assert_eq!(visible, ["c.proto", "d.proto"]);
 }
 
-impl Protobuf for FieldDescriptorProto {
-  fn Decode<'a>(&mut self, reader: &'a mut Reader) -> bool {
-    for tag_option in TagIter{reader: reader} {
-      match tag_option {
-        Raw(1, name) => {
-          assert!(self.name.is_none());
-          self.name = Some(from_utf8(name).unwrap().to_owned())
-        }
-        Varint(3, number) => {
-          assert!(self.number.is_none());
-          self.number = Some(number as i32);
-        }
-        Varint(4, label) => {
-          assert!(self.label.is_none());
-          self.label = Some(label_from_u64(label));
-        }
-        Varint(5, Type) => {
-          assert!(self.Type.is_none());
-          self.Type = Some(type_from_u64(Type));
-        }
-        Raw(6, type_name) => {
-          assert!(self.type_name.is_none());
-          self.type_name = Some(from_utf8(type_name).unwrap().to_owned());
-        }
-        Raw(7, default_value) => {
-          assert!(self.default_value.is_none());
-          self.default_value = Some(from_utf8(default_value).unwrap().to_owned());
-        }
-        _ => ()
+impl FileDescriptorProto {
+  fn to_proto_str(&self) -> String {
+    printer::print_file(self)
+  }
+}
+
+// Settings taken from CodeGeneratorRequest.parameter, which protoc fills in
+// from `--rust_out=opt1,key=value:dir`.
+struct GeneratorOptions {
+  // The crate name of this runtime, or a path such as crate::pb that
+  // reaches it.
+  runtime_crate: String,
+  // Traits derived on every generated struct and enum besides the defaults.
+  derives: Vec<String>,
+  string_type: String,
+  bytes_type: String,
+  // Module path under which the generated package modules are mounted;
+  // references to other packages are made absolute through it.
+  module_prefix: Option<String>,
+  generate_encode: bool,
+  generate_json: bool,
+  generate_reflection: bool,
+  // serde impls, with enums as their value names unless serde_enums=number.
+  generate_serde: bool,
+  serde_enum_numbers: bool,
+  // FieldMasked impls, and Clone derived for them.
+  generate_field_mask: bool,
+  // Messages borrow their sub-messages and repeated fields from an Arena
+  // they are decoded into, and strings and bytes are slices of the input
+  // copied there, whatever string_type and bytes_type say.
+  arena: bool
+}
+
+impl GeneratorOptions {
+  fn new() -> GeneratorOptions {
+    GeneratorOptions {
+      runtime_crate: "protobuf".to_string(),
+      derives: Vec::new(),
+      string_type: "String".to_string(),
+      bytes_type: "Vec<u8>".to_string(),
+      module_prefix: None,
+      generate_encode: true,
+      generate_json: false,
+      generate_reflection: false,
+      generate_serde: false,
+      serde_enum_numbers: false,
+      generate_field_mask: false,
+      arena: false
+    }
+  }
+}
+
+fn parse_flag(key: &str, value: Option<&str>) -> Result<bool, String> {
+  match value {
+    None | Some("true") => Ok(true),
+    Some("false") => Ok(false),
+    Some(other) => Err(format!("option {} takes true or false, not \"{}\"", key, other))
+  }
+}
+
+fn option_value<'a>(key: &str, value: Option<&'a str>) -> Result<&'a str, String> {
+  match value {
+    Some(value) => Ok(value),
+    None => Err(format!("option {} needs a value", key))
+  }
+}
+
+fn parse_generator_options(parameter: &str) -> Result<GeneratorOptions, String> {
+  let mut options = GeneratorOptions::new();
+  for option in parameter.split(',') {
+    let option = option.trim();
+    if option.is_empty() {
+      continue;
+    }
+    let (key, value) = match option.find('=') {
+      Some(index) => (&option[..index], Some(&option[index + 1..])),
+      None => (option, None)
+    };
+    match key {
+      "runtime_crate" => options.runtime_crate = option_value(key, value)?.to_string(),
+      "derive" => options.derives.push(option_value(key, value)?.to_string()),
+      "string_type" => {
+        options.string_type = match option_value(key, value)? {
+          other @ ("String" | "Box<str>" | "BytesStr") => other.to_string(),
+          other => return Err(format!("unsupported string_type \"{}\"", other))
+        };
+      }
+      "bytes_type" => {
+        options.bytes_type = match option_value(key, value)? {
+          other @ ("Vec<u8>" | "Box<[u8]>" | "Bytes") => other.to_string(),
+          other => return Err(format!("unsupported bytes_type \"{}\"", other))
+        };
       }
+      "arena" => options.arena = parse_flag(key, value)?,
+      "module_prefix" => options.module_prefix = Some(option_value(key, value)?.to_string()),
+      "encode" => options.generate_encode = parse_flag(key, value)?,
+      "json" => options.generate_json = parse_flag(key, value)?,
+      "reflection" => options.generate_reflection = parse_flag(key, value)?,
+      "serde" => options.generate_serde = parse_flag(key, value)?,
+      "serde_enums" => {
+        options.serde_enum_numbers = match option_value(key, value)? {
+          "name" => false,
+          "number" => true,
+          other => return Err(format!("serde_enums takes name or number, not \"{}\"", other))
+        };
+      }
+      "field_mask" => options.generate_field_mask = parse_flag(key, value)?,
+      _ => return Err(format!("unknown option \"{}\"", key))
     }
-    return true;
   }
+  // serde and field masks make and copy messages on their own, which arena
+  // messages can't do without an arena.
+  if options.arena && (options.generate_serde || options.generate_field_mask) {
+    return Err("arena can't be combined with serde or field_mask".to_string());
+  }
+  Ok(options)
+}
+
+#[test]
+fn test_parse_generator_options() {
+  let options = parse_generator_options("").unwrap();
+  assert_eq!(options.runtime_crate, "protobuf");
+  assert!(options.generate_encode && !options.generate_json);
+
+  let options = parse_generator_options("json,encode=false,derive=Hash,derive=Ord,bytes_type=Box<[u8]>").unwrap();
+  assert!(options.generate_json && !options.generate_encode);
+  assert_eq!(options.derives, ["Hash", "Ord"]);
+  assert_eq!(options.bytes_type, "Box<[u8]>");
+
+  let options = parse_generator_options("runtime_crate=pb, module_prefix=raft::wire").unwrap();
+  assert_eq!(options.runtime_crate, "pb");
+  assert_eq!(options.module_prefix, Some("raft::wire".to_string()));
+
+  assert!(parse_generator_options("colour=blue").is_err());
+  assert!(parse_generator_options("json=maybe").is_err());
+  assert!(parse_generator_options("derive").is_err());
+  assert!(parse_generator_options("string_type=&str").is_err());
+  let options = parse_generator_options("bytes_type=Bytes,string_type=BytesStr").unwrap();
+  assert_eq!((options.bytes_type.as_str(), options.string_type.as_str()), ("Bytes", "BytesStr"));
+  assert!(parse_generator_options("arena,json").unwrap().arena);
+  assert!(parse_generator_options("arena,serde").is_err());
+  assert!(parse_generator_options("field_mask,arena").is_err());
+
+  let options = parse_generator_options("serde,serde_enums=number").unwrap();
+  assert!(options.generate_serde && options.serde_enum_numbers);
+  assert!(!parse_generator_options("serde").unwrap().serde_enum_numbers);
+  assert!(parse_generator_options("serde_enums=ordinal").is_err());
+  assert!(parse_generator_options("field_mask").unwrap().generate_field_mask);
 }
 
 struct ProtobufGenerator<'a> {
   request: &'a CodeGeneratorRequest,
-  current_package: Option<~str>,
-  indent: uint,
-  indent_str: ~str,
-  buf: std::io::MemWriter
+  current_package: Option<String>,
+  indent: usize,
+  indent_str: String,
+  buf: String,
+  options: GeneratorOptions,
+  // Names of the messages enclosing the one being generated.
+  scopes: Vec<String>,
+  current_file: String,
+  // The current file, its imports and what they publicly import.
+  visible_files: Vec<String>,
+  // SourceCodeInfo path of the element being generated, and the doc
+  // comment of every path in the current file that has one.
+  path: Vec<i32>,
+  comments: HashMap<Vec<i32>, String>,
+  errors: Vec<String>
 }
 
 impl<'a> ProtobufGenerator<'a> {
 
-  fn translate_package_namespace<'a>(&'a mut self, absolute: &str, current: &str) -> ~str {
-    let id = if (absolute.starts_with("." + self.current_package.get_ref().as_slice())) {
-      absolute.slice_from(current.len() + 1).to_owned()
-    } else {
-      absolute.to_owned()
-    };
-    self.translate_type_name(id)
-  }
-
-  fn new<'a>(request: &'a CodeGeneratorRequest) -> ProtobufGenerator<'a> {
+  fn new(request: &'a CodeGeneratorRequest) -> ProtobufGenerator<'a> {
     ProtobufGenerator {
-      request: request,
+      request,
       current_package: None,
       indent: 0,
-      indent_str: ~"  ",
-      buf: std::io::MemWriter::new()
-    }
-  }
-
-  fn translate_type_name(&mut self, type_name: ~str) -> ~str {
-    type_name.trim_left_chars(&'.').replace(".", "::")
-  }
-
-  fn translate_identifier(&mut self, identifier: ~str) -> ~str {
-    match identifier {
-      ~"type" => ~"type__",
-      id => id
-    }
-  }
-
-  fn translate_empty_message(&mut self, identifier: Option<&str>, descriptor: &DescriptorProto) -> std::fmt::Result {
-    let prefix = identifier.map(|id| format!("let {:s} = ", id)).unwrap_or(~"");
-    self.append_line(format!("{:s}{:s}\\{", prefix, descriptor.name.get_ref().as_slice()));
-    self.indent += 1;
-    for field in descriptor.field.iter() {
-      let name = field.name.get_ref().as_slice();
-      let label = field.label.unwrap();
-      let field_type = field.Type.unwrap();
-      match field_type {
-        MessageType => (),/*{
-          let child_descriptor = self.lookup_descriptor(name);
-          translate_empty_message(name, child_descriptor)
-        },*/
-        _ => {
-          let bare_val = match field_type {
-            StringType => "~\"\"",
-            BytesType => "~[]",
-            BoolType => "false",
-            FloatType | DoubleType => "0.0",
-            _ => "0"
-          };
-          let full_val = match label {
-            RequiredLabel => bare_val,
-            OptionalLabel => "None",
-            RepeatedLabel => "~[]",
-          };
-          self.append_line(format!("{:s}: {:s},", bare_val, full_val));
-        }
-      }
+      indent_str: "  ".to_string(),
+      buf: String::new(),
+      options: GeneratorOptions::new(),
+      scopes: Vec::new(),
+      current_file: String::new(),
+      visible_files: Vec::new(),
+      path: Vec::new(),
+      comments: HashMap::new(),
+      errors: Vec::new()
     }
-    self.indent -= 1;
-    self.append_line("}")
   }
 
-  fn translate_field_impl(&mut self, field: &FieldDescriptorProto) -> std::fmt::Result {
-    let field_name = field.name.get_ref().as_slice();
-    let tag = field.number.unwrap();
-    let label = field.label.unwrap();
-    let field_type = field.Type.unwrap();
+  fn field_bare_type(&mut self, field: &FieldDescriptorProto) -> String {
+    match field.get_type() {
+      TypeDouble => "f64".to_string(),
+      TypeFloat => "f32".to_string(),
+      TypeInt32 => "i32".to_string(),
+      TypeInt64 => "i64".to_string(),
+      TypeUint32 => "u32".to_string(),
+      TypeUint64 => "u64".to_string(),
+      TypeSint32 => "i32".to_string(),
+      TypeSint64 => "i64".to_string(),
+      TypeFixed32 => "u32".to_string(),
+      TypeFixed64 => "u64".to_string(),
+      TypeSfixed32 => "i32".to_string(),
+      TypeSfixed64 => "i64".to_string(),
+      TypeBool => "bool".to_string(),
+      TypeString if self.options.string_type == "BytesStr" => format!("{}::BytesStr", self.runtime_path()),
+      TypeString => self.options.string_type.clone(),
+      TypeBytes if self.options.bytes_type == "Bytes" => format!("{}::Bytes", self.runtime_path()),
+      TypeBytes => self.options.bytes_type.clone(),
+      TypeMessage | TypeEnum => self.type_path(field.get_type_name()),
+      TypeGroup => format!("UNKNOWN({:?})", field.get_type())
+    }
+  }
 
-    match field_type {
-      StringType | BytesType | MessageType => {
-        self.append_line(format!("Raw({:d}, {:s}) => \\{", tag, field_name));
+  // The bare type in arena mode, where strings, bytes and messages borrow
+  // from the arena.
+  fn arena_bare_type(&mut self, field: &FieldDescriptorProto) -> String {
+    match field.get_type() {
+      TypeString => "&'a str".to_string(),
+      TypeBytes => "&'a [u8]".to_string(),
+      TypeMessage => format!("{}<'a>", self.field_bare_type(field)),
+      _ => self.field_bare_type(field)
+    }
+  }
+
+  // The header of the impl of `trait_name` for the message `name`, which
+  // has a lifetime in arena mode.
+  fn impl_header(&self, trait_name: &str, name: &str) -> String {
+    if self.options.arena {
+      format!("impl<'a> {} for {}<'a> {{", trait_name, name)
+    } else {
+      format!("impl {} for {} {{", trait_name, name)
+    }
+  }
+
+  // The Rust path to a message or enum from the scope being generated.
+  // Types of this file are reached with `super::` out of the nested type
+  // modules. Each generated file is a module of its own, so types of other
+  // files go through that module, mounted at the crate root or under
+  // module_prefix.
+  fn type_path(&self, type_name: &str) -> String {
+    // check_field has made sure the type exists.
+    let proto_file = self.lookup_file(type_name).unwrap();
+    let scope = package_scope(proto_file);
+    let components: Vec<&str> = type_name[scope.len() + 1..].split('.').collect();
+    let mut path: Vec<String> = Vec::new();
+    for (i, component) in components.iter().enumerate() {
+      if i + 1 < components.len() {
+        path.push(snake_identifier(component));
+      } else {
+        path.push(camel_identifier(component));
+      }
+    }
+
+    let mut prefix: Vec<String> = Vec::new();
+    if proto_file.get_name() == self.current_file {
+      for _ in self.scopes.iter() {
+        prefix.push("super".to_string());
       }
-      Fixed32Type | SFixed32Type | FloatType => {
-        self.append_line(format!("Fixed32({:d}, {:s}) => \\{", tag, field_name));
+    } else {
+      prefix.push("crate".to_string());
+      if let Some(module_prefix) = self.options.module_prefix.as_ref() {
+        prefix.push(module_prefix.clone());
       }
-      Fixed64Type | SFixed64Type | DoubleType => {
-        self.append_line(format!("Fixed64({:d}, {:s}) => \\{", tag, field_name));
+      prefix.push(rs_module_name(proto_file.get_name()));
+      if proto_file.has_package() {
+        for package in proto_file.get_package().split('.') {
+          prefix.push(escape_keyword(package.to_string()));
+        }
       }
-      Int32Type | Int64Type | SInt32Type | SInt64Type | UInt32Type | UInt64Type | BoolType | EnumType => {
-        self.append_line(format!("Varint({:d}, {:s}) => \\{", tag, field_name));
+    }
+    prefix.extend(path);
+    prefix.join("::")
+  }
+
+  // Finds the file declaring the message or enum with the fully qualified
+  // name `type_name`, such as ".google.protobuf.FieldDescriptorProto.Type".
+  fn lookup_file(&self, type_name: &str) -> Option<&'a FileDescriptorProto> {
+    fn declares(scope: &str, descriptor: &DescriptorProto, type_name: &str) -> bool {
+      let path = format!("{}.{}", scope, descriptor.get_name());
+      path == type_name ||
+        descriptor.get_enum_type().iter().any(|e| format!("{}.{}", path, e.get_name()) == type_name) ||
+        descriptor.get_nested_type().iter().any(|nested| declares(&path, nested, type_name))
+    }
+
+    self.request.get_proto_file().iter().find(|proto_file| {
+      let scope = package_scope(proto_file);
+      proto_file.get_message_type().iter().any(|m| declares(&scope, m, type_name)) ||
+        proto_file.get_enum_type().iter().any(|e| format!("{}.{}", scope, e.get_name()) == type_name)
+    })
+  }
+
+  // Finds the message a field refers to, like lookup_enum().
+  fn lookup_message(&self, type_name: &str) -> Option<&'a DescriptorProto> {
+    fn search<'b>(prefix: &str, descriptor: &'b DescriptorProto, type_name: &str) -> Option<&'b DescriptorProto> {
+      let path = format!("{}.{}", prefix, descriptor.get_name());
+      if path == type_name {
+        return Some(descriptor);
       }
-      GroupType => fail!(),
+      descriptor.get_nested_type().iter().find_map(|nested_type| search(&path, nested_type, type_name))
     }
-    self.indent += 1;
 
-    let bare_val = match field_type {
-      MessageType => {
-        let type_name = field.type_name.get_ref().as_slice();
-        self.append_line(format!("let reader = std::io::MemReader::new({:s});", field_name));
-        //translate_empty_message(Some(field_name), self.lookup_descriptor(type_name));
-        self.append_line(format!("assert!({:s}.Decode(&mut reader));", field_name));
-        field_name
+    self.request.get_proto_file().iter().find_map(|proto_file| {
+      let prefix = package_scope(proto_file);
+      proto_file.get_message_type().iter().find_map(|message_type| search(&prefix, message_type, type_name))
+    })
+  }
+
+  // Finds the enum a field refers to, searching every file in the request
+  // since the type may come from a dependency.
+  fn lookup_enum(&self, type_name: &str) -> Option<&'a EnumDescriptorProto> {
+    fn search<'b>(prefix: &str, descriptor: &'b DescriptorProto, type_name: &str) -> Option<&'b EnumDescriptorProto> {
+      let path = format!("{}.{}", prefix, descriptor.get_name());
+      for enum_type in descriptor.get_enum_type().iter() {
+        if format!("{}.{}", path, enum_type.get_name()) == type_name {
+          return Some(enum_type);
+        }
       }
-      StringType => {
-        self.append_line(format!("let {:s} = std::str::from_utf8({:s}).unwrap();", field_name, field_name));
-        field_name
+      for nested_type in descriptor.get_nested_type().iter() {
+        let found = search(&path, nested_type, type_name);
+        if found.is_some() {
+          return found;
+        }
       }
-      _ => field_name
-    };
+      None
+    }
 
-    match label {
-      RequiredLabel | OptionalLabel => {
-        let val = match label {
-          RequiredLabel => bare_val.to_owned(),
-          OptionalLabel => format!("Some({:s})", bare_val),
-          _ => fail!()
-        };
-        format!("self.{:s} = {:s};", field_name, val);
+    for proto_file in self.request.get_proto_file().iter() {
+      let prefix = package_scope(proto_file);
+      for enum_type in proto_file.get_enum_type().iter() {
+        if format!("{}.{}", prefix, enum_type.get_name()) == type_name {
+          return Some(enum_type);
+        }
       }
-      RepeatedLabel => {
-        format!("self.{:s}.push({:s});", field_name, bare_val);
+      for message_type in proto_file.get_message_type().iter() {
+        let found = search(&prefix, message_type, type_name);
+        if found.is_some() {
+          return found;
+        }
       }
     }
-    self.indent -= 1;
-    self.append_line("}")
+    None
   }
 
-  fn translate_field(&mut self, field: &FieldDescriptorProto) -> std::fmt::Result {
-    let bare_type = match field.Type.unwrap() {
-      DoubleType => ~"f64",
-      FloatType => ~"f32",
-      Int32Type => ~"i32",
-      Int64Type => ~"i64",
-      UInt32Type => ~"u32",
-      UInt64Type => ~"u64",
-      SInt32Type => ~"i32",
-      SInt64Type => ~"i64",
-      Fixed32Type => ~"u32",
-      Fixed64Type => ~"u64",
-      SFixed32Type => ~"i32",
-      SFixed64Type => ~"i64",
-      BoolType => ~"bool",
-      StringType => ~"~str",
-      BytesType => ~"~[u8]",
-      MessageType | EnumType => {
-        let current_namespace = self.current_package.get_ref().to_owned();
-        self.translate_package_namespace(field.type_name.get_ref().as_slice(), current_namespace)
-      },
-      _ => format!("UNKNOWN({})", field.Type.unwrap().to_str())
-    };
-
-    let full_type = match field.label.unwrap() {
-      RepeatedLabel => format!("~[{:s}]", bare_type),
-      OptionalLabel => format!("Option<{:s}>", bare_type),
-      RequiredLabel => bare_type
-    };
+  // Records a problem with `element` in the file being generated. The
+  // errors are returned together through CodeGeneratorResponse.error.
+  fn error(&mut self, element: &str, message: &str) {
+    self.errors.push(format!("{}: {}: {}", self.current_file, element, message));
+  }
 
-    let id = self.translate_identifier(field.name.get_ref().to_owned());
-    self.append_line(format!("{}: {},", id, full_type))
+  // Reports everything that would keep a message from generating; the rest
+  // of the generator relies on these checks having passed.
+  fn check_descriptor(&mut self, descriptor: &DescriptorProto) -> bool {
+    if !descriptor.has_name() {
+      let element = self.full_name("<unnamed>");
+      self.error(&element, "message has no name");
+      return false;
+    }
+    let scope = self.full_name(descriptor.get_name());
+    // Every struct also stores the fields its schema doesn't know.
+    let mut names = vec![("unknown_fields".to_string(), "the unknown fields".to_string())];
+    for field in descriptor.get_field().iter().filter(|field| field.has_name()) {
+      names.push((snake_identifier(field.get_name()), format!("{}.{}", scope, field.get_name())));
+    }
+    let mut valid = self.check_collisions(&names);
+    for field in descriptor.get_field().iter() {
+      if let Err(message) = self.check_field(field) {
+        let field_name = if field.has_name() { field.get_name() } else { "<unnamed>" };
+        let element = format!("{}.{}", scope, field_name);
+        self.error(&element, &message);
+        valid = false;
+      }
+    }
+    valid
   }
 
-  fn translate_enum(&mut self, descriptor: &EnumDescriptorProto) -> std::fmt::Result {
-    self.append_line(format!("enum {:s} \\{", descriptor.name.get_ref().to_owned()));
-    self.indent += 1;
-    for value in descriptor.value.iter() {
-      let name = value.name.get_ref().to_owned();
-      let number = value.number.unwrap();
-      self.append_line(format!("{:s} = {:d},", name, number));
+  // Reports elements that would be given the same Rust identifier in one
+  // scope. `names` pairs each identifier with the element it names.
+  fn check_collisions(&mut self, names: &[(String, String)]) -> bool {
+    let mut valid = true;
+    for (i, (identifier, element)) in names.iter().enumerate() {
+      if let Some((_, first)) = names[..i].iter().find(|(other, _)| other == identifier) {
+        self.error(element, &format!("is generated as {}, like {}", identifier, first));
+        valid = false;
+      }
     }
-    self.indent -= 1;
-    self.append_line("}")
+    valid
   }
 
-  fn translate_descriptor_impl(&mut self, descriptor: &DescriptorProto) -> std::fmt::Result {
-    self.append_line(format!("impl Protobuf for {:s} \\{", descriptor.name.get_ref().to_owned()));
-    self.indent += 1;
-    /*
-  fn Decode<'a>(&mut self, reader: &'a mut Reader) -> bool {
-    for tag_option in TagIter{reader: reader} {
-      match tag_option {
-        Raw(1, name) => {
-          assert!(self.name.is_none());
-          self.name = from_utf8(name).map(|n| n.to_owned());
-        }
-    */
-    self.append_line("fn Decode<'a>(&mut self, reader: &'a mut Reader) -> bool {");
-    self.indent += 1;
-    self.append_line("for tag_option in TagIter{reader: reader} {");
-    self.indent += 1;
-    self.append_line("match tag_option {");
-    self.indent += 1;
-    for field in descriptor.field.iter() {
-      self.translate_field_impl(field);
+  // Types, the modules of their nested types, enum values, services and
+  // extension constants all share the module they are generated in.
+  fn check_scope(&mut self, messages: &[DescriptorProto], enums: &[EnumDescriptorProto],
+                 extensions: &[FieldDescriptorProto], services: &[ServiceDescriptorProto]) -> bool {
+    let mut names = Vec::new();
+    for message in messages.iter().filter(|message| message.has_name()) {
+      let element = self.full_name(message.get_name());
+      names.push((camel_identifier(message.get_name()), element.clone()));
+      if message.nested_type_len() > 0 || message.enum_type_len() > 0 {
+        names.push((snake_identifier(message.get_name()), element));
+      }
     }
-    self.indent -= 1;
-    self.append_line("}");
-    self.indent -= 1;
-    self.append_line("}");
-    self.indent -= 1;
-    self.append_line("}");
-    self.indent -= 1;
-    self.append_line("}")
+    for descriptor in enums.iter().filter(|descriptor| descriptor.has_name()) {
+      let element = self.full_name(descriptor.get_name());
+      names.push((camel_identifier(descriptor.get_name()), element.clone()));
+      for value in descriptor.get_value().iter().filter(|value| value.has_name()) {
+        names.push((enum_value_identifier(descriptor, value.get_name()),
+                    format!("{}.{}", element, value.get_name())));
+      }
+    }
+    for extension in extensions.iter().filter(|extension| extension.has_name()) {
+      names.push((extension_constant(extension.get_name()), self.full_name(extension.get_name())));
+    }
+    for service in services.iter().filter(|service| service.has_name()) {
+      names.push((camel_identifier(service.get_name()), self.full_name(service.get_name())));
+    }
+    self.check_collisions(&names)
   }
 
-  fn translate_descriptor(&mut self, descriptor: &DescriptorProto) -> std::fmt::Result {
-    self.append_line(format!("struct {:s} \\{", descriptor.name.get_ref().to_owned()));
-    self.indent += 1;
-    for field in descriptor.field.iter() {
-      self.translate_field(field);
+  fn check_field(&mut self, field: &FieldDescriptorProto) -> Result<(), String> {
+    if !field.has_name() {
+      return Err("field has no name".to_string());
     }
-    self.indent -= 1;
-    self.append_line("}");
-    if descriptor.nested_type.len() > 0 || descriptor.enum_type.len() > 0 {
-      self.append_line(format!("pub mod {:s} \\{", descriptor.name.get_ref().to_owned()));
-      self.indent += 1;
-      for ty in descriptor.nested_type.iter() {
-        self.translate_descriptor(ty);
+    if !field.has_number() {
+      return Err("field has no number".to_string());
+    }
+    // Labels and types this generator doesn't know are decoded as unknown
+    // fields, so they look missing here.
+    if !field.has_label() {
+      return Err("field has a missing or unknown label".to_string());
+    }
+    if !field.has_type() {
+      return Err("field has a missing or unknown type".to_string());
+    }
+    match field.get_type() {
+      TypeGroup => return Err("groups are not supported".to_string()),
+      TypeMessage | TypeEnum if !field.has_type_name() => {
+        return Err("field has no type_name".to_string());
       }
-      for ty in descriptor.enum_type.iter() {
-        self.translate_enum(ty);
+      TypeMessage => {
+        let type_name = field.get_type_name();
+        if self.lookup_file(type_name).is_none() {
+          return Err(format!("message {} is not defined in the request", type_name));
+        }
+        self.check_visible(type_name)?;
       }
-      self.indent -= 1;
-      self.append_line("}");
+      TypeEnum => {
+        let type_name = field.get_type_name();
+        match self.lookup_enum(type_name) {
+          None => return Err(format!("enum {} is not defined in the request", type_name)),
+          Some(descriptor) if descriptor.value_len() == 0 => {
+            return Err(format!("enum {} has no values", type_name));
+          }
+          Some(_) => ()
+        }
+        self.check_visible(type_name)?;
+      }
+      _ => ()
+    }
+    if !field.has_default_value() {
+      return Ok(());
+    }
+    match self.parse_default_value(field, field.get_default_value()) {
+      Ok(_) => Ok(()),
+      Err(message) => Err(format!("bad default value: {}", message))
     }
-
-    self.translate_descriptor_impl(descriptor)
   }
 
-  fn pad(&self, line: &str) -> ~str {
-    let mut buf = ~"";
-    for i in std::iter::range(0, self.indent) {
-      buf.push_str(self.indent_str);
+  // protoc only lets a file use the types of files it imports; `type_name`
+  // must already be known to exist.
+  fn check_visible(&self, type_name: &str) -> Result<(), String> {
+    let declaring = self.lookup_file(type_name).unwrap().get_name();
+    if self.visible_files.iter().any(|name| name == declaring) {
+      Ok(())
+    } else {
+      Err(format!("{} is declared in {}, which is not imported", type_name, declaring))
     }
-    buf.push_str(line);
-    buf
   }
 
-  fn append_line(&mut self, line: &str) -> std::fmt::Result {
-    assert!(!line.ends_with("\n"));
-    let complete_line = self.pad(line) + "\n";
-    self.buf.write(complete_line.as_bytes())
+  fn check_service(&mut self, service: &ServiceDescriptorProto) -> bool {
+    let element = self.full_name(if service.has_name() { service.get_name() } else { "<unnamed>" });
+    if !service.has_name() {
+      self.error(&element, "service has no name");
+      return false;
+    }
+    let mut valid = true;
+    for method in service.get_method().iter() {
+      let method_name = if method.has_name() { method.get_name() } else { "<unnamed>" };
+      let problem = if !method.has_name() {
+        Err("method has no name".to_string())
+      } else {
+        self.check_method_type(method.get_input_type()).and(self.check_method_type(method.get_output_type()))
+      };
+      if let Err(message) = problem {
+        self.error(&format!("{}.{}", element, method_name), &message);
+        valid = false;
+      }
+    }
+    valid
   }
 
-  fn translate_file(&mut self, proto: &FileDescriptorProto) {
-    let mut buf = ~"";
-    self.current_package = proto.package.clone();
-    self.append_line("extern crate protobuf;");
-    self.append_line("");
-
-    let package_path_components = proto.package.get_ref().split('.').map(|p| p.to_owned()).to_owned_vec();
-    self.translate_package(proto, package_path_components);
+  fn check_method_type(&self, type_name: &str) -> Result<(), String> {
+    if type_name.is_empty() {
+      return Err("method is missing its input or output type".to_string());
+    }
+    if self.lookup_file(type_name).is_none() || self.lookup_enum(type_name).is_some() {
+      return Err(format!("message {} is not defined in the request", type_name));
+    }
+    self.check_visible(type_name)
   }
 
-  fn translate_package(&mut self, proto: &FileDescriptorProto, package_path_components: &[~str]) {
-    self.append_line(format!("pub mod {:s} \\{", package_path_components[0]));
-    self.indent += 1;
-    if (package_path_components.len() > 1) {
-      self.translate_package(proto, package_path_components.slice_from(1));
-    } else {
-      for message_type in proto.message_type.iter() {
-        self.translate_descriptor(message_type);
+  fn check_enum(&mut self, descriptor: &EnumDescriptorProto) -> bool {
+    let element = self.full_name(if descriptor.has_name() { descriptor.get_name() } else { "<unnamed>" });
+    if !descriptor.has_name() {
+      self.error(&element, "enum has no name");
+      return false;
+    }
+    if descriptor.value_len() == 0 {
+      self.error(&element, "enum has no values");
+      return false;
+    }
+    if descriptor.get_value().iter().any(|v| !v.has_name() || !v.has_number()) {
+      self.error(&element, "enum value without a name or number");
+      return false;
+    }
+    // allow_alias defaults to true in descriptor.proto, but protoc only
+    // accepts aliases when it is set explicitly.
+    let options = descriptor.get_options();
+    let allow_alias = options.has_allow_alias() && options.get_allow_alias();
+    for (i, value) in descriptor.get_value().iter().enumerate() {
+      match descriptor.get_value()[..i].iter().find(|v| v.get_number() == value.get_number()) {
+        Some(original) if !allow_alias => {
+          self.error(&format!("{}.{}", element, value.get_name()),
+                     &format!("reuses the number of {} without allow_alias", original.get_name()));
+          return false;
+        }
+        _ => ()
       }
     }
-    self.indent -= 1;
-    self.append_line("}");
+    true
   }
 
-  fn translate(&mut self) {
-    let files_to_generate: HashSet<&~str> = FromIterator::from_iterator(&mut self.request.file_to_generate.iter());
-    for proto_file in self.request.proto_file.iter() {
-      if files_to_generate.contains(&proto_file.name.get_ref()) {
-        self.translate_file(proto_file);
-      }
+  // Converts a borrowed &str expression to the configured string type.
+  fn owned_string(&self, borrowed: &str) -> String {
+    match self.options.string_type.as_str() {
+      "Box<str>" => format!("Box::from({})", borrowed),
+      "BytesStr" => format!("{}::BytesStr::from({})", self.runtime_path(), borrowed),
+      _ => format!("String::from({})", borrowed)
     }
-    let bytes = self.buf.get_ref();
-    println!("{}", from_utf8(bytes).unwrap());
   }
-}
 
-impl DescriptorProto {
-  fn rs_name(&self, prefix: &str) -> ~str {
-    format!("{:s}_{:s}", prefix, *self.name.get_ref())
+  // Converts a borrowed &[u8] (or byte string literal) expression to the
+  // configured bytes type.
+  fn owned_bytes(&self, borrowed: &str) -> String {
+    match self.options.bytes_type.as_str() {
+      "Box<[u8]>" => format!("{}.to_vec().into_boxed_slice()", borrowed),
+      "Bytes" => format!("{}::Bytes::from({}.to_vec())", self.runtime_path(), borrowed),
+      _ => format!("{}.to_vec()", borrowed)
+    }
   }
-}
 
-impl FileDescriptorProto {
-  fn rs_package_name(&self) -> ~str {
-    (*self.package.get_ref()).replace(".", "_")
+  // The value `get_` returns while the field is unset, as an expression of
+  // the field's bare type (or its borrowed form for strings and bytes).
+  fn field_default_value(&mut self, field: &FieldDescriptorProto) -> String {
+    if field.has_default_value() {
+      return match self.parse_default_value(field, field.get_default_value()) {
+        Ok(value) => value,
+        // Rejected by check_field before generation starts.
+        Err(_) => unreachable!()
+      };
+    }
+    match field.get_type() {
+      TypeDouble | TypeFloat => "0.0".to_string(),
+      TypeBool => "false".to_string(),
+      TypeString => "\"\"".to_string(),
+      TypeBytes => "b\"\"".to_string(),
+      TypeMessage => format!("{}::default_instance()", self.field_bare_type(field)),
+      TypeEnum => {
+        let descriptor = self.lookup_enum(field.get_type_name()).unwrap();
+        self.enum_value_path(field, descriptor.get_value()[0].get_name())
+      }
+      _ => "0".to_string()
+    }
   }
-}
 
-fn main() {
-  let mut stdin_reader = stdin();
-  let mut request = CodeGeneratorRequest{
-    file_to_generate: ~[],
-    parameter: None,
-    proto_file: ~[],
-  };
-  assert!(request.Decode(&mut stdin_reader));
-  let mut gen = ProtobufGenerator::new(&request);
-  gen.translate();
-}
+  // C-like enum variants are re-exported into the module enclosing the enum.
+  fn enum_value_path(&mut self, field: &FieldDescriptorProto, value_name: &str) -> String {
+    let value = enum_value_identifier(self.lookup_enum(field.get_type_name()).unwrap(), value_name);
+    let enum_path = self.field_bare_type(field);
+    match enum_path.rfind("::") {
+      Some(index) => format!("{}{}", &enum_path[..index + 2], value),
+      None => value
+    }
+  }
 
-#[deriving(Show)]
-struct EnumDescriptorProto {
-  name: Option<~str>,
-  value: ~[EnumValueDescriptorProto]
-}
+  // Turns `FieldDescriptorProto.default_value` into a Rust literal of the
+  // field's type. protoc leaves string defaults unescaped and C-escapes bytes.
+  fn parse_default_value(&mut self, field: &FieldDescriptorProto, declared: &str) -> Result<String, String> {
+    fn integer<T: FromStr + ToString>(declared: &str) -> Result<String, String> {
+      match declared.parse::<T>() {
+        Ok(value) => Ok(value.to_string()),
+        Err(_) => Err(format!("\"{}\" is out of range or not an integer", declared))
+      }
+    }
 
-impl Protobuf for EnumDescriptorProto {
-  fn Decode<'a>(&mut self, reader: &'a mut Reader) -> bool {
-    for tag_option in TagIter{reader: reader} {
-      match tag_option {
-        Raw(1, name) => {
-          assert!(self.name.is_none());
-          self.name = from_utf8(name).map(|n| n.to_owned());
+    match field.get_type() {
+      TypeInt32 | TypeSint32 | TypeSfixed32 => integer::<i32>(declared),
+      TypeInt64 | TypeSint64 | TypeSfixed64 => integer::<i64>(declared),
+      TypeUint32 | TypeFixed32 => integer::<u32>(declared),
+      TypeUint64 | TypeFixed64 => integer::<u64>(declared),
+      TypeFloat => float_literal("f32", declared),
+      TypeDouble => float_literal("f64", declared),
+      TypeBool => {
+        match declared {
+          "true" | "false" => Ok(declared.to_string()),
+          _ => Err(format!("\"{}\" is not a bool", declared))
         }
-        Raw(2, value) => {
-          let mut reader = MemReader::new(value);
-          let mut enum_value_descriptor_proto = EnumValueDescriptorProto{
-            name: None,
-            number: None
-          };
-          assert!(enum_value_descriptor_proto.Decode(&mut reader));
-          self.value.push(enum_value_descriptor_proto);
+      }
+      TypeString => Ok(format!("\"{}\"", declared.escape_default())),
+      TypeBytes => {
+        let bytes = unescape_c_bytes(declared)?;
+        Ok(format!("b\"{}\"", bytes.escape_ascii()))
+      }
+      TypeEnum => {
+        let type_name = field.get_type_name();
+        let known = match self.lookup_enum(type_name) {
+          Some(descriptor) => descriptor.get_value().iter().any(|v| v.get_name() == declared),
+          None => return Err(format!("unknown enum type {}", type_name))
+        };
+        if !known {
+          return Err(format!("{} has no value named {}", type_name, declared));
         }
-        _ => ()
+        Ok(self.enum_value_path(field, declared))
       }
+      TypeMessage | TypeGroup => Err("message fields cannot have defaults".to_string())
     }
-    true
   }
-}
 
-#[deriving(Show)]
-struct EnumValueDescriptorProto {
-  name: Option<~str>,
-  number: Option<i32>
-}
+  // Embedded message fields marked [lazy = true] are parsed on first
+  // access. Like any implementation, this one may parse them eagerly, as
+  // it does for serde, which can't see into the lazy storage, and in arena
+  // mode, where parsing is cheap.
+  fn is_lazy(&self, field: &FieldDescriptorProto) -> bool {
+    field.get_type() == TypeMessage && field.get_options().get_lazy() && !self.options.generate_serde &&
+      !self.options.arena
+  }
 
-impl Protobuf for EnumValueDescriptorProto {
-  fn Decode<'a>(&mut self, reader: &'a mut Reader) -> bool {
-    for tag_option in TagIter{reader: reader} {
-      match tag_option {
-        Raw(1, name) => {
-          assert!(self.name.is_none());
-          self.name = from_utf8(name).map(|n| n.to_owned());
-        }
-        Varint(2, number) => {
-          assert!(self.number.is_none());
-          self.number = Some(number as i32);
-        }
-        _ => ()
-      }
+  // The field of `message` as its Option or Vec, parsed first if it is
+  // lazy.
+  fn field_storage(&self, field: &FieldDescriptorProto, message: &str) -> String {
+    let id = snake_identifier(field.get_name());
+    if self.is_lazy(field) {
+      format!("{}.{}.get()", message, id)
+    } else {
+      format!("{}.{}", message, id)
     }
-    true
   }
-}
\ No newline at end of file
+
+  fn field_storage_mut(&self, field: &FieldDescriptorProto) -> String {
+    let id = snake_identifier(field.get_name());
+    if self.is_lazy(field) {
+      format!("self.{}.get_mut()", id)
+    } else {
+      format!("self.{}", id)
+    }
+  }
+
+  fn translate_constructor(&mut self, descriptor: &DescriptorProto) {
+    let name = camel_identifier(descriptor.get_name());
+    let (vec_new, full_name, static_name) = if self.options.arena {
+      (format!("{}::RepeatedField::new()", self.runtime_path()), format!("{}<'a>", name), format!("{}<'static>", name))
+    } else {
+      ("Vec::new()".to_string(), name.clone(), name.clone())
+    };
+    self.append_line(&format!("pub const fn new() -> {} {{", full_name));
+    self.indent += 1;
+    self.append_line(&format!("{} {{", name));
+    self.indent += 1;
+    for field in descriptor.get_field().iter() {
+      let id = snake_identifier(field.get_name());
+      if self.is_lazy(field) {
+        let runtime = self.runtime_path();
+        let parse = match field.get_label() {
+          LabelRepeated => "ParseLazyMessages",
+          LabelOptional | LabelRequired => "ParseLazyMessage"
+        };
+        let bare_type = self.field_bare_type(field);
+        self.append_line(&format!("{}: {}::Lazy::new(|raw| {}::{}(raw, {}::new)),", id, runtime, runtime, parse, bare_type));
+        continue;
+      }
+      let empty = match field.get_label() {
+        LabelRepeated => vec_new.as_str(),
+        LabelOptional | LabelRequired => "None"
+      };
+      self.append_line(&format!("{}: {},", id, empty));
+    }
+    self.append_line(&format!("unknown_fields: {},", vec_new));
+    self.indent -= 1;
+    self.append_line("}");
+    self.indent -= 1;
+    self.append_line("}");
+    self.append_line("");
+
+    // Unset message fields borrow a shared empty instance from `get_`.
+    self.append_line(&format!("pub fn default_instance() -> &'static {} {{", static_name));
+    self.indent += 1;
+    self.append_line(&format!("static INSTANCE: {} = {}::new();", static_name, name));
+    self.append_line("&INSTANCE");
+    self.indent -= 1;
+    self.append_line("}")
+  }
+
+  fn translate_accessors(&mut self, field: &FieldDescriptorProto) {
+    // Accessor names are prefixed, so only the field itself needs escaping.
+    let name = snake_case(field.get_name());
+    let id = snake_identifier(field.get_name());
+    let bare_type = self.field_bare_type(field);
+    let field_type = field.get_type();
+
+    if self.is_lazy(field) {
+      return self.translate_lazy_accessors(field);
+    }
+    if self.options.arena {
+      return self.translate_arena_accessors(field);
+    }
+    if field.get_label() == LabelRepeated {
+      self.append_line(&format!("pub fn get_{}(&self) -> &[{}] {{", name, bare_type));
+      self.append_line(&format!("{}&self.{}", self.indent_str, id));
+      self.append_line("}");
+      self.append_line("");
+      self.append_line(&format!("pub fn set_{}(&mut self, value: Vec<{}>) {{", name, bare_type));
+      self.append_line(&format!("{}self.{} = value;", self.indent_str, id));
+      self.append_line("}");
+      self.append_line("");
+      self.append_line(&format!("pub fn clear_{}(&mut self) {{", name));
+      self.append_line(&format!("{}self.{}.clear();", self.indent_str, id));
+      self.append_line("}");
+      self.append_line("");
+      self.append_line(&format!("pub fn mut_{}(&mut self) -> &mut Vec<{}> {{", name, bare_type));
+      self.append_line(&format!("{}&mut self.{}", self.indent_str, id));
+      self.append_line("}");
+      self.append_line("");
+      self.append_line(&format!("pub fn take_{}(&mut self) -> Vec<{}> {{", name, bare_type));
+      self.append_line(&format!("{}core::mem::take(&mut self.{})", self.indent_str, id));
+      self.append_line("}");
+      self.append_line("");
+      self.append_line(&format!("pub fn add_{}(&mut self, value: {}) {{", name, bare_type));
+      self.append_line(&format!("{}self.{}.push(value);", self.indent_str, id));
+      self.append_line("}");
+      self.append_line("");
+      self.append_line(&format!("pub fn {}_len(&self) -> usize {{", name));
+      self.append_line(&format!("{}self.{}.len()", self.indent_str, id));
+      return self.append_line("}");
+    }
+
+    let default_value = self.field_default_value(field);
+    // The expressions reading the field, making it present and taking it
+    // while it may be unset.
+    let (get_type, get_body, insert, take) = match field_type {
+      TypeString => ("&str".to_string(),
+                     format!("self.{}.as_deref().unwrap_or({})", id, default_value),
+                     format!("get_or_insert_with(|| {})", self.owned_string(&default_value)),
+                     format!("unwrap_or_else(|| {})", self.owned_string(&default_value))),
+      TypeBytes => ("&[u8]".to_string(),
+                    format!("self.{}.as_deref().unwrap_or({})", id, default_value),
+                    format!("get_or_insert_with(|| {})", self.owned_bytes(&default_value)),
+                    format!("unwrap_or_else(|| {})", self.owned_bytes(&default_value))),
+      TypeMessage => (format!("&{}", bare_type),
+                      format!("match self.{} {{ Some(ref value) => value, None => {}::default_instance() }}", id, bare_type),
+                      format!("get_or_insert_with({}::new)", bare_type),
+                      format!("unwrap_or_else({}::new)", bare_type)),
+      _ => (bare_type.clone(),
+            format!("self.{}.unwrap_or({})", id, default_value),
+            format!("get_or_insert({})", default_value),
+            format!("unwrap_or({})", default_value))
+    };
+
+    self.append_line(&format!("pub fn has_{}(&self) -> bool {{", name));
+    self.append_line(&format!("{}self.{}.is_some()", self.indent_str, id));
+    self.append_line("}");
+    self.append_line("");
+    self.append_line(&format!("pub fn get_{}(&self) -> {} {{", name, get_type));
+    self.append_line(&format!("{}{}", self.indent_str, get_body));
+    self.append_line("}");
+    self.append_line("");
+    self.append_line(&format!("pub fn set_{}(&mut self, value: {}) {{", name, bare_type));
+    self.append_line(&format!("{}self.{} = Some(value);", self.indent_str, id));
+    self.append_line("}");
+    self.append_line("");
+    self.append_line(&format!("pub fn clear_{}(&mut self) {{", name));
+    self.append_line(&format!("{}self.{} = None;", self.indent_str, id));
+    self.append_line("}");
+    self.append_line("");
+    self.append_line(&format!("pub fn mut_{}(&mut self) -> &mut {} {{", name, bare_type));
+    self.append_line(&format!("{}self.{}.{}", self.indent_str, id, insert));
+    self.append_line("}");
+    self.append_line("");
+    self.append_line(&format!("pub fn take_{}(&mut self) -> {} {{", name, bare_type));
+    self.append_line(&format!("{}self.{}.take().{}", self.indent_str, id, take));
+    self.append_line("}")
+  }
+
+  // The accessors of lazy fields have the same signatures, and parse the
+  // field when they need its contents.
+  fn translate_lazy_accessors(&mut self, field: &FieldDescriptorProto) {
+    let name = snake_case(field.get_name());
+    let id = snake_identifier(field.get_name());
+    let bare_type = self.field_bare_type(field);
+    let (get_type, full_type) = match field.get_label() {
+      LabelRepeated => (format!("&[{}]", bare_type), format!("Vec<{}>", bare_type)),
+      LabelOptional | LabelRequired => (format!("&{}", bare_type), bare_type.clone())
+    };
+    let accessors = match field.get_label() {
+      LabelRepeated => vec![
+        (format!("get_{}(&self) -> {}", name, get_type), format!("self.{}.get()", id)),
+        (format!("set_{}(&mut self, value: {})", name, full_type), format!("self.{}.set(value);", id)),
+        (format!("clear_{}(&mut self)", name), format!("self.{}.clear();", id)),
+        (format!("mut_{}(&mut self) -> &mut {}", name, full_type), format!("self.{}.get_mut()", id)),
+        (format!("take_{}(&mut self) -> {}", name, full_type), format!("self.{}.take()", id)),
+        (format!("add_{}(&mut self, value: {})", name, bare_type), format!("self.{}.get_mut().push(value);", id)),
+        (format!("{}_len(&self) -> usize", name), format!("self.{}.get().len()", id))
+      ],
+      LabelOptional | LabelRequired => vec![
+        (format!("has_{}(&self) -> bool", name), format!("self.{}.is_set()", id)),
+        (format!("get_{}(&self) -> {}", name, get_type),
+         format!("match *self.{}.get() {{ Some(ref value) => value, None => {}::default_instance() }}", id, bare_type)),
+        (format!("set_{}(&mut self, value: {})", name, full_type), format!("self.{}.set(Some(value));", id)),
+        (format!("clear_{}(&mut self)", name), format!("self.{}.clear();", id)),
+        (format!("mut_{}(&mut self) -> &mut {}", name, full_type), format!("self.{}.get_mut().get_or_insert_with({}::new)", id, bare_type)),
+        (format!("take_{}(&mut self) -> {}", name, full_type), format!("self.{}.take().unwrap_or_else({}::new)", id, bare_type))
+      ]
+    };
+    for (i, (signature, body)) in accessors.iter().enumerate() {
+      if i > 0 {
+        self.append_line("");
+      }
+      self.append_line(&format!("pub fn {} {{", signature));
+      self.append_line(&format!("{}{}", self.indent_str, body));
+      self.append_line("}");
+    }
+  }
+
+  // Arena messages can't be built without the arena, so the accessors
+  // adding to or making them take it. Strings and bytes are set to slices
+  // that live as long as it, such as literals or copies made in it.
+  fn translate_arena_accessors(&mut self, field: &FieldDescriptorProto) {
+    let name = snake_case(field.get_name());
+    let id = snake_identifier(field.get_name());
+    let bare_type = self.field_bare_type(field);
+    let value_type = self.arena_bare_type(field);
+    let runtime = self.runtime_path();
+    let arena = format!("arena: &'a {}::Arena", runtime);
+    let accessors = if field.get_label() == LabelRepeated {
+      vec![
+        (format!("get_{}(&self) -> &[{}]", name, value_type), format!("&self.{}", id)),
+        (format!("clear_{}(&mut self)", name), format!("self.{}.clear();", id)),
+        (format!("mut_{}(&mut self) -> &mut [{}]", name, value_type), format!("&mut self.{}", id)),
+        (format!("add_{}(&mut self, {}, value: {})", name, arena, value_type), format!("self.{}.push(arena, value);", id)),
+        (format!("{}_len(&self) -> usize", name), format!("self.{}.len()", id))
+      ]
+    } else if field.get_type() == TypeMessage {
+      vec![
+        (format!("has_{}(&self) -> bool", name), format!("self.{}.is_some()", id)),
+        (format!("get_{}(&self) -> &{}", name, value_type),
+         format!("match self.{} {{ Some(ref value) => value, None => {}::default_instance() }}", id, bare_type)),
+        (format!("set_{}(&mut self, {}, value: {})", name, arena, value_type),
+         format!("self.{} = Some({}::ArenaBox::new_in(arena, value));", id, runtime)),
+        (format!("clear_{}(&mut self)", name), format!("self.{} = None;", id)),
+        (format!("mut_{}(&mut self, {}) -> &mut {}", name, arena, value_type),
+         format!("self.{}.get_or_insert_with(|| {}::ArenaBox::new_in(arena, {}::new()))", id, runtime, bare_type))
+      ]
+    } else {
+      let default_value = self.field_default_value(field);
+      let mut accessors = vec![
+        (format!("has_{}(&self) -> bool", name), format!("self.{}.is_some()", id)),
+        (format!("get_{}(&self) -> {}", name, value_type), format!("self.{}.unwrap_or({})", id, default_value)),
+        (format!("set_{}(&mut self, value: {})", name, value_type), format!("self.{} = Some(value);", id)),
+        (format!("clear_{}(&mut self)", name), format!("self.{} = None;", id))
+      ];
+      // A string or bytes field is changed by setting it to another slice.
+      if field.get_type() != TypeString && field.get_type() != TypeBytes {
+        accessors.push((format!("mut_{}(&mut self) -> &mut {}", name, value_type),
+                        format!("self.{}.get_or_insert({})", id, default_value)));
+      }
+      accessors.push((format!("take_{}(&mut self) -> {}", name, value_type),
+                      format!("self.{}.take().unwrap_or({})", id, default_value)));
+      accessors
+    };
+    for (i, (signature, body)) in accessors.iter().enumerate() {
+      if i > 0 {
+        self.append_line("");
+      }
+      self.append_line(&format!("pub fn {} {{", signature));
+      self.append_line(&format!("{}{}", self.indent_str, body));
+      self.append_line("}");
+    }
+  }
+
+  // The expression converting the decoded wire value `value` into the
+  // field's bare type.
+  fn translate_wire_value(&mut self, field: &FieldDescriptorProto, value: &str) -> String {
+    match field.get_type() {
+      TypeInt32 | TypeSfixed32 => format!("{} as i32", value),
+      TypeInt64 | TypeSfixed64 => format!("{} as i64", value),
+      TypeUint32 => format!("{} as u32", value),
+      TypeSint32 => format!("DecodeZigZag32({})", value),
+      TypeSint64 => format!("DecodeZigZag64({})", value),
+      TypeBool => format!("{} != 0", value),
+      TypeFloat => format!("DecodeFloat({})", value),
+      TypeDouble => format!("DecodeDouble({})", value),
+      TypeBytes if self.options.arena => value.to_string(),
+      TypeBytes => match self.options.bytes_type.as_str() {
+        "Box<[u8]>" => format!("{}.into_vec().into_boxed_slice()", value),
+        "Bytes" => value.to_string(),
+        _ => format!("{}.into_vec()", value)
+      },
+      _ => value.to_string()
+    }
+  }
+
+  fn translate_field_impl(&mut self, field: &FieldDescriptorProto) {
+    let name = snake_case(field.get_name());
+    let tag = field.get_number();
+    let label = field.get_label();
+    let field_type = field.get_type();
+    // Arena messages decode from ArenaValues, and take the arena to grow.
+    let arena = self.options.arena;
+    let value_type = if arena { "ArenaValue" } else { "TaggedValue" };
+    let (store, push_unknown) = match label {
+      LabelRepeated if arena => (format!("add_{}(arena, ", name), "push(arena, ArenaValue"),
+      LabelRepeated => (format!("add_{}(", name), "push(TaggedValue"),
+      LabelOptional | LabelRequired if arena => (format!("set_{}(", name), "push(arena, ArenaValue"),
+      LabelOptional | LabelRequired => (format!("set_{}(", name), "push(TaggedValue")
+    };
+
+    match field_type {
+      TypeString | TypeBytes | TypeMessage => {
+        self.append_line(&format!("{}::Raw({}, value) => {{", value_type, tag));
+      }
+      TypeFixed32 | TypeSfixed32 | TypeFloat => {
+        self.append_line(&format!("{}::Fixed32({}, value) => {{", value_type, tag));
+      }
+      TypeFixed64 | TypeSfixed64 | TypeDouble => {
+        self.append_line(&format!("{}::Fixed64({}, value) => {{", value_type, tag));
+      }
+      TypeInt32 | TypeInt64 | TypeSint32 | TypeSint64 | TypeUint32 | TypeUint64 | TypeBool => {
+        self.append_line(&format!("{}::Varint({}, value) => {{", value_type, tag));
+      }
+      TypeEnum => {
+        self.append_line(&format!("{}::Varint({}, value) => {{", value_type, tag));
+      }
+      TypeGroup => unreachable!()
+    }
+    self.indent += 1;
+
+    // A lazy field keeps the occurrence unless it has been changed since
+    // decoding, and then it is merged like an eager one.
+    let lazy = self.is_lazy(field);
+    if lazy {
+      self.append_line(&format!("if let Err(value) = self.{}.push_raw(value) {{", snake_identifier(field.get_name())));
+      self.indent += 1;
+    }
+    match field_type {
+      TypeMessage if arena => {
+        if label == LabelRepeated {
+          let bare_type = self.field_bare_type(field);
+          self.append_line(&format!("let mut message = {}::new();", bare_type));
+          self.append_line("if !message.DecodePartialIn(arena, value) {");
+          self.append_line(&format!("{}return false;", self.indent_str));
+          self.append_line("}");
+          self.append_line(&format!("self.{}message);", store));
+        } else {
+          self.append_line(&format!("if !self.mut_{}(arena).DecodePartialIn(arena, value) {{", name));
+          self.append_line(&format!("{}return false;", self.indent_str));
+          self.append_line("}");
+        }
+      }
+      // A message is decoded out of the payload's Bytes when its fields
+      // share it: those of the Bytes types, and messages, which would each
+      // be copied again out of a slice. Otherwise a slice saves the
+      // reference count.
+      TypeMessage => {
+        let nests_messages = self.lookup_message(field.get_type_name()).is_some_and(|message| {
+          message.get_field().iter().any(|field| field.get_type() == TypeMessage)
+        });
+        if nests_messages || self.options.string_type == "BytesStr" || self.options.bytes_type == "Bytes" {
+          self.append_line("let mut reader = value;");
+        } else {
+          self.append_line("let mut reader: &[u8] = &value;");
+        }
+        if label == LabelRepeated {
+          let bare_type = self.field_bare_type(field);
+          self.append_line(&format!("let mut message = {}::new();", bare_type));
+          self.append_line("if !message.DecodePartial(&mut reader) {");
+          self.append_line(&format!("{}return false;", self.indent_str));
+          self.append_line("}");
+          self.append_line(&format!("self.{}message);", store));
+        } else {
+          // A repeated occurrence of a singular message merges into it.
+          self.append_line(&format!("if !self.mut_{}().DecodePartial(&mut reader) {{", name));
+          self.append_line(&format!("{}return false;", self.indent_str));
+          self.append_line("}");
+        }
+      }
+      // Numbers the enum doesn't know are kept rather than failing decode.
+      TypeEnum => {
+        let enum_type = self.field_bare_type(field);
+        self.append_line(&format!("match {}::from_i32(value as i32) {{", enum_type));
+        self.append_line(&format!("{}Some(value) => self.{}value),", self.indent_str, store));
+        self.append_line(&format!("{}None => self.unknown_fields.{}::Varint({}, value))", self.indent_str, push_unknown, tag));
+        self.append_line("}");
+      }
+      // Invalid UTF-8 fails the decode like any other malformed input.
+      TypeString => {
+        let (check, converted) = match self.options.string_type.as_str() {
+          _ if arena => ("core::str::from_utf8(value)".to_string(), "value"),
+          "Box<str>" => ("String::from_utf8(value.into_vec())".to_string(), "value.into_boxed_str()"),
+          "BytesStr" => (format!("{}::BytesStr::from_utf8(value)", self.runtime_path()), "value"),
+          _ => ("String::from_utf8(value.into_vec())".to_string(), "value")
+        };
+        self.append_line(&format!("match {} {{", check));
+        self.append_line(&format!("{}Ok(value) => self.{}{}),", self.indent_str, store, converted));
+        self.append_line(&format!("{}Err(_) => return false", self.indent_str));
+        self.append_line("}");
+      }
+      _ => {
+        let converted = self.translate_wire_value(field, "value");
+        self.append_line(&format!("self.{}{});", store, converted));
+      }
+    }
+    if lazy {
+      self.indent -= 1;
+      self.append_line("}");
+    }
+    self.indent -= 1;
+    self.append_line("}");
+
+    if label != LabelRepeated {
+      return;
+    }
+    let (unpack, unpack_in) = match field_type {
+      TypeFixed32 | TypeSfixed32 | TypeFloat => ("DecodePackedFixed32", "UnpackFixed32"),
+      TypeFixed64 | TypeSfixed64 | TypeDouble => ("DecodePackedFixed64", "UnpackFixed64"),
+      TypeInt32 | TypeInt64 | TypeSint32 | TypeSint64 | TypeUint32 | TypeUint64 | TypeBool | TypeEnum => {
+        ("DecodePackedVarints", "UnpackVarints")
+      }
+      _ => return
+    };
+    self.append_line(&format!("{}::Raw({}, packed) => {{", value_type, tag));
+    self.indent += 1;
+    // Arena fields take each element as it is unpacked, rather than from a
+    // Vec of them all.
+    if arena {
+      if field_type == TypeEnum {
+        let enum_type = self.field_bare_type(field);
+        self.append_line(&format!("let unpacked = {}(packed, |value| {{", unpack_in));
+        self.indent += 1;
+        self.append_line(&format!("match {}::from_i32(value as i32) {{", enum_type));
+        self.append_line(&format!("{}Some(value) => self.{}value),", self.indent_str, store));
+        self.append_line(&format!("{}None => self.unknown_fields.{}::Varint({}, value))", self.indent_str, push_unknown, tag));
+        self.append_line("}");
+        self.indent -= 1;
+        self.append_line("});");
+        self.append_line("if !unpacked {");
+      } else {
+        let converted = self.translate_wire_value(field, "value");
+        self.append_line(&format!("if !{}(packed, |value| self.{}{})) {{", unpack_in, store, converted));
+      }
+      self.append_line(&format!("{}return false;", self.indent_str));
+      self.append_line("}");
+      self.indent -= 1;
+      return self.append_line("}");
+    }
+    self.append_line(&format!("match {}(&packed) {{", unpack));
+    self.indent += 1;
+    self.append_line("Some(values) => {");
+    self.indent += 1;
+    self.append_line("for value in values.iter() {");
+    if field_type == TypeEnum {
+      let enum_type = self.field_bare_type(field);
+      self.indent += 1;
+      self.append_line(&format!("match {}::from_i32(*value as i32) {{", enum_type));
+      self.append_line(&format!("{}Some(value) => self.{}value),", self.indent_str, store));
+      self.append_line(&format!("{}None => self.unknown_fields.{}::Varint({}, *value))", self.indent_str, push_unknown, tag));
+      self.append_line("}");
+      self.indent -= 1;
+    } else {
+      let converted = self.translate_wire_value(field, "*value");
+      self.append_line(&format!("{}self.{}{});", self.indent_str, store, converted));
+    }
+    self.append_line("}");
+    self.indent -= 1;
+    self.append_line("}");
+    self.append_line("None => return false");
+    self.indent -= 1;
+    self.append_line("}");
+    self.indent -= 1;
+    self.append_line("}")
+  }
+
+  fn translate_field(&mut self, field: &FieldDescriptorProto) {
+    if self.options.arena {
+      let bare_type = self.arena_bare_type(field);
+      let runtime = self.runtime_path();
+      let full_type = match field.get_label() {
+        LabelRepeated => format!("{}::RepeatedField<'a, {}>", runtime, bare_type),
+        LabelOptional | LabelRequired if field.get_type() == TypeMessage => {
+          format!("Option<{}::ArenaBox<'a, {}>>", runtime, bare_type)
+        }
+        LabelOptional | LabelRequired => format!("Option<{}>", bare_type)
+      };
+      self.translate_comments();
+      return self.append_line(&format!("{}: {},", snake_identifier(field.get_name()), full_type));
+    }
+    let bare_type = self.field_bare_type(field);
+
+    // Singular fields are optional in storage whatever their label so that
+    // presence can be tracked.
+    let mut full_type = match field.get_label() {
+      LabelRepeated => format!("Vec<{}>", bare_type),
+      LabelOptional | LabelRequired => format!("Option<{}>", bare_type)
+    };
+    if self.is_lazy(field) {
+      full_type = format!("{}::Lazy<{}>", self.runtime_path(), full_type);
+    }
+
+    let id = snake_identifier(field.get_name());
+    self.translate_comments();
+    if self.options.generate_serde {
+      self.translate_serde_field_attributes(field, &id);
+    }
+    self.append_line(&format!("{}: {},", id, full_type))
+  }
+
+  fn translate_enum(&mut self, descriptor: &EnumDescriptorProto) {
+    if !self.check_enum(descriptor) {
+      return;
+    }
+    let name = camel_identifier(descriptor.get_name());
+
+    // Rust enums cannot repeat a discriminant, so with allow_alias every
+    // later name for a number becomes a constant aliasing the first.
+    // Values keep their index in the descriptor for finding their comments.
+    let mut variants: Vec<(usize, &EnumValueDescriptorProto)> = Vec::new();
+    let mut aliases: Vec<(usize, &EnumValueDescriptorProto, &EnumValueDescriptorProto)> = Vec::new();
+    for (i, value) in descriptor.get_value().iter().enumerate() {
+      match variants.iter().find(|(_, v)| v.get_number() == value.get_number()) {
+        Some(&(_, original)) => aliases.push((i, value, original)),
+        None => variants.push((i, value))
+      }
+    }
+
+    self.translate_comments();
+    self.translate_derives(&["Clone", "Copy", "PartialEq", "Eq", "Debug"]);
+    self.append_line(&format!("pub enum {} {{", name));
+    self.indent += 1;
+    for &(i, value) in variants.iter() {
+      self.enter(kEnumValueField, i);
+      self.translate_comments();
+      self.leave();
+      self.append_line(&format!("{} = {},", camel_identifier(value.get_name()), value.get_number()));
+    }
+    self.indent -= 1;
+    self.append_line("}");
+    self.append_line("");
+    // Variants are named on their own, as C-like enum values are in .proto
+    // files.
+    let variant_names: Vec<String> = variants.iter().map(|&(_, value)| camel_identifier(value.get_name())).collect();
+    self.append_line(&format!("pub use self::{}::{{{}}};", name, variant_names.join(", ")));
+    self.append_line("");
+
+    for &(i, alias, original) in aliases.iter() {
+      self.enter(kEnumValueField, i);
+      self.translate_comments();
+      self.leave();
+      self.append_line(&format!("pub const {}: {} = {};", enum_value_identifier(descriptor, alias.get_name()),
+                                name, camel_identifier(original.get_name())));
+    }
+    if !aliases.is_empty() {
+      self.append_line("");
+    }
+
+    self.append_line(&format!("impl {} {{", name));
+    self.indent += 1;
+    self.append_line(&format!("pub fn from_i32(value: i32) -> Option<{}> {{", name));
+    self.indent += 1;
+    self.append_line("match value {");
+    self.indent += 1;
+    for &(_, value) in variants.iter() {
+      self.append_line(&format!("{} => Some({}),", value.get_number(), camel_identifier(value.get_name())));
+    }
+    self.append_line("_ => None");
+    self.indent -= 1;
+    self.append_line("}");
+    self.indent -= 1;
+    self.append_line("}");
+    self.append_line("");
+
+    self.append_line("pub fn value(&self) -> i32 {");
+    self.append_line(&format!("{}*self as i32", self.indent_str));
+    self.append_line("}");
+    self.append_line("");
+
+    self.append_line("pub fn name(&self) -> &'static str {");
+    self.indent += 1;
+    self.append_line("match *self {");
+    self.indent += 1;
+    for &(_, value) in variants.iter() {
+      self.append_line(&format!("{} => \"{}\",", camel_identifier(value.get_name()), value.get_name()));
+    }
+    self.indent -= 1;
+    self.append_line("}");
+    self.indent -= 1;
+    self.append_line("}");
+    self.append_line("");
+
+    self.append_line(&format!("pub fn from_name(name: &str) -> Option<{}> {{", name));
+    self.indent += 1;
+    self.append_line("match name {");
+    self.indent += 1;
+    for value in descriptor.get_value().iter() {
+      self.append_line(&format!("\"{}\" => Some({}),", value.get_name(),
+                                enum_value_identifier(descriptor, value.get_name())));
+    }
+    self.append_line("_ => None");
+    self.indent -= 1;
+    self.append_line("}");
+    self.indent -= 1;
+    self.append_line("}");
+    self.indent -= 1;
+    self.append_line("}");
+    self.append_line("");
+
+    // The first listed value is the default, as in proto2.
+    self.append_line(&format!("impl Default for {} {{", name));
+    self.indent += 1;
+    self.append_line(&format!("fn default() -> {} {{", name));
+    self.append_line(&format!("{}{}", self.indent_str, camel_identifier(descriptor.get_value()[0].get_name())));
+    self.append_line("}");
+    self.indent -= 1;
+    self.append_line("}");
+    self.append_line("");
+
+    if self.options.generate_json {
+      self.append_line(&format!("impl ToJson for {} {{", name));
+      self.indent += 1;
+      self.append_line("fn to_json(&self) -> Json {");
+      self.append_line(&format!("{}Json::String(self.name().to_string())", self.indent_str));
+      self.append_line("}");
+      self.indent -= 1;
+      self.append_line("}");
+      self.append_line("");
+    }
+    if self.options.generate_serde {
+      self.translate_serde_enum_impls(&name);
+      self.append_line("");
+    }
+  }
+
+  // Fields go by their names in the .proto, and unset ones are left out
+  // rather than written as null or [].
+  fn translate_serde_field_attributes(&mut self, field: &FieldDescriptorProto, id: &str) {
+    if id != field.get_name() {
+      self.append_line(&format!("#[serde(rename = \"{}\")]", field.get_name()));
+    }
+    let is_unset = match field.get_label() {
+      LabelRepeated => "Vec::is_empty",
+      LabelOptional | LabelRequired => "Option::is_none"
+    };
+    self.append_line(&format!("#[serde(default, skip_serializing_if = \"{}\")]", is_unset));
+  }
+
+  // Written by hand rather than derived so that an enum goes by its value
+  // names, as in the .proto, or by its numbers. Deserializing takes the
+  // same form as serializing writes.
+  fn translate_serde_enum_impls(&mut self, name: &str) {
+    self.append_line(&format!("impl serde::Serialize for {} {{", name));
+    self.indent += 1;
+    self.append_line("fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {");
+    if self.options.serde_enum_numbers {
+      self.append_line(&format!("{}serializer.serialize_i32(self.value())", self.indent_str));
+    } else {
+      self.append_line(&format!("{}serializer.serialize_str(self.name())", self.indent_str));
+    }
+    self.append_line("}");
+    self.indent -= 1;
+    self.append_line("}");
+    self.append_line("");
+
+    self.append_line(&format!("impl<'de> serde::Deserialize<'de> for {} {{", name));
+    self.indent += 1;
+    self.append_line(&format!("fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<{}, D::Error> {{", name));
+    self.indent += 1;
+    if self.options.serde_enum_numbers {
+      self.append_line("let value = <i32 as serde::Deserialize>::deserialize(deserializer)?;");
+      self.append_line(&format!("{}::from_i32(value).ok_or_else(|| {{", name));
+      self.append_line(&format!("{}serde::de::Error::invalid_value(serde::de::Unexpected::Signed(value as i64), &\"a {} number\")",
+                                self.indent_str, name));
+      self.append_line("})");
+    } else {
+      // A visitor, so that a name needn't be copied out of the input.
+      self.append_line("struct NameVisitor;");
+      self.append_line("");
+      self.append_line("impl serde::de::Visitor<'_> for NameVisitor {");
+      self.indent += 1;
+      self.append_line(&format!("type Value = {};", name));
+      self.append_line("");
+      self.append_line("fn expecting(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {");
+      self.append_line(&format!("{}f.write_str(\"a {} name\")", self.indent_str, name));
+      self.append_line("}");
+      self.append_line("");
+      self.append_line(&format!("fn visit_str<E: serde::de::Error>(self, name: &str) -> Result<{}, E> {{", name));
+      self.append_line(&format!("{}{}::from_name(name).ok_or_else(|| E::invalid_value(serde::de::Unexpected::Str(name), &self))",
+                                self.indent_str, name));
+      self.append_line("}");
+      self.indent -= 1;
+      self.append_line("}");
+      self.append_line("");
+      self.append_line("deserializer.deserialize_str(NameVisitor)");
+    }
+    self.indent -= 1;
+    self.append_line("}");
+    self.indent -= 1;
+    self.append_line("}");
+  }
+
+  fn translate_descriptor_impl(&mut self, descriptor: &DescriptorProto) {
+    let name = camel_identifier(descriptor.get_name());
+    if self.options.arena {
+      let header = self.impl_header("ArenaMessage<'a>", &name);
+      self.append_line(&header);
+      self.indent += 1;
+      self.append_line(&format!("fn DecodePartialIn(&mut self, arena: &'a {}::Arena, data: &'a [u8]) -> bool {{", self.runtime_path()));
+      self.indent += 1;
+      self.append_line("let mut tags = ArenaTagIter::new(data);");
+    } else {
+      self.append_line(&format!("impl Protobuf for {} {{", name));
+      self.indent += 1;
+      self.append_line("fn DecodePartial(&mut self, reader: &mut dyn Reader) -> bool {");
+      self.indent += 1;
+      self.append_line("let mut tags = TagIter::new(reader);");
+    }
+    self.append_line("for tag_option in tags.by_ref() {");
+    self.indent += 1;
+    self.append_line("match tag_option {");
+    self.indent += 1;
+    for field in descriptor.get_field().iter() {
+      self.translate_field_impl(field);
+    }
+    if self.options.arena {
+      self.append_line("unknown => self.unknown_fields.push(arena, unknown)");
+    } else {
+      self.append_line("unknown => self.unknown_fields.push(unknown)");
+    }
+    self.indent -= 1;
+    self.append_line("}");
+    self.indent -= 1;
+    self.append_line("}");
+    self.append_line("!tags.is_malformed()");
+    self.indent -= 1;
+    self.append_line("}");
+    self.append_line("");
+    self.translate_missing_fields(descriptor);
+    self.indent -= 1;
+    self.append_line("}")
+  }
+
+  fn translate_missing_fields(&mut self, descriptor: &DescriptorProto) {
+    // The contents of lazy fields are never checked, only their presence.
+    let checked: Vec<&FieldDescriptorProto> = descriptor.get_field().iter().filter(|field| {
+      field.get_label() == LabelRequired || (field.get_type() == TypeMessage && !self.is_lazy(field))
+    }).collect();
+    if checked.is_empty() {
+      self.append_line("fn AppendMissingFields(&self, _prefix: &str, _missing: &mut Vec<String>) {");
+      return self.append_line("}");
+    }
+
+    self.append_line("fn AppendMissingFields(&self, prefix: &str, missing: &mut Vec<String>) {");
+    self.indent += 1;
+    for field in checked.iter() {
+      let name = field.get_name();
+      let id = snake_identifier(name);
+      let label = field.get_label();
+      if self.is_lazy(field) {
+        self.append_line(&format!("if !self.{}.is_set() {{", id));
+        self.append_line(&format!("{}missing.push(format!(\"{{}}{}\", prefix));", self.indent_str, name));
+        self.append_line("}");
+        continue;
+      }
+      if field.get_type() != TypeMessage {
+        self.append_line(&format!("if self.{}.is_none() {{", id));
+        self.append_line(&format!("{}missing.push(format!(\"{{}}{}\", prefix));", self.indent_str, name));
+        self.append_line("}");
+        continue;
+      }
+      // Paths are prefixed only once something is missing, so checking a
+      // complete tree allocates nothing.
+      if label == LabelRepeated {
+        self.append_line(&format!("for (i, value) in self.{}.iter().enumerate() {{", id));
+        self.indent += 1;
+        self.append_line("for path in value.missing_fields() {");
+        self.append_line(&format!("{}missing.push(format!(\"{{}}{}[{{}}].{{}}\", prefix, i, path));", self.indent_str, name));
+        self.append_line("}");
+        self.indent -= 1;
+        self.append_line("}");
+        continue;
+      }
+      self.append_line(&format!("match self.{} {{", id));
+      self.indent += 1;
+      self.append_line("Some(ref value) => {");
+      self.indent += 1;
+      self.append_line("for path in value.missing_fields() {");
+      self.append_line(&format!("{}missing.push(format!(\"{{}}{}.{{}}\", prefix, path));", self.indent_str, name));
+      self.append_line("}");
+      self.indent -= 1;
+      self.append_line("}");
+      if label == LabelRequired {
+        self.append_line(&format!("None => missing.push(format!(\"{{}}{}\", prefix))", name));
+      } else {
+        self.append_line("None => ()");
+      }
+      self.indent -= 1;
+      self.append_line("}");
+    }
+    self.indent -= 1;
+    self.append_line("}")
+  }
+
+  fn translate_encode_impl(&mut self, descriptor: &DescriptorProto) {
+    let mut fields: Vec<&FieldDescriptorProto> = descriptor.get_field().iter().collect();
+    fields.sort_by_key(|field| field.get_number());
+
+    let header = self.impl_header("ProtobufEncode", &camel_identifier(descriptor.get_name()));
+    self.append_line(&header);
+    self.indent += 1;
+    self.append_line("fn Encode(&self, writer: &mut dyn Writer) -> Result<(), EncodeError> {");
+    self.indent += 1;
+    for field in fields.iter() {
+      let id = snake_identifier(field.get_name());
+      let tag = field.get_number();
+      let encode = match field.get_type() {
+        TypeInt32 => format!("EncodeVarintField(writer, {}, *value as i64 as u64)", tag),
+        TypeInt64 | TypeUint32 => format!("EncodeVarintField(writer, {}, *value as u64)", tag),
+        TypeUint64 => format!("EncodeVarintField(writer, {}, *value)", tag),
+        TypeSint32 => format!("EncodeVarintField(writer, {}, EncodeZigZag32(*value))", tag),
+        TypeSint64 => format!("EncodeVarintField(writer, {}, EncodeZigZag64(*value))", tag),
+        TypeBool => format!("EncodeVarintField(writer, {}, *value as u64)", tag),
+        TypeEnum => format!("EncodeVarintField(writer, {}, value.value() as i64 as u64)", tag),
+        TypeFixed32 => format!("EncodeFixed32Field(writer, {}, *value)", tag),
+        TypeSfixed32 => format!("EncodeFixed32Field(writer, {}, *value as u32)", tag),
+        TypeFloat => format!("EncodeFixed32Field(writer, {}, EncodeFloat(*value))", tag),
+        TypeFixed64 => format!("EncodeFixed64Field(writer, {}, *value)", tag),
+        TypeSfixed64 => format!("EncodeFixed64Field(writer, {}, *value as u64)", tag),
+        TypeDouble => format!("EncodeFixed64Field(writer, {}, EncodeDouble(*value))", tag),
+        TypeString => format!("EncodeRawField(writer, {}, value.as_bytes())", tag),
+        TypeBytes => format!("EncodeRawField(writer, {}, value)", tag),
+        TypeMessage => format!("EncodeMessageField(writer, {}, value)", tag),
+        TypeGroup => unreachable!()
+      };
+      // An unchanged lazy field is written as it was decoded.
+      if self.is_lazy(field) {
+        self.append_line(&format!("match self.{}.raw() {{", id));
+        self.indent += 1;
+        self.append_line("Some(raw) => {");
+        self.append_line(&format!("{}for value in raw.iter() {{", self.indent_str));
+        self.append_line(&format!("{}{}EncodeRawField(writer, {}, value)?;", self.indent_str, self.indent_str, tag));
+        self.append_line(&format!("{}}}", self.indent_str));
+        self.append_line("}");
+        self.append_line("None => {");
+        self.append_line(&format!("{}for value in self.{}.get().iter() {{", self.indent_str, id));
+        self.append_line(&format!("{}{}{}?;", self.indent_str, self.indent_str, encode));
+        self.append_line(&format!("{}}}", self.indent_str));
+        self.append_line("}");
+        self.indent -= 1;
+        self.append_line("}");
+        continue;
+      }
+      // Option and Vec iterate the same way, so singular and repeated
+      // fields share one loop.
+      self.append_line(&format!("for value in self.{}.iter() {{", id));
+      self.append_line(&format!("{}{}?;", self.indent_str, encode));
+      self.append_line("}");
+    }
+    let encode_unknown = if self.options.arena { "EncodeArenaValue" } else { "EncodeTaggedValue" };
+    self.append_line("for unknown in self.unknown_fields.iter() {");
+    self.append_line(&format!("{}{}(writer, unknown)?;", self.indent_str, encode_unknown));
+    self.append_line("}");
+    self.append_line("Ok(())");
+    self.indent -= 1;
+    self.append_line("}");
+    self.indent -= 1;
+    self.append_line("}")
+  }
+
+  // Follows the proto3 JSON mapping where it can: 64-bit integers become
+  // strings, bytes become base64 and enums their value names.
+  fn translate_json_impl(&mut self, descriptor: &DescriptorProto) {
+    let header = self.impl_header("ToJson", &camel_identifier(descriptor.get_name()));
+    self.append_line(&header);
+    self.indent += 1;
+    self.append_line("fn to_json(&self) -> Json {");
+    self.indent += 1;
+    if descriptor.get_field().is_empty() {
+      self.append_line("let object = json::Object::new();");
+    } else {
+      self.append_line("let mut object = json::Object::new();");
+    }
+    for field in descriptor.get_field().iter() {
+      let name = field.get_name();
+      let storage = self.field_storage(field, "self");
+      let json = match field.get_type() {
+        TypeInt64 | TypeUint64 | TypeSint64 | TypeFixed64 | TypeSfixed64 => "Json::String(value.to_string())",
+        TypeString => "Json::String(value.to_string())",
+        TypeBytes => "Json::String(json::to_base64(value))",
+        _ => "value.to_json()"
+      };
+      if field.get_label() == LabelRepeated {
+        self.append_line(&format!("if !{}.is_empty() {{", storage));
+        self.append_line(&format!("{}object.insert(\"{}\".to_string(), Json::List({}.iter().map(|value| {}).collect()));",
+                                  self.indent_str, name, storage, json));
+      } else {
+        self.append_line(&format!("for value in {}.iter() {{", storage));
+        self.append_line(&format!("{}object.insert(\"{}\".to_string(), {});", self.indent_str, name, json));
+      }
+      self.append_line("}");
+    }
+    self.append_line("Json::Object(object)");
+    self.indent -= 1;
+    self.append_line("}");
+    self.indent -= 1;
+    self.append_line("}")
+  }
+
+  fn translate_reflection_impl(&mut self, descriptor: &DescriptorProto) {
+    let name = descriptor.get_name();
+    let header = self.impl_header("Reflect", &camel_identifier(name));
+    self.append_line(&header);
+    self.indent += 1;
+    self.append_line("fn message_info(&self) -> &'static MessageInfo {");
+    self.indent += 1;
+    self.append_line("static INFO: MessageInfo = MessageInfo {");
+    self.indent += 1;
+    let full_name = self.full_name(name);
+    self.append_line(&format!("full_name: \"{}\",", full_name));
+    self.append_line("fields: &[");
+    self.indent += 1;
+    for field in descriptor.get_field().iter() {
+      let type_name = match field.get_type() {
+        TypeMessage | TypeEnum => field.get_type_name().trim_start_matches('.').to_string(),
+        other => other.to_proto_str()
+      };
+      let label = field.get_label();
+      // Types of lite runtime files have no reflection to link to.
+      let message = match field.get_type() {
+        TypeMessage if self.lookup_file(field.get_type_name()).unwrap().get_options().get_optimize_for() != LiteRuntime =>
+          format!("Some(|| {}::default_instance().message_info())", self.field_bare_type(field)),
+        _ => "None".to_string()
+      };
+      self.append_line(&format!("FieldInfo {{ name: \"{}\", number: {}, type_name: \"{}\", repeated: {}, required: {}, message: {} }},",
+                                field.get_name(), field.get_number(), type_name,
+                                label == LabelRepeated, label == LabelRequired, message));
+    }
+    self.indent -= 1;
+    self.append_line("]");
+    self.indent -= 1;
+    self.append_line("};");
+    self.append_line("&INFO");
+    self.indent -= 1;
+    self.append_line("}");
+    self.indent -= 1;
+    self.append_line("}")
+  }
+
+  // Paths name fields as the .proto file does. The elements of repeated
+  // message fields can be trimmed by subpaths, but are only copied whole.
+  fn translate_field_mask_impl(&mut self, descriptor: &DescriptorProto) {
+    let runtime = self.runtime_path();
+    let fields = descriptor.get_field();
+    let through_repeated = if fields.iter().any(|field| field.get_type() == TypeMessage) {
+      "through_repeated"
+    } else {
+      "_through_repeated"
+    };
+    self.append_line(&format!("impl FieldMasked for {} {{", camel_identifier(descriptor.get_name())));
+    self.indent += 1;
+    self.append_line(&format!("fn IsMaskPath(path: &[&str], {}: bool) -> bool {{", through_repeated));
+    self.indent += 1;
+    self.append_line("match path {");
+    self.indent += 1;
+    for field in fields.iter() {
+      let name = field.get_name();
+      if field.get_type() != TypeMessage {
+        self.append_line(&format!("[\"{}\"] => true,", name));
+        continue;
+      }
+      let bare_type = self.field_bare_type(field);
+      let repeated = if field.get_label() == LabelRepeated { "through_repeated && " } else { "" };
+      self.append_line(&format!("[\"{}\", rest @ ..] => rest.is_empty() || {}{}::IsMaskPath(rest, through_repeated),",
+                                name, repeated, bare_type));
+    }
+    self.append_line("_ => false");
+    self.indent -= 1;
+    self.append_line("}");
+    self.indent -= 1;
+    self.append_line("}");
+    self.append_line("");
+
+    self.append_line(&format!("fn CopyMasked(&mut self, from: &Self, mask: &{}::FieldMask, merge: bool) {{", runtime));
+    self.indent += 1;
+    for field in fields.iter() {
+      let name = field.get_name();
+      let id = snake_identifier(name);
+      let from = self.field_storage(field, "from");
+      let to = self.field_storage_mut(field);
+      if field.get_label() == LabelRepeated {
+        self.append_line(&format!("if mask.field(\"{}\").is_some() {{", name));
+        self.indent += 1;
+        self.append_line("if merge {");
+        self.append_line(&format!("{}{}.extend({}.iter().cloned());", self.indent_str, to, from));
+        self.append_line("} else {");
+        self.append_line(&format!("{}self.{} = from.{}.clone();", self.indent_str, id, id));
+        self.append_line("}");
+        self.indent -= 1;
+        self.append_line("}");
+        continue;
+      }
+      if field.get_type() != TypeMessage {
+        self.append_line(&format!("if mask.field(\"{}\").is_some() && (!merge || from.{}.is_some()) {{", name, id));
+        self.append_line(&format!("{}self.{} = from.{}.clone();", self.indent_str, id, id));
+        self.append_line("}");
+        continue;
+      }
+      // Without `merge`, the masked subfields of a message missing from
+      // `from` are cleared.
+      let bare_type = self.field_bare_type(field);
+      self.append_line(&format!("if let Some(mask) = mask.field(\"{}\") {{", name));
+      self.indent += 1;
+      self.append_line("if mask.is_all() && !merge {");
+      self.append_line(&format!("{}self.{} = from.{}.clone();", self.indent_str, id, id));
+      self.append_line(&format!("}} else if let Some(value) = {}.as_ref() {{", from));
+      self.append_line(&format!("{}{}.get_or_insert_with({}::new).CopyMasked(value, mask, merge);",
+                                self.indent_str, to, bare_type));
+      self.append_line("} else if !merge {");
+      self.indent += 1;
+      self.append_line(&format!("if let Some(value) = {}.as_mut() {{", to));
+      self.append_line(&format!("{}value.CopyMasked({}::default_instance(), mask, merge);", self.indent_str, bare_type));
+      self.append_line("}");
+      self.indent -= 1;
+      self.append_line("}");
+      self.indent -= 1;
+      self.append_line("}");
+    }
+    self.append_line("if merge && mask.is_all() {");
+    self.append_line(&format!("{}self.unknown_fields.extend(from.unknown_fields.iter().cloned());", self.indent_str));
+    self.append_line("}");
+    self.indent -= 1;
+    self.append_line("}");
+    self.append_line("");
+
+    self.append_line(&format!("fn ClearUnmasked(&mut self, mask: &{}::FieldMask) {{", runtime));
+    self.indent += 1;
+    self.append_line("if mask.is_all() {");
+    self.append_line(&format!("{}return;", self.indent_str));
+    self.append_line("}");
+    for field in fields.iter() {
+      let name = field.get_name();
+      let clear = format!("self.clear_{}()", snake_case(name));
+      if field.get_type() != TypeMessage {
+        self.append_line(&format!("if mask.field(\"{}\").is_none() {{", name));
+        self.append_line(&format!("{}{};", self.indent_str, clear));
+        self.append_line("}");
+        continue;
+      }
+      let to = self.field_storage_mut(field);
+      self.append_line(&format!("match mask.field(\"{}\") {{", name));
+      self.indent += 1;
+      self.append_line("Some(mask) if mask.is_all() => (),");
+      self.append_line("Some(mask) => {");
+      self.indent += 1;
+      self.append_line(&format!("for value in {}.iter_mut() {{", to));
+      self.append_line(&format!("{}value.ClearUnmasked(mask);", self.indent_str));
+      self.append_line("}");
+      self.indent -= 1;
+      self.append_line("}");
+      self.append_line(&format!("None => {}", clear));
+      self.indent -= 1;
+      self.append_line("}");
+    }
+    self.append_line("self.unknown_fields.clear();");
+    self.indent -= 1;
+    self.append_line("}");
+    self.indent -= 1;
+    self.append_line("}")
+  }
+
+  // The dotted name of a type declared in the scope currently being
+  // generated.
+  fn full_name(&self, name: &str) -> String {
+    let mut components = Vec::new();
+    if let Some(package) = self.current_package.as_ref() {
+      components.push(package.clone());
+    }
+    for scope in self.scopes.iter() {
+      components.push(scope.clone());
+    }
+    components.push(name.to_string());
+    components.join(".")
+  }
+
+  fn translate_derives(&mut self, defaults: &[&str]) {
+    let mut derives: Vec<String> = defaults.iter().map(|d| d.to_string()).collect();
+    for derive in self.options.derives.iter() {
+      if !derives.contains(derive) {
+        derives.push(derive.clone());
+      }
+    }
+    if derives.is_empty() {
+      return;
+    }
+    self.append_line(&format!("#[derive({})]", derives.join(", ")))
+  }
+
+  fn translate_descriptor(&mut self, descriptor: &DescriptorProto) {
+    if !self.check_descriptor(descriptor) {
+      return;
+    }
+    let name = camel_identifier(descriptor.get_name());
+    self.translate_comments();
+    let mut derives = Vec::new();
+    if self.options.generate_serde {
+      derives.extend(["serde::Serialize", "serde::Deserialize"]);
+    }
+    if self.options.generate_field_mask {
+      derives.push("Clone");
+    }
+    self.translate_derives(&derives);
+    let (full_name, unknown_type) = if self.options.arena {
+      (format!("{}<'a>", name), format!("{}::RepeatedField<'a, ArenaValue<'a>>", self.runtime_path()))
+    } else {
+      (name.clone(), "Vec<TaggedValue>".to_string())
+    };
+    self.append_line(&format!("pub struct {} {{", full_name));
+    self.indent += 1;
+    for (i, field) in descriptor.get_field().iter().enumerate() {
+      self.enter(kMessageFieldField, i);
+      self.translate_field(field);
+      self.leave();
+    }
+    if self.options.generate_serde {
+      self.append_line("#[serde(skip)]");
+    }
+    self.append_line(&format!("unknown_fields: {},", unknown_type));
+    self.indent -= 1;
+    self.append_line("}");
+    self.append_line("");
+
+    if self.options.arena {
+      self.append_line(&format!("impl<'a> {} {{", full_name));
+    } else {
+      self.append_line(&format!("impl {} {{", name));
+    }
+    self.indent += 1;
+    self.translate_constructor(descriptor);
+    for field in descriptor.get_field().iter() {
+      self.append_line("");
+      self.translate_accessors(field);
+    }
+    self.append_line("");
+    self.append_line("// Fields this message's schema doesn't know, and enum numbers outside");
+    self.append_line("// the enum, in the order they were decoded.");
+    let unknown_value = if self.options.arena { "ArenaValue<'a>" } else { "TaggedValue" };
+    self.append_line(&format!("pub fn get_unknown_fields(&self) -> &[{}] {{", unknown_value));
+    self.append_line(&format!("{}&self.unknown_fields", self.indent_str));
+    self.append_line("}");
+    self.indent -= 1;
+    self.append_line("}");
+    self.append_line("");
+
+    if descriptor.nested_type_len() > 0 || descriptor.enum_type_len() > 0 {
+      // Named apart from the struct, which shares the module namespace.
+      self.append_line(&format!("pub mod {} {{", snake_identifier(descriptor.get_name())));
+      self.indent += 1;
+      self.scopes.push(descriptor.get_name().to_string());
+      self.check_scope(descriptor.get_nested_type(), descriptor.get_enum_type(), &[], &[]);
+      self.translate_imports();
+      for (i, ty) in descriptor.get_nested_type().iter().enumerate() {
+        self.enter(kMessageNestedTypeField, i);
+        self.translate_descriptor(ty);
+        self.leave();
+      }
+      for (i, ty) in descriptor.get_enum_type().iter().enumerate() {
+        self.enter(kMessageEnumTypeField, i);
+        self.translate_enum(ty);
+        self.leave();
+      }
+      self.scopes.pop();
+      self.indent -= 1;
+      self.append_line("}");
+      self.append_line("");
+    }
+
+    self.translate_descriptor_impl(descriptor);
+    if self.options.generate_encode {
+      self.append_line("");
+      self.translate_encode_impl(descriptor);
+    }
+    if self.options.generate_json {
+      self.append_line("");
+      self.translate_json_impl(descriptor);
+    }
+    if self.options.generate_reflection {
+      self.append_line("");
+      self.translate_reflection_impl(descriptor);
+    }
+    if self.options.generate_field_mask {
+      self.append_line("");
+      self.translate_field_mask_impl(descriptor);
+    }
+    self.append_line("")
+  }
+
+  // Steps into element `index` of the repeated descriptor field `field_number`
+  // of the element being generated.
+  fn enter(&mut self, field_number: i32, index: usize) {
+    self.path.push(field_number);
+    self.path.push(index as i32);
+  }
+
+  fn leave(&mut self) {
+    self.path.pop();
+    self.path.pop();
+  }
+
+  fn translate_comments(&mut self) {
+    let comment = match self.comments.get(&self.path) {
+      Some(comment) => comment.clone(),
+      None => return
+    };
+    for line in comment.lines() {
+      self.append_line(line);
+    }
+  }
+
+  // The runtime is named from every module the generated code opens; a
+  // bare crate name is made absolute so that a module of the same name,
+  // such as the one of a `package protobuf;`, can't shadow it.
+  fn runtime_path(&self) -> String {
+    if self.options.runtime_crate.contains("::") {
+      self.options.runtime_crate.clone()
+    } else {
+      format!("::{}", self.options.runtime_crate)
+    }
+  }
+
+  fn translate_imports(&mut self) {
+    let runtime = self.runtime_path();
+    self.append_line("#[allow(unused_imports)]");
+    self.append_line(&format!("use {}::{{", runtime));
+    self.indent += 1;
+    self.append_line("Protobuf, Reader, TagIter, TaggedValue,");
+    if self.options.arena {
+      self.append_line("ArenaMessage, ArenaTagIter, ArenaValue, UnpackVarints, UnpackFixed32, UnpackFixed64,");
+    }
+    self.append_line("DecodeZigZag32, DecodeZigZag64, DecodeFloat, DecodeDouble,");
+    self.append_line("DecodePackedVarints, DecodePackedFixed32, DecodePackedFixed64,");
+    if self.options.generate_encode {
+      self.append_line("ProtobufEncode, Writer, EncodeError,");
+      self.append_line("EncodeTaggedValue, EncodeMessageField, EncodeRawField,");
+      if self.options.arena {
+        self.append_line("EncodeArenaValue,");
+      }
+      self.append_line("EncodeVarintField, EncodeFixed32Field, EncodeFixed64Field,");
+      self.append_line("EncodeZigZag32, EncodeZigZag64, EncodeFloat, EncodeDouble,");
+    }
+    if self.options.generate_json {
+      self.append_line("json, Json, ToJson,");
+    }
+    if self.options.generate_reflection {
+      self.append_line("Reflect, MessageInfo, FieldInfo,");
+    }
+    if self.options.generate_field_mask {
+      self.append_line("FieldMasked,");
+    }
+    self.indent -= 1;
+    self.append_line("};");
+    self.append_line("#[allow(unused_imports)]");
+    self.append_line(&format!("use {}::prelude::*;", runtime));
+    self.append_line("")
+  }
+
+  fn pad(&self, line: &str) -> String {
+    let mut buf = String::new();
+    for _ in 0..self.indent {
+      buf.push_str(&self.indent_str);
+    }
+    buf.push_str(line);
+    buf
+  }
+
+  fn append_line(&mut self, line: &str) {
+    assert!(!line.ends_with('\n'));
+    if !line.is_empty() {
+      let padded = self.pad(line);
+      self.buf.push_str(&padded);
+    }
+    self.buf.push('\n');
+  }
+
+  fn translate_file(&mut self, proto: &FileDescriptorProto) {
+    self.current_file = if proto.has_name() { proto.get_name().to_string() } else { "<unnamed>".to_string() };
+    self.current_package = if proto.has_package() { Some(proto.get_package().to_string()) } else { None };
+    self.visible_files = visible_files(self.request.get_proto_file(), &self.current_file);
+    self.comments = HashMap::new();
+    for location in proto.get_source_code_info().get_location().iter() {
+      let lines = doc_comment_lines(location.get_leading_comments(), location.get_trailing_comments());
+      if !lines.is_empty() {
+        self.comments.insert(location.get_path().to_vec(), lines.join("\n"));
+      }
+    }
+    for dependency in proto.get_dependency().iter() {
+      if !self.request.get_proto_file().iter().any(|f| f.get_name() == dependency) {
+        let element = self.current_file.clone();
+        self.error(&element, &format!("imports {}, which is not in the request", dependency));
+      }
+    }
+    self.indent = 0;
+    // The lite runtime has no descriptors to reflect on, so code generated
+    // for it has no reflection either.
+    let generate_reflection = self.options.generate_reflection;
+    if proto.get_options().get_optimize_for() == LiteRuntime {
+      self.options.generate_reflection = false;
+    }
+    self.append_line(&format!("// Generated by protoc-gen-rust from {}. Do not edit.", self.current_file));
+    self.append_line("");
+
+    if proto.has_package() {
+      let package_path_components: Vec<String> = proto.get_package().split('.').map(|p| p.to_string()).collect();
+      self.translate_package(proto, &package_path_components);
+    } else {
+      self.translate_file_items(proto);
+    }
+    self.options.generate_reflection = generate_reflection;
+  }
+
+  fn translate_package(&mut self, proto: &FileDescriptorProto, package_path_components: &[String]) {
+    self.append_line(&format!("pub mod {} {{", escape_keyword(package_path_components[0].clone())));
+    self.indent += 1;
+    if package_path_components.len() > 1 {
+      self.translate_package(proto, &package_path_components[1..]);
+    } else {
+      self.translate_file_items(proto);
+    }
+    self.indent -= 1;
+    self.append_line("}");
+  }
+
+  fn translate_file_items(&mut self, proto: &FileDescriptorProto) {
+    self.check_scope(proto.get_message_type(), proto.get_enum_type(), proto.get_extension(), proto.get_service());
+    self.translate_imports();
+    for (i, message_type) in proto.get_message_type().iter().enumerate() {
+      self.enter(kFileMessageTypeField, i);
+      self.translate_descriptor(message_type);
+      self.leave();
+    }
+    for (i, enum_type) in proto.get_enum_type().iter().enumerate() {
+      self.enter(kFileEnumTypeField, i);
+      self.translate_enum(enum_type);
+      self.leave();
+    }
+    for (i, extension) in proto.get_extension().iter().enumerate() {
+      self.enter(kFileExtensionField, i);
+      self.translate_extension(extension);
+      self.leave();
+    }
+    for (i, service) in proto.get_service().iter().enumerate() {
+      self.enter(kFileServiceField, i);
+      self.translate_service(service);
+      self.leave();
+    }
+  }
+
+  // Extensions aren't given accessors: their values stay in the extendee's
+  // unknown fields, where the field number finds them.
+  fn translate_extension(&mut self, extension: &FieldDescriptorProto) {
+    if !extension.has_name() || !extension.has_number() || !extension.has_extendee() {
+      let element = self.full_name(if extension.has_name() { extension.get_name() } else { "<unnamed>" });
+      self.error(&element, "extension needs a name, a number and an extendee");
+      return;
+    }
+    self.translate_comments();
+    self.append_line(&format!("// Extends {}.", extension.get_extendee().trim_start_matches('.')));
+    self.append_line(&format!("pub const {}: u32 = {};", extension_constant(extension.get_name()),
+                              extension.get_number()));
+    self.append_line("")
+  }
+
+  // A service becomes a trait for its implementations to provide, one
+  // method per rpc. In arena mode the response is built in the arena the
+  // request was decoded into.
+  fn translate_service(&mut self, service: &ServiceDescriptorProto) {
+    if !self.check_service(service) {
+      return;
+    }
+    self.translate_comments();
+    self.append_line(&format!("pub trait {} {{", camel_identifier(service.get_name())));
+    self.indent += 1;
+    for (i, method) in service.get_method().iter().enumerate() {
+      let input = self.type_path(method.get_input_type());
+      let output = self.type_path(method.get_output_type());
+      self.enter(kServiceMethodField, i);
+      self.translate_comments();
+      self.leave();
+      if self.options.arena {
+        self.append_line(&format!("fn {}<'a>(&mut self, arena: &'a {}::Arena, request: &{}<'a>) -> Result<{}<'a>, String>;",
+                                  snake_identifier(method.get_name()), self.runtime_path(), input, output));
+        continue;
+      }
+      self.append_line(&format!("fn {}(&mut self, request: &{}) -> Result<{}, String>;",
+                                snake_identifier(method.get_name()), input, output));
+    }
+    self.indent -= 1;
+    self.append_line("}");
+    self.append_line("")
+  }
+
+  // Generates one output file per requested .proto, in request order.
+  fn translate(&mut self) -> CodeGeneratorResponse {
+    let mut response = CodeGeneratorResponse::new();
+    response.set_supported_features(FeatureProto3Optional.value() as u64);
+    match parse_generator_options(self.request.get_parameter()) {
+      Ok(options) => self.options = options,
+      Err(message) => {
+        response.set_error(message);
+        return response;
+      }
+    }
+    for proto_file in self.request.get_proto_file().iter() {
+      if !self.request.get_file_to_generate().iter().any(|name| name == proto_file.get_name()) {
+        continue;
+      }
+      self.translate_file(proto_file);
+      let mut file = code_generator_response::File::new();
+      file.set_name(rs_file_name(&self.current_file));
+      file.set_content(std::mem::take(&mut self.buf));
+      response.add_file(file);
+    }
+    if !self.errors.is_empty() {
+      response.set_error(self.errors.join("\n"));
+    }
+    response
+  }
+}
+
+#[test]
+fn test_identifier_collisions() {
+  fn field(name: &str, number: i32) -> FieldDescriptorProto {
+    let mut field = FieldDescriptorProto::new();
+    field.set_name(name.to_string());
+    field.set_number(number);
+    field.set_label(LabelOptional);
+    field.set_type(TypeInt32);
+    field
+  }
+
+  let mut message = DescriptorProto::new();
+  message.set_name("Vote".to_string());
+  message.add_field(field("VoteGranted", 1));
+  message.add_field(field("vote_granted", 2));
+  message.add_field(field("match", 3));
+  let mut nested = DescriptorProto::new();
+  nested.set_name("vote".to_string());
+  let mut proto = FileDescriptorProto::new();
+  proto.set_name("vote.proto".to_string());
+  proto.set_package("raft".to_string());
+  proto.add_message_type(message);
+  proto.add_message_type(nested);
+  let mut request = CodeGeneratorRequest::new();
+  request.add_file_to_generate("vote.proto".to_string());
+  request.add_proto_file(proto);
+
+  let response = ProtobufGenerator::new(&request).translate();
+  let errors: Vec<&str> = response.get_error().lines().collect();
+  assert_eq!(errors, ["vote.proto: raft.vote: is generated as Vote, like raft.Vote",
+                      "vote.proto: raft.Vote.vote_granted: is generated as vote_granted, like raft.Vote.VoteGranted"]);
+}
+
+// Without protoc: "protoc-gen-rust -I DIR --rust_out=[OPTIONS:]DIR FILE..."
+// parses the files itself, naming them as they would be imported, and
+// writes the generated code under the output directory.
+fn compile(args: &[String]) -> Result<(), String> {
+  let mut codegen = codegen::Codegen::new();
+  let mut out_dir = false;
+  let mut files = 0;
+  let mut i = 0;
+  while i < args.len() {
+    let arg = args[i].as_str();
+    if arg == "-I" {
+      i += 1;
+      if i == args.len() {
+        return Err("-I needs a directory".to_string());
+      }
+      codegen.include(&args[i]);
+    } else if let Some(dir) = arg.strip_prefix("--proto_path=") {
+      codegen.include(dir);
+    } else if let Some(dir) = arg.strip_prefix("-I") {
+      codegen.include(dir);
+    } else if let Some(out) = arg.strip_prefix("--rust_out=") {
+      let dir = match out.rfind(':') {
+        Some(colon) => {
+          codegen.option(&out[..colon]);
+          &out[colon + 1..]
+        }
+        None => out
+      };
+      codegen.out_dir(dir);
+      out_dir = true;
+    } else if arg.starts_with('-') {
+      return Err(format!("unknown flag: {}", arg));
+    } else {
+      codegen.file(arg);
+      files += 1;
+    }
+    i += 1;
+  }
+  if !out_dir {
+    return Err("missing --rust_out".to_string());
+  }
+  if files == 0 {
+    return Err("no input files".to_string());
+  }
+  codegen.run().map(|_| ()).map_err(|error| error.to_string())
+}
+
+// "protoc-gen-rust diff -I DIR FILE.proto... --type=NAME A B" lists the
+// differences between the messages encoded in files A and B, by a schema
+// from .proto files or from --descriptor_set_in=FILE. --ignore=PATH,
+// --repeated_as_sets and --float_tolerance=X set the DiffOptions.
+fn diff_files(args: &[String]) -> Result<Vec<protobuf::Difference>, String> {
+  let mut include_paths = Vec::new();
+  let mut proto_files = Vec::new();
+  let mut descriptor_set = None;
+  let mut type_name = None;
+  let mut messages = Vec::new();
+  let mut options = protobuf::DiffOptions::default();
+  let mut i = 0;
+  while i < args.len() {
+    let arg = args[i].as_str();
+    if arg == "-I" {
+      i += 1;
+      if i == args.len() {
+        return Err("-I needs a directory".to_string());
+      }
+      include_paths.push(std::path::PathBuf::from(&args[i]));
+    } else if let Some(dir) = arg.strip_prefix("--proto_path=").or_else(|| arg.strip_prefix("-I")) {
+      include_paths.push(std::path::PathBuf::from(dir));
+    } else if let Some(file) = arg.strip_prefix("--descriptor_set_in=") {
+      descriptor_set = Some(file);
+    } else if let Some(name) = arg.strip_prefix("--type=") {
+      type_name = Some(name);
+    } else if let Some(path) = arg.strip_prefix("--ignore=") {
+      options.ignored.push(path.to_string());
+    } else if arg == "--repeated_as_sets" {
+      options.repeated_as_sets = true;
+    } else if let Some(tolerance) = arg.strip_prefix("--float_tolerance=") {
+      options.float_tolerance = match tolerance.parse() {
+        Ok(tolerance) => tolerance,
+        Err(_) => return Err(format!("bad float tolerance: {}", tolerance))
+      };
+    } else if arg.starts_with('-') {
+      return Err(format!("unknown flag: {}", arg));
+    } else if arg.ends_with(".proto") {
+      proto_files.push(arg.to_string());
+    } else {
+      messages.push(arg);
+    }
+    i += 1;
+  }
+  let type_name = match type_name {
+    Some(type_name) => type_name,
+    None => return Err("missing --type".to_string())
+  };
+  if messages.len() != 2 {
+    return Err("expected two message files".to_string());
+  }
+  let read = |file: &str| std::fs::read(file).map_err(|error| format!("cannot read {}: {}", file, error));
+  let schema = match descriptor_set {
+    Some(file) => diff::Schema::from_descriptor_set(&read(file)?),
+    None if proto_files.is_empty() => return Err("no .proto files or --descriptor_set_in".to_string()),
+    None if include_paths.is_empty() => diff::Schema::load(&proto_files, &[std::path::PathBuf::from(".")]),
+    None => diff::Schema::load(&proto_files, &include_paths)
+  };
+  let schema = schema.map_err(|error| error.to_string())?;
+  schema.diff(type_name, &read(messages[0])?, &read(messages[1])?, &options).map_err(|error| error.to_string())
+}
+
+// "protoc-gen-rust print [--file] DESCRIPTORS [OUT_DIR]" prints the .proto
+// source of an encoded FileDescriptorSet, or with --file of a single
+// FileDescriptorProto, into OUT_DIR under the files' names, or to stdout.
+fn print_files(args: &[String]) -> Result<(), String> {
+  let single = args.first().map(String::as_str) == Some("--file");
+  let args = if single { &args[1..] } else { args };
+  if args.is_empty() || args.len() > 2 || args.iter().any(|arg| arg.starts_with('-')) {
+    return Err("usage: protoc-gen-rust print [--file] DESCRIPTORS [OUT_DIR]".to_string());
+  }
+  let data = std::fs::read(&args[0]).map_err(|error| format!("cannot read {}: {}", args[0], error))?;
+  let files = if single {
+    printer::print_file_descriptor(&data).map(|source| vec![(String::new(), source)])
+  } else {
+    printer::print_descriptor_set(&data)
+  };
+  let files = files.map_err(|error| format!("cannot decode {}: {}", args[0], error))?;
+  for (name, source) in files.iter() {
+    let out_dir = match args.get(1) {
+      Some(out_dir) => std::path::Path::new(out_dir),
+      None => {
+        print!("{}", source);
+        continue;
+      }
+    };
+    let name = if name.is_empty() { "descriptor.proto" } else { name.as_str() };
+    let path = out_dir.join(name);
+    let written = std::fs::create_dir_all(path.parent().unwrap()).and_then(|_| std::fs::write(&path, source));
+    written.map_err(|error| format!("cannot write {}: {}", path.display(), error))?;
+  }
+  Ok(())
+}
+
+// The plugin's entry point, called from main.rs. With arguments it
+// compiles .proto files itself, diffs messages or prints descriptors;
+// without, protoc drives it through stdin and stdout.
+pub fn main() {
+  let args: Vec<String> = std::env::args().skip(1).collect();
+  // As diff(1) does, exits with 1 when the messages differ and 2 when they
+  // can't be compared.
+  if args.first().map(String::as_str) == Some("diff") {
+    match diff_files(&args[1..]) {
+      Ok(differences) => {
+        for difference in differences.iter() {
+          println!("{}", difference);
+        }
+        std::process::exit(if differences.is_empty() { 0 } else { 1 });
+      }
+      Err(error) => {
+        eprintln!("protoc-gen-rust: {}", error);
+        std::process::exit(2);
+      }
+    }
+  }
+  if args.first().map(String::as_str) == Some("print") {
+    if let Err(error) = print_files(&args[1..]) {
+      eprintln!("protoc-gen-rust: {}", error);
+      std::process::exit(1);
+    }
+    return;
+  }
+  if !args.is_empty() {
+    if let Err(error) = compile(&args) {
+      eprintln!("protoc-gen-rust: {}", error);
+      std::process::exit(1);
+    }
+    return;
+  }
+
+  let mut input = Vec::new();
+  let mut request = CodeGeneratorRequest::new();
+  // An unreadable request is protoc's problem rather than the .proto
+  // author's, so it goes to stderr instead of the response.
+  let decoded = match std::io::stdin().read_to_end(&mut input) {
+    Ok(_) => request.Decode(&mut input.as_slice()).map_err(|error| error.to_string()),
+    Err(error) => Err(error.to_string())
+  };
+  if let Err(error) = decoded {
+    eprintln!("protoc-gen-rust: cannot decode CodeGeneratorRequest: {}", error);
+    std::process::exit(1);
+  }
+  let mut gen = ProtobufGenerator::new(&request);
+  let response = gen.translate();
+  let mut output = Vec::new();
+  let written = response.Encode(&mut output).map_err(|error| error.to_string()).and_then(|_| {
+    let mut stdout = std::io::stdout();
+    stdout.write_all(&output).and_then(|_| stdout.flush()).map_err(|error| error.to_string())
+  });
+  // A closed stdout, as when protoc has already failed, isn't a bug.
+  if let Err(error) = written {
+    eprintln!("protoc-gen-rust: cannot write CodeGeneratorResponse: {}", error);
+    std::process::exit(1);
+  }
+}
+
+// A request like protoc's for `files` under `include`, run with
+// --rust_out=PARAMETER:DIR, built by this crate's parser so that it follows
+// the checked-in .proto files.
+#[cfg(test)]
+fn parsed_request(include: &str, files: &[&str], parameter: &str) -> CodeGeneratorRequest {
+  let files: Vec<String> = files.iter().map(|file| file.to_string()).collect();
+  let mut request = CodeGeneratorRequest::new();
+  request.set_file_to_generate(files.clone());
+  request.set_parameter(parameter.to_string());
+  request.set_proto_file(parser::load_files(&files, &[std::path::PathBuf::from(include)]).unwrap());
+  request
+}
+
+// The requests for parsed_request, each named for its snapshot directory,
+// with the include directory, the files and the parameter.
+#[cfg(test)]
+static kParsedRequests: &[(&str, &str, &[&str], &str)] = &[
+  ("ArenaRequest", ".", &["proto/append_entries_request.proto", "testdata/inventory.proto"],
+   "runtime_crate=::protobuf,arena,json"),
+  ("FieldMaskRequest", "testdata", &["raft_state.proto"], "field_mask,runtime_crate=::protobuf"),
+  ("JsonRequest", ".", &["proto/log_entry.proto", "testdata/metrics.proto"], "runtime_crate=::protobuf,json,reflection"),
+  ("LazyRequest", "testdata", &["batch.proto"], "runtime_crate=::protobuf,json"),
+  ("SerdeRequest", "testdata", &["inventory.proto"], "runtime_crate=::protobuf,serde"),
+  ("SharedBytesRequest", ".", &["proto/append_entries_request.proto"],
+   "runtime_crate=::protobuf,bytes_type=Bytes,string_type=BytesStr"),
+];
+
+// Snapshot tests: every request in testdata/, as captured from protoc, and
+// in kParsedRequests is run through the generator, and each generated file
+// is compared with its copy under testdata/golden/<request>/. After an
+// intended change to the output, run the tests with UPDATE_GOLDEN=1 to
+// rewrite the copies, and review the diff.
+#[test]
+fn test_golden_outputs() {
+  use std::fs;
+  use std::path::Path;
+  use protobuf::IoReader;
+
+  let update = std::env::var_os("UPDATE_GOLDEN").is_some();
+  let mut requests = Vec::new();
+  for entry in fs::read_dir("testdata").unwrap() {
+    let path = entry.unwrap().path();
+    if path.extension().and_then(|extension| extension.to_str()) != Some("pbf") {
+      continue;
+    }
+    let mut request = CodeGeneratorRequest::new();
+    assert!(request.Decode(&mut IoReader::new(fs::File::open(&path).unwrap())).is_ok());
+    requests.push((path.file_stem().unwrap().to_str().unwrap().to_string(), request));
+  }
+  assert!(!requests.is_empty());
+  for &(name, include, files, parameter) in kParsedRequests.iter() {
+    requests.push((name.to_string(), parsed_request(include, files, parameter)));
+  }
+  for (name, request) in requests.iter() {
+    let response = ProtobufGenerator::new(request).translate();
+    assert!(!response.has_error(), "{}: {}", name, response.get_error());
+    let golden_dir = Path::new("testdata/golden").join(name);
+    for file in response.get_file().iter() {
+      let golden = golden_dir.join(file.get_name());
+      if update {
+        fs::create_dir_all(golden.parent().unwrap()).unwrap();
+        fs::write(&golden, file.get_content()).unwrap();
+        continue;
+      }
+      let expected = fs::read_to_string(&golden).unwrap();
+      assert!(expected == file.get_content(),
+              "{} differs from the generated code; rerun with UPDATE_GOLDEN=1 if that is intended",
+              golden.display());
+    }
+  }
+}
+
+// The snapshots of RaftRequest.pbf are built into the tests, so they must
+// compile against this runtime. The request reaches the runtime through
+// "runtime_crate=::protobuf", the extern crate this one depends on.
+#[cfg(test)]
+#[allow(dead_code, clippy::all)]
+#[path="testdata/golden/RaftRequest/proto"]
+mod golden {
+  pub mod append_entries_request;
+  pub mod append_entries_responses;
+  pub mod log_entry;
+  pub mod request_vote_request;
+  pub mod request_vote_responses;
+}
+
+// CodeGenRequest.pbf asks for proto/log_entry.proto without parameters, so
+// its snapshot shows the default runtime path compiling too.
+#[cfg(test)]
+#[allow(dead_code, clippy::all)]
+#[path="testdata/golden/CodeGenRequest/proto/log_entry.rs"]
+mod golden_code_gen;
+
+// ArenaRequest generates the raft request and testdata/inventory.proto with
+// "arena".
+#[cfg(test)]
+#[allow(dead_code, clippy::all)]
+#[path="testdata/golden/ArenaRequest"]
+mod golden_arena {
+  #[path="proto/append_entries_request.rs"]
+  pub mod append_entries_request;
+  #[path="testdata/inventory.rs"]
+  pub mod inventory;
+}
+
+#[test]
+fn test_golden_arena_decodes_into_one_arena() {
+  use protobuf::{Arena, ArenaValue, DecodeIn, MissingRequiredFields};
+  use golden_arena::append_entries_request::protobuf::ProtoAppendEntriesRequest;
+  use golden_arena::append_entries_request::protobuf::proto_append_entries_request::ProtoLogEntry;
+  use golden_arena::inventory::inventory::{Item, Used};
+
+  let arena = Arena::new();
+  let mut request = ProtoAppendEntriesRequest::new();
+  request.set_term(2);
+  request.set_prev_log_index(6);
+  request.set_prev_log_term(1);
+  request.set_commit_index(5);
+  request.set_leader_name("n1");
+  for index in 0..100 {
+    let mut entry = ProtoLogEntry::new();
+    entry.set_index(index);
+    entry.set_term(2);
+    entry.set_command_name(arena.alloc_str(&format!("put{}", index)));
+    request.add_entries(&arena, entry);
+  }
+  let mut writer = Vec::new();
+  assert!(request.Encode(&mut writer).is_ok());
+
+  // The input is copied into the arena, so the tree doesn't borrow it.
+  let decoded = DecodeIn(&arena, &writer, ProtoAppendEntriesRequest::new()).unwrap();
+  drop(writer);
+  assert_eq!(decoded.entries_len(), 100);
+  assert_eq!(decoded.get_entries()[99].get_index(), 99);
+  assert_eq!(decoded.get_entries()[99].get_command_name(), "put99");
+  assert_eq!(decoded.get_leader_name(), "n1");
+
+  // Name "a", packed tags 1 and 2, packed history Used and the unknown 7,
+  // and location aisle "b".
+  let input = [0x0a, 0x01, b'a', 0x1a, 0x02, 0x01, 0x02, 0x32, 0x02, 0x01, 0x07, 0x2a, 0x03, 0x0a, 0x01, b'b'];
+  let item = DecodeIn(&arena, &input, Item::new()).unwrap();
+  assert_eq!(item.get_name(), "a");
+  assert_eq!(item.get_tags(), [1, 2]);
+  assert_eq!(item.get_history(), [Used]);
+  assert_eq!(item.get_unknown_fields(), [ArenaValue::Varint(6, 7)]);
+  assert_eq!(item.get_location().get_aisle(), "b");
+  assert!(!Item::new().has_location() && Item::new().get_location().get_aisle().is_empty());
+  assert!(DecodeIn(&arena, &input[..6], Item::new()).is_err());
+  assert_eq!(DecodeIn(&arena, &[], Item::new()).err(), Some(MissingRequiredFields(vec!["Name".to_string()])));
+}
+
+// LazyRequest generates testdata/batch.proto, whose entries are lazy.
+#[cfg(test)]
+#[allow(dead_code, clippy::all)]
+#[path="testdata/golden/LazyRequest/batch.rs"]
+mod golden_lazy;
+
+#[test]
+fn test_golden_lazy_fields() {
+  use golden_lazy::batch::Batch;
+
+  // Term 3, then an entry with its fields out of order (command 0xab, then
+  // index 9) and a last entry missing its required index.
+  let input: &[u8] = &[0x08, 0x03, 0x1a, 0x05, 0x12, 0x01, 0xab, 0x08, 0x09, 0x22, 0x00];
+  let mut batch = Batch::new();
+  assert!(batch.Decode(&mut &input[..]).is_ok());
+  assert_eq!(batch.get_term(), 3);
+  assert!(batch.has_last());
+  assert_eq!(batch.get_entries()[0].get_index(), 9);
+  assert_eq!(batch.get_entries()[0].get_command(), [0xab]);
+
+  // Read but unchanged, the entries are written back byte for byte.
+  let mut writer = Vec::new();
+  assert!(batch.Encode(&mut writer).is_ok());
+  assert_eq!(writer, input);
+
+  batch.mut_entries()[0].set_index(10);
+  let mut writer = Vec::new();
+  assert!(batch.Encode(&mut writer).is_ok());
+  assert_eq!(writer, [0x08, 0x03, 0x1a, 0x05, 0x08, 0x0a, 0x12, 0x01, 0xab, 0x22, 0x00]);
+
+  // A later occurrence merges into the changed field.
+  assert!(batch.DecodePartial(&mut &[0x1a, 0x02, 0x08, 0x0b][..]));
+  assert_eq!(batch.entries_len(), 2);
+  assert_eq!(batch.get_entries()[1].get_index(), 11);
+}
+
+// SerdeRequest generates testdata/inventory.proto with "serde".
+#[cfg(test)]
+#[allow(dead_code, clippy::all)]
+#[path="testdata/golden/SerdeRequest/inventory.rs"]
+mod golden_serde;
+
+// SharedBytesRequest generates the raft request with
+// "bytes_type=Bytes,string_type=BytesStr".
+#[cfg(test)]
+#[allow(dead_code, clippy::all)]
+#[path="testdata/golden/SharedBytesRequest/proto/append_entries_request.rs"]
+mod golden_shared_bytes;
+
+#[test]
+fn test_golden_shared_bytes_copy_nothing() {
+  use protobuf::Bytes;
+  use golden_shared_bytes::protobuf::ProtoAppendEntriesRequest;
+  use golden_shared_bytes::protobuf::proto_append_entries_request::ProtoLogEntry;
+
+  let mut entry = ProtoLogEntry::new();
+  entry.set_index(7);
+  entry.set_term(2);
+  entry.set_command_name("put".into());
+  entry.set_command(Bytes::from(vec![0xab; 1024]));
+  let mut request = ProtoAppendEntriesRequest::new();
+  request.set_term(2);
+  request.set_prev_log_index(6);
+  request.set_prev_log_term(1);
+  request.set_commit_index(5);
+  request.set_leader_name("n1".into());
+  request.add_entries(entry);
+  let mut writer = Vec::new();
+  assert!(request.Encode(&mut writer).is_ok());
+
+  let input = Bytes::from(writer.clone());
+  let mut decoded = ProtoAppendEntriesRequest::new();
+  assert!(decoded.Decode(&mut input.clone()).is_ok());
+  let command = decoded.get_entries()[0].get_command();
+  assert_eq!(command, &[0xab; 1024][..]);
+  // The payload is a slice of the input buffer, not a copy of it.
+  assert!(input.as_ptr_range().contains(&command.as_ptr()));
+  assert!(input.as_ptr_range().contains(&decoded.get_leader_name().as_ptr()));
+
+  let mut reencoded = Vec::new();
+  assert!(decoded.Encode(&mut reencoded).is_ok());
+  assert_eq!(reencoded, writer);
+}
+
+#[test]
+fn test_golden_serde_round_trips() {
+  use golden_serde::inventory::{Item, Location, Refurbished, Used};
+
+  let mut location = Location::new();
+  location.set_shelf(3);
+  let mut item = Item::new();
+  item.set_name("lamp".to_string());
+  item.set_condition(Used);
+  item.set_location(location);
+  item.add_history(Refurbished);
+  item.set_type(vec![1, 2]);
+  let text = serde_json::to_string(&item).unwrap();
+  assert_eq!(text, r#"{"Name":"lamp","condition":"USED","type":[1,2],"location":{"shelf":3},"history":["REFURBISHED"]}"#);
+
+  let decoded: Item = serde_json::from_str(&text).unwrap();
+  assert_eq!(decoded.get_name(), "lamp");
+  assert_eq!(decoded.get_condition(), Used);
+  assert!(!decoded.get_location().has_aisle());
+  assert_eq!(decoded.get_location().get_shelf(), 3);
+  assert_eq!(decoded.get_history(), [Refurbished]);
+  assert!(serde_json::from_str::<Item>(r#"{"condition":"BROKEN"}"#).is_err());
+  assert!(serde_json::from_str::<Item>(r#"{"condition":1}"#).is_err());
+}
+
+// FieldMaskRequest generates testdata/raft_state.proto with "field_mask".
+#[cfg(test)]
+#[allow(dead_code, clippy::all)]
+#[path="testdata/golden/FieldMaskRequest/raft_state.rs"]
+mod golden_field_mask;
+
+#[test]
+fn test_golden_field_masks() {
+  use protobuf::{FieldMask, FieldMaskError, FieldMasked};
+  use golden_field_mask::raft_state::{Entry, HardState, RaftState};
+
+  let entry = |index, term| {
+    let mut entry = Entry::new();
+    entry.set_index(index);
+    entry.set_term(term);
+    entry
+  };
+  let mut hard_state = HardState::new();
+  hard_state.set_term(2);
+  hard_state.set_voted_for("n1".to_string());
+  hard_state.set_commit(5);
+  let mut stored = RaftState::new();
+  stored.set_hard_state(hard_state);
+  stored.add_entries(entry(5, 2));
+  stored.add_peers("n2".to_string());
+  stored.set_snapshot(entry(4, 1));
+
+  let mut update = RaftState::new();
+  update.mut_hard_state().set_term(3);
+  update.add_entries(entry(6, 3));
+  update.set_peers(vec!["n2".to_string(), "n3".to_string()]);
+  let mask = FieldMask::parse::<RaftState>("hard_state.term,peers").unwrap();
+  assert!(stored.copy_masked(&update, &mask).is_ok());
+  assert_eq!((stored.get_hard_state().get_term(), stored.get_hard_state().get_voted_for()), (3, "n1"));
+  assert_eq!(stored.get_peers(), ["n2", "n3"]);
+  assert_eq!(stored.entries_len(), 1);
+
+  // Merging appends, and copying from a message without the field clears
+  // only the masked subfields.
+  let mask = FieldMask::parse::<RaftState>("entries,snapshot.term,hard_state.voted_for").unwrap();
+  assert!(stored.merge_masked(&update, &mask).is_ok());
+  assert_eq!(stored.get_entries().iter().map(|entry| entry.get_index()).collect::<Vec<_>>(), [5, 6]);
+  assert_eq!(stored.get_hard_state().get_voted_for(), "n1");
+  assert!(stored.copy_masked(&RaftState::new(), &mask).is_ok());
+  assert_eq!(stored.entries_len(), 0);
+  assert!(!stored.get_hard_state().has_voted_for());
+  assert!(!stored.get_snapshot().has_term() && stored.get_snapshot().has_index());
+
+  let mask = FieldMask::parse::<RaftState>("entries.term,hard_state.commit").unwrap();
+  assert_eq!(stored.copy_masked(&update, &mask), Err(FieldMaskError::RepeatedSubpath("entries.term".to_string())));
+  stored.add_entries(entry(7, 3));
+  let trimmed = stored.trimmed(&mask).unwrap();
+  assert!(!trimmed.has_snapshot() && trimmed.get_peers().is_empty());
+  assert_eq!(trimmed.get_hard_state().get_commit(), 5);
+  assert!(!trimmed.get_hard_state().has_term());
+  assert_eq!(trimmed.get_entries()[0].get_term(), 3);
+  assert!(!trimmed.get_entries()[0].has_index());
+
+  assert_eq!(FieldMask::parse::<RaftState>("hard_state.leader"),
+             Err(FieldMaskError::InvalidPath("hard_state.leader".to_string())));
+  assert!(FieldMask::parse::<RaftState>("peers.name").is_err());
+}
+
+// JsonRequest generates the raft log entry and testdata/metrics.proto with
+// "json,reflection", which DiffMessages takes.
+#[cfg(test)]
+#[allow(dead_code, clippy::all)]
+#[path="testdata/golden/JsonRequest"]
+mod golden_json {
+  #[path="proto/log_entry.rs"]
+  pub mod log_entry;
+  #[path="testdata/metrics.rs"]
+  pub mod metrics;
+}
+
+#[test]
+fn test_golden_diffs() {
+  use protobuf::{Change, DiffMessages, DiffOptions, Json};
+  use golden_json::log_entry::protobuf::ProtoLogEntry;
+  use golden_json::metrics::metrics::{End, Sample, Series, Window};
+
+  let mut a = ProtoLogEntry::new();
+  a.set_index(7);
+  a.set_term(2);
+  a.set_command_name("put".to_string());
+  a.set_command(vec![1, 2]);
+  let mut b = ProtoLogEntry::new();
+  b.set_index(7);
+  b.set_term(3);
+  b.set_command_name("put".to_string());
+  let differences = DiffMessages(&a, &b, &DiffOptions::default());
+  assert_eq!(differences.iter().map(|difference| difference.to_string()).collect::<Vec<_>>(),
+             ["- Command: \"AQI=\"", "~ Term: \"2\" -> \"3\""]);
+  assert_eq!(differences[1].change, Change::Changed(Json::String("2".to_string()), Json::String("3".to_string())));
+
+  // Their bytes and the .proto file give the same differences.
+  let dir = std::env::temp_dir().join(format!("diff-{}", std::process::id()));
+  std::fs::create_dir_all(&dir).unwrap();
+  for (name, entry) in [("a.bin", &a), ("b.bin", &b)] {
+    let mut data = Vec::new();
+    assert!(entry.Encode(&mut data).is_ok());
+    std::fs::write(dir.join(name), data).unwrap();
+  }
+  let args = |extra: &[&str]| -> Vec<String> {
+    let mut args = vec!["proto/log_entry.proto".to_string(), "--type=protobuf.ProtoLogEntry".to_string()];
+    args.push(dir.join("a.bin").display().to_string());
+    args.push(dir.join("b.bin").display().to_string());
+    args.extend(extra.iter().map(|arg| arg.to_string()));
+    args
+  };
+  assert_eq!(diff_files(&args(&[])).unwrap(), differences);
+  assert_eq!(diff_files(&args(&["--ignore=Command", "--ignore=Term"])).unwrap(), []);
+  assert_eq!(diff_files(&args(&["--type=protobuf.Missing"])).unwrap_err(), "unknown message type protobuf.Missing");
+  assert!(diff_files(&args(&["--float_tolerance=x"])).is_err());
+  std::fs::remove_dir_all(&dir).unwrap();
+
+  // Reflection tells the float and double fields, nested ones too, apart
+  // from the integers the tolerance doesn't apply to.
+  let series = |value: f64, count: u32, window_scale: f32| {
+    let mut sample = Sample::new();
+    sample.set_value(value);
+    sample.set_count(count);
+    let mut window = Window::new();
+    window.set_scale(window_scale);
+    let mut series = Series::new();
+    series.add_samples(sample);
+    series.set_window(window);
+    series
+  };
+  let options = DiffOptions { float_tolerance: 0.001, ..DiffOptions::default() };
+  let differences = DiffMessages(&series(1.0, 3, 0.25), &series(1.0000001, 4, 0.2500001), &options);
+  assert_eq!(differences.into_iter().map(|difference| difference.path).collect::<Vec<_>>(), ["samples[0].count"]);
+  let options = DiffOptions { float_tolerance: 2.0, ..DiffOptions::default() };
+  assert_eq!(DiffMessages(&series(1.0, 3, 0.25), &series(1.0, 4, 0.25), &options).len(), 1);
+  assert_eq!(DiffMessages(&End::new(), &End::new(), &options), []);
+}
+
+#[test]
+fn test_golden_code_round_trips() {
+  use protobuf::Reflect;
+  use golden::append_entries_request::protobuf::ProtoAppendEntriesRequest;
+  use golden::append_entries_request::protobuf::proto_append_entries_request::ProtoLogEntry;
+
+  let mut entry = ProtoLogEntry::new();
+  entry.set_index(7);
+  entry.set_term(2);
+  entry.set_command_name("nop".to_string());
+  let mut request = ProtoAppendEntriesRequest::new();
+  request.set_term(2);
+  request.set_prev_log_index(6);
+  request.set_prev_log_term(1);
+  request.set_commit_index(5);
+  request.set_leader_name("n1".to_string());
+  request.add_entries(entry);
+
+  let mut writer = Vec::new();
+  assert!(request.Encode(&mut writer).is_ok());
+  let mut decoded = ProtoAppendEntriesRequest::new();
+  assert!(decoded.Decode(&mut writer.as_slice()).is_ok());
+  assert_eq!(decoded.get_leader_name(), "n1");
+  assert_eq!(decoded.entries_len(), 1);
+  assert_eq!(decoded.get_entries()[0].get_command_name(), "nop");
+  assert!(!decoded.get_entries()[0].has_command());
+  assert_eq!(decoded.message_info().full_name, "protobuf.ProtoAppendEntriesRequest");
+}
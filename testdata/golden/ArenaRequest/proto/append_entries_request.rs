@@ -0,0 +1,520 @@
+// Generated by protoc-gen-rust from proto/append_entries_request.proto. Do not edit.
+
+pub mod protobuf {
+  #[allow(unused_imports)]
+  use ::protobuf::{
+    Protobuf, Reader, TagIter, TaggedValue,
+    ArenaMessage, ArenaTagIter, ArenaValue, UnpackVarints, UnpackFixed32, UnpackFixed64,
+    DecodeZigZag32, DecodeZigZag64, DecodeFloat, DecodeDouble,
+    DecodePackedVarints, DecodePackedFixed32, DecodePackedFixed64,
+    ProtobufEncode, Writer, EncodeError,
+    EncodeTaggedValue, EncodeMessageField, EncodeRawField,
+    EncodeArenaValue,
+    EncodeVarintField, EncodeFixed32Field, EncodeFixed64Field,
+    EncodeZigZag32, EncodeZigZag64, EncodeFloat, EncodeDouble,
+    json, Json, ToJson,
+  };
+  #[allow(unused_imports)]
+  use ::protobuf::prelude::*;
+
+  pub struct ProtoAppendEntriesRequest<'a> {
+    term: Option<u64>,
+    prev_log_index: Option<u64>,
+    prev_log_term: Option<u64>,
+    commit_index: Option<u64>,
+    leader_name: Option<&'a str>,
+    entries: ::protobuf::RepeatedField<'a, proto_append_entries_request::ProtoLogEntry<'a>>,
+    unknown_fields: ::protobuf::RepeatedField<'a, ArenaValue<'a>>,
+  }
+
+  impl<'a> ProtoAppendEntriesRequest<'a> {
+    pub const fn new() -> ProtoAppendEntriesRequest<'a> {
+      ProtoAppendEntriesRequest {
+        term: None,
+        prev_log_index: None,
+        prev_log_term: None,
+        commit_index: None,
+        leader_name: None,
+        entries: ::protobuf::RepeatedField::new(),
+        unknown_fields: ::protobuf::RepeatedField::new(),
+      }
+    }
+
+    pub fn default_instance() -> &'static ProtoAppendEntriesRequest<'static> {
+      static INSTANCE: ProtoAppendEntriesRequest<'static> = ProtoAppendEntriesRequest::new();
+      &INSTANCE
+    }
+
+    pub fn has_term(&self) -> bool {
+      self.term.is_some()
+    }
+
+    pub fn get_term(&self) -> u64 {
+      self.term.unwrap_or(0)
+    }
+
+    pub fn set_term(&mut self, value: u64) {
+      self.term = Some(value);
+    }
+
+    pub fn clear_term(&mut self) {
+      self.term = None;
+    }
+
+    pub fn mut_term(&mut self) -> &mut u64 {
+      self.term.get_or_insert(0)
+    }
+
+    pub fn take_term(&mut self) -> u64 {
+      self.term.take().unwrap_or(0)
+    }
+
+    pub fn has_prev_log_index(&self) -> bool {
+      self.prev_log_index.is_some()
+    }
+
+    pub fn get_prev_log_index(&self) -> u64 {
+      self.prev_log_index.unwrap_or(0)
+    }
+
+    pub fn set_prev_log_index(&mut self, value: u64) {
+      self.prev_log_index = Some(value);
+    }
+
+    pub fn clear_prev_log_index(&mut self) {
+      self.prev_log_index = None;
+    }
+
+    pub fn mut_prev_log_index(&mut self) -> &mut u64 {
+      self.prev_log_index.get_or_insert(0)
+    }
+
+    pub fn take_prev_log_index(&mut self) -> u64 {
+      self.prev_log_index.take().unwrap_or(0)
+    }
+
+    pub fn has_prev_log_term(&self) -> bool {
+      self.prev_log_term.is_some()
+    }
+
+    pub fn get_prev_log_term(&self) -> u64 {
+      self.prev_log_term.unwrap_or(0)
+    }
+
+    pub fn set_prev_log_term(&mut self, value: u64) {
+      self.prev_log_term = Some(value);
+    }
+
+    pub fn clear_prev_log_term(&mut self) {
+      self.prev_log_term = None;
+    }
+
+    pub fn mut_prev_log_term(&mut self) -> &mut u64 {
+      self.prev_log_term.get_or_insert(0)
+    }
+
+    pub fn take_prev_log_term(&mut self) -> u64 {
+      self.prev_log_term.take().unwrap_or(0)
+    }
+
+    pub fn has_commit_index(&self) -> bool {
+      self.commit_index.is_some()
+    }
+
+    pub fn get_commit_index(&self) -> u64 {
+      self.commit_index.unwrap_or(0)
+    }
+
+    pub fn set_commit_index(&mut self, value: u64) {
+      self.commit_index = Some(value);
+    }
+
+    pub fn clear_commit_index(&mut self) {
+      self.commit_index = None;
+    }
+
+    pub fn mut_commit_index(&mut self) -> &mut u64 {
+      self.commit_index.get_or_insert(0)
+    }
+
+    pub fn take_commit_index(&mut self) -> u64 {
+      self.commit_index.take().unwrap_or(0)
+    }
+
+    pub fn has_leader_name(&self) -> bool {
+      self.leader_name.is_some()
+    }
+
+    pub fn get_leader_name(&self) -> &'a str {
+      self.leader_name.unwrap_or("")
+    }
+
+    pub fn set_leader_name(&mut self, value: &'a str) {
+      self.leader_name = Some(value);
+    }
+
+    pub fn clear_leader_name(&mut self) {
+      self.leader_name = None;
+    }
+
+    pub fn take_leader_name(&mut self) -> &'a str {
+      self.leader_name.take().unwrap_or("")
+    }
+
+    pub fn get_entries(&self) -> &[proto_append_entries_request::ProtoLogEntry<'a>] {
+      &self.entries
+    }
+
+    pub fn clear_entries(&mut self) {
+      self.entries.clear();
+    }
+
+    pub fn mut_entries(&mut self) -> &mut [proto_append_entries_request::ProtoLogEntry<'a>] {
+      &mut self.entries
+    }
+
+    pub fn add_entries(&mut self, arena: &'a ::protobuf::Arena, value: proto_append_entries_request::ProtoLogEntry<'a>) {
+      self.entries.push(arena, value);
+    }
+
+    pub fn entries_len(&self) -> usize {
+      self.entries.len()
+    }
+
+    // Fields this message's schema doesn't know, and enum numbers outside
+    // the enum, in the order they were decoded.
+    pub fn get_unknown_fields(&self) -> &[ArenaValue<'a>] {
+      &self.unknown_fields
+    }
+  }
+
+  pub mod proto_append_entries_request {
+    #[allow(unused_imports)]
+    use ::protobuf::{
+      Protobuf, Reader, TagIter, TaggedValue,
+      ArenaMessage, ArenaTagIter, ArenaValue, UnpackVarints, UnpackFixed32, UnpackFixed64,
+      DecodeZigZag32, DecodeZigZag64, DecodeFloat, DecodeDouble,
+      DecodePackedVarints, DecodePackedFixed32, DecodePackedFixed64,
+      ProtobufEncode, Writer, EncodeError,
+      EncodeTaggedValue, EncodeMessageField, EncodeRawField,
+      EncodeArenaValue,
+      EncodeVarintField, EncodeFixed32Field, EncodeFixed64Field,
+      EncodeZigZag32, EncodeZigZag64, EncodeFloat, EncodeDouble,
+      json, Json, ToJson,
+    };
+    #[allow(unused_imports)]
+    use ::protobuf::prelude::*;
+
+    pub struct ProtoLogEntry<'a> {
+      index: Option<u64>,
+      term: Option<u64>,
+      command_name: Option<&'a str>,
+      command: Option<&'a [u8]>,
+      unknown_fields: ::protobuf::RepeatedField<'a, ArenaValue<'a>>,
+    }
+
+    impl<'a> ProtoLogEntry<'a> {
+      pub const fn new() -> ProtoLogEntry<'a> {
+        ProtoLogEntry {
+          index: None,
+          term: None,
+          command_name: None,
+          command: None,
+          unknown_fields: ::protobuf::RepeatedField::new(),
+        }
+      }
+
+      pub fn default_instance() -> &'static ProtoLogEntry<'static> {
+        static INSTANCE: ProtoLogEntry<'static> = ProtoLogEntry::new();
+        &INSTANCE
+      }
+
+      pub fn has_index(&self) -> bool {
+        self.index.is_some()
+      }
+
+      pub fn get_index(&self) -> u64 {
+        self.index.unwrap_or(0)
+      }
+
+      pub fn set_index(&mut self, value: u64) {
+        self.index = Some(value);
+      }
+
+      pub fn clear_index(&mut self) {
+        self.index = None;
+      }
+
+      pub fn mut_index(&mut self) -> &mut u64 {
+        self.index.get_or_insert(0)
+      }
+
+      pub fn take_index(&mut self) -> u64 {
+        self.index.take().unwrap_or(0)
+      }
+
+      pub fn has_term(&self) -> bool {
+        self.term.is_some()
+      }
+
+      pub fn get_term(&self) -> u64 {
+        self.term.unwrap_or(0)
+      }
+
+      pub fn set_term(&mut self, value: u64) {
+        self.term = Some(value);
+      }
+
+      pub fn clear_term(&mut self) {
+        self.term = None;
+      }
+
+      pub fn mut_term(&mut self) -> &mut u64 {
+        self.term.get_or_insert(0)
+      }
+
+      pub fn take_term(&mut self) -> u64 {
+        self.term.take().unwrap_or(0)
+      }
+
+      pub fn has_command_name(&self) -> bool {
+        self.command_name.is_some()
+      }
+
+      pub fn get_command_name(&self) -> &'a str {
+        self.command_name.unwrap_or("")
+      }
+
+      pub fn set_command_name(&mut self, value: &'a str) {
+        self.command_name = Some(value);
+      }
+
+      pub fn clear_command_name(&mut self) {
+        self.command_name = None;
+      }
+
+      pub fn take_command_name(&mut self) -> &'a str {
+        self.command_name.take().unwrap_or("")
+      }
+
+      pub fn has_command(&self) -> bool {
+        self.command.is_some()
+      }
+
+      pub fn get_command(&self) -> &'a [u8] {
+        self.command.unwrap_or(b"")
+      }
+
+      pub fn set_command(&mut self, value: &'a [u8]) {
+        self.command = Some(value);
+      }
+
+      pub fn clear_command(&mut self) {
+        self.command = None;
+      }
+
+      pub fn take_command(&mut self) -> &'a [u8] {
+        self.command.take().unwrap_or(b"")
+      }
+
+      // Fields this message's schema doesn't know, and enum numbers outside
+      // the enum, in the order they were decoded.
+      pub fn get_unknown_fields(&self) -> &[ArenaValue<'a>] {
+        &self.unknown_fields
+      }
+    }
+
+    impl<'a> ArenaMessage<'a> for ProtoLogEntry<'a> {
+      fn DecodePartialIn(&mut self, arena: &'a ::protobuf::Arena, data: &'a [u8]) -> bool {
+        let mut tags = ArenaTagIter::new(data);
+        for tag_option in tags.by_ref() {
+          match tag_option {
+            ArenaValue::Varint(1, value) => {
+              self.set_index(value);
+            }
+            ArenaValue::Varint(2, value) => {
+              self.set_term(value);
+            }
+            ArenaValue::Raw(3, value) => {
+              match core::str::from_utf8(value) {
+                Ok(value) => self.set_command_name(value),
+                Err(_) => return false
+              }
+            }
+            ArenaValue::Raw(4, value) => {
+              self.set_command(value);
+            }
+            unknown => self.unknown_fields.push(arena, unknown)
+          }
+        }
+        !tags.is_malformed()
+      }
+
+      fn AppendMissingFields(&self, prefix: &str, missing: &mut Vec<String>) {
+        if self.index.is_none() {
+          missing.push(format!("{}Index", prefix));
+        }
+        if self.term.is_none() {
+          missing.push(format!("{}Term", prefix));
+        }
+        if self.command_name.is_none() {
+          missing.push(format!("{}CommandName", prefix));
+        }
+      }
+    }
+
+    impl<'a> ProtobufEncode for ProtoLogEntry<'a> {
+      fn Encode(&self, writer: &mut dyn Writer) -> Result<(), EncodeError> {
+        for value in self.index.iter() {
+          EncodeVarintField(writer, 1, *value)?;
+        }
+        for value in self.term.iter() {
+          EncodeVarintField(writer, 2, *value)?;
+        }
+        for value in self.command_name.iter() {
+          EncodeRawField(writer, 3, value.as_bytes())?;
+        }
+        for value in self.command.iter() {
+          EncodeRawField(writer, 4, value)?;
+        }
+        for unknown in self.unknown_fields.iter() {
+          EncodeArenaValue(writer, unknown)?;
+        }
+        Ok(())
+      }
+    }
+
+    impl<'a> ToJson for ProtoLogEntry<'a> {
+      fn to_json(&self) -> Json {
+        let mut object = json::Object::new();
+        for value in self.index.iter() {
+          object.insert("Index".to_string(), Json::String(value.to_string()));
+        }
+        for value in self.term.iter() {
+          object.insert("Term".to_string(), Json::String(value.to_string()));
+        }
+        for value in self.command_name.iter() {
+          object.insert("CommandName".to_string(), Json::String(value.to_string()));
+        }
+        for value in self.command.iter() {
+          object.insert("Command".to_string(), Json::String(json::to_base64(value)));
+        }
+        Json::Object(object)
+      }
+    }
+
+  }
+
+  impl<'a> ArenaMessage<'a> for ProtoAppendEntriesRequest<'a> {
+    fn DecodePartialIn(&mut self, arena: &'a ::protobuf::Arena, data: &'a [u8]) -> bool {
+      let mut tags = ArenaTagIter::new(data);
+      for tag_option in tags.by_ref() {
+        match tag_option {
+          ArenaValue::Varint(1, value) => {
+            self.set_term(value);
+          }
+          ArenaValue::Varint(2, value) => {
+            self.set_prev_log_index(value);
+          }
+          ArenaValue::Varint(3, value) => {
+            self.set_prev_log_term(value);
+          }
+          ArenaValue::Varint(4, value) => {
+            self.set_commit_index(value);
+          }
+          ArenaValue::Raw(5, value) => {
+            match core::str::from_utf8(value) {
+              Ok(value) => self.set_leader_name(value),
+              Err(_) => return false
+            }
+          }
+          ArenaValue::Raw(6, value) => {
+            let mut message = proto_append_entries_request::ProtoLogEntry::new();
+            if !message.DecodePartialIn(arena, value) {
+              return false;
+            }
+            self.add_entries(arena, message);
+          }
+          unknown => self.unknown_fields.push(arena, unknown)
+        }
+      }
+      !tags.is_malformed()
+    }
+
+    fn AppendMissingFields(&self, prefix: &str, missing: &mut Vec<String>) {
+      if self.term.is_none() {
+        missing.push(format!("{}Term", prefix));
+      }
+      if self.prev_log_index.is_none() {
+        missing.push(format!("{}PrevLogIndex", prefix));
+      }
+      if self.prev_log_term.is_none() {
+        missing.push(format!("{}PrevLogTerm", prefix));
+      }
+      if self.commit_index.is_none() {
+        missing.push(format!("{}CommitIndex", prefix));
+      }
+      if self.leader_name.is_none() {
+        missing.push(format!("{}LeaderName", prefix));
+      }
+      for (i, value) in self.entries.iter().enumerate() {
+        for path in value.missing_fields() {
+          missing.push(format!("{}Entries[{}].{}", prefix, i, path));
+        }
+      }
+    }
+  }
+
+  impl<'a> ProtobufEncode for ProtoAppendEntriesRequest<'a> {
+    fn Encode(&self, writer: &mut dyn Writer) -> Result<(), EncodeError> {
+      for value in self.term.iter() {
+        EncodeVarintField(writer, 1, *value)?;
+      }
+      for value in self.prev_log_index.iter() {
+        EncodeVarintField(writer, 2, *value)?;
+      }
+      for value in self.prev_log_term.iter() {
+        EncodeVarintField(writer, 3, *value)?;
+      }
+      for value in self.commit_index.iter() {
+        EncodeVarintField(writer, 4, *value)?;
+      }
+      for value in self.leader_name.iter() {
+        EncodeRawField(writer, 5, value.as_bytes())?;
+      }
+      for value in self.entries.iter() {
+        EncodeMessageField(writer, 6, value)?;
+      }
+      for unknown in self.unknown_fields.iter() {
+        EncodeArenaValue(writer, unknown)?;
+      }
+      Ok(())
+    }
+  }
+
+  impl<'a> ToJson for ProtoAppendEntriesRequest<'a> {
+    fn to_json(&self) -> Json {
+      let mut object = json::Object::new();
+      for value in self.term.iter() {
+        object.insert("Term".to_string(), Json::String(value.to_string()));
+      }
+      for value in self.prev_log_index.iter() {
+        object.insert("PrevLogIndex".to_string(), Json::String(value.to_string()));
+      }
+      for value in self.prev_log_term.iter() {
+        object.insert("PrevLogTerm".to_string(), Json::String(value.to_string()));
+      }
+      for value in self.commit_index.iter() {
+        object.insert("CommitIndex".to_string(), Json::String(value.to_string()));
+      }
+      for value in self.leader_name.iter() {
+        object.insert("LeaderName".to_string(), Json::String(value.to_string()));
+      }
+      if !self.entries.is_empty() {
+        object.insert("Entries".to_string(), Json::List(self.entries.iter().map(|value| value.to_json()).collect()));
+      }
+      Json::Object(object)
+    }
+  }
+
+}
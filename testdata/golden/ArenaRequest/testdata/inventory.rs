@@ -0,0 +1,478 @@
+// Generated by protoc-gen-rust from testdata/inventory.proto. Do not edit.
+
+pub mod inventory {
+  #[allow(unused_imports)]
+  use ::protobuf::{
+    Protobuf, Reader, TagIter, TaggedValue,
+    ArenaMessage, ArenaTagIter, ArenaValue, UnpackVarints, UnpackFixed32, UnpackFixed64,
+    DecodeZigZag32, DecodeZigZag64, DecodeFloat, DecodeDouble,
+    DecodePackedVarints, DecodePackedFixed32, DecodePackedFixed64,
+    ProtobufEncode, Writer, EncodeError,
+    EncodeTaggedValue, EncodeMessageField, EncodeRawField,
+    EncodeArenaValue,
+    EncodeVarintField, EncodeFixed32Field, EncodeFixed64Field,
+    EncodeZigZag32, EncodeZigZag64, EncodeFloat, EncodeDouble,
+    json, Json, ToJson,
+  };
+  #[allow(unused_imports)]
+  use ::protobuf::prelude::*;
+
+  pub struct Location<'a> {
+    aisle: Option<&'a str>,
+    shelf: Option<u32>,
+    unknown_fields: ::protobuf::RepeatedField<'a, ArenaValue<'a>>,
+  }
+
+  impl<'a> Location<'a> {
+    pub const fn new() -> Location<'a> {
+      Location {
+        aisle: None,
+        shelf: None,
+        unknown_fields: ::protobuf::RepeatedField::new(),
+      }
+    }
+
+    pub fn default_instance() -> &'static Location<'static> {
+      static INSTANCE: Location<'static> = Location::new();
+      &INSTANCE
+    }
+
+    pub fn has_aisle(&self) -> bool {
+      self.aisle.is_some()
+    }
+
+    pub fn get_aisle(&self) -> &'a str {
+      self.aisle.unwrap_or("")
+    }
+
+    pub fn set_aisle(&mut self, value: &'a str) {
+      self.aisle = Some(value);
+    }
+
+    pub fn clear_aisle(&mut self) {
+      self.aisle = None;
+    }
+
+    pub fn take_aisle(&mut self) -> &'a str {
+      self.aisle.take().unwrap_or("")
+    }
+
+    pub fn has_shelf(&self) -> bool {
+      self.shelf.is_some()
+    }
+
+    pub fn get_shelf(&self) -> u32 {
+      self.shelf.unwrap_or(0)
+    }
+
+    pub fn set_shelf(&mut self, value: u32) {
+      self.shelf = Some(value);
+    }
+
+    pub fn clear_shelf(&mut self) {
+      self.shelf = None;
+    }
+
+    pub fn mut_shelf(&mut self) -> &mut u32 {
+      self.shelf.get_or_insert(0)
+    }
+
+    pub fn take_shelf(&mut self) -> u32 {
+      self.shelf.take().unwrap_or(0)
+    }
+
+    // Fields this message's schema doesn't know, and enum numbers outside
+    // the enum, in the order they were decoded.
+    pub fn get_unknown_fields(&self) -> &[ArenaValue<'a>] {
+      &self.unknown_fields
+    }
+  }
+
+  impl<'a> ArenaMessage<'a> for Location<'a> {
+    fn DecodePartialIn(&mut self, arena: &'a ::protobuf::Arena, data: &'a [u8]) -> bool {
+      let mut tags = ArenaTagIter::new(data);
+      for tag_option in tags.by_ref() {
+        match tag_option {
+          ArenaValue::Raw(1, value) => {
+            match core::str::from_utf8(value) {
+              Ok(value) => self.set_aisle(value),
+              Err(_) => return false
+            }
+          }
+          ArenaValue::Varint(2, value) => {
+            self.set_shelf(value as u32);
+          }
+          unknown => self.unknown_fields.push(arena, unknown)
+        }
+      }
+      !tags.is_malformed()
+    }
+
+    fn AppendMissingFields(&self, _prefix: &str, _missing: &mut Vec<String>) {
+    }
+  }
+
+  impl<'a> ProtobufEncode for Location<'a> {
+    fn Encode(&self, writer: &mut dyn Writer) -> Result<(), EncodeError> {
+      for value in self.aisle.iter() {
+        EncodeRawField(writer, 1, value.as_bytes())?;
+      }
+      for value in self.shelf.iter() {
+        EncodeVarintField(writer, 2, *value as u64)?;
+      }
+      for unknown in self.unknown_fields.iter() {
+        EncodeArenaValue(writer, unknown)?;
+      }
+      Ok(())
+    }
+  }
+
+  impl<'a> ToJson for Location<'a> {
+    fn to_json(&self) -> Json {
+      let mut object = json::Object::new();
+      for value in self.aisle.iter() {
+        object.insert("aisle".to_string(), Json::String(value.to_string()));
+      }
+      for value in self.shelf.iter() {
+        object.insert("shelf".to_string(), value.to_json());
+      }
+      Json::Object(object)
+    }
+  }
+
+  pub struct Item<'a> {
+    name: Option<&'a str>,
+    condition: Option<Condition>,
+    tags: ::protobuf::RepeatedField<'a, i64>,
+    type_: Option<&'a [u8]>,
+    location: Option<::protobuf::ArenaBox<'a, Location<'a>>>,
+    history: ::protobuf::RepeatedField<'a, Condition>,
+    unknown_fields: ::protobuf::RepeatedField<'a, ArenaValue<'a>>,
+  }
+
+  impl<'a> Item<'a> {
+    pub const fn new() -> Item<'a> {
+      Item {
+        name: None,
+        condition: None,
+        tags: ::protobuf::RepeatedField::new(),
+        type_: None,
+        location: None,
+        history: ::protobuf::RepeatedField::new(),
+        unknown_fields: ::protobuf::RepeatedField::new(),
+      }
+    }
+
+    pub fn default_instance() -> &'static Item<'static> {
+      static INSTANCE: Item<'static> = Item::new();
+      &INSTANCE
+    }
+
+    pub fn has_name(&self) -> bool {
+      self.name.is_some()
+    }
+
+    pub fn get_name(&self) -> &'a str {
+      self.name.unwrap_or("")
+    }
+
+    pub fn set_name(&mut self, value: &'a str) {
+      self.name = Some(value);
+    }
+
+    pub fn clear_name(&mut self) {
+      self.name = None;
+    }
+
+    pub fn take_name(&mut self) -> &'a str {
+      self.name.take().unwrap_or("")
+    }
+
+    pub fn has_condition(&self) -> bool {
+      self.condition.is_some()
+    }
+
+    pub fn get_condition(&self) -> Condition {
+      self.condition.unwrap_or(New)
+    }
+
+    pub fn set_condition(&mut self, value: Condition) {
+      self.condition = Some(value);
+    }
+
+    pub fn clear_condition(&mut self) {
+      self.condition = None;
+    }
+
+    pub fn mut_condition(&mut self) -> &mut Condition {
+      self.condition.get_or_insert(New)
+    }
+
+    pub fn take_condition(&mut self) -> Condition {
+      self.condition.take().unwrap_or(New)
+    }
+
+    pub fn get_tags(&self) -> &[i64] {
+      &self.tags
+    }
+
+    pub fn clear_tags(&mut self) {
+      self.tags.clear();
+    }
+
+    pub fn mut_tags(&mut self) -> &mut [i64] {
+      &mut self.tags
+    }
+
+    pub fn add_tags(&mut self, arena: &'a ::protobuf::Arena, value: i64) {
+      self.tags.push(arena, value);
+    }
+
+    pub fn tags_len(&self) -> usize {
+      self.tags.len()
+    }
+
+    pub fn has_type(&self) -> bool {
+      self.type_.is_some()
+    }
+
+    pub fn get_type(&self) -> &'a [u8] {
+      self.type_.unwrap_or(b"")
+    }
+
+    pub fn set_type(&mut self, value: &'a [u8]) {
+      self.type_ = Some(value);
+    }
+
+    pub fn clear_type(&mut self) {
+      self.type_ = None;
+    }
+
+    pub fn take_type(&mut self) -> &'a [u8] {
+      self.type_.take().unwrap_or(b"")
+    }
+
+    pub fn has_location(&self) -> bool {
+      self.location.is_some()
+    }
+
+    pub fn get_location(&self) -> &Location<'a> {
+      match self.location { Some(ref value) => value, None => Location::default_instance() }
+    }
+
+    pub fn set_location(&mut self, arena: &'a ::protobuf::Arena, value: Location<'a>) {
+      self.location = Some(::protobuf::ArenaBox::new_in(arena, value));
+    }
+
+    pub fn clear_location(&mut self) {
+      self.location = None;
+    }
+
+    pub fn mut_location(&mut self, arena: &'a ::protobuf::Arena) -> &mut Location<'a> {
+      self.location.get_or_insert_with(|| ::protobuf::ArenaBox::new_in(arena, Location::new()))
+    }
+
+    pub fn get_history(&self) -> &[Condition] {
+      &self.history
+    }
+
+    pub fn clear_history(&mut self) {
+      self.history.clear();
+    }
+
+    pub fn mut_history(&mut self) -> &mut [Condition] {
+      &mut self.history
+    }
+
+    pub fn add_history(&mut self, arena: &'a ::protobuf::Arena, value: Condition) {
+      self.history.push(arena, value);
+    }
+
+    pub fn history_len(&self) -> usize {
+      self.history.len()
+    }
+
+    // Fields this message's schema doesn't know, and enum numbers outside
+    // the enum, in the order they were decoded.
+    pub fn get_unknown_fields(&self) -> &[ArenaValue<'a>] {
+      &self.unknown_fields
+    }
+  }
+
+  impl<'a> ArenaMessage<'a> for Item<'a> {
+    fn DecodePartialIn(&mut self, arena: &'a ::protobuf::Arena, data: &'a [u8]) -> bool {
+      let mut tags = ArenaTagIter::new(data);
+      for tag_option in tags.by_ref() {
+        match tag_option {
+          ArenaValue::Raw(1, value) => {
+            match core::str::from_utf8(value) {
+              Ok(value) => self.set_name(value),
+              Err(_) => return false
+            }
+          }
+          ArenaValue::Varint(2, value) => {
+            match Condition::from_i32(value as i32) {
+              Some(value) => self.set_condition(value),
+              None => self.unknown_fields.push(arena, ArenaValue::Varint(2, value))
+            }
+          }
+          ArenaValue::Varint(3, value) => {
+            self.add_tags(arena, value as i64);
+          }
+          ArenaValue::Raw(3, packed) => {
+            if !UnpackVarints(packed, |value| self.add_tags(arena, value as i64)) {
+              return false;
+            }
+          }
+          ArenaValue::Raw(4, value) => {
+            self.set_type(value);
+          }
+          ArenaValue::Raw(5, value) => {
+            if !self.mut_location(arena).DecodePartialIn(arena, value) {
+              return false;
+            }
+          }
+          ArenaValue::Varint(6, value) => {
+            match Condition::from_i32(value as i32) {
+              Some(value) => self.add_history(arena, value),
+              None => self.unknown_fields.push(arena, ArenaValue::Varint(6, value))
+            }
+          }
+          ArenaValue::Raw(6, packed) => {
+            let unpacked = UnpackVarints(packed, |value| {
+              match Condition::from_i32(value as i32) {
+                Some(value) => self.add_history(arena, value),
+                None => self.unknown_fields.push(arena, ArenaValue::Varint(6, value))
+              }
+            });
+            if !unpacked {
+              return false;
+            }
+          }
+          unknown => self.unknown_fields.push(arena, unknown)
+        }
+      }
+      !tags.is_malformed()
+    }
+
+    fn AppendMissingFields(&self, prefix: &str, missing: &mut Vec<String>) {
+      if self.name.is_none() {
+        missing.push(format!("{}Name", prefix));
+      }
+      match self.location {
+        Some(ref value) => {
+          for path in value.missing_fields() {
+            missing.push(format!("{}location.{}", prefix, path));
+          }
+        }
+        None => ()
+      }
+    }
+  }
+
+  impl<'a> ProtobufEncode for Item<'a> {
+    fn Encode(&self, writer: &mut dyn Writer) -> Result<(), EncodeError> {
+      for value in self.name.iter() {
+        EncodeRawField(writer, 1, value.as_bytes())?;
+      }
+      for value in self.condition.iter() {
+        EncodeVarintField(writer, 2, value.value() as i64 as u64)?;
+      }
+      for value in self.tags.iter() {
+        EncodeVarintField(writer, 3, *value as u64)?;
+      }
+      for value in self.type_.iter() {
+        EncodeRawField(writer, 4, value)?;
+      }
+      for value in self.location.iter() {
+        EncodeMessageField(writer, 5, value)?;
+      }
+      for value in self.history.iter() {
+        EncodeVarintField(writer, 6, value.value() as i64 as u64)?;
+      }
+      for unknown in self.unknown_fields.iter() {
+        EncodeArenaValue(writer, unknown)?;
+      }
+      Ok(())
+    }
+  }
+
+  impl<'a> ToJson for Item<'a> {
+    fn to_json(&self) -> Json {
+      let mut object = json::Object::new();
+      for value in self.name.iter() {
+        object.insert("Name".to_string(), Json::String(value.to_string()));
+      }
+      for value in self.condition.iter() {
+        object.insert("condition".to_string(), value.to_json());
+      }
+      if !self.tags.is_empty() {
+        object.insert("tags".to_string(), Json::List(self.tags.iter().map(|value| Json::String(value.to_string())).collect()));
+      }
+      for value in self.type_.iter() {
+        object.insert("type".to_string(), Json::String(json::to_base64(value)));
+      }
+      for value in self.location.iter() {
+        object.insert("location".to_string(), value.to_json());
+      }
+      if !self.history.is_empty() {
+        object.insert("history".to_string(), Json::List(self.history.iter().map(|value| value.to_json()).collect()));
+      }
+      Json::Object(object)
+    }
+  }
+
+  #[derive(Clone, Copy, PartialEq, Eq, Debug)]
+  pub enum Condition {
+    New = 0,
+    Used = 1,
+    Refurbished = 2,
+  }
+
+  pub use self::Condition::{New, Used, Refurbished};
+
+  impl Condition {
+    pub fn from_i32(value: i32) -> Option<Condition> {
+      match value {
+        0 => Some(New),
+        1 => Some(Used),
+        2 => Some(Refurbished),
+        _ => None
+      }
+    }
+
+    pub fn value(&self) -> i32 {
+      *self as i32
+    }
+
+    pub fn name(&self) -> &'static str {
+      match *self {
+        New => "NEW",
+        Used => "USED",
+        Refurbished => "REFURBISHED",
+      }
+    }
+
+    pub fn from_name(name: &str) -> Option<Condition> {
+      match name {
+        "NEW" => Some(New),
+        "USED" => Some(Used),
+        "REFURBISHED" => Some(Refurbished),
+        _ => None
+      }
+    }
+  }
+
+  impl Default for Condition {
+    fn default() -> Condition {
+      New
+    }
+  }
+
+  impl ToJson for Condition {
+    fn to_json(&self) -> Json {
+      Json::String(self.name().to_string())
+    }
+  }
+
+}
@@ -0,0 +1,714 @@
+// Generated by protoc-gen-rust from raft_state.proto. Do not edit.
+
+pub mod raft_state {
+  #[allow(unused_imports)]
+  use ::protobuf::{
+    Protobuf, Reader, TagIter, TaggedValue,
+    DecodeZigZag32, DecodeZigZag64, DecodeFloat, DecodeDouble,
+    DecodePackedVarints, DecodePackedFixed32, DecodePackedFixed64,
+    ProtobufEncode, Writer, EncodeError,
+    EncodeTaggedValue, EncodeMessageField, EncodeRawField,
+    EncodeVarintField, EncodeFixed32Field, EncodeFixed64Field,
+    EncodeZigZag32, EncodeZigZag64, EncodeFloat, EncodeDouble,
+    FieldMasked,
+  };
+  #[allow(unused_imports)]
+  use ::protobuf::prelude::*;
+
+  #[derive(Clone)]
+  pub struct HardState {
+    term: Option<u64>,
+    voted_for: Option<String>,
+    commit: Option<u64>,
+    unknown_fields: Vec<TaggedValue>,
+  }
+
+  impl HardState {
+    pub const fn new() -> HardState {
+      HardState {
+        term: None,
+        voted_for: None,
+        commit: None,
+        unknown_fields: Vec::new(),
+      }
+    }
+
+    pub fn default_instance() -> &'static HardState {
+      static INSTANCE: HardState = HardState::new();
+      &INSTANCE
+    }
+
+    pub fn has_term(&self) -> bool {
+      self.term.is_some()
+    }
+
+    pub fn get_term(&self) -> u64 {
+      self.term.unwrap_or(0)
+    }
+
+    pub fn set_term(&mut self, value: u64) {
+      self.term = Some(value);
+    }
+
+    pub fn clear_term(&mut self) {
+      self.term = None;
+    }
+
+    pub fn mut_term(&mut self) -> &mut u64 {
+      self.term.get_or_insert(0)
+    }
+
+    pub fn take_term(&mut self) -> u64 {
+      self.term.take().unwrap_or(0)
+    }
+
+    pub fn has_voted_for(&self) -> bool {
+      self.voted_for.is_some()
+    }
+
+    pub fn get_voted_for(&self) -> &str {
+      self.voted_for.as_deref().unwrap_or("")
+    }
+
+    pub fn set_voted_for(&mut self, value: String) {
+      self.voted_for = Some(value);
+    }
+
+    pub fn clear_voted_for(&mut self) {
+      self.voted_for = None;
+    }
+
+    pub fn mut_voted_for(&mut self) -> &mut String {
+      self.voted_for.get_or_insert_with(|| String::from(""))
+    }
+
+    pub fn take_voted_for(&mut self) -> String {
+      self.voted_for.take().unwrap_or_else(|| String::from(""))
+    }
+
+    pub fn has_commit(&self) -> bool {
+      self.commit.is_some()
+    }
+
+    pub fn get_commit(&self) -> u64 {
+      self.commit.unwrap_or(0)
+    }
+
+    pub fn set_commit(&mut self, value: u64) {
+      self.commit = Some(value);
+    }
+
+    pub fn clear_commit(&mut self) {
+      self.commit = None;
+    }
+
+    pub fn mut_commit(&mut self) -> &mut u64 {
+      self.commit.get_or_insert(0)
+    }
+
+    pub fn take_commit(&mut self) -> u64 {
+      self.commit.take().unwrap_or(0)
+    }
+
+    // Fields this message's schema doesn't know, and enum numbers outside
+    // the enum, in the order they were decoded.
+    pub fn get_unknown_fields(&self) -> &[TaggedValue] {
+      &self.unknown_fields
+    }
+  }
+
+  impl Protobuf for HardState {
+    fn DecodePartial(&mut self, reader: &mut dyn Reader) -> bool {
+      let mut tags = TagIter::new(reader);
+      for tag_option in tags.by_ref() {
+        match tag_option {
+          TaggedValue::Varint(1, value) => {
+            self.set_term(value);
+          }
+          TaggedValue::Raw(2, value) => {
+            match String::from_utf8(value.into_vec()) {
+              Ok(value) => self.set_voted_for(value),
+              Err(_) => return false
+            }
+          }
+          TaggedValue::Varint(3, value) => {
+            self.set_commit(value);
+          }
+          unknown => self.unknown_fields.push(unknown)
+        }
+      }
+      !tags.is_malformed()
+    }
+
+    fn AppendMissingFields(&self, _prefix: &str, _missing: &mut Vec<String>) {
+    }
+  }
+
+  impl ProtobufEncode for HardState {
+    fn Encode(&self, writer: &mut dyn Writer) -> Result<(), EncodeError> {
+      for value in self.term.iter() {
+        EncodeVarintField(writer, 1, *value)?;
+      }
+      for value in self.voted_for.iter() {
+        EncodeRawField(writer, 2, value.as_bytes())?;
+      }
+      for value in self.commit.iter() {
+        EncodeVarintField(writer, 3, *value)?;
+      }
+      for unknown in self.unknown_fields.iter() {
+        EncodeTaggedValue(writer, unknown)?;
+      }
+      Ok(())
+    }
+  }
+
+  impl FieldMasked for HardState {
+    fn IsMaskPath(path: &[&str], _through_repeated: bool) -> bool {
+      match path {
+        ["term"] => true,
+        ["voted_for"] => true,
+        ["commit"] => true,
+        _ => false
+      }
+    }
+
+    fn CopyMasked(&mut self, from: &Self, mask: &::protobuf::FieldMask, merge: bool) {
+      if mask.field("term").is_some() && (!merge || from.term.is_some()) {
+        self.term = from.term.clone();
+      }
+      if mask.field("voted_for").is_some() && (!merge || from.voted_for.is_some()) {
+        self.voted_for = from.voted_for.clone();
+      }
+      if mask.field("commit").is_some() && (!merge || from.commit.is_some()) {
+        self.commit = from.commit.clone();
+      }
+      if merge && mask.is_all() {
+        self.unknown_fields.extend(from.unknown_fields.iter().cloned());
+      }
+    }
+
+    fn ClearUnmasked(&mut self, mask: &::protobuf::FieldMask) {
+      if mask.is_all() {
+        return;
+      }
+      if mask.field("term").is_none() {
+        self.clear_term();
+      }
+      if mask.field("voted_for").is_none() {
+        self.clear_voted_for();
+      }
+      if mask.field("commit").is_none() {
+        self.clear_commit();
+      }
+      self.unknown_fields.clear();
+    }
+  }
+
+  #[derive(Clone)]
+  pub struct Entry {
+    index: Option<u64>,
+    term: Option<u64>,
+    command: Option<Vec<u8>>,
+    unknown_fields: Vec<TaggedValue>,
+  }
+
+  impl Entry {
+    pub const fn new() -> Entry {
+      Entry {
+        index: None,
+        term: None,
+        command: None,
+        unknown_fields: Vec::new(),
+      }
+    }
+
+    pub fn default_instance() -> &'static Entry {
+      static INSTANCE: Entry = Entry::new();
+      &INSTANCE
+    }
+
+    pub fn has_index(&self) -> bool {
+      self.index.is_some()
+    }
+
+    pub fn get_index(&self) -> u64 {
+      self.index.unwrap_or(0)
+    }
+
+    pub fn set_index(&mut self, value: u64) {
+      self.index = Some(value);
+    }
+
+    pub fn clear_index(&mut self) {
+      self.index = None;
+    }
+
+    pub fn mut_index(&mut self) -> &mut u64 {
+      self.index.get_or_insert(0)
+    }
+
+    pub fn take_index(&mut self) -> u64 {
+      self.index.take().unwrap_or(0)
+    }
+
+    pub fn has_term(&self) -> bool {
+      self.term.is_some()
+    }
+
+    pub fn get_term(&self) -> u64 {
+      self.term.unwrap_or(0)
+    }
+
+    pub fn set_term(&mut self, value: u64) {
+      self.term = Some(value);
+    }
+
+    pub fn clear_term(&mut self) {
+      self.term = None;
+    }
+
+    pub fn mut_term(&mut self) -> &mut u64 {
+      self.term.get_or_insert(0)
+    }
+
+    pub fn take_term(&mut self) -> u64 {
+      self.term.take().unwrap_or(0)
+    }
+
+    pub fn has_command(&self) -> bool {
+      self.command.is_some()
+    }
+
+    pub fn get_command(&self) -> &[u8] {
+      self.command.as_deref().unwrap_or(b"")
+    }
+
+    pub fn set_command(&mut self, value: Vec<u8>) {
+      self.command = Some(value);
+    }
+
+    pub fn clear_command(&mut self) {
+      self.command = None;
+    }
+
+    pub fn mut_command(&mut self) -> &mut Vec<u8> {
+      self.command.get_or_insert_with(|| b"".to_vec())
+    }
+
+    pub fn take_command(&mut self) -> Vec<u8> {
+      self.command.take().unwrap_or_else(|| b"".to_vec())
+    }
+
+    // Fields this message's schema doesn't know, and enum numbers outside
+    // the enum, in the order they were decoded.
+    pub fn get_unknown_fields(&self) -> &[TaggedValue] {
+      &self.unknown_fields
+    }
+  }
+
+  impl Protobuf for Entry {
+    fn DecodePartial(&mut self, reader: &mut dyn Reader) -> bool {
+      let mut tags = TagIter::new(reader);
+      for tag_option in tags.by_ref() {
+        match tag_option {
+          TaggedValue::Varint(1, value) => {
+            self.set_index(value);
+          }
+          TaggedValue::Varint(2, value) => {
+            self.set_term(value);
+          }
+          TaggedValue::Raw(3, value) => {
+            self.set_command(value.into_vec());
+          }
+          unknown => self.unknown_fields.push(unknown)
+        }
+      }
+      !tags.is_malformed()
+    }
+
+    fn AppendMissingFields(&self, prefix: &str, missing: &mut Vec<String>) {
+      if self.index.is_none() {
+        missing.push(format!("{}index", prefix));
+      }
+      if self.term.is_none() {
+        missing.push(format!("{}term", prefix));
+      }
+    }
+  }
+
+  impl ProtobufEncode for Entry {
+    fn Encode(&self, writer: &mut dyn Writer) -> Result<(), EncodeError> {
+      for value in self.index.iter() {
+        EncodeVarintField(writer, 1, *value)?;
+      }
+      for value in self.term.iter() {
+        EncodeVarintField(writer, 2, *value)?;
+      }
+      for value in self.command.iter() {
+        EncodeRawField(writer, 3, value)?;
+      }
+      for unknown in self.unknown_fields.iter() {
+        EncodeTaggedValue(writer, unknown)?;
+      }
+      Ok(())
+    }
+  }
+
+  impl FieldMasked for Entry {
+    fn IsMaskPath(path: &[&str], _through_repeated: bool) -> bool {
+      match path {
+        ["index"] => true,
+        ["term"] => true,
+        ["command"] => true,
+        _ => false
+      }
+    }
+
+    fn CopyMasked(&mut self, from: &Self, mask: &::protobuf::FieldMask, merge: bool) {
+      if mask.field("index").is_some() && (!merge || from.index.is_some()) {
+        self.index = from.index.clone();
+      }
+      if mask.field("term").is_some() && (!merge || from.term.is_some()) {
+        self.term = from.term.clone();
+      }
+      if mask.field("command").is_some() && (!merge || from.command.is_some()) {
+        self.command = from.command.clone();
+      }
+      if merge && mask.is_all() {
+        self.unknown_fields.extend(from.unknown_fields.iter().cloned());
+      }
+    }
+
+    fn ClearUnmasked(&mut self, mask: &::protobuf::FieldMask) {
+      if mask.is_all() {
+        return;
+      }
+      if mask.field("index").is_none() {
+        self.clear_index();
+      }
+      if mask.field("term").is_none() {
+        self.clear_term();
+      }
+      if mask.field("command").is_none() {
+        self.clear_command();
+      }
+      self.unknown_fields.clear();
+    }
+  }
+
+  /// What a node keeps on disk, of which a FieldMask picks the parts to
+  /// update.
+  #[derive(Clone)]
+  pub struct RaftState {
+    hard_state: Option<HardState>,
+    entries: Vec<Entry>,
+    peers: Vec<String>,
+    snapshot: ::protobuf::Lazy<Option<Entry>>,
+    unknown_fields: Vec<TaggedValue>,
+  }
+
+  impl RaftState {
+    pub const fn new() -> RaftState {
+      RaftState {
+        hard_state: None,
+        entries: Vec::new(),
+        peers: Vec::new(),
+        snapshot: ::protobuf::Lazy::new(|raw| ::protobuf::ParseLazyMessage(raw, Entry::new)),
+        unknown_fields: Vec::new(),
+      }
+    }
+
+    pub fn default_instance() -> &'static RaftState {
+      static INSTANCE: RaftState = RaftState::new();
+      &INSTANCE
+    }
+
+    pub fn has_hard_state(&self) -> bool {
+      self.hard_state.is_some()
+    }
+
+    pub fn get_hard_state(&self) -> &HardState {
+      match self.hard_state { Some(ref value) => value, None => HardState::default_instance() }
+    }
+
+    pub fn set_hard_state(&mut self, value: HardState) {
+      self.hard_state = Some(value);
+    }
+
+    pub fn clear_hard_state(&mut self) {
+      self.hard_state = None;
+    }
+
+    pub fn mut_hard_state(&mut self) -> &mut HardState {
+      self.hard_state.get_or_insert_with(HardState::new)
+    }
+
+    pub fn take_hard_state(&mut self) -> HardState {
+      self.hard_state.take().unwrap_or_else(HardState::new)
+    }
+
+    pub fn get_entries(&self) -> &[Entry] {
+      &self.entries
+    }
+
+    pub fn set_entries(&mut self, value: Vec<Entry>) {
+      self.entries = value;
+    }
+
+    pub fn clear_entries(&mut self) {
+      self.entries.clear();
+    }
+
+    pub fn mut_entries(&mut self) -> &mut Vec<Entry> {
+      &mut self.entries
+    }
+
+    pub fn take_entries(&mut self) -> Vec<Entry> {
+      core::mem::take(&mut self.entries)
+    }
+
+    pub fn add_entries(&mut self, value: Entry) {
+      self.entries.push(value);
+    }
+
+    pub fn entries_len(&self) -> usize {
+      self.entries.len()
+    }
+
+    pub fn get_peers(&self) -> &[String] {
+      &self.peers
+    }
+
+    pub fn set_peers(&mut self, value: Vec<String>) {
+      self.peers = value;
+    }
+
+    pub fn clear_peers(&mut self) {
+      self.peers.clear();
+    }
+
+    pub fn mut_peers(&mut self) -> &mut Vec<String> {
+      &mut self.peers
+    }
+
+    pub fn take_peers(&mut self) -> Vec<String> {
+      core::mem::take(&mut self.peers)
+    }
+
+    pub fn add_peers(&mut self, value: String) {
+      self.peers.push(value);
+    }
+
+    pub fn peers_len(&self) -> usize {
+      self.peers.len()
+    }
+
+    pub fn has_snapshot(&self) -> bool {
+      self.snapshot.is_set()
+    }
+
+    pub fn get_snapshot(&self) -> &Entry {
+      match *self.snapshot.get() { Some(ref value) => value, None => Entry::default_instance() }
+    }
+
+    pub fn set_snapshot(&mut self, value: Entry) {
+      self.snapshot.set(Some(value));
+    }
+
+    pub fn clear_snapshot(&mut self) {
+      self.snapshot.clear();
+    }
+
+    pub fn mut_snapshot(&mut self) -> &mut Entry {
+      self.snapshot.get_mut().get_or_insert_with(Entry::new)
+    }
+
+    pub fn take_snapshot(&mut self) -> Entry {
+      self.snapshot.take().unwrap_or_else(Entry::new)
+    }
+
+    // Fields this message's schema doesn't know, and enum numbers outside
+    // the enum, in the order they were decoded.
+    pub fn get_unknown_fields(&self) -> &[TaggedValue] {
+      &self.unknown_fields
+    }
+  }
+
+  impl Protobuf for RaftState {
+    fn DecodePartial(&mut self, reader: &mut dyn Reader) -> bool {
+      let mut tags = TagIter::new(reader);
+      for tag_option in tags.by_ref() {
+        match tag_option {
+          TaggedValue::Raw(1, value) => {
+            let mut reader: &[u8] = &value;
+            if !self.mut_hard_state().DecodePartial(&mut reader) {
+              return false;
+            }
+          }
+          TaggedValue::Raw(2, value) => {
+            let mut reader: &[u8] = &value;
+            let mut message = Entry::new();
+            if !message.DecodePartial(&mut reader) {
+              return false;
+            }
+            self.add_entries(message);
+          }
+          TaggedValue::Raw(3, value) => {
+            match String::from_utf8(value.into_vec()) {
+              Ok(value) => self.add_peers(value),
+              Err(_) => return false
+            }
+          }
+          TaggedValue::Raw(4, value) => {
+            if let Err(value) = self.snapshot.push_raw(value) {
+              let mut reader: &[u8] = &value;
+              if !self.mut_snapshot().DecodePartial(&mut reader) {
+                return false;
+              }
+            }
+          }
+          unknown => self.unknown_fields.push(unknown)
+        }
+      }
+      !tags.is_malformed()
+    }
+
+    fn AppendMissingFields(&self, prefix: &str, missing: &mut Vec<String>) {
+      match self.hard_state {
+        Some(ref value) => {
+          for path in value.missing_fields() {
+            missing.push(format!("{}hard_state.{}", prefix, path));
+          }
+        }
+        None => ()
+      }
+      for (i, value) in self.entries.iter().enumerate() {
+        for path in value.missing_fields() {
+          missing.push(format!("{}entries[{}].{}", prefix, i, path));
+        }
+      }
+    }
+  }
+
+  impl ProtobufEncode for RaftState {
+    fn Encode(&self, writer: &mut dyn Writer) -> Result<(), EncodeError> {
+      for value in self.hard_state.iter() {
+        EncodeMessageField(writer, 1, value)?;
+      }
+      for value in self.entries.iter() {
+        EncodeMessageField(writer, 2, value)?;
+      }
+      for value in self.peers.iter() {
+        EncodeRawField(writer, 3, value.as_bytes())?;
+      }
+      match self.snapshot.raw() {
+        Some(raw) => {
+          for value in raw.iter() {
+            EncodeRawField(writer, 4, value)?;
+          }
+        }
+        None => {
+          for value in self.snapshot.get().iter() {
+            EncodeMessageField(writer, 4, value)?;
+          }
+        }
+      }
+      for unknown in self.unknown_fields.iter() {
+        EncodeTaggedValue(writer, unknown)?;
+      }
+      Ok(())
+    }
+  }
+
+  impl FieldMasked for RaftState {
+    fn IsMaskPath(path: &[&str], through_repeated: bool) -> bool {
+      match path {
+        ["hard_state", rest @ ..] => rest.is_empty() || HardState::IsMaskPath(rest, through_repeated),
+        ["entries", rest @ ..] => rest.is_empty() || through_repeated && Entry::IsMaskPath(rest, through_repeated),
+        ["peers"] => true,
+        ["snapshot", rest @ ..] => rest.is_empty() || Entry::IsMaskPath(rest, through_repeated),
+        _ => false
+      }
+    }
+
+    fn CopyMasked(&mut self, from: &Self, mask: &::protobuf::FieldMask, merge: bool) {
+      if let Some(mask) = mask.field("hard_state") {
+        if mask.is_all() && !merge {
+          self.hard_state = from.hard_state.clone();
+        } else if let Some(value) = from.hard_state.as_ref() {
+          self.hard_state.get_or_insert_with(HardState::new).CopyMasked(value, mask, merge);
+        } else if !merge {
+          if let Some(value) = self.hard_state.as_mut() {
+            value.CopyMasked(HardState::default_instance(), mask, merge);
+          }
+        }
+      }
+      if mask.field("entries").is_some() {
+        if merge {
+          self.entries.extend(from.entries.iter().cloned());
+        } else {
+          self.entries = from.entries.clone();
+        }
+      }
+      if mask.field("peers").is_some() {
+        if merge {
+          self.peers.extend(from.peers.iter().cloned());
+        } else {
+          self.peers = from.peers.clone();
+        }
+      }
+      if let Some(mask) = mask.field("snapshot") {
+        if mask.is_all() && !merge {
+          self.snapshot = from.snapshot.clone();
+        } else if let Some(value) = from.snapshot.get().as_ref() {
+          self.snapshot.get_mut().get_or_insert_with(Entry::new).CopyMasked(value, mask, merge);
+        } else if !merge {
+          if let Some(value) = self.snapshot.get_mut().as_mut() {
+            value.CopyMasked(Entry::default_instance(), mask, merge);
+          }
+        }
+      }
+      if merge && mask.is_all() {
+        self.unknown_fields.extend(from.unknown_fields.iter().cloned());
+      }
+    }
+
+    fn ClearUnmasked(&mut self, mask: &::protobuf::FieldMask) {
+      if mask.is_all() {
+        return;
+      }
+      match mask.field("hard_state") {
+        Some(mask) if mask.is_all() => (),
+        Some(mask) => {
+          for value in self.hard_state.iter_mut() {
+            value.ClearUnmasked(mask);
+          }
+        }
+        None => self.clear_hard_state()
+      }
+      match mask.field("entries") {
+        Some(mask) if mask.is_all() => (),
+        Some(mask) => {
+          for value in self.entries.iter_mut() {
+            value.ClearUnmasked(mask);
+          }
+        }
+        None => self.clear_entries()
+      }
+      if mask.field("peers").is_none() {
+        self.clear_peers();
+      }
+      match mask.field("snapshot") {
+        Some(mask) if mask.is_all() => (),
+        Some(mask) => {
+          for value in self.snapshot.get_mut().iter_mut() {
+            value.ClearUnmasked(mask);
+          }
+        }
+        None => self.clear_snapshot()
+      }
+      self.unknown_fields.clear();
+    }
+  }
+
+}
@@ -0,0 +1,598 @@
+// Generated by protoc-gen-rust from testdata/metrics.proto. Do not edit.
+
+pub mod metrics {
+  #[allow(unused_imports)]
+  use ::protobuf::{
+    Protobuf, Reader, TagIter, TaggedValue,
+    DecodeZigZag32, DecodeZigZag64, DecodeFloat, DecodeDouble,
+    DecodePackedVarints, DecodePackedFixed32, DecodePackedFixed64,
+    ProtobufEncode, Writer, EncodeError,
+    EncodeTaggedValue, EncodeMessageField, EncodeRawField,
+    EncodeVarintField, EncodeFixed32Field, EncodeFixed64Field,
+    EncodeZigZag32, EncodeZigZag64, EncodeFloat, EncodeDouble,
+    json, Json, ToJson,
+    Reflect, MessageInfo, FieldInfo,
+  };
+  #[allow(unused_imports)]
+  use ::protobuf::prelude::*;
+
+  pub struct Sample {
+    name: Option<String>,
+    value: Option<f64>,
+    count: Option<u32>,
+    unknown_fields: Vec<TaggedValue>,
+  }
+
+  impl Sample {
+    pub const fn new() -> Sample {
+      Sample {
+        name: None,
+        value: None,
+        count: None,
+        unknown_fields: Vec::new(),
+      }
+    }
+
+    pub fn default_instance() -> &'static Sample {
+      static INSTANCE: Sample = Sample::new();
+      &INSTANCE
+    }
+
+    pub fn has_name(&self) -> bool {
+      self.name.is_some()
+    }
+
+    pub fn get_name(&self) -> &str {
+      self.name.as_deref().unwrap_or("")
+    }
+
+    pub fn set_name(&mut self, value: String) {
+      self.name = Some(value);
+    }
+
+    pub fn clear_name(&mut self) {
+      self.name = None;
+    }
+
+    pub fn mut_name(&mut self) -> &mut String {
+      self.name.get_or_insert_with(|| String::from(""))
+    }
+
+    pub fn take_name(&mut self) -> String {
+      self.name.take().unwrap_or_else(|| String::from(""))
+    }
+
+    pub fn has_value(&self) -> bool {
+      self.value.is_some()
+    }
+
+    pub fn get_value(&self) -> f64 {
+      self.value.unwrap_or(0.0)
+    }
+
+    pub fn set_value(&mut self, value: f64) {
+      self.value = Some(value);
+    }
+
+    pub fn clear_value(&mut self) {
+      self.value = None;
+    }
+
+    pub fn mut_value(&mut self) -> &mut f64 {
+      self.value.get_or_insert(0.0)
+    }
+
+    pub fn take_value(&mut self) -> f64 {
+      self.value.take().unwrap_or(0.0)
+    }
+
+    pub fn has_count(&self) -> bool {
+      self.count.is_some()
+    }
+
+    pub fn get_count(&self) -> u32 {
+      self.count.unwrap_or(0)
+    }
+
+    pub fn set_count(&mut self, value: u32) {
+      self.count = Some(value);
+    }
+
+    pub fn clear_count(&mut self) {
+      self.count = None;
+    }
+
+    pub fn mut_count(&mut self) -> &mut u32 {
+      self.count.get_or_insert(0)
+    }
+
+    pub fn take_count(&mut self) -> u32 {
+      self.count.take().unwrap_or(0)
+    }
+
+    // Fields this message's schema doesn't know, and enum numbers outside
+    // the enum, in the order they were decoded.
+    pub fn get_unknown_fields(&self) -> &[TaggedValue] {
+      &self.unknown_fields
+    }
+  }
+
+  impl Protobuf for Sample {
+    fn DecodePartial(&mut self, reader: &mut dyn Reader) -> bool {
+      let mut tags = TagIter::new(reader);
+      for tag_option in tags.by_ref() {
+        match tag_option {
+          TaggedValue::Raw(1, value) => {
+            match String::from_utf8(value.into_vec()) {
+              Ok(value) => self.set_name(value),
+              Err(_) => return false
+            }
+          }
+          TaggedValue::Fixed64(2, value) => {
+            self.set_value(DecodeDouble(value));
+          }
+          TaggedValue::Varint(3, value) => {
+            self.set_count(value as u32);
+          }
+          unknown => self.unknown_fields.push(unknown)
+        }
+      }
+      !tags.is_malformed()
+    }
+
+    fn AppendMissingFields(&self, _prefix: &str, _missing: &mut Vec<String>) {
+    }
+  }
+
+  impl ProtobufEncode for Sample {
+    fn Encode(&self, writer: &mut dyn Writer) -> Result<(), EncodeError> {
+      for value in self.name.iter() {
+        EncodeRawField(writer, 1, value.as_bytes())?;
+      }
+      for value in self.value.iter() {
+        EncodeFixed64Field(writer, 2, EncodeDouble(*value))?;
+      }
+      for value in self.count.iter() {
+        EncodeVarintField(writer, 3, *value as u64)?;
+      }
+      for unknown in self.unknown_fields.iter() {
+        EncodeTaggedValue(writer, unknown)?;
+      }
+      Ok(())
+    }
+  }
+
+  impl ToJson for Sample {
+    fn to_json(&self) -> Json {
+      let mut object = json::Object::new();
+      for value in self.name.iter() {
+        object.insert("name".to_string(), Json::String(value.to_string()));
+      }
+      for value in self.value.iter() {
+        object.insert("value".to_string(), value.to_json());
+      }
+      for value in self.count.iter() {
+        object.insert("count".to_string(), value.to_json());
+      }
+      Json::Object(object)
+    }
+  }
+
+  impl Reflect for Sample {
+    fn message_info(&self) -> &'static MessageInfo {
+      static INFO: MessageInfo = MessageInfo {
+        full_name: "metrics.Sample",
+        fields: &[
+          FieldInfo { name: "name", number: 1, type_name: "string", repeated: false, required: false, message: None },
+          FieldInfo { name: "value", number: 2, type_name: "double", repeated: false, required: false, message: None },
+          FieldInfo { name: "count", number: 3, type_name: "uint32", repeated: false, required: false, message: None },
+        ]
+      };
+      &INFO
+    }
+  }
+
+  pub struct Window {
+    length: Option<u32>,
+    scale: Option<f32>,
+    unknown_fields: Vec<TaggedValue>,
+  }
+
+  impl Window {
+    pub const fn new() -> Window {
+      Window {
+        length: None,
+        scale: None,
+        unknown_fields: Vec::new(),
+      }
+    }
+
+    pub fn default_instance() -> &'static Window {
+      static INSTANCE: Window = Window::new();
+      &INSTANCE
+    }
+
+    pub fn has_length(&self) -> bool {
+      self.length.is_some()
+    }
+
+    pub fn get_length(&self) -> u32 {
+      self.length.unwrap_or(0)
+    }
+
+    pub fn set_length(&mut self, value: u32) {
+      self.length = Some(value);
+    }
+
+    pub fn clear_length(&mut self) {
+      self.length = None;
+    }
+
+    pub fn mut_length(&mut self) -> &mut u32 {
+      self.length.get_or_insert(0)
+    }
+
+    pub fn take_length(&mut self) -> u32 {
+      self.length.take().unwrap_or(0)
+    }
+
+    pub fn has_scale(&self) -> bool {
+      self.scale.is_some()
+    }
+
+    pub fn get_scale(&self) -> f32 {
+      self.scale.unwrap_or(0.0)
+    }
+
+    pub fn set_scale(&mut self, value: f32) {
+      self.scale = Some(value);
+    }
+
+    pub fn clear_scale(&mut self) {
+      self.scale = None;
+    }
+
+    pub fn mut_scale(&mut self) -> &mut f32 {
+      self.scale.get_or_insert(0.0)
+    }
+
+    pub fn take_scale(&mut self) -> f32 {
+      self.scale.take().unwrap_or(0.0)
+    }
+
+    // Fields this message's schema doesn't know, and enum numbers outside
+    // the enum, in the order they were decoded.
+    pub fn get_unknown_fields(&self) -> &[TaggedValue] {
+      &self.unknown_fields
+    }
+  }
+
+  impl Protobuf for Window {
+    fn DecodePartial(&mut self, reader: &mut dyn Reader) -> bool {
+      let mut tags = TagIter::new(reader);
+      for tag_option in tags.by_ref() {
+        match tag_option {
+          TaggedValue::Varint(1, value) => {
+            self.set_length(value as u32);
+          }
+          TaggedValue::Fixed32(2, value) => {
+            self.set_scale(DecodeFloat(value));
+          }
+          unknown => self.unknown_fields.push(unknown)
+        }
+      }
+      !tags.is_malformed()
+    }
+
+    fn AppendMissingFields(&self, _prefix: &str, _missing: &mut Vec<String>) {
+    }
+  }
+
+  impl ProtobufEncode for Window {
+    fn Encode(&self, writer: &mut dyn Writer) -> Result<(), EncodeError> {
+      for value in self.length.iter() {
+        EncodeVarintField(writer, 1, *value as u64)?;
+      }
+      for value in self.scale.iter() {
+        EncodeFixed32Field(writer, 2, EncodeFloat(*value))?;
+      }
+      for unknown in self.unknown_fields.iter() {
+        EncodeTaggedValue(writer, unknown)?;
+      }
+      Ok(())
+    }
+  }
+
+  impl ToJson for Window {
+    fn to_json(&self) -> Json {
+      let mut object = json::Object::new();
+      for value in self.length.iter() {
+        object.insert("length".to_string(), value.to_json());
+      }
+      for value in self.scale.iter() {
+        object.insert("scale".to_string(), value.to_json());
+      }
+      Json::Object(object)
+    }
+  }
+
+  impl Reflect for Window {
+    fn message_info(&self) -> &'static MessageInfo {
+      static INFO: MessageInfo = MessageInfo {
+        full_name: "metrics.Window",
+        fields: &[
+          FieldInfo { name: "length", number: 1, type_name: "uint32", repeated: false, required: false, message: None },
+          FieldInfo { name: "scale", number: 2, type_name: "float", repeated: false, required: false, message: None },
+        ]
+      };
+      &INFO
+    }
+  }
+
+  /// Samples taken together, of which only the float and double fields are
+  /// compared within a tolerance.
+  pub struct Series {
+    samples: Vec<Sample>,
+    scale: Option<f32>,
+    window: Option<Window>,
+    unknown_fields: Vec<TaggedValue>,
+  }
+
+  impl Series {
+    pub const fn new() -> Series {
+      Series {
+        samples: Vec::new(),
+        scale: None,
+        window: None,
+        unknown_fields: Vec::new(),
+      }
+    }
+
+    pub fn default_instance() -> &'static Series {
+      static INSTANCE: Series = Series::new();
+      &INSTANCE
+    }
+
+    pub fn get_samples(&self) -> &[Sample] {
+      &self.samples
+    }
+
+    pub fn set_samples(&mut self, value: Vec<Sample>) {
+      self.samples = value;
+    }
+
+    pub fn clear_samples(&mut self) {
+      self.samples.clear();
+    }
+
+    pub fn mut_samples(&mut self) -> &mut Vec<Sample> {
+      &mut self.samples
+    }
+
+    pub fn take_samples(&mut self) -> Vec<Sample> {
+      core::mem::take(&mut self.samples)
+    }
+
+    pub fn add_samples(&mut self, value: Sample) {
+      self.samples.push(value);
+    }
+
+    pub fn samples_len(&self) -> usize {
+      self.samples.len()
+    }
+
+    pub fn has_scale(&self) -> bool {
+      self.scale.is_some()
+    }
+
+    pub fn get_scale(&self) -> f32 {
+      self.scale.unwrap_or(0.0)
+    }
+
+    pub fn set_scale(&mut self, value: f32) {
+      self.scale = Some(value);
+    }
+
+    pub fn clear_scale(&mut self) {
+      self.scale = None;
+    }
+
+    pub fn mut_scale(&mut self) -> &mut f32 {
+      self.scale.get_or_insert(0.0)
+    }
+
+    pub fn take_scale(&mut self) -> f32 {
+      self.scale.take().unwrap_or(0.0)
+    }
+
+    pub fn has_window(&self) -> bool {
+      self.window.is_some()
+    }
+
+    pub fn get_window(&self) -> &Window {
+      match self.window { Some(ref value) => value, None => Window::default_instance() }
+    }
+
+    pub fn set_window(&mut self, value: Window) {
+      self.window = Some(value);
+    }
+
+    pub fn clear_window(&mut self) {
+      self.window = None;
+    }
+
+    pub fn mut_window(&mut self) -> &mut Window {
+      self.window.get_or_insert_with(Window::new)
+    }
+
+    pub fn take_window(&mut self) -> Window {
+      self.window.take().unwrap_or_else(Window::new)
+    }
+
+    // Fields this message's schema doesn't know, and enum numbers outside
+    // the enum, in the order they were decoded.
+    pub fn get_unknown_fields(&self) -> &[TaggedValue] {
+      &self.unknown_fields
+    }
+  }
+
+  impl Protobuf for Series {
+    fn DecodePartial(&mut self, reader: &mut dyn Reader) -> bool {
+      let mut tags = TagIter::new(reader);
+      for tag_option in tags.by_ref() {
+        match tag_option {
+          TaggedValue::Raw(1, value) => {
+            let mut reader: &[u8] = &value;
+            let mut message = Sample::new();
+            if !message.DecodePartial(&mut reader) {
+              return false;
+            }
+            self.add_samples(message);
+          }
+          TaggedValue::Fixed32(2, value) => {
+            self.set_scale(DecodeFloat(value));
+          }
+          TaggedValue::Raw(3, value) => {
+            let mut reader: &[u8] = &value;
+            if !self.mut_window().DecodePartial(&mut reader) {
+              return false;
+            }
+          }
+          unknown => self.unknown_fields.push(unknown)
+        }
+      }
+      !tags.is_malformed()
+    }
+
+    fn AppendMissingFields(&self, prefix: &str, missing: &mut Vec<String>) {
+      for (i, value) in self.samples.iter().enumerate() {
+        for path in value.missing_fields() {
+          missing.push(format!("{}samples[{}].{}", prefix, i, path));
+        }
+      }
+      match self.window {
+        Some(ref value) => {
+          for path in value.missing_fields() {
+            missing.push(format!("{}window.{}", prefix, path));
+          }
+        }
+        None => ()
+      }
+    }
+  }
+
+  impl ProtobufEncode for Series {
+    fn Encode(&self, writer: &mut dyn Writer) -> Result<(), EncodeError> {
+      for value in self.samples.iter() {
+        EncodeMessageField(writer, 1, value)?;
+      }
+      for value in self.scale.iter() {
+        EncodeFixed32Field(writer, 2, EncodeFloat(*value))?;
+      }
+      for value in self.window.iter() {
+        EncodeMessageField(writer, 3, value)?;
+      }
+      for unknown in self.unknown_fields.iter() {
+        EncodeTaggedValue(writer, unknown)?;
+      }
+      Ok(())
+    }
+  }
+
+  impl ToJson for Series {
+    fn to_json(&self) -> Json {
+      let mut object = json::Object::new();
+      if !self.samples.is_empty() {
+        object.insert("samples".to_string(), Json::List(self.samples.iter().map(|value| value.to_json()).collect()));
+      }
+      for value in self.scale.iter() {
+        object.insert("scale".to_string(), value.to_json());
+      }
+      for value in self.window.iter() {
+        object.insert("window".to_string(), value.to_json());
+      }
+      Json::Object(object)
+    }
+  }
+
+  impl Reflect for Series {
+    fn message_info(&self) -> &'static MessageInfo {
+      static INFO: MessageInfo = MessageInfo {
+        full_name: "metrics.Series",
+        fields: &[
+          FieldInfo { name: "samples", number: 1, type_name: "metrics.Sample", repeated: true, required: false, message: Some(|| Sample::default_instance().message_info()) },
+          FieldInfo { name: "scale", number: 2, type_name: "float", repeated: false, required: false, message: None },
+          FieldInfo { name: "window", number: 3, type_name: "metrics.Window", repeated: false, required: false, message: Some(|| Window::default_instance().message_info()) },
+        ]
+      };
+      &INFO
+    }
+  }
+
+  /// Ends a stream of series. It has no fields, so its JSON is always {}.
+  pub struct End {
+    unknown_fields: Vec<TaggedValue>,
+  }
+
+  impl End {
+    pub const fn new() -> End {
+      End {
+        unknown_fields: Vec::new(),
+      }
+    }
+
+    pub fn default_instance() -> &'static End {
+      static INSTANCE: End = End::new();
+      &INSTANCE
+    }
+
+    // Fields this message's schema doesn't know, and enum numbers outside
+    // the enum, in the order they were decoded.
+    pub fn get_unknown_fields(&self) -> &[TaggedValue] {
+      &self.unknown_fields
+    }
+  }
+
+  impl Protobuf for End {
+    fn DecodePartial(&mut self, reader: &mut dyn Reader) -> bool {
+      let mut tags = TagIter::new(reader);
+      for tag_option in tags.by_ref() {
+        match tag_option {
+          unknown => self.unknown_fields.push(unknown)
+        }
+      }
+      !tags.is_malformed()
+    }
+
+    fn AppendMissingFields(&self, _prefix: &str, _missing: &mut Vec<String>) {
+    }
+  }
+
+  impl ProtobufEncode for End {
+    fn Encode(&self, writer: &mut dyn Writer) -> Result<(), EncodeError> {
+      for unknown in self.unknown_fields.iter() {
+        EncodeTaggedValue(writer, unknown)?;
+      }
+      Ok(())
+    }
+  }
+
+  impl ToJson for End {
+    fn to_json(&self) -> Json {
+      let object = json::Object::new();
+      Json::Object(object)
+    }
+  }
+
+  impl Reflect for End {
+    fn message_info(&self) -> &'static MessageInfo {
+      static INFO: MessageInfo = MessageInfo {
+        full_name: "metrics.End",
+        fields: &[
+        ]
+      };
+      &INFO
+    }
+  }
+
+}
@@ -0,0 +1,377 @@
+// Generated by protoc-gen-rust from batch.proto. Do not edit.
+
+pub mod batch {
+  #[allow(unused_imports)]
+  use ::protobuf::{
+    Protobuf, Reader, TagIter, TaggedValue,
+    DecodeZigZag32, DecodeZigZag64, DecodeFloat, DecodeDouble,
+    DecodePackedVarints, DecodePackedFixed32, DecodePackedFixed64,
+    ProtobufEncode, Writer, EncodeError,
+    EncodeTaggedValue, EncodeMessageField, EncodeRawField,
+    EncodeVarintField, EncodeFixed32Field, EncodeFixed64Field,
+    EncodeZigZag32, EncodeZigZag64, EncodeFloat, EncodeDouble,
+    json, Json, ToJson,
+  };
+  #[allow(unused_imports)]
+  use ::protobuf::prelude::*;
+
+  pub struct Entry {
+    index: Option<u64>,
+    command: Option<Vec<u8>>,
+    unknown_fields: Vec<TaggedValue>,
+  }
+
+  impl Entry {
+    pub const fn new() -> Entry {
+      Entry {
+        index: None,
+        command: None,
+        unknown_fields: Vec::new(),
+      }
+    }
+
+    pub fn default_instance() -> &'static Entry {
+      static INSTANCE: Entry = Entry::new();
+      &INSTANCE
+    }
+
+    pub fn has_index(&self) -> bool {
+      self.index.is_some()
+    }
+
+    pub fn get_index(&self) -> u64 {
+      self.index.unwrap_or(0)
+    }
+
+    pub fn set_index(&mut self, value: u64) {
+      self.index = Some(value);
+    }
+
+    pub fn clear_index(&mut self) {
+      self.index = None;
+    }
+
+    pub fn mut_index(&mut self) -> &mut u64 {
+      self.index.get_or_insert(0)
+    }
+
+    pub fn take_index(&mut self) -> u64 {
+      self.index.take().unwrap_or(0)
+    }
+
+    pub fn has_command(&self) -> bool {
+      self.command.is_some()
+    }
+
+    pub fn get_command(&self) -> &[u8] {
+      self.command.as_deref().unwrap_or(b"")
+    }
+
+    pub fn set_command(&mut self, value: Vec<u8>) {
+      self.command = Some(value);
+    }
+
+    pub fn clear_command(&mut self) {
+      self.command = None;
+    }
+
+    pub fn mut_command(&mut self) -> &mut Vec<u8> {
+      self.command.get_or_insert_with(|| b"".to_vec())
+    }
+
+    pub fn take_command(&mut self) -> Vec<u8> {
+      self.command.take().unwrap_or_else(|| b"".to_vec())
+    }
+
+    // Fields this message's schema doesn't know, and enum numbers outside
+    // the enum, in the order they were decoded.
+    pub fn get_unknown_fields(&self) -> &[TaggedValue] {
+      &self.unknown_fields
+    }
+  }
+
+  impl Protobuf for Entry {
+    fn DecodePartial(&mut self, reader: &mut dyn Reader) -> bool {
+      let mut tags = TagIter::new(reader);
+      for tag_option in tags.by_ref() {
+        match tag_option {
+          TaggedValue::Varint(1, value) => {
+            self.set_index(value);
+          }
+          TaggedValue::Raw(2, value) => {
+            self.set_command(value.into_vec());
+          }
+          unknown => self.unknown_fields.push(unknown)
+        }
+      }
+      !tags.is_malformed()
+    }
+
+    fn AppendMissingFields(&self, prefix: &str, missing: &mut Vec<String>) {
+      if self.index.is_none() {
+        missing.push(format!("{}index", prefix));
+      }
+    }
+  }
+
+  impl ProtobufEncode for Entry {
+    fn Encode(&self, writer: &mut dyn Writer) -> Result<(), EncodeError> {
+      for value in self.index.iter() {
+        EncodeVarintField(writer, 1, *value)?;
+      }
+      for value in self.command.iter() {
+        EncodeRawField(writer, 2, value)?;
+      }
+      for unknown in self.unknown_fields.iter() {
+        EncodeTaggedValue(writer, unknown)?;
+      }
+      Ok(())
+    }
+  }
+
+  impl ToJson for Entry {
+    fn to_json(&self) -> Json {
+      let mut object = json::Object::new();
+      for value in self.index.iter() {
+        object.insert("index".to_string(), Json::String(value.to_string()));
+      }
+      for value in self.command.iter() {
+        object.insert("command".to_string(), Json::String(json::to_base64(value)));
+      }
+      Json::Object(object)
+    }
+  }
+
+  /// Consumers often read only the term and commit index of a batch.
+  pub struct Batch {
+    term: Option<u64>,
+    commit_index: Option<u64>,
+    entries: ::protobuf::Lazy<Vec<Entry>>,
+    last: ::protobuf::Lazy<Option<Entry>>,
+    unknown_fields: Vec<TaggedValue>,
+  }
+
+  impl Batch {
+    pub const fn new() -> Batch {
+      Batch {
+        term: None,
+        commit_index: None,
+        entries: ::protobuf::Lazy::new(|raw| ::protobuf::ParseLazyMessages(raw, Entry::new)),
+        last: ::protobuf::Lazy::new(|raw| ::protobuf::ParseLazyMessage(raw, Entry::new)),
+        unknown_fields: Vec::new(),
+      }
+    }
+
+    pub fn default_instance() -> &'static Batch {
+      static INSTANCE: Batch = Batch::new();
+      &INSTANCE
+    }
+
+    pub fn has_term(&self) -> bool {
+      self.term.is_some()
+    }
+
+    pub fn get_term(&self) -> u64 {
+      self.term.unwrap_or(0)
+    }
+
+    pub fn set_term(&mut self, value: u64) {
+      self.term = Some(value);
+    }
+
+    pub fn clear_term(&mut self) {
+      self.term = None;
+    }
+
+    pub fn mut_term(&mut self) -> &mut u64 {
+      self.term.get_or_insert(0)
+    }
+
+    pub fn take_term(&mut self) -> u64 {
+      self.term.take().unwrap_or(0)
+    }
+
+    pub fn has_commit_index(&self) -> bool {
+      self.commit_index.is_some()
+    }
+
+    pub fn get_commit_index(&self) -> u64 {
+      self.commit_index.unwrap_or(0)
+    }
+
+    pub fn set_commit_index(&mut self, value: u64) {
+      self.commit_index = Some(value);
+    }
+
+    pub fn clear_commit_index(&mut self) {
+      self.commit_index = None;
+    }
+
+    pub fn mut_commit_index(&mut self) -> &mut u64 {
+      self.commit_index.get_or_insert(0)
+    }
+
+    pub fn take_commit_index(&mut self) -> u64 {
+      self.commit_index.take().unwrap_or(0)
+    }
+
+    pub fn get_entries(&self) -> &[Entry] {
+      self.entries.get()
+    }
+
+    pub fn set_entries(&mut self, value: Vec<Entry>) {
+      self.entries.set(value);
+    }
+
+    pub fn clear_entries(&mut self) {
+      self.entries.clear();
+    }
+
+    pub fn mut_entries(&mut self) -> &mut Vec<Entry> {
+      self.entries.get_mut()
+    }
+
+    pub fn take_entries(&mut self) -> Vec<Entry> {
+      self.entries.take()
+    }
+
+    pub fn add_entries(&mut self, value: Entry) {
+      self.entries.get_mut().push(value);
+    }
+
+    pub fn entries_len(&self) -> usize {
+      self.entries.get().len()
+    }
+
+    pub fn has_last(&self) -> bool {
+      self.last.is_set()
+    }
+
+    pub fn get_last(&self) -> &Entry {
+      match *self.last.get() { Some(ref value) => value, None => Entry::default_instance() }
+    }
+
+    pub fn set_last(&mut self, value: Entry) {
+      self.last.set(Some(value));
+    }
+
+    pub fn clear_last(&mut self) {
+      self.last.clear();
+    }
+
+    pub fn mut_last(&mut self) -> &mut Entry {
+      self.last.get_mut().get_or_insert_with(Entry::new)
+    }
+
+    pub fn take_last(&mut self) -> Entry {
+      self.last.take().unwrap_or_else(Entry::new)
+    }
+
+    // Fields this message's schema doesn't know, and enum numbers outside
+    // the enum, in the order they were decoded.
+    pub fn get_unknown_fields(&self) -> &[TaggedValue] {
+      &self.unknown_fields
+    }
+  }
+
+  impl Protobuf for Batch {
+    fn DecodePartial(&mut self, reader: &mut dyn Reader) -> bool {
+      let mut tags = TagIter::new(reader);
+      for tag_option in tags.by_ref() {
+        match tag_option {
+          TaggedValue::Varint(1, value) => {
+            self.set_term(value);
+          }
+          TaggedValue::Varint(2, value) => {
+            self.set_commit_index(value);
+          }
+          TaggedValue::Raw(3, value) => {
+            if let Err(value) = self.entries.push_raw(value) {
+              let mut reader: &[u8] = &value;
+              let mut message = Entry::new();
+              if !message.DecodePartial(&mut reader) {
+                return false;
+              }
+              self.add_entries(message);
+            }
+          }
+          TaggedValue::Raw(4, value) => {
+            if let Err(value) = self.last.push_raw(value) {
+              let mut reader: &[u8] = &value;
+              if !self.mut_last().DecodePartial(&mut reader) {
+                return false;
+              }
+            }
+          }
+          unknown => self.unknown_fields.push(unknown)
+        }
+      }
+      !tags.is_malformed()
+    }
+
+    fn AppendMissingFields(&self, prefix: &str, missing: &mut Vec<String>) {
+      if self.term.is_none() {
+        missing.push(format!("{}term", prefix));
+      }
+    }
+  }
+
+  impl ProtobufEncode for Batch {
+    fn Encode(&self, writer: &mut dyn Writer) -> Result<(), EncodeError> {
+      for value in self.term.iter() {
+        EncodeVarintField(writer, 1, *value)?;
+      }
+      for value in self.commit_index.iter() {
+        EncodeVarintField(writer, 2, *value)?;
+      }
+      match self.entries.raw() {
+        Some(raw) => {
+          for value in raw.iter() {
+            EncodeRawField(writer, 3, value)?;
+          }
+        }
+        None => {
+          for value in self.entries.get().iter() {
+            EncodeMessageField(writer, 3, value)?;
+          }
+        }
+      }
+      match self.last.raw() {
+        Some(raw) => {
+          for value in raw.iter() {
+            EncodeRawField(writer, 4, value)?;
+          }
+        }
+        None => {
+          for value in self.last.get().iter() {
+            EncodeMessageField(writer, 4, value)?;
+          }
+        }
+      }
+      for unknown in self.unknown_fields.iter() {
+        EncodeTaggedValue(writer, unknown)?;
+      }
+      Ok(())
+    }
+  }
+
+  impl ToJson for Batch {
+    fn to_json(&self) -> Json {
+      let mut object = json::Object::new();
+      for value in self.term.iter() {
+        object.insert("term".to_string(), Json::String(value.to_string()));
+      }
+      for value in self.commit_index.iter() {
+        object.insert("commit_index".to_string(), Json::String(value.to_string()));
+      }
+      if !self.entries.get().is_empty() {
+        object.insert("entries".to_string(), Json::List(self.entries.get().iter().map(|value| value.to_json()).collect()));
+      }
+      for value in self.last.get().iter() {
+        object.insert("last".to_string(), value.to_json());
+      }
+      Json::Object(object)
+    }
+  }
+
+}
@@ -0,0 +1,220 @@
+// Generated by protoc-gen-rust from proto/append_entries_responses.proto. Do not edit.
+
+pub mod protobuf {
+  #[allow(unused_imports)]
+  use ::protobuf::{
+    Protobuf, Reader, TagIter, TaggedValue,
+    DecodeZigZag32, DecodeZigZag64, DecodeFloat, DecodeDouble,
+    DecodePackedVarints, DecodePackedFixed32, DecodePackedFixed64,
+    ProtobufEncode, Writer, EncodeError,
+    EncodeTaggedValue, EncodeMessageField, EncodeRawField,
+    EncodeVarintField, EncodeFixed32Field, EncodeFixed64Field,
+    EncodeZigZag32, EncodeZigZag64, EncodeFloat, EncodeDouble,
+    Reflect, MessageInfo, FieldInfo,
+  };
+  #[allow(unused_imports)]
+  use ::protobuf::prelude::*;
+
+  pub struct ProtoAppendEntriesResponse {
+    term: Option<u64>,
+    index: Option<u64>,
+    commit_index: Option<u64>,
+    success: Option<bool>,
+    unknown_fields: Vec<TaggedValue>,
+  }
+
+  impl ProtoAppendEntriesResponse {
+    pub const fn new() -> ProtoAppendEntriesResponse {
+      ProtoAppendEntriesResponse {
+        term: None,
+        index: None,
+        commit_index: None,
+        success: None,
+        unknown_fields: Vec::new(),
+      }
+    }
+
+    pub fn default_instance() -> &'static ProtoAppendEntriesResponse {
+      static INSTANCE: ProtoAppendEntriesResponse = ProtoAppendEntriesResponse::new();
+      &INSTANCE
+    }
+
+    pub fn has_term(&self) -> bool {
+      self.term.is_some()
+    }
+
+    pub fn get_term(&self) -> u64 {
+      self.term.unwrap_or(0)
+    }
+
+    pub fn set_term(&mut self, value: u64) {
+      self.term = Some(value);
+    }
+
+    pub fn clear_term(&mut self) {
+      self.term = None;
+    }
+
+    pub fn mut_term(&mut self) -> &mut u64 {
+      self.term.get_or_insert(0)
+    }
+
+    pub fn take_term(&mut self) -> u64 {
+      self.term.take().unwrap_or(0)
+    }
+
+    pub fn has_index(&self) -> bool {
+      self.index.is_some()
+    }
+
+    pub fn get_index(&self) -> u64 {
+      self.index.unwrap_or(0)
+    }
+
+    pub fn set_index(&mut self, value: u64) {
+      self.index = Some(value);
+    }
+
+    pub fn clear_index(&mut self) {
+      self.index = None;
+    }
+
+    pub fn mut_index(&mut self) -> &mut u64 {
+      self.index.get_or_insert(0)
+    }
+
+    pub fn take_index(&mut self) -> u64 {
+      self.index.take().unwrap_or(0)
+    }
+
+    pub fn has_commit_index(&self) -> bool {
+      self.commit_index.is_some()
+    }
+
+    pub fn get_commit_index(&self) -> u64 {
+      self.commit_index.unwrap_or(0)
+    }
+
+    pub fn set_commit_index(&mut self, value: u64) {
+      self.commit_index = Some(value);
+    }
+
+    pub fn clear_commit_index(&mut self) {
+      self.commit_index = None;
+    }
+
+    pub fn mut_commit_index(&mut self) -> &mut u64 {
+      self.commit_index.get_or_insert(0)
+    }
+
+    pub fn take_commit_index(&mut self) -> u64 {
+      self.commit_index.take().unwrap_or(0)
+    }
+
+    pub fn has_success(&self) -> bool {
+      self.success.is_some()
+    }
+
+    pub fn get_success(&self) -> bool {
+      self.success.unwrap_or(false)
+    }
+
+    pub fn set_success(&mut self, value: bool) {
+      self.success = Some(value);
+    }
+
+    pub fn clear_success(&mut self) {
+      self.success = None;
+    }
+
+    pub fn mut_success(&mut self) -> &mut bool {
+      self.success.get_or_insert(false)
+    }
+
+    pub fn take_success(&mut self) -> bool {
+      self.success.take().unwrap_or(false)
+    }
+
+    // Fields this message's schema doesn't know, and enum numbers outside
+    // the enum, in the order they were decoded.
+    pub fn get_unknown_fields(&self) -> &[TaggedValue] {
+      &self.unknown_fields
+    }
+  }
+
+  impl Protobuf for ProtoAppendEntriesResponse {
+    fn DecodePartial(&mut self, reader: &mut dyn Reader) -> bool {
+      let mut tags = TagIter::new(reader);
+      for tag_option in tags.by_ref() {
+        match tag_option {
+          TaggedValue::Varint(1, value) => {
+            self.set_term(value);
+          }
+          TaggedValue::Varint(2, value) => {
+            self.set_index(value);
+          }
+          TaggedValue::Varint(3, value) => {
+            self.set_commit_index(value);
+          }
+          TaggedValue::Varint(4, value) => {
+            self.set_success(value != 0);
+          }
+          unknown => self.unknown_fields.push(unknown)
+        }
+      }
+      !tags.is_malformed()
+    }
+
+    fn AppendMissingFields(&self, prefix: &str, missing: &mut Vec<String>) {
+      if self.term.is_none() {
+        missing.push(format!("{}Term", prefix));
+      }
+      if self.index.is_none() {
+        missing.push(format!("{}Index", prefix));
+      }
+      if self.commit_index.is_none() {
+        missing.push(format!("{}CommitIndex", prefix));
+      }
+      if self.success.is_none() {
+        missing.push(format!("{}Success", prefix));
+      }
+    }
+  }
+
+  impl ProtobufEncode for ProtoAppendEntriesResponse {
+    fn Encode(&self, writer: &mut dyn Writer) -> Result<(), EncodeError> {
+      for value in self.term.iter() {
+        EncodeVarintField(writer, 1, *value)?;
+      }
+      for value in self.index.iter() {
+        EncodeVarintField(writer, 2, *value)?;
+      }
+      for value in self.commit_index.iter() {
+        EncodeVarintField(writer, 3, *value)?;
+      }
+      for value in self.success.iter() {
+        EncodeVarintField(writer, 4, *value as u64)?;
+      }
+      for unknown in self.unknown_fields.iter() {
+        EncodeTaggedValue(writer, unknown)?;
+      }
+      Ok(())
+    }
+  }
+
+  impl Reflect for ProtoAppendEntriesResponse {
+    fn message_info(&self) -> &'static MessageInfo {
+      static INFO: MessageInfo = MessageInfo {
+        full_name: "protobuf.ProtoAppendEntriesResponse",
+        fields: &[
+          FieldInfo { name: "Term", number: 1, type_name: "uint64", repeated: false, required: true, message: None },
+          FieldInfo { name: "Index", number: 2, type_name: "uint64", repeated: false, required: true, message: None },
+          FieldInfo { name: "CommitIndex", number: 3, type_name: "uint64", repeated: false, required: true, message: None },
+          FieldInfo { name: "Success", number: 4, type_name: "bool", repeated: false, required: true, message: None },
+        ]
+      };
+      &INFO
+    }
+  }
+
+}
@@ -0,0 +1,221 @@
+// Generated by protoc-gen-rust from proto/log_entry.proto. Do not edit.
+
+pub mod protobuf {
+  #[allow(unused_imports)]
+  use ::protobuf::{
+    Protobuf, Reader, TagIter, TaggedValue,
+    DecodeZigZag32, DecodeZigZag64, DecodeFloat, DecodeDouble,
+    DecodePackedVarints, DecodePackedFixed32, DecodePackedFixed64,
+    ProtobufEncode, Writer, EncodeError,
+    EncodeTaggedValue, EncodeMessageField, EncodeRawField,
+    EncodeVarintField, EncodeFixed32Field, EncodeFixed64Field,
+    EncodeZigZag32, EncodeZigZag64, EncodeFloat, EncodeDouble,
+    Reflect, MessageInfo, FieldInfo,
+  };
+  #[allow(unused_imports)]
+  use ::protobuf::prelude::*;
+
+  pub struct ProtoLogEntry {
+    index: Option<u64>,
+    term: Option<u64>,
+    command_name: Option<String>,
+    /// for nop-command
+    command: Option<Vec<u8>>,
+    unknown_fields: Vec<TaggedValue>,
+  }
+
+  impl ProtoLogEntry {
+    pub const fn new() -> ProtoLogEntry {
+      ProtoLogEntry {
+        index: None,
+        term: None,
+        command_name: None,
+        command: None,
+        unknown_fields: Vec::new(),
+      }
+    }
+
+    pub fn default_instance() -> &'static ProtoLogEntry {
+      static INSTANCE: ProtoLogEntry = ProtoLogEntry::new();
+      &INSTANCE
+    }
+
+    pub fn has_index(&self) -> bool {
+      self.index.is_some()
+    }
+
+    pub fn get_index(&self) -> u64 {
+      self.index.unwrap_or(0)
+    }
+
+    pub fn set_index(&mut self, value: u64) {
+      self.index = Some(value);
+    }
+
+    pub fn clear_index(&mut self) {
+      self.index = None;
+    }
+
+    pub fn mut_index(&mut self) -> &mut u64 {
+      self.index.get_or_insert(0)
+    }
+
+    pub fn take_index(&mut self) -> u64 {
+      self.index.take().unwrap_or(0)
+    }
+
+    pub fn has_term(&self) -> bool {
+      self.term.is_some()
+    }
+
+    pub fn get_term(&self) -> u64 {
+      self.term.unwrap_or(0)
+    }
+
+    pub fn set_term(&mut self, value: u64) {
+      self.term = Some(value);
+    }
+
+    pub fn clear_term(&mut self) {
+      self.term = None;
+    }
+
+    pub fn mut_term(&mut self) -> &mut u64 {
+      self.term.get_or_insert(0)
+    }
+
+    pub fn take_term(&mut self) -> u64 {
+      self.term.take().unwrap_or(0)
+    }
+
+    pub fn has_command_name(&self) -> bool {
+      self.command_name.is_some()
+    }
+
+    pub fn get_command_name(&self) -> &str {
+      self.command_name.as_deref().unwrap_or("")
+    }
+
+    pub fn set_command_name(&mut self, value: String) {
+      self.command_name = Some(value);
+    }
+
+    pub fn clear_command_name(&mut self) {
+      self.command_name = None;
+    }
+
+    pub fn mut_command_name(&mut self) -> &mut String {
+      self.command_name.get_or_insert_with(|| String::from(""))
+    }
+
+    pub fn take_command_name(&mut self) -> String {
+      self.command_name.take().unwrap_or_else(|| String::from(""))
+    }
+
+    pub fn has_command(&self) -> bool {
+      self.command.is_some()
+    }
+
+    pub fn get_command(&self) -> &[u8] {
+      self.command.as_deref().unwrap_or(b"")
+    }
+
+    pub fn set_command(&mut self, value: Vec<u8>) {
+      self.command = Some(value);
+    }
+
+    pub fn clear_command(&mut self) {
+      self.command = None;
+    }
+
+    pub fn mut_command(&mut self) -> &mut Vec<u8> {
+      self.command.get_or_insert_with(|| b"".to_vec())
+    }
+
+    pub fn take_command(&mut self) -> Vec<u8> {
+      self.command.take().unwrap_or_else(|| b"".to_vec())
+    }
+
+    // Fields this message's schema doesn't know, and enum numbers outside
+    // the enum, in the order they were decoded.
+    pub fn get_unknown_fields(&self) -> &[TaggedValue] {
+      &self.unknown_fields
+    }
+  }
+
+  impl Protobuf for ProtoLogEntry {
+    fn DecodePartial(&mut self, reader: &mut dyn Reader) -> bool {
+      let mut tags = TagIter::new(reader);
+      for tag_option in tags.by_ref() {
+        match tag_option {
+          TaggedValue::Varint(1, value) => {
+            self.set_index(value);
+          }
+          TaggedValue::Varint(2, value) => {
+            self.set_term(value);
+          }
+          TaggedValue::Raw(3, value) => {
+            match String::from_utf8(value.into_vec()) {
+              Ok(value) => self.set_command_name(value),
+              Err(_) => return false
+            }
+          }
+          TaggedValue::Raw(4, value) => {
+            self.set_command(value.into_vec());
+          }
+          unknown => self.unknown_fields.push(unknown)
+        }
+      }
+      !tags.is_malformed()
+    }
+
+    fn AppendMissingFields(&self, prefix: &str, missing: &mut Vec<String>) {
+      if self.index.is_none() {
+        missing.push(format!("{}Index", prefix));
+      }
+      if self.term.is_none() {
+        missing.push(format!("{}Term", prefix));
+      }
+      if self.command_name.is_none() {
+        missing.push(format!("{}CommandName", prefix));
+      }
+    }
+  }
+
+  impl ProtobufEncode for ProtoLogEntry {
+    fn Encode(&self, writer: &mut dyn Writer) -> Result<(), EncodeError> {
+      for value in self.index.iter() {
+        EncodeVarintField(writer, 1, *value)?;
+      }
+      for value in self.term.iter() {
+        EncodeVarintField(writer, 2, *value)?;
+      }
+      for value in self.command_name.iter() {
+        EncodeRawField(writer, 3, value.as_bytes())?;
+      }
+      for value in self.command.iter() {
+        EncodeRawField(writer, 4, value)?;
+      }
+      for unknown in self.unknown_fields.iter() {
+        EncodeTaggedValue(writer, unknown)?;
+      }
+      Ok(())
+    }
+  }
+
+  impl Reflect for ProtoLogEntry {
+    fn message_info(&self) -> &'static MessageInfo {
+      static INFO: MessageInfo = MessageInfo {
+        full_name: "protobuf.ProtoLogEntry",
+        fields: &[
+          FieldInfo { name: "Index", number: 1, type_name: "uint64", repeated: false, required: true, message: None },
+          FieldInfo { name: "Term", number: 2, type_name: "uint64", repeated: false, required: true, message: None },
+          FieldInfo { name: "CommandName", number: 3, type_name: "string", repeated: false, required: true, message: None },
+          FieldInfo { name: "Command", number: 4, type_name: "bytes", repeated: false, required: false, message: None },
+        ]
+      };
+      &INFO
+    }
+  }
+
+}
@@ -0,0 +1,223 @@
+// Generated by protoc-gen-rust from proto/request_vote_request.proto. Do not edit.
+
+pub mod protobuf {
+  #[allow(unused_imports)]
+  use ::protobuf::{
+    Protobuf, Reader, TagIter, TaggedValue,
+    DecodeZigZag32, DecodeZigZag64, DecodeFloat, DecodeDouble,
+    DecodePackedVarints, DecodePackedFixed32, DecodePackedFixed64,
+    ProtobufEncode, Writer, EncodeError,
+    EncodeTaggedValue, EncodeMessageField, EncodeRawField,
+    EncodeVarintField, EncodeFixed32Field, EncodeFixed64Field,
+    EncodeZigZag32, EncodeZigZag64, EncodeFloat, EncodeDouble,
+    Reflect, MessageInfo, FieldInfo,
+  };
+  #[allow(unused_imports)]
+  use ::protobuf::prelude::*;
+
+  pub struct ProtoRequestVoteRequest {
+    term: Option<u64>,
+    last_log_index: Option<u64>,
+    last_log_term: Option<u64>,
+    candidate_name: Option<String>,
+    unknown_fields: Vec<TaggedValue>,
+  }
+
+  impl ProtoRequestVoteRequest {
+    pub const fn new() -> ProtoRequestVoteRequest {
+      ProtoRequestVoteRequest {
+        term: None,
+        last_log_index: None,
+        last_log_term: None,
+        candidate_name: None,
+        unknown_fields: Vec::new(),
+      }
+    }
+
+    pub fn default_instance() -> &'static ProtoRequestVoteRequest {
+      static INSTANCE: ProtoRequestVoteRequest = ProtoRequestVoteRequest::new();
+      &INSTANCE
+    }
+
+    pub fn has_term(&self) -> bool {
+      self.term.is_some()
+    }
+
+    pub fn get_term(&self) -> u64 {
+      self.term.unwrap_or(0)
+    }
+
+    pub fn set_term(&mut self, value: u64) {
+      self.term = Some(value);
+    }
+
+    pub fn clear_term(&mut self) {
+      self.term = None;
+    }
+
+    pub fn mut_term(&mut self) -> &mut u64 {
+      self.term.get_or_insert(0)
+    }
+
+    pub fn take_term(&mut self) -> u64 {
+      self.term.take().unwrap_or(0)
+    }
+
+    pub fn has_last_log_index(&self) -> bool {
+      self.last_log_index.is_some()
+    }
+
+    pub fn get_last_log_index(&self) -> u64 {
+      self.last_log_index.unwrap_or(0)
+    }
+
+    pub fn set_last_log_index(&mut self, value: u64) {
+      self.last_log_index = Some(value);
+    }
+
+    pub fn clear_last_log_index(&mut self) {
+      self.last_log_index = None;
+    }
+
+    pub fn mut_last_log_index(&mut self) -> &mut u64 {
+      self.last_log_index.get_or_insert(0)
+    }
+
+    pub fn take_last_log_index(&mut self) -> u64 {
+      self.last_log_index.take().unwrap_or(0)
+    }
+
+    pub fn has_last_log_term(&self) -> bool {
+      self.last_log_term.is_some()
+    }
+
+    pub fn get_last_log_term(&self) -> u64 {
+      self.last_log_term.unwrap_or(0)
+    }
+
+    pub fn set_last_log_term(&mut self, value: u64) {
+      self.last_log_term = Some(value);
+    }
+
+    pub fn clear_last_log_term(&mut self) {
+      self.last_log_term = None;
+    }
+
+    pub fn mut_last_log_term(&mut self) -> &mut u64 {
+      self.last_log_term.get_or_insert(0)
+    }
+
+    pub fn take_last_log_term(&mut self) -> u64 {
+      self.last_log_term.take().unwrap_or(0)
+    }
+
+    pub fn has_candidate_name(&self) -> bool {
+      self.candidate_name.is_some()
+    }
+
+    pub fn get_candidate_name(&self) -> &str {
+      self.candidate_name.as_deref().unwrap_or("")
+    }
+
+    pub fn set_candidate_name(&mut self, value: String) {
+      self.candidate_name = Some(value);
+    }
+
+    pub fn clear_candidate_name(&mut self) {
+      self.candidate_name = None;
+    }
+
+    pub fn mut_candidate_name(&mut self) -> &mut String {
+      self.candidate_name.get_or_insert_with(|| String::from(""))
+    }
+
+    pub fn take_candidate_name(&mut self) -> String {
+      self.candidate_name.take().unwrap_or_else(|| String::from(""))
+    }
+
+    // Fields this message's schema doesn't know, and enum numbers outside
+    // the enum, in the order they were decoded.
+    pub fn get_unknown_fields(&self) -> &[TaggedValue] {
+      &self.unknown_fields
+    }
+  }
+
+  impl Protobuf for ProtoRequestVoteRequest {
+    fn DecodePartial(&mut self, reader: &mut dyn Reader) -> bool {
+      let mut tags = TagIter::new(reader);
+      for tag_option in tags.by_ref() {
+        match tag_option {
+          TaggedValue::Varint(1, value) => {
+            self.set_term(value);
+          }
+          TaggedValue::Varint(2, value) => {
+            self.set_last_log_index(value);
+          }
+          TaggedValue::Varint(3, value) => {
+            self.set_last_log_term(value);
+          }
+          TaggedValue::Raw(4, value) => {
+            match String::from_utf8(value.into_vec()) {
+              Ok(value) => self.set_candidate_name(value),
+              Err(_) => return false
+            }
+          }
+          unknown => self.unknown_fields.push(unknown)
+        }
+      }
+      !tags.is_malformed()
+    }
+
+    fn AppendMissingFields(&self, prefix: &str, missing: &mut Vec<String>) {
+      if self.term.is_none() {
+        missing.push(format!("{}Term", prefix));
+      }
+      if self.last_log_index.is_none() {
+        missing.push(format!("{}LastLogIndex", prefix));
+      }
+      if self.last_log_term.is_none() {
+        missing.push(format!("{}LastLogTerm", prefix));
+      }
+      if self.candidate_name.is_none() {
+        missing.push(format!("{}CandidateName", prefix));
+      }
+    }
+  }
+
+  impl ProtobufEncode for ProtoRequestVoteRequest {
+    fn Encode(&self, writer: &mut dyn Writer) -> Result<(), EncodeError> {
+      for value in self.term.iter() {
+        EncodeVarintField(writer, 1, *value)?;
+      }
+      for value in self.last_log_index.iter() {
+        EncodeVarintField(writer, 2, *value)?;
+      }
+      for value in self.last_log_term.iter() {
+        EncodeVarintField(writer, 3, *value)?;
+      }
+      for value in self.candidate_name.iter() {
+        EncodeRawField(writer, 4, value.as_bytes())?;
+      }
+      for unknown in self.unknown_fields.iter() {
+        EncodeTaggedValue(writer, unknown)?;
+      }
+      Ok(())
+    }
+  }
+
+  impl Reflect for ProtoRequestVoteRequest {
+    fn message_info(&self) -> &'static MessageInfo {
+      static INFO: MessageInfo = MessageInfo {
+        full_name: "protobuf.ProtoRequestVoteRequest",
+        fields: &[
+          FieldInfo { name: "Term", number: 1, type_name: "uint64", repeated: false, required: true, message: None },
+          FieldInfo { name: "LastLogIndex", number: 2, type_name: "uint64", repeated: false, required: true, message: None },
+          FieldInfo { name: "LastLogTerm", number: 3, type_name: "uint64", repeated: false, required: true, message: None },
+          FieldInfo { name: "CandidateName", number: 4, type_name: "string", repeated: false, required: true, message: None },
+        ]
+      };
+      &INFO
+    }
+  }
+
+}
@@ -0,0 +1,148 @@
+// Generated by protoc-gen-rust from proto/request_vote_responses.proto. Do not edit.
+
+pub mod protobuf {
+  #[allow(unused_imports)]
+  use ::protobuf::{
+    Protobuf, Reader, TagIter, TaggedValue,
+    DecodeZigZag32, DecodeZigZag64, DecodeFloat, DecodeDouble,
+    DecodePackedVarints, DecodePackedFixed32, DecodePackedFixed64,
+    ProtobufEncode, Writer, EncodeError,
+    EncodeTaggedValue, EncodeMessageField, EncodeRawField,
+    EncodeVarintField, EncodeFixed32Field, EncodeFixed64Field,
+    EncodeZigZag32, EncodeZigZag64, EncodeFloat, EncodeDouble,
+    Reflect, MessageInfo, FieldInfo,
+  };
+  #[allow(unused_imports)]
+  use ::protobuf::prelude::*;
+
+  pub struct ProtoRequestVoteResponse {
+    term: Option<u64>,
+    vote_granted: Option<bool>,
+    unknown_fields: Vec<TaggedValue>,
+  }
+
+  impl ProtoRequestVoteResponse {
+    pub const fn new() -> ProtoRequestVoteResponse {
+      ProtoRequestVoteResponse {
+        term: None,
+        vote_granted: None,
+        unknown_fields: Vec::new(),
+      }
+    }
+
+    pub fn default_instance() -> &'static ProtoRequestVoteResponse {
+      static INSTANCE: ProtoRequestVoteResponse = ProtoRequestVoteResponse::new();
+      &INSTANCE
+    }
+
+    pub fn has_term(&self) -> bool {
+      self.term.is_some()
+    }
+
+    pub fn get_term(&self) -> u64 {
+      self.term.unwrap_or(0)
+    }
+
+    pub fn set_term(&mut self, value: u64) {
+      self.term = Some(value);
+    }
+
+    pub fn clear_term(&mut self) {
+      self.term = None;
+    }
+
+    pub fn mut_term(&mut self) -> &mut u64 {
+      self.term.get_or_insert(0)
+    }
+
+    pub fn take_term(&mut self) -> u64 {
+      self.term.take().unwrap_or(0)
+    }
+
+    pub fn has_vote_granted(&self) -> bool {
+      self.vote_granted.is_some()
+    }
+
+    pub fn get_vote_granted(&self) -> bool {
+      self.vote_granted.unwrap_or(false)
+    }
+
+    pub fn set_vote_granted(&mut self, value: bool) {
+      self.vote_granted = Some(value);
+    }
+
+    pub fn clear_vote_granted(&mut self) {
+      self.vote_granted = None;
+    }
+
+    pub fn mut_vote_granted(&mut self) -> &mut bool {
+      self.vote_granted.get_or_insert(false)
+    }
+
+    pub fn take_vote_granted(&mut self) -> bool {
+      self.vote_granted.take().unwrap_or(false)
+    }
+
+    // Fields this message's schema doesn't know, and enum numbers outside
+    // the enum, in the order they were decoded.
+    pub fn get_unknown_fields(&self) -> &[TaggedValue] {
+      &self.unknown_fields
+    }
+  }
+
+  impl Protobuf for ProtoRequestVoteResponse {
+    fn DecodePartial(&mut self, reader: &mut dyn Reader) -> bool {
+      let mut tags = TagIter::new(reader);
+      for tag_option in tags.by_ref() {
+        match tag_option {
+          TaggedValue::Varint(1, value) => {
+            self.set_term(value);
+          }
+          TaggedValue::Varint(2, value) => {
+            self.set_vote_granted(value != 0);
+          }
+          unknown => self.unknown_fields.push(unknown)
+        }
+      }
+      !tags.is_malformed()
+    }
+
+    fn AppendMissingFields(&self, prefix: &str, missing: &mut Vec<String>) {
+      if self.term.is_none() {
+        missing.push(format!("{}Term", prefix));
+      }
+      if self.vote_granted.is_none() {
+        missing.push(format!("{}VoteGranted", prefix));
+      }
+    }
+  }
+
+  impl ProtobufEncode for ProtoRequestVoteResponse {
+    fn Encode(&self, writer: &mut dyn Writer) -> Result<(), EncodeError> {
+      for value in self.term.iter() {
+        EncodeVarintField(writer, 1, *value)?;
+      }
+      for value in self.vote_granted.iter() {
+        EncodeVarintField(writer, 2, *value as u64)?;
+      }
+      for unknown in self.unknown_fields.iter() {
+        EncodeTaggedValue(writer, unknown)?;
+      }
+      Ok(())
+    }
+  }
+
+  impl Reflect for ProtoRequestVoteResponse {
+    fn message_info(&self) -> &'static MessageInfo {
+      static INFO: MessageInfo = MessageInfo {
+        full_name: "protobuf.ProtoRequestVoteResponse",
+        fields: &[
+          FieldInfo { name: "Term", number: 1, type_name: "uint64", repeated: false, required: true, message: None },
+          FieldInfo { name: "VoteGranted", number: 2, type_name: "bool", repeated: false, required: true, message: None },
+        ]
+      };
+      &INFO
+    }
+  }
+
+}
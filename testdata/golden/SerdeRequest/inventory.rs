@@ -0,0 +1,511 @@
+// Generated by protoc-gen-rust from inventory.proto. Do not edit.
+
+pub mod inventory {
+  #[allow(unused_imports)]
+  use ::protobuf::{
+    Protobuf, Reader, TagIter, TaggedValue,
+    DecodeZigZag32, DecodeZigZag64, DecodeFloat, DecodeDouble,
+    DecodePackedVarints, DecodePackedFixed32, DecodePackedFixed64,
+    ProtobufEncode, Writer, EncodeError,
+    EncodeTaggedValue, EncodeMessageField, EncodeRawField,
+    EncodeVarintField, EncodeFixed32Field, EncodeFixed64Field,
+    EncodeZigZag32, EncodeZigZag64, EncodeFloat, EncodeDouble,
+  };
+  #[allow(unused_imports)]
+  use ::protobuf::prelude::*;
+
+  #[derive(serde::Serialize, serde::Deserialize)]
+  pub struct Location {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    aisle: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    shelf: Option<u32>,
+    #[serde(skip)]
+    unknown_fields: Vec<TaggedValue>,
+  }
+
+  impl Location {
+    pub const fn new() -> Location {
+      Location {
+        aisle: None,
+        shelf: None,
+        unknown_fields: Vec::new(),
+      }
+    }
+
+    pub fn default_instance() -> &'static Location {
+      static INSTANCE: Location = Location::new();
+      &INSTANCE
+    }
+
+    pub fn has_aisle(&self) -> bool {
+      self.aisle.is_some()
+    }
+
+    pub fn get_aisle(&self) -> &str {
+      self.aisle.as_deref().unwrap_or("")
+    }
+
+    pub fn set_aisle(&mut self, value: String) {
+      self.aisle = Some(value);
+    }
+
+    pub fn clear_aisle(&mut self) {
+      self.aisle = None;
+    }
+
+    pub fn mut_aisle(&mut self) -> &mut String {
+      self.aisle.get_or_insert_with(|| String::from(""))
+    }
+
+    pub fn take_aisle(&mut self) -> String {
+      self.aisle.take().unwrap_or_else(|| String::from(""))
+    }
+
+    pub fn has_shelf(&self) -> bool {
+      self.shelf.is_some()
+    }
+
+    pub fn get_shelf(&self) -> u32 {
+      self.shelf.unwrap_or(0)
+    }
+
+    pub fn set_shelf(&mut self, value: u32) {
+      self.shelf = Some(value);
+    }
+
+    pub fn clear_shelf(&mut self) {
+      self.shelf = None;
+    }
+
+    pub fn mut_shelf(&mut self) -> &mut u32 {
+      self.shelf.get_or_insert(0)
+    }
+
+    pub fn take_shelf(&mut self) -> u32 {
+      self.shelf.take().unwrap_or(0)
+    }
+
+    // Fields this message's schema doesn't know, and enum numbers outside
+    // the enum, in the order they were decoded.
+    pub fn get_unknown_fields(&self) -> &[TaggedValue] {
+      &self.unknown_fields
+    }
+  }
+
+  impl Protobuf for Location {
+    fn DecodePartial(&mut self, reader: &mut dyn Reader) -> bool {
+      let mut tags = TagIter::new(reader);
+      for tag_option in tags.by_ref() {
+        match tag_option {
+          TaggedValue::Raw(1, value) => {
+            match String::from_utf8(value.into_vec()) {
+              Ok(value) => self.set_aisle(value),
+              Err(_) => return false
+            }
+          }
+          TaggedValue::Varint(2, value) => {
+            self.set_shelf(value as u32);
+          }
+          unknown => self.unknown_fields.push(unknown)
+        }
+      }
+      !tags.is_malformed()
+    }
+
+    fn AppendMissingFields(&self, _prefix: &str, _missing: &mut Vec<String>) {
+    }
+  }
+
+  impl ProtobufEncode for Location {
+    fn Encode(&self, writer: &mut dyn Writer) -> Result<(), EncodeError> {
+      for value in self.aisle.iter() {
+        EncodeRawField(writer, 1, value.as_bytes())?;
+      }
+      for value in self.shelf.iter() {
+        EncodeVarintField(writer, 2, *value as u64)?;
+      }
+      for unknown in self.unknown_fields.iter() {
+        EncodeTaggedValue(writer, unknown)?;
+      }
+      Ok(())
+    }
+  }
+
+  #[derive(serde::Serialize, serde::Deserialize)]
+  pub struct Item {
+    #[serde(rename = "Name")]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    name: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    condition: Option<Condition>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    tags: Vec<i64>,
+    #[serde(rename = "type")]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    type_: Option<Vec<u8>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    location: Option<Location>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    history: Vec<Condition>,
+    #[serde(skip)]
+    unknown_fields: Vec<TaggedValue>,
+  }
+
+  impl Item {
+    pub const fn new() -> Item {
+      Item {
+        name: None,
+        condition: None,
+        tags: Vec::new(),
+        type_: None,
+        location: None,
+        history: Vec::new(),
+        unknown_fields: Vec::new(),
+      }
+    }
+
+    pub fn default_instance() -> &'static Item {
+      static INSTANCE: Item = Item::new();
+      &INSTANCE
+    }
+
+    pub fn has_name(&self) -> bool {
+      self.name.is_some()
+    }
+
+    pub fn get_name(&self) -> &str {
+      self.name.as_deref().unwrap_or("")
+    }
+
+    pub fn set_name(&mut self, value: String) {
+      self.name = Some(value);
+    }
+
+    pub fn clear_name(&mut self) {
+      self.name = None;
+    }
+
+    pub fn mut_name(&mut self) -> &mut String {
+      self.name.get_or_insert_with(|| String::from(""))
+    }
+
+    pub fn take_name(&mut self) -> String {
+      self.name.take().unwrap_or_else(|| String::from(""))
+    }
+
+    pub fn has_condition(&self) -> bool {
+      self.condition.is_some()
+    }
+
+    pub fn get_condition(&self) -> Condition {
+      self.condition.unwrap_or(New)
+    }
+
+    pub fn set_condition(&mut self, value: Condition) {
+      self.condition = Some(value);
+    }
+
+    pub fn clear_condition(&mut self) {
+      self.condition = None;
+    }
+
+    pub fn mut_condition(&mut self) -> &mut Condition {
+      self.condition.get_or_insert(New)
+    }
+
+    pub fn take_condition(&mut self) -> Condition {
+      self.condition.take().unwrap_or(New)
+    }
+
+    pub fn get_tags(&self) -> &[i64] {
+      &self.tags
+    }
+
+    pub fn set_tags(&mut self, value: Vec<i64>) {
+      self.tags = value;
+    }
+
+    pub fn clear_tags(&mut self) {
+      self.tags.clear();
+    }
+
+    pub fn mut_tags(&mut self) -> &mut Vec<i64> {
+      &mut self.tags
+    }
+
+    pub fn take_tags(&mut self) -> Vec<i64> {
+      core::mem::take(&mut self.tags)
+    }
+
+    pub fn add_tags(&mut self, value: i64) {
+      self.tags.push(value);
+    }
+
+    pub fn tags_len(&self) -> usize {
+      self.tags.len()
+    }
+
+    pub fn has_type(&self) -> bool {
+      self.type_.is_some()
+    }
+
+    pub fn get_type(&self) -> &[u8] {
+      self.type_.as_deref().unwrap_or(b"")
+    }
+
+    pub fn set_type(&mut self, value: Vec<u8>) {
+      self.type_ = Some(value);
+    }
+
+    pub fn clear_type(&mut self) {
+      self.type_ = None;
+    }
+
+    pub fn mut_type(&mut self) -> &mut Vec<u8> {
+      self.type_.get_or_insert_with(|| b"".to_vec())
+    }
+
+    pub fn take_type(&mut self) -> Vec<u8> {
+      self.type_.take().unwrap_or_else(|| b"".to_vec())
+    }
+
+    pub fn has_location(&self) -> bool {
+      self.location.is_some()
+    }
+
+    pub fn get_location(&self) -> &Location {
+      match self.location { Some(ref value) => value, None => Location::default_instance() }
+    }
+
+    pub fn set_location(&mut self, value: Location) {
+      self.location = Some(value);
+    }
+
+    pub fn clear_location(&mut self) {
+      self.location = None;
+    }
+
+    pub fn mut_location(&mut self) -> &mut Location {
+      self.location.get_or_insert_with(Location::new)
+    }
+
+    pub fn take_location(&mut self) -> Location {
+      self.location.take().unwrap_or_else(Location::new)
+    }
+
+    pub fn get_history(&self) -> &[Condition] {
+      &self.history
+    }
+
+    pub fn set_history(&mut self, value: Vec<Condition>) {
+      self.history = value;
+    }
+
+    pub fn clear_history(&mut self) {
+      self.history.clear();
+    }
+
+    pub fn mut_history(&mut self) -> &mut Vec<Condition> {
+      &mut self.history
+    }
+
+    pub fn take_history(&mut self) -> Vec<Condition> {
+      core::mem::take(&mut self.history)
+    }
+
+    pub fn add_history(&mut self, value: Condition) {
+      self.history.push(value);
+    }
+
+    pub fn history_len(&self) -> usize {
+      self.history.len()
+    }
+
+    // Fields this message's schema doesn't know, and enum numbers outside
+    // the enum, in the order they were decoded.
+    pub fn get_unknown_fields(&self) -> &[TaggedValue] {
+      &self.unknown_fields
+    }
+  }
+
+  impl Protobuf for Item {
+    fn DecodePartial(&mut self, reader: &mut dyn Reader) -> bool {
+      let mut tags = TagIter::new(reader);
+      for tag_option in tags.by_ref() {
+        match tag_option {
+          TaggedValue::Raw(1, value) => {
+            match String::from_utf8(value.into_vec()) {
+              Ok(value) => self.set_name(value),
+              Err(_) => return false
+            }
+          }
+          TaggedValue::Varint(2, value) => {
+            match Condition::from_i32(value as i32) {
+              Some(value) => self.set_condition(value),
+              None => self.unknown_fields.push(TaggedValue::Varint(2, value))
+            }
+          }
+          TaggedValue::Varint(3, value) => {
+            self.add_tags(value as i64);
+          }
+          TaggedValue::Raw(3, packed) => {
+            match DecodePackedVarints(&packed) {
+              Some(values) => {
+                for value in values.iter() {
+                  self.add_tags(*value as i64);
+                }
+              }
+              None => return false
+            }
+          }
+          TaggedValue::Raw(4, value) => {
+            self.set_type(value.into_vec());
+          }
+          TaggedValue::Raw(5, value) => {
+            let mut reader: &[u8] = &value;
+            if !self.mut_location().DecodePartial(&mut reader) {
+              return false;
+            }
+          }
+          TaggedValue::Varint(6, value) => {
+            match Condition::from_i32(value as i32) {
+              Some(value) => self.add_history(value),
+              None => self.unknown_fields.push(TaggedValue::Varint(6, value))
+            }
+          }
+          TaggedValue::Raw(6, packed) => {
+            match DecodePackedVarints(&packed) {
+              Some(values) => {
+                for value in values.iter() {
+                  match Condition::from_i32(*value as i32) {
+                    Some(value) => self.add_history(value),
+                    None => self.unknown_fields.push(TaggedValue::Varint(6, *value))
+                  }
+                }
+              }
+              None => return false
+            }
+          }
+          unknown => self.unknown_fields.push(unknown)
+        }
+      }
+      !tags.is_malformed()
+    }
+
+    fn AppendMissingFields(&self, prefix: &str, missing: &mut Vec<String>) {
+      if self.name.is_none() {
+        missing.push(format!("{}Name", prefix));
+      }
+      match self.location {
+        Some(ref value) => {
+          for path in value.missing_fields() {
+            missing.push(format!("{}location.{}", prefix, path));
+          }
+        }
+        None => ()
+      }
+    }
+  }
+
+  impl ProtobufEncode for Item {
+    fn Encode(&self, writer: &mut dyn Writer) -> Result<(), EncodeError> {
+      for value in self.name.iter() {
+        EncodeRawField(writer, 1, value.as_bytes())?;
+      }
+      for value in self.condition.iter() {
+        EncodeVarintField(writer, 2, value.value() as i64 as u64)?;
+      }
+      for value in self.tags.iter() {
+        EncodeVarintField(writer, 3, *value as u64)?;
+      }
+      for value in self.type_.iter() {
+        EncodeRawField(writer, 4, value)?;
+      }
+      for value in self.location.iter() {
+        EncodeMessageField(writer, 5, value)?;
+      }
+      for value in self.history.iter() {
+        EncodeVarintField(writer, 6, value.value() as i64 as u64)?;
+      }
+      for unknown in self.unknown_fields.iter() {
+        EncodeTaggedValue(writer, unknown)?;
+      }
+      Ok(())
+    }
+  }
+
+  #[derive(Clone, Copy, PartialEq, Eq, Debug)]
+  pub enum Condition {
+    New = 0,
+    Used = 1,
+    Refurbished = 2,
+  }
+
+  pub use self::Condition::{New, Used, Refurbished};
+
+  impl Condition {
+    pub fn from_i32(value: i32) -> Option<Condition> {
+      match value {
+        0 => Some(New),
+        1 => Some(Used),
+        2 => Some(Refurbished),
+        _ => None
+      }
+    }
+
+    pub fn value(&self) -> i32 {
+      *self as i32
+    }
+
+    pub fn name(&self) -> &'static str {
+      match *self {
+        New => "NEW",
+        Used => "USED",
+        Refurbished => "REFURBISHED",
+      }
+    }
+
+    pub fn from_name(name: &str) -> Option<Condition> {
+      match name {
+        "NEW" => Some(New),
+        "USED" => Some(Used),
+        "REFURBISHED" => Some(Refurbished),
+        _ => None
+      }
+    }
+  }
+
+  impl Default for Condition {
+    fn default() -> Condition {
+      New
+    }
+  }
+
+  impl serde::Serialize for Condition {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+      serializer.serialize_str(self.name())
+    }
+  }
+
+  impl<'de> serde::Deserialize<'de> for Condition {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Condition, D::Error> {
+      struct NameVisitor;
+
+      impl serde::de::Visitor<'_> for NameVisitor {
+        type Value = Condition;
+
+        fn expecting(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+          f.write_str("a Condition name")
+        }
+
+        fn visit_str<E: serde::de::Error>(self, name: &str) -> Result<Condition, E> {
+          Condition::from_name(name).ok_or_else(|| E::invalid_value(serde::de::Unexpected::Str(name), &self))
+        }
+      }
+
+      deserializer.deserialize_str(NameVisitor)
+    }
+  }
+
+}
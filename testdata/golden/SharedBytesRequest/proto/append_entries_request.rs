@@ -0,0 +1,491 @@
+// Generated by protoc-gen-rust from proto/append_entries_request.proto. Do not edit.
+
+pub mod protobuf {
+  #[allow(unused_imports)]
+  use ::protobuf::{
+    Protobuf, Reader, TagIter, TaggedValue,
+    DecodeZigZag32, DecodeZigZag64, DecodeFloat, DecodeDouble,
+    DecodePackedVarints, DecodePackedFixed32, DecodePackedFixed64,
+    ProtobufEncode, Writer, EncodeError,
+    EncodeTaggedValue, EncodeMessageField, EncodeRawField,
+    EncodeVarintField, EncodeFixed32Field, EncodeFixed64Field,
+    EncodeZigZag32, EncodeZigZag64, EncodeFloat, EncodeDouble,
+  };
+  #[allow(unused_imports)]
+  use ::protobuf::prelude::*;
+
+  pub struct ProtoAppendEntriesRequest {
+    term: Option<u64>,
+    prev_log_index: Option<u64>,
+    prev_log_term: Option<u64>,
+    commit_index: Option<u64>,
+    leader_name: Option<::protobuf::BytesStr>,
+    entries: Vec<proto_append_entries_request::ProtoLogEntry>,
+    unknown_fields: Vec<TaggedValue>,
+  }
+
+  impl ProtoAppendEntriesRequest {
+    pub const fn new() -> ProtoAppendEntriesRequest {
+      ProtoAppendEntriesRequest {
+        term: None,
+        prev_log_index: None,
+        prev_log_term: None,
+        commit_index: None,
+        leader_name: None,
+        entries: Vec::new(),
+        unknown_fields: Vec::new(),
+      }
+    }
+
+    pub fn default_instance() -> &'static ProtoAppendEntriesRequest {
+      static INSTANCE: ProtoAppendEntriesRequest = ProtoAppendEntriesRequest::new();
+      &INSTANCE
+    }
+
+    pub fn has_term(&self) -> bool {
+      self.term.is_some()
+    }
+
+    pub fn get_term(&self) -> u64 {
+      self.term.unwrap_or(0)
+    }
+
+    pub fn set_term(&mut self, value: u64) {
+      self.term = Some(value);
+    }
+
+    pub fn clear_term(&mut self) {
+      self.term = None;
+    }
+
+    pub fn mut_term(&mut self) -> &mut u64 {
+      self.term.get_or_insert(0)
+    }
+
+    pub fn take_term(&mut self) -> u64 {
+      self.term.take().unwrap_or(0)
+    }
+
+    pub fn has_prev_log_index(&self) -> bool {
+      self.prev_log_index.is_some()
+    }
+
+    pub fn get_prev_log_index(&self) -> u64 {
+      self.prev_log_index.unwrap_or(0)
+    }
+
+    pub fn set_prev_log_index(&mut self, value: u64) {
+      self.prev_log_index = Some(value);
+    }
+
+    pub fn clear_prev_log_index(&mut self) {
+      self.prev_log_index = None;
+    }
+
+    pub fn mut_prev_log_index(&mut self) -> &mut u64 {
+      self.prev_log_index.get_or_insert(0)
+    }
+
+    pub fn take_prev_log_index(&mut self) -> u64 {
+      self.prev_log_index.take().unwrap_or(0)
+    }
+
+    pub fn has_prev_log_term(&self) -> bool {
+      self.prev_log_term.is_some()
+    }
+
+    pub fn get_prev_log_term(&self) -> u64 {
+      self.prev_log_term.unwrap_or(0)
+    }
+
+    pub fn set_prev_log_term(&mut self, value: u64) {
+      self.prev_log_term = Some(value);
+    }
+
+    pub fn clear_prev_log_term(&mut self) {
+      self.prev_log_term = None;
+    }
+
+    pub fn mut_prev_log_term(&mut self) -> &mut u64 {
+      self.prev_log_term.get_or_insert(0)
+    }
+
+    pub fn take_prev_log_term(&mut self) -> u64 {
+      self.prev_log_term.take().unwrap_or(0)
+    }
+
+    pub fn has_commit_index(&self) -> bool {
+      self.commit_index.is_some()
+    }
+
+    pub fn get_commit_index(&self) -> u64 {
+      self.commit_index.unwrap_or(0)
+    }
+
+    pub fn set_commit_index(&mut self, value: u64) {
+      self.commit_index = Some(value);
+    }
+
+    pub fn clear_commit_index(&mut self) {
+      self.commit_index = None;
+    }
+
+    pub fn mut_commit_index(&mut self) -> &mut u64 {
+      self.commit_index.get_or_insert(0)
+    }
+
+    pub fn take_commit_index(&mut self) -> u64 {
+      self.commit_index.take().unwrap_or(0)
+    }
+
+    pub fn has_leader_name(&self) -> bool {
+      self.leader_name.is_some()
+    }
+
+    pub fn get_leader_name(&self) -> &str {
+      self.leader_name.as_deref().unwrap_or("")
+    }
+
+    pub fn set_leader_name(&mut self, value: ::protobuf::BytesStr) {
+      self.leader_name = Some(value);
+    }
+
+    pub fn clear_leader_name(&mut self) {
+      self.leader_name = None;
+    }
+
+    pub fn mut_leader_name(&mut self) -> &mut ::protobuf::BytesStr {
+      self.leader_name.get_or_insert_with(|| ::protobuf::BytesStr::from(""))
+    }
+
+    pub fn take_leader_name(&mut self) -> ::protobuf::BytesStr {
+      self.leader_name.take().unwrap_or_else(|| ::protobuf::BytesStr::from(""))
+    }
+
+    pub fn get_entries(&self) -> &[proto_append_entries_request::ProtoLogEntry] {
+      &self.entries
+    }
+
+    pub fn set_entries(&mut self, value: Vec<proto_append_entries_request::ProtoLogEntry>) {
+      self.entries = value;
+    }
+
+    pub fn clear_entries(&mut self) {
+      self.entries.clear();
+    }
+
+    pub fn mut_entries(&mut self) -> &mut Vec<proto_append_entries_request::ProtoLogEntry> {
+      &mut self.entries
+    }
+
+    pub fn take_entries(&mut self) -> Vec<proto_append_entries_request::ProtoLogEntry> {
+      core::mem::take(&mut self.entries)
+    }
+
+    pub fn add_entries(&mut self, value: proto_append_entries_request::ProtoLogEntry) {
+      self.entries.push(value);
+    }
+
+    pub fn entries_len(&self) -> usize {
+      self.entries.len()
+    }
+
+    // Fields this message's schema doesn't know, and enum numbers outside
+    // the enum, in the order they were decoded.
+    pub fn get_unknown_fields(&self) -> &[TaggedValue] {
+      &self.unknown_fields
+    }
+  }
+
+  pub mod proto_append_entries_request {
+    #[allow(unused_imports)]
+    use ::protobuf::{
+      Protobuf, Reader, TagIter, TaggedValue,
+      DecodeZigZag32, DecodeZigZag64, DecodeFloat, DecodeDouble,
+      DecodePackedVarints, DecodePackedFixed32, DecodePackedFixed64,
+      ProtobufEncode, Writer, EncodeError,
+      EncodeTaggedValue, EncodeMessageField, EncodeRawField,
+      EncodeVarintField, EncodeFixed32Field, EncodeFixed64Field,
+      EncodeZigZag32, EncodeZigZag64, EncodeFloat, EncodeDouble,
+    };
+    #[allow(unused_imports)]
+    use ::protobuf::prelude::*;
+
+    pub struct ProtoLogEntry {
+      index: Option<u64>,
+      term: Option<u64>,
+      command_name: Option<::protobuf::BytesStr>,
+      command: Option<::protobuf::Bytes>,
+      unknown_fields: Vec<TaggedValue>,
+    }
+
+    impl ProtoLogEntry {
+      pub const fn new() -> ProtoLogEntry {
+        ProtoLogEntry {
+          index: None,
+          term: None,
+          command_name: None,
+          command: None,
+          unknown_fields: Vec::new(),
+        }
+      }
+
+      pub fn default_instance() -> &'static ProtoLogEntry {
+        static INSTANCE: ProtoLogEntry = ProtoLogEntry::new();
+        &INSTANCE
+      }
+
+      pub fn has_index(&self) -> bool {
+        self.index.is_some()
+      }
+
+      pub fn get_index(&self) -> u64 {
+        self.index.unwrap_or(0)
+      }
+
+      pub fn set_index(&mut self, value: u64) {
+        self.index = Some(value);
+      }
+
+      pub fn clear_index(&mut self) {
+        self.index = None;
+      }
+
+      pub fn mut_index(&mut self) -> &mut u64 {
+        self.index.get_or_insert(0)
+      }
+
+      pub fn take_index(&mut self) -> u64 {
+        self.index.take().unwrap_or(0)
+      }
+
+      pub fn has_term(&self) -> bool {
+        self.term.is_some()
+      }
+
+      pub fn get_term(&self) -> u64 {
+        self.term.unwrap_or(0)
+      }
+
+      pub fn set_term(&mut self, value: u64) {
+        self.term = Some(value);
+      }
+
+      pub fn clear_term(&mut self) {
+        self.term = None;
+      }
+
+      pub fn mut_term(&mut self) -> &mut u64 {
+        self.term.get_or_insert(0)
+      }
+
+      pub fn take_term(&mut self) -> u64 {
+        self.term.take().unwrap_or(0)
+      }
+
+      pub fn has_command_name(&self) -> bool {
+        self.command_name.is_some()
+      }
+
+      pub fn get_command_name(&self) -> &str {
+        self.command_name.as_deref().unwrap_or("")
+      }
+
+      pub fn set_command_name(&mut self, value: ::protobuf::BytesStr) {
+        self.command_name = Some(value);
+      }
+
+      pub fn clear_command_name(&mut self) {
+        self.command_name = None;
+      }
+
+      pub fn mut_command_name(&mut self) -> &mut ::protobuf::BytesStr {
+        self.command_name.get_or_insert_with(|| ::protobuf::BytesStr::from(""))
+      }
+
+      pub fn take_command_name(&mut self) -> ::protobuf::BytesStr {
+        self.command_name.take().unwrap_or_else(|| ::protobuf::BytesStr::from(""))
+      }
+
+      pub fn has_command(&self) -> bool {
+        self.command.is_some()
+      }
+
+      pub fn get_command(&self) -> &[u8] {
+        self.command.as_deref().unwrap_or(b"")
+      }
+
+      pub fn set_command(&mut self, value: ::protobuf::Bytes) {
+        self.command = Some(value);
+      }
+
+      pub fn clear_command(&mut self) {
+        self.command = None;
+      }
+
+      pub fn mut_command(&mut self) -> &mut ::protobuf::Bytes {
+        self.command.get_or_insert_with(|| ::protobuf::Bytes::from(b"".to_vec()))
+      }
+
+      pub fn take_command(&mut self) -> ::protobuf::Bytes {
+        self.command.take().unwrap_or_else(|| ::protobuf::Bytes::from(b"".to_vec()))
+      }
+
+      // Fields this message's schema doesn't know, and enum numbers outside
+      // the enum, in the order they were decoded.
+      pub fn get_unknown_fields(&self) -> &[TaggedValue] {
+        &self.unknown_fields
+      }
+    }
+
+    impl Protobuf for ProtoLogEntry {
+      fn DecodePartial(&mut self, reader: &mut dyn Reader) -> bool {
+        let mut tags = TagIter::new(reader);
+        for tag_option in tags.by_ref() {
+          match tag_option {
+            TaggedValue::Varint(1, value) => {
+              self.set_index(value);
+            }
+            TaggedValue::Varint(2, value) => {
+              self.set_term(value);
+            }
+            TaggedValue::Raw(3, value) => {
+              match ::protobuf::BytesStr::from_utf8(value) {
+                Ok(value) => self.set_command_name(value),
+                Err(_) => return false
+              }
+            }
+            TaggedValue::Raw(4, value) => {
+              self.set_command(value);
+            }
+            unknown => self.unknown_fields.push(unknown)
+          }
+        }
+        !tags.is_malformed()
+      }
+
+      fn AppendMissingFields(&self, prefix: &str, missing: &mut Vec<String>) {
+        if self.index.is_none() {
+          missing.push(format!("{}Index", prefix));
+        }
+        if self.term.is_none() {
+          missing.push(format!("{}Term", prefix));
+        }
+        if self.command_name.is_none() {
+          missing.push(format!("{}CommandName", prefix));
+        }
+      }
+    }
+
+    impl ProtobufEncode for ProtoLogEntry {
+      fn Encode(&self, writer: &mut dyn Writer) -> Result<(), EncodeError> {
+        for value in self.index.iter() {
+          EncodeVarintField(writer, 1, *value)?;
+        }
+        for value in self.term.iter() {
+          EncodeVarintField(writer, 2, *value)?;
+        }
+        for value in self.command_name.iter() {
+          EncodeRawField(writer, 3, value.as_bytes())?;
+        }
+        for value in self.command.iter() {
+          EncodeRawField(writer, 4, value)?;
+        }
+        for unknown in self.unknown_fields.iter() {
+          EncodeTaggedValue(writer, unknown)?;
+        }
+        Ok(())
+      }
+    }
+
+  }
+
+  impl Protobuf for ProtoAppendEntriesRequest {
+    fn DecodePartial(&mut self, reader: &mut dyn Reader) -> bool {
+      let mut tags = TagIter::new(reader);
+      for tag_option in tags.by_ref() {
+        match tag_option {
+          TaggedValue::Varint(1, value) => {
+            self.set_term(value);
+          }
+          TaggedValue::Varint(2, value) => {
+            self.set_prev_log_index(value);
+          }
+          TaggedValue::Varint(3, value) => {
+            self.set_prev_log_term(value);
+          }
+          TaggedValue::Varint(4, value) => {
+            self.set_commit_index(value);
+          }
+          TaggedValue::Raw(5, value) => {
+            match ::protobuf::BytesStr::from_utf8(value) {
+              Ok(value) => self.set_leader_name(value),
+              Err(_) => return false
+            }
+          }
+          TaggedValue::Raw(6, value) => {
+            let mut reader = value;
+            let mut message = proto_append_entries_request::ProtoLogEntry::new();
+            if !message.DecodePartial(&mut reader) {
+              return false;
+            }
+            self.add_entries(message);
+          }
+          unknown => self.unknown_fields.push(unknown)
+        }
+      }
+      !tags.is_malformed()
+    }
+
+    fn AppendMissingFields(&self, prefix: &str, missing: &mut Vec<String>) {
+      if self.term.is_none() {
+        missing.push(format!("{}Term", prefix));
+      }
+      if self.prev_log_index.is_none() {
+        missing.push(format!("{}PrevLogIndex", prefix));
+      }
+      if self.prev_log_term.is_none() {
+        missing.push(format!("{}PrevLogTerm", prefix));
+      }
+      if self.commit_index.is_none() {
+        missing.push(format!("{}CommitIndex", prefix));
+      }
+      if self.leader_name.is_none() {
+        missing.push(format!("{}LeaderName", prefix));
+      }
+      for (i, value) in self.entries.iter().enumerate() {
+        for path in value.missing_fields() {
+          missing.push(format!("{}Entries[{}].{}", prefix, i, path));
+        }
+      }
+    }
+  }
+
+  impl ProtobufEncode for ProtoAppendEntriesRequest {
+    fn Encode(&self, writer: &mut dyn Writer) -> Result<(), EncodeError> {
+      for value in self.term.iter() {
+        EncodeVarintField(writer, 1, *value)?;
+      }
+      for value in self.prev_log_index.iter() {
+        EncodeVarintField(writer, 2, *value)?;
+      }
+      for value in self.prev_log_term.iter() {
+        EncodeVarintField(writer, 3, *value)?;
+      }
+      for value in self.commit_index.iter() {
+        EncodeVarintField(writer, 4, *value)?;
+      }
+      for value in self.leader_name.iter() {
+        EncodeRawField(writer, 5, value.as_bytes())?;
+      }
+      for value in self.entries.iter() {
+        EncodeMessageField(writer, 6, value)?;
+      }
+      for unknown in self.unknown_fields.iter() {
+        EncodeTaggedValue(writer, unknown)?;
+      }
+      Ok(())
+    }
+  }
+
+}